@@ -10,6 +10,8 @@ use crate::{
     algebra::abstr::{AbsDiffEq, RelativeEq},
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{
     cmp::Ordering,
     fmt,
@@ -18,6 +20,7 @@ use std::{
 };
 
 /// Complex number in cartesian form
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy)]
 pub struct Complex<T> {
     /// Real portion of the complex number
@@ -0,0 +1,41 @@
+use crate::algebra::abstr::{Complex, Real};
+
+/// Complex conjugation
+///
+/// For real numbers the conjugate is the number itself.
+pub trait Conjugate {
+    /// Returns the complex conjugate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::{Complex, Conjugate};
+    ///
+    /// assert_eq!(Conjugate::conj(Complex::new(1.0, 2.0)), Complex::new(1.0, -2.0));
+    /// assert_eq!(Conjugate::conj(3.0), 3.0);
+    /// ```
+    fn conj(self) -> Self;
+}
+
+macro_rules! impl_conjugate {
+    ($($t:ty),*) => {
+        $(
+        impl Conjugate for $t {
+            fn conj(self) -> Self {
+                self
+            }
+        }
+        )*
+    };
+}
+
+impl_conjugate!(f32, f64);
+
+impl<T> Conjugate for Complex<T>
+where
+    T: Real,
+{
+    fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+}
@@ -9,6 +9,7 @@ pub use self::{
     abeliangroup::{AbelianGroup, AbelianGroupAdd, AbelianGroupMul},
     abs_diff_eq::{AbsDiff, AbsDiffEq},
     complex::Complex,
+    conjugate::Conjugate,
     field::Field,
     group::{Group, GroupAdd, GroupMul},
     identity::Identity,
@@ -55,3 +56,4 @@ mod polynomial;
 //pub mod natural;
 mod real;
 mod complex;
+mod conjugate;
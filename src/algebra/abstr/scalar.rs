@@ -1,4 +1,4 @@
-use crate::algebra::abstr::Complex;
+use crate::algebra::abstr::{Complex, Conjugate};
 use crate::algebra::abstr::{
     cast::{FromPrimitive, NumCast, ToPrimitive},
     Sign,
@@ -20,6 +20,7 @@ pub trait Scalar<Rhs = Self, Output = Self>:
     + Copy
     + PartialOrd
     + Sign
+    + Conjugate
     + MatrixMultiply
 {
 }
@@ -56,6 +57,7 @@ pub trait Scalar<Rhs = Self, Output = Self>:
     + Copy
     + PartialOrd
     + Sign
+    + Conjugate
     + Blas
     + Lapack
 {
@@ -118,6 +120,9 @@ pub trait Lapack: Sized + Zero {
         info: &mut i32,
     ) -> i32;
 
+    /// For real types w holds the real parts and wi the imaginary parts of the
+    /// eigenvalues, complex types return the eigenvalues in w and leave wi
+    /// untouched
     fn xgeev(
         jobvl: u8,
         jobvr: u8,
@@ -125,6 +130,7 @@ pub trait Lapack: Sized + Zero {
         a: &mut [Self],
         lda: i32,
         w: &mut [Self],
+        wi: &mut [Self],
         vl: &mut [Self],
         ldvl: i32,
         vr: &mut [Self],
@@ -141,6 +147,7 @@ pub trait Lapack: Sized + Zero {
         a: &mut [Self],
         lda: i32,
         w: &mut [Self],
+        wi: &mut [Self],
         vl: &mut [Self],
         ldvl: i32,
         vr: &mut [Self],
@@ -148,6 +155,93 @@ pub trait Lapack: Sized + Zero {
         info: &mut i32,
     ) -> i32;
 
+    fn xsyevd(
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        w: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+        liwork: i32,
+        info: &mut i32,
+    );
+
+    fn xsyevd_work_size(
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        w: &mut [Self],
+        info: &mut i32,
+    ) -> (i32, i32);
+
+    fn xgesdd(
+        jobz: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [Self],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+        info: &mut i32,
+    );
+
+    fn xgesdd_work_size(
+        jobz: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [Self],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        iwork: &mut [i32],
+        info: &mut i32,
+    ) -> i32;
+
+    fn xgelsd(
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        s: &mut [Self],
+        rcond: Self,
+        rank: &mut i32,
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+        info: &mut i32,
+    );
+
+    fn xgelsd_work_size(
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        s: &mut [Self],
+        rcond: Self,
+        rank: &mut i32,
+        info: &mut i32,
+    ) -> (i32, i32);
+
     fn xgetrf(m: i32, n: i32, a: &mut [Self], lda: i32, ipiv: &mut [i32], info: &mut i32);
 
     fn xgeqrf(
@@ -170,6 +264,28 @@ pub trait Lapack: Sized + Zero {
         info: &mut i32,
     ) -> i32;
 
+    fn xgeqp3(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        jpvt: &mut [i32],
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        info: &mut i32,
+    );
+
+    fn xgeqp3_work_size(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        jpvt: &mut [i32],
+        tau: &mut [Self],
+        info: &mut i32,
+    ) -> i32;
+
     fn xorgqr(
         m: i32,
         n: i32,
@@ -216,6 +332,72 @@ pub trait Lapack: Sized + Zero {
         ldb: i32,
         info: &mut i32,
     );
+
+    fn xggev(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        alphar: &mut [Self],
+        alphai: &mut [Self],
+        beta: &mut [Self],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        work: &mut [Self],
+        lwork: i32,
+        info: &mut i32,
+    );
+
+    fn xggev_work_size(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        alphar: &mut [Self],
+        alphai: &mut [Self],
+        beta: &mut [Self],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        info: &mut i32,
+    ) -> i32;
+
+    fn xsygv(
+        itype: i32,
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        w: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        info: &mut i32,
+    );
+
+    fn xsygv_work_size(
+        itype: i32,
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        w: &mut [Self],
+        info: &mut i32,
+    ) -> i32;
 }
 
 #[cfg(feature = "lapack")]
@@ -0,0 +1,155 @@
+use crate::algebra::{
+    abstr::{Complex, Real, Scalar},
+    linear::{
+        equations,
+        matrix::{ConjugateTranspose, General, SchurDecomposition, Transpose},
+        LinalgError,
+    },
+};
+
+/// Solves the continuous Lyapunov equation
+///
+/// ```math
+/// AX + XA^T + Q = 0
+/// ```
+/// as the Sylvester equation $AX + XA^T = -Q$. If $A$ is stable, i.e. all
+/// eigenvalues have a negative real part, and $Q$ is symmetric positive
+/// semidefinite, the solution $X$ is symmetric positive semidefinite. A
+/// symmetric solution is returned for a symmetric $Q$.
+///
+/// # Errors
+///
+/// Returns [`LinalgError::Singular`] if $\lambda_i + \lambda_j = 0$ for two
+/// eigenvalues of $A$.
+///
+/// # Panics
+///
+/// if A and Q are not square matrices of the same dimension
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{equations, matrix::General};
+/// use mathru::{assert_relative_eq, matrix};
+///
+/// let a: General<f64> = matrix![  -2.0, 0.0;
+///                                 0.0, -1.0];
+/// let q: General<f64> = matrix![  4.0, 0.0;
+///                                 0.0, 2.0];
+///
+/// let x: General<f64> = equations::solve_continuous_lyapunov(&a, &q).unwrap();
+///
+/// assert_relative_eq!(x, General::one(2), epsilon = 1.0e-10);
+/// ```
+pub fn solve_continuous_lyapunov<T>(
+    a: &General<T>,
+    q: &General<T>,
+) -> Result<General<T>, LinalgError>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    let n: usize = a.nrows();
+    assert_eq!(a.dim(), (n, n), "A must be a square matrix");
+    assert_eq!(q.dim(), (n, n), "The dimensions of A and Q must match");
+
+    let x: General<T> = equations::solve_sylvester(a, &a.clone().transpose(), &(q * &-T::one()))?;
+    if q == &q.clone().transpose() {
+        Ok(equations::symmetrize(x))
+    } else {
+        Ok(x)
+    }
+}
+
+/// Solves the discrete Lyapunov (Stein) equation
+///
+/// ```math
+/// AXA^T - X + Q = 0
+/// ```
+/// With the complex Schur decomposition $A = QUQ^*$ the equation is reduced
+/// to $UYU^* - Y + Q^*QQ = 0$, which is solved column by column starting
+/// with the last one. If all eigenvalues of $A$ lie inside the unit circle
+/// and $Q$ is symmetric positive semidefinite, the solution $X$ is
+/// symmetric positive semidefinite. A symmetric solution is returned for a
+/// symmetric $Q$.
+///
+/// # Errors
+///
+/// Returns [`LinalgError::Singular`] if $\lambda_i \lambda_j = 1$ for two
+/// eigenvalues of $A$.
+///
+/// # Panics
+///
+/// if A and Q are not square matrices of the same dimension
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{equations, matrix::{General, Transpose}};
+/// use mathru::{assert_relative_eq, matrix};
+///
+/// let a: General<f64> = matrix![  0.5, 1.0;
+///                                 0.0, -0.2];
+/// let q: General<f64> = matrix![  1.0, 0.0;
+///                                 0.0, 1.0];
+///
+/// let x: General<f64> = equations::solve_discrete_lyapunov(&a, &q).unwrap();
+///
+/// assert_relative_eq!(&(&(&a * &x) * &a.clone().transpose()) - &x, &q * &-1.0, epsilon = 1.0e-10);
+/// ```
+pub fn solve_discrete_lyapunov<T>(a: &General<T>, q: &General<T>) -> Result<General<T>, LinalgError>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    let n: usize = a.nrows();
+    assert_eq!(a.dim(), (n, n), "A must be a square matrix");
+    assert_eq!(q.dim(), (n, n), "The dimensions of A and Q must match");
+
+    let (q_a, u) = General::from(a).dec_schur()?.qu();
+    let u: General<Complex<T>> = u.into();
+    let mut y: General<Complex<T>> = &(&q_a.clone().conj_transpose() * &General::from(q)) * &q_a;
+
+    let threshold: T = T::default_epsilon()
+        * u.iter()
+            .map(|u_ij| equations::modulus(*u_ij))
+            .fold(T::one(), |acc, a| acc.max(a * a));
+
+    for j in (0..n).rev() {
+        // (conj(u_jj) U - I) y_j = -f_j - U w with w = sum_{l > j} conj(u_jl) y_l
+        let mut w: Vec<Complex<T>> = vec![Complex::new(T::zero(), T::zero()); n];
+        for l in (j + 1)..n {
+            let u_jl: Complex<T> = u[[j, l]].conj();
+            for (i, w_i) in w.iter_mut().enumerate() {
+                *w_i += u_jl * y[[i, l]];
+            }
+        }
+        for i in 0..n {
+            let mut u_w: Complex<T> = Complex::new(T::zero(), T::zero());
+            for (l, w_l) in w.iter().enumerate().skip(i) {
+                u_w += u[[i, l]] * *w_l;
+            }
+            y[[i, j]] = -y[[i, j]] - u_w;
+        }
+
+        let u_jj: Complex<T> = u[[j, j]].conj();
+        for i in (0..n).rev() {
+            let mut s: Complex<T> = y[[i, j]];
+            for l in (i + 1)..n {
+                s -= u_jj * u[[i, l]] * y[[l, j]];
+            }
+            let d: Complex<T> = u_jj * u[[i, i]] - Complex::new(T::one(), T::zero());
+            if equations::modulus(d) <= threshold {
+                return Err(LinalgError::Singular);
+            }
+            y[[i, j]] = s / d;
+        }
+    }
+
+    let x: General<T> = equations::real_part(&(&(&q_a * &y) * &q_a.conj_transpose()));
+    if q == &q.clone().transpose() {
+        Ok(equations::symmetrize(x))
+    } else {
+        Ok(x)
+    }
+}
@@ -0,0 +1,80 @@
+//! Solvers for matrix equations
+//!
+//! The linear matrix equations are solved with the Bartels-Stewart
+//! algorithm: the coefficient matrices are reduced to the complex Schur form
+//! $A = QUQ^*$ and the resulting triangular equations are solved by
+//! substitution.
+//!
+//! - [`solve_sylvester`]: $AX + XB = C$
+//! - [`solve_continuous_lyapunov`]: $AX + XA^T + Q = 0$
+//! - [`solve_discrete_lyapunov`]: $AXA^T - X + Q = 0$
+//!
+//! The algebraic Riccati equations are solved with the Schur method: the
+//! solution is computed from an orthonormal basis of the stable invariant
+//! subspace of the Hamiltonian matrix or the stable deflating subspace of
+//! the symplectic pencil, which is obtained by reordering the Schur or the
+//! generalized Schur decomposition.
+//!
+//! - [`solve_continuous_are`]: $A^TX + XA - XBR^{-1}B^TX + Q = 0$
+//! - [`solve_discrete_are`]: $A^TXA - X - A^TXB(R + B^TXB)^{-1}B^TXA + Q =
+//!   0$
+//!
+//! # Example
+//!
+//! ```
+//! use mathru::algebra::linear::{equations, matrix::{General, Transpose}};
+//! use mathru::{assert_relative_eq, matrix};
+//!
+//! let a: General<f64> = matrix![  -1.0, 2.0;
+//!                                 0.0, -3.0];
+//! let q: General<f64> = matrix![  1.0, 0.0;
+//!                                 0.0, 1.0];
+//!
+//! let x: General<f64> = equations::solve_continuous_lyapunov(&a, &q).unwrap();
+//!
+//! assert_relative_eq!(&(&a * &x) + &(&x * &a.clone().transpose()), &q * &-1.0, epsilon = 1.0e-10);
+//! ```
+
+mod lyapunov;
+mod riccati;
+mod sylvester;
+
+pub use lyapunov::{solve_continuous_lyapunov, solve_discrete_lyapunov};
+pub use riccati::{solve_continuous_are, solve_discrete_are};
+pub use sylvester::solve_sylvester;
+
+use crate::algebra::{
+    abstr::{Complex, Real},
+    linear::matrix::General,
+};
+
+fn real_part<T>(a: &General<Complex<T>>) -> General<T>
+where
+    T: Real,
+{
+    let (m, n): (usize, usize) = a.dim();
+    General::new(m, n, a.iter().map(|a_ij| a_ij.re).collect())
+}
+
+/// Returns $(X + X^T) / 2$
+fn symmetrize<T>(mut x: General<T>) -> General<T>
+where
+    T: Real,
+{
+    let n: usize = x.nrows();
+    for j in 0..n {
+        for i in 0..j {
+            let x_ij: T = (x[[i, j]] + x[[j, i]]) / T::from_f64(2.0);
+            x[[i, j]] = x_ij;
+            x[[j, i]] = x_ij;
+        }
+    }
+    x
+}
+
+fn modulus<T>(z: Complex<T>) -> T
+where
+    T: Real,
+{
+    (z.re * z.re + z.im * z.im).sqrt()
+}
@@ -0,0 +1,241 @@
+use crate::algebra::{
+    abstr::{Complex, Real, Scalar},
+    linear::{
+        equations,
+        matrix::{
+            General, GeneralizedSchurDec, GeneralizedSchurDecomposition, SchurDec,
+            SchurDecomposition, Solve, Transpose,
+        },
+        LinalgError,
+    },
+};
+
+/// Solves the continuous algebraic Riccati equation
+///
+/// ```math
+/// A^TX + XA - XBR^{-1}B^TX + Q = 0
+/// ```
+/// with $A, Q \in T^{n \times n}$, $B \in T^{n \times m}$ and $R \in T^{m
+/// \times m}$ using the Schur method (Laub). The stable invariant subspace
+/// of the Hamiltonian matrix
+///
+/// ```math
+/// H = \begin{pmatrix} A & -BR^{-1}B^T \\ -Q & -A^T \end{pmatrix}
+/// ```
+/// is spanned by $\begin{pmatrix} U_1 \\ U_2 \end{pmatrix}$, which is
+/// obtained by reordering the Schur decomposition of $H$. The stabilizing
+/// solution is $X = U_2U_1^{-1}$, i.e. all eigenvalues of the closed loop
+/// $A - BR^{-1}B^TX$ have a negative real part. $Q$ and $R$ are expected to
+/// be symmetric, the returned solution is symmetric.
+///
+/// # Errors
+///
+/// Returns [`LinalgError::Singular`] if R is singular and
+/// [`LinalgError::NoStabilizingSolution`] if the equation does not have a
+/// stabilizing solution, e.g. if $H$ has eigenvalues on the imaginary axis
+/// or $(A, B)$ is not stabilizable, which is detected by $U_1$ being
+/// numerically singular.
+///
+/// # Panics
+///
+/// if the dimensions of A, B, Q and R do not match
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{equations, matrix::General};
+/// use mathru::{assert_relative_eq, matrix};
+///
+/// // double integrator
+/// let a: General<f64> = matrix![  0.0, 1.0;
+///                                 0.0, 0.0];
+/// let b: General<f64> = matrix![  0.0;
+///                                 1.0];
+/// let q: General<f64> = General::one(2);
+/// let r: General<f64> = matrix![1.0];
+///
+/// let x: General<f64> = equations::solve_continuous_are(&a, &b, &q, &r).unwrap();
+///
+/// let s: f64 = 3.0f64.sqrt();
+/// assert_relative_eq!(x, matrix![s, 1.0; 1.0, s], epsilon = 1.0e-10);
+/// ```
+pub fn solve_continuous_are<T>(
+    a: &General<T>,
+    b: &General<T>,
+    q: &General<T>,
+    r: &General<T>,
+) -> Result<General<T>, LinalgError>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    let n: usize = check_dimensions(a, b, q, r);
+    let g: General<T> = &b.clone() * &r.solve(&b.clone().transpose())?;
+
+    let mut h: General<T> = General::zero(2 * n, 2 * n);
+    h = h.set_slice(a, 0, 0);
+    h = h.set_slice(&(&g * &-T::one()), 0, n);
+    h = h.set_slice(&(q * &-T::one()), n, 0);
+    h = h.set_slice(&(&a.clone().transpose() * &-T::one()), n, n);
+
+    stable_solution(&h, n, |lambda| lambda.re < T::zero())
+}
+
+/// Solves the discrete algebraic Riccati equation
+///
+/// ```math
+/// A^TXA - X - A^TXB(R + B^TXB)^{-1}B^TXA + Q = 0
+/// ```
+/// with $A, Q \in T^{n \times n}$, $B \in T^{n \times m}$ and $R \in T^{m
+/// \times m}$ using the generalized Schur method (Pappas, Laub, Sandell).
+/// With $G = BR^{-1}B^T$ the stable deflating subspace of the symplectic
+/// pencil
+///
+/// ```math
+/// L - \lambda M = \begin{pmatrix} A & 0 \\ -Q & I \end{pmatrix} - \lambda
+/// \begin{pmatrix} I & G \\ 0 & A^T \end{pmatrix}
+/// ```
+/// is spanned by $\begin{pmatrix} U_1 \\ U_2 \end{pmatrix}$, which is
+/// obtained by reordering the generalized Schur decomposition of $(L, M)$.
+/// A is not inverted, hence a singular A is admissible. The stabilizing
+/// solution is $X = U_2U_1^{-1}$, i.e. all eigenvalues of the closed loop
+/// $A - B(R + B^TXB)^{-1}B^TXA$ lie inside the unit circle. $Q$ and $R$ are
+/// expected to be symmetric, the returned solution is symmetric.
+///
+/// # Errors
+///
+/// Returns [`LinalgError::Singular`] if R is singular and
+/// [`LinalgError::NoStabilizingSolution`] if the equation does not have a
+/// stabilizing solution, e.g. if $(L, M)$ has eigenvalues on the unit circle
+/// or $(A, B)$ is not stabilizable, which is detected by $U_1$ being
+/// numerically singular.
+///
+/// # Panics
+///
+/// if the dimensions of A, B, Q and R do not match
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{equations, matrix::General};
+/// use mathru::{assert_relative_eq, matrix};
+///
+/// let a: General<f64> = matrix![1.0];
+/// let b: General<f64> = matrix![1.0];
+/// let q: General<f64> = matrix![1.0];
+/// let r: General<f64> = matrix![1.0];
+///
+/// let x: General<f64> = equations::solve_discrete_are(&a, &b, &q, &r).unwrap();
+///
+/// // x^2 - x - 1 = 0
+/// assert_relative_eq!(x[[0, 0]], (1.0 + 5.0f64.sqrt()) / 2.0, epsilon = 1.0e-10);
+/// ```
+pub fn solve_discrete_are<T>(
+    a: &General<T>,
+    b: &General<T>,
+    q: &General<T>,
+    r: &General<T>,
+) -> Result<General<T>, LinalgError>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    let n: usize = check_dimensions(a, b, q, r);
+    let g: General<T> = &b.clone() * &r.solve(&b.clone().transpose())?;
+
+    let mut l: General<T> = General::zero(2 * n, 2 * n);
+    l = l.set_slice(a, 0, 0);
+    l = l.set_slice(&(q * &-T::one()), n, 0);
+    l = l.set_slice(&General::one(n), n, n);
+
+    let mut m: General<T> = General::one(2 * n);
+    m = m.set_slice(&g, 0, n);
+    m = m.set_slice(&a.clone().transpose(), n, n);
+
+    let schur: GeneralizedSchurDec<Complex<T>> =
+        General::from(&l).dec_generalized_schur(&General::from(&m))?;
+    let s: General<Complex<T>> = schur.clone().s().into();
+    let t: General<Complex<T>> = schur.clone().t().into();
+
+    // |s_ii / t_ii| < 1, infinite eigenvalues are unstable
+    let select: Vec<bool> = (0..2 * n)
+        .map(|i| equations::modulus(s[[i, i]]) < equations::modulus(t[[i, i]]))
+        .collect();
+    if select.iter().filter(|s| **s).count() != n {
+        return Err(LinalgError::NoStabilizingSolution);
+    }
+    let z: General<Complex<T>> = schur.reorder(&select).z();
+
+    solution(&z, n)
+}
+
+/// Returns the dimension n
+fn check_dimensions<T>(a: &General<T>, b: &General<T>, q: &General<T>, r: &General<T>) -> usize {
+    let (n, m): (usize, usize) = b.dim();
+    assert_eq!(
+        a.dim(),
+        (n, n),
+        "A must be a square matrix with the rows of B"
+    );
+    assert_eq!(q.dim(), (n, n), "The dimensions of A and Q must match");
+    assert_eq!(
+        r.dim(),
+        (m, m),
+        "R must be a square matrix with the columns of B"
+    );
+    n
+}
+
+/// Computes $X = U_2U_1^{-1}$ from the invariant subspace of the $2n \times
+/// 2n$ matrix h belonging to the stable eigenvalues
+fn stable_solution<T, F>(h: &General<T>, n: usize, stable: F) -> Result<General<T>, LinalgError>
+where
+    T: Real,
+    Complex<T>: Scalar,
+    F: Fn(Complex<T>) -> bool,
+{
+    let schur: SchurDec<Complex<T>> = General::from(h).dec_schur()?;
+    let u: General<Complex<T>> = schur.clone().u().into();
+
+    let select: Vec<bool> = (0..2 * n).map(|i| stable(u[[i, i]])).collect();
+    if select.iter().filter(|s| **s).count() != n {
+        return Err(LinalgError::NoStabilizingSolution);
+    }
+    let q: General<Complex<T>> = schur.reorder(&select).q();
+
+    solution(&q, n)
+}
+
+/// Computes $X = U_2U_1^{-1}$ from the first n columns $\begin{pmatrix} U_1
+/// \\ U_2 \end{pmatrix}$ of the unitary matrix u
+fn solution<T>(u: &General<Complex<T>>, n: usize) -> Result<General<T>, LinalgError>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    // X U_1 = U_2
+    let u_1: General<Complex<T>> = u.get_slice(0, n - 1, 0, n - 1);
+    let u_2: General<Complex<T>> = u.get_slice(n, 2 * n - 1, 0, n - 1);
+    let x_t: General<Complex<T>> = u_1
+        .transpose()
+        .solve(&u_2.transpose())
+        .map_err(|_| LinalgError::NoStabilizingSolution)?;
+
+    // The columns of [U_1; U_2] are orthonormal, hence the smallest singular
+    // value of U_1 is (1 + ||X||_2^2)^(-1/2). U_1 is treated as singular if
+    // it is below the square root of the machine epsilon.
+    let norm: T = x_t
+        .iter()
+        .fold(T::zero(), |acc, x_ij| {
+            let m: T = equations::modulus(*x_ij);
+            acc + m * m
+        })
+        .sqrt();
+    if norm * T::default_epsilon().sqrt() >= T::one() {
+        return Err(LinalgError::NoStabilizingSolution);
+    }
+
+    Ok(equations::symmetrize(equations::real_part(
+        &x_t.transpose(),
+    )))
+}
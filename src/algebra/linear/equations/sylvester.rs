@@ -0,0 +1,119 @@
+use crate::algebra::{
+    abstr::{Complex, Real, Scalar},
+    linear::{
+        equations,
+        matrix::{ConjugateTranspose, General, SchurDecomposition},
+        LinalgError,
+    },
+};
+
+/// Solves the Sylvester equation
+///
+/// ```math
+/// AX + XB = C
+/// ```
+/// with $A \in T^{m \times m}$, $B \in T^{n \times n}$ and $C, X \in T^{m
+/// \times n}$ using the Bartels-Stewart algorithm. The solution is unique if
+/// and only if $\lambda_i(A) + \mu_j(B) \neq 0$ for all eigenvalues
+/// $\lambda_i$ of $A$ and $\mu_j$ of $B$.
+///
+/// # Errors
+///
+/// Returns [`LinalgError::Singular`] if $A$ and $-B$ have a common
+/// eigenvalue.
+///
+/// # Panics
+///
+/// if A or B is not square or the dimension of C does not match
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{equations, matrix::General};
+/// use mathru::{assert_relative_eq, matrix};
+///
+/// let a: General<f64> = matrix![  1.0, 2.0;
+///                                 0.0, 3.0];
+/// let b: General<f64> = matrix![  4.0, 0.0, 1.0;
+///                                 1.0, 5.0, 0.0;
+///                                 0.0, 0.0, 6.0];
+/// let c: General<f64> = matrix![  1.0, 0.0, 2.0;
+///                                 3.0, 1.0, 0.0];
+///
+/// let x: General<f64> = equations::solve_sylvester(&a, &b, &c).unwrap();
+///
+/// assert_relative_eq!(&(&a * &x) + &(&x * &b), c, epsilon = 1.0e-10);
+/// ```
+pub fn solve_sylvester<T>(
+    a: &General<T>,
+    b: &General<T>,
+    c: &General<T>,
+) -> Result<General<T>, LinalgError>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    let (m, n): (usize, usize) = c.dim();
+    assert_eq!(a.dim(), (m, m), "A must be a square matrix with m rows");
+    assert_eq!(b.dim(), (n, n), "B must be a square matrix with n columns");
+
+    let (q_a, u_a) = General::from(a).dec_schur()?.qu();
+    let (q_b, u_b) = General::from(b).dec_schur()?.qu();
+
+    // U_a Y + Y U_b = Q_a^* C Q_b with Y = Q_a^* X Q_b
+    let f: General<Complex<T>> = &(&q_a.clone().conj_transpose() * &General::from(c)) * &q_b;
+    let y: General<Complex<T>> = sylvester_triangular(&u_a.into(), &u_b.into(), f)?;
+
+    Ok(equations::real_part(
+        &(&(&q_a * &y) * &q_b.conj_transpose()),
+    ))
+}
+
+/// Solves $UY + YV = F$ with upper triangular matrices U and V column by
+/// column
+fn sylvester_triangular<T>(
+    u: &General<Complex<T>>,
+    v: &General<Complex<T>>,
+    mut f: General<Complex<T>>,
+) -> Result<General<Complex<T>>, LinalgError>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    let (m, n): (usize, usize) = f.dim();
+    let scale: T = max_modulus(u) + max_modulus(v);
+    let threshold: T = T::default_epsilon() * scale;
+
+    for j in 0..n {
+        // (U + v_jj I) y_j = f_j - sum_{k < j} v_kj y_k
+        for k in 0..j {
+            let v_kj: Complex<T> = v[[k, j]];
+            for i in 0..m {
+                let y_ik: Complex<T> = f[[i, k]];
+                f[[i, j]] -= v_kj * y_ik;
+            }
+        }
+        let v_jj: Complex<T> = v[[j, j]];
+        for i in (0..m).rev() {
+            let mut s: Complex<T> = f[[i, j]];
+            for l in (i + 1)..m {
+                s -= u[[i, l]] * f[[l, j]];
+            }
+            let d: Complex<T> = u[[i, i]] + v_jj;
+            if equations::modulus(d) <= threshold {
+                return Err(LinalgError::Singular);
+            }
+            f[[i, j]] = s / d;
+        }
+    }
+    Ok(f)
+}
+
+fn max_modulus<T>(a: &General<Complex<T>>) -> T
+where
+    T: Real,
+{
+    a.iter()
+        .map(|a_ij| equations::modulus(*a_ij))
+        .fold(T::zero(), |acc, a| acc.max(a))
+}
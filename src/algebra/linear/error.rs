@@ -0,0 +1,76 @@
+use std::fmt::{self, Display};
+
+/// Errors of decompositions, solvers and the algorithms built upon them
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{
+///     matrix::{General, Solve},
+///     vector::Vector,
+///     LinalgError,
+/// };
+/// use mathru::{matrix, vector};
+///
+/// let a: General<f64> = matrix![  1.0, 2.0;
+///                                 2.0, 4.0];
+/// let b: Vector<f64> = vector![1.0; 3.0];
+///
+/// match a.solve(&b) {
+///     Err(LinalgError::Singular) => (),
+///     _ => panic!("The matrix is singular"),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinalgError {
+    /// The matrix is singular or does not have full rank
+    Singular,
+    /// The matrix is not positive definite
+    NotPositiveDefinite,
+    /// The dimensions of the operands do not match
+    DimensionMismatch,
+    /// An iterative algorithm did not converge within `iters` iterations
+    NoConvergence { iters: usize },
+    /// The LAPACK routine returned the error code `info`
+    BackendError { info: i32 },
+    /// The condition number exceeds the requested bound
+    IllConditioned,
+    /// The matrix has complex eigenvalues, but real ones were requested
+    ComplexEigenvalues,
+    /// The spectrum of the matrix is outside the domain of the requested
+    /// matrix function, e.g. a negative real eigenvalue for the logarithm
+    OutOfDomain,
+    /// The algebraic Riccati equation does not have a stabilizing solution
+    NoStabilizingSolution,
+}
+
+impl Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinalgError::Singular => write!(f, "The matrix is singular"),
+            LinalgError::NotPositiveDefinite => write!(f, "The matrix is not positive definite"),
+            LinalgError::DimensionMismatch => write!(f, "The dimensions do not match"),
+            LinalgError::NoConvergence { iters } => {
+                write!(f, "No convergence after {} iterations", iters)
+            }
+            LinalgError::BackendError { info } => {
+                write!(f, "LAPACK reported the error code {}", info)
+            }
+            LinalgError::IllConditioned => write!(f, "The matrix is ill-conditioned"),
+            LinalgError::ComplexEigenvalues => write!(
+                f,
+                "The matrix has complex eigenvalues, use ComplexEigenDecomposition"
+            ),
+            LinalgError::OutOfDomain => write!(
+                f,
+                "The matrix function is not defined for the eigenvalues of the matrix"
+            ),
+            LinalgError::NoStabilizingSolution => write!(
+                f,
+                "The Riccati equation does not have a stabilizing solution"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LinalgError {}
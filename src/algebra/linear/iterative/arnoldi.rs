@@ -0,0 +1,192 @@
+use crate::algebra::{
+    abstr::{Complex, Real, Scalar},
+    linear::{
+        iterative::{
+            krylov::{self, KrylovDecomposition},
+            lanczos, IterativeEigenResult, LinearOperator, Which,
+        },
+        matrix::{ComplexEigenDecomposition, General},
+        vector::Vector,
+        LinalgError,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Restarted Arnoldi method
+///
+/// Computes $k$ eigenpairs of a general real matrix $A$. The Arnoldi process
+/// builds an orthonormal basis $V_m$ of the Krylov subspace
+/// $\mathcal{K}_m(A, v_0)$, the eigenpairs of the projected matrix $H_m =
+/// V_m^TAV_m$ are the Ritz pairs. The eigenvalues and eigenvectors are
+/// complex in general.
+///
+/// The dimension of the subspace is $m = \max(2k + 1, 20)$, limited by the
+/// dimension of $A$. If not all wanted Ritz pairs have converged, the method
+/// is restarted with the real invariant subspace of $H_m$ belonging to the
+/// $k + (m - k) / 2$ most wanted Ritz values, extended by the complex
+/// conjugates, which is mathematically equivalent to the implicit restart
+/// with exact shifts.
+///
+/// A Ritz pair $(\theta, x)$ has converged, if $\lVert Ax - \theta x
+/// \rVert_2 \leq \epsilon \max(|\theta|, \epsilon_{mach}^{2/3})$.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::abstr::Complex;
+/// use mathru::algebra::linear::{
+///     iterative::{Arnoldi, IterativeEigenResult, Which},
+///     matrix::General,
+///     vector::Vector,
+/// };
+/// use mathru::{assert_relative_eq, matrix};
+///
+/// let a: General<f64> = matrix![  1.0, 2.0, 0.0;
+///                                 0.0, 3.0, 1.0;
+///                                 0.0, 0.0, -5.0];
+///
+/// let arnoldi: Arnoldi<f64> = Arnoldi::new(10, 1.0e-10);
+/// let result: IterativeEigenResult<f64, Complex<f64>> = arnoldi.solve(&a, &Vector::one(3), 1, Which::LargestMagnitude).unwrap();
+///
+/// assert_relative_eq!(result.values()[0], Complex::new(-5.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Arnoldi<T> {
+    max_iter: usize,
+    tolerance: T,
+}
+
+impl<T> Arnoldi<T> {
+    /// Creates an instance of the restarted Arnoldi method
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iter`: Maximum number of restarts
+    /// * `tolerance`: Relative residual tolerance of the eigenpairs
+    pub fn new(max_iter: usize, tolerance: T) -> Arnoldi<T> {
+        Arnoldi {
+            max_iter,
+            tolerance,
+        }
+    }
+}
+
+impl<T> Arnoldi<T>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes $k$ eigenpairs of the matrix $A$ starting with the vector v_0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the eigenvalues of the projected matrix can not
+    /// be computed. If the tolerance is not reached within the maximum
+    /// number of restarts, the current approximations are returned and the
+    /// result is marked as not converged.
+    ///
+    /// # Panics
+    ///
+    /// if $k$ is zero or larger than the dimension of $A$ or v_0 is zero
+    pub fn solve<A>(
+        &self,
+        a: &A,
+        v_0: &Vector<T>,
+        k: usize,
+        which: Which,
+    ) -> Result<IterativeEigenResult<T, Complex<T>>, LinalgError>
+    where
+        A: LinearOperator<T>,
+    {
+        let n: usize = v_0.dim().0;
+        let m: usize = lanczos::subspace_dim(n, k);
+        let mut basis: KrylovDecomposition<T> = KrylovDecomposition::new(v_0, m);
+
+        let mut iteration: usize = 0;
+        loop {
+            basis.expand(a);
+
+            let (theta, y): (Vec<Complex<T>>, General<Complex<T>>) =
+                basis.h().dec_eigen_complex()?.pair();
+
+            let values: Vec<(T, T)> = theta.iter().map(|t| (t.re, t.im)).collect();
+            let order: Vec<usize> = which.ordering(&values);
+            let beta: T = basis.residual_norm();
+            let residuals: Vec<T> = order[..k]
+                .iter()
+                .map(|i| beta * modulus(y[[m - 1, *i]]) / column_norm(&y, *i))
+                .collect();
+            let converged: bool = order[..k]
+                .iter()
+                .zip(residuals.iter())
+                .all(|(i, r)| krylov::converged(*r, modulus(theta[*i]), self.tolerance));
+
+            if converged || iteration == self.max_iter || m == n {
+                let mut vectors: General<Complex<T>> = General::zero(n, k);
+                for (j, i) in order[..k].iter().enumerate() {
+                    let y_re: Vec<T> = (0..m).map(|l| y[[l, *i]].re).collect();
+                    let y_im: Vec<T> = (0..m).map(|l| y[[l, *i]].im).collect();
+                    let x_re: Vector<T> = basis.combine(&y_re);
+                    let x_im: Vector<T> = basis.combine(&y_im);
+                    let norm: T = (x_re.dotp(&x_re) + x_im.dotp(&x_im)).sqrt();
+                    for l in 0..n {
+                        vectors[[l, j]] = Complex::new(x_re[l] / norm, x_im[l] / norm);
+                    }
+                }
+                let values: Vec<Complex<T>> = order[..k].iter().map(|i| theta[*i]).collect();
+                return Ok(IterativeEigenResult::new(
+                    values, vectors, iteration, residuals, converged,
+                ));
+            }
+
+            // Real orthonormal basis of the invariant subspace belonging to
+            // the kept Ritz values and their complex conjugates
+            let threshold: T = T::default_epsilon().sqrt();
+            let parts: [fn(Complex<T>) -> T; 2] = [|c| c.re, |c| c.im];
+            let mut u: Vec<Vector<T>> = Vec::new();
+            for i in order[..lanczos::restart_dim(m, k)].iter() {
+                for part in parts.iter() {
+                    if u.len() + 1 == m {
+                        break;
+                    }
+                    let mut w: Vector<T> =
+                        Vector::new_column((0..m).map(|l| part(y[[l, *i]])).collect());
+                    let scale: T = column_norm(&y, *i);
+                    krylov::orthogonalize(&u, &mut w);
+                    let norm: T = w.eucl_norm();
+                    if norm > threshold * scale {
+                        u.push(w / norm);
+                    }
+                }
+            }
+            let mut u_matrix: General<T> = General::zero(m, u.len());
+            for (j, u_j) in u.iter().enumerate() {
+                u_matrix.set_column(u_j, j);
+            }
+            basis.restart(&u_matrix);
+            iteration += 1;
+        }
+    }
+}
+
+fn modulus<T>(c: Complex<T>) -> T
+where
+    T: Real,
+{
+    (c.re * c.re + c.im * c.im).sqrt()
+}
+
+fn column_norm<T>(y: &General<Complex<T>>, j: usize) -> T
+where
+    T: Real,
+{
+    (0..y.nrows())
+        .map(|i| {
+            let y_ij: Complex<T> = y[[i, j]];
+            y_ij.re * y_ij.re + y_ij.im * y_ij.im
+        })
+        .fold(T::zero(), |acc, a| acc + a)
+        .sqrt()
+}
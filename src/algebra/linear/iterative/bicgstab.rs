@@ -0,0 +1,150 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        iterative::{
+            IdentityPreconditioner, IterativeResult, LinearOperator, Preconditioner,
+        },
+        vector::Vector,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Biconjugate gradient stabilized method (BiCGSTAB)
+///
+/// Solves $Ax = b$ for a general square matrix $A$. The preconditioner is
+/// applied from the right, so the residual norms are the norms of the
+/// unpreconditioned residual $b - Ax_k$.
+///
+/// The iteration stops as soon as $\lVert b - Ax_k \rVert_2 \leq \epsilon
+/// \lVert b \rVert_2$, the maximum number of iterations is reached or the
+/// method breaks down.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{iterative::{BiCgStab, IterativeResult}, matrix::General, vector::Vector};
+/// use mathru::{assert_relative_eq, matrix, vector};
+///
+/// let a: General<f64> = matrix![4.0, 1.0;
+///                               -2.0, 3.0];
+/// let b: Vector<f64> = vector![5.0; 1.0];
+///
+/// let bicgstab: BiCgStab<f64> = BiCgStab::new(10, 1.0e-12);
+/// let result: IterativeResult<f64> = bicgstab.solve(&a, &b, &Vector::zero(2));
+///
+/// assert_relative_eq!(*result.x(), vector![1.0; 1.0], epsilon = 1.0e-10);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct BiCgStab<T> {
+    max_iter: usize,
+    tolerance: T,
+}
+
+impl<T> BiCgStab<T> {
+    /// Creates an instance of the biconjugate gradient stabilized method
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iter`: Maximum number of iterations
+    /// * `tolerance`: Relative residual tolerance
+    pub fn new(max_iter: usize, tolerance: T) -> BiCgStab<T> {
+        BiCgStab {
+            max_iter,
+            tolerance,
+        }
+    }
+}
+
+impl<T> BiCgStab<T>
+where
+    T: Real,
+{
+    /// Solves $Ax = b$ starting with the initial approximation x_0
+    pub fn solve<A>(&self, a: &A, b: &Vector<T>, x_0: &Vector<T>) -> IterativeResult<T>
+    where
+        A: LinearOperator<T>,
+    {
+        self.solve_preconditioned(a, b, x_0, &IdentityPreconditioner)
+    }
+
+    /// Solves $Ax = b$ starting with the initial approximation x_0 using the
+    /// preconditioner m
+    pub fn solve_preconditioned<A, M>(
+        &self,
+        a: &A,
+        b: &Vector<T>,
+        x_0: &Vector<T>,
+        m: &M,
+    ) -> IterativeResult<T>
+    where
+        A: LinearOperator<T>,
+        M: Preconditioner<T>,
+    {
+        let n: usize = b.dim().0;
+        let threshold: T = self.tolerance * b.eucl_norm();
+
+        let mut x: Vector<T> = x_0.clone();
+        let mut r: Vector<T> = b - &a.mul_vec(&x);
+        let mut residuals: Vec<T> = vec![r.eucl_norm()];
+
+        if residuals[0] <= threshold {
+            return IterativeResult::new(x, 0, residuals, true);
+        }
+
+        let r_hat: Vector<T> = r.clone();
+        let mut rho: T = T::one();
+        let mut alpha: T = T::one();
+        let mut omega: T = T::one();
+        let mut p: Vector<T> = Vector::zero(n);
+        let mut v: Vector<T> = Vector::zero(n);
+
+        for k in 1..=self.max_iter {
+            let rho_next: T = r_hat.dotp(&r);
+            if rho_next == T::zero() || omega == T::zero() {
+                return IterativeResult::new(x, k - 1, residuals, false);
+            }
+
+            let beta: T = (rho_next / rho) * (alpha / omega);
+            p = &r + &((p - &v * &omega) * beta);
+            rho = rho_next;
+
+            let p_hat: Vector<T> = m.precondition(&p);
+            v = a.mul_vec(&p_hat);
+            let r_hat_v: T = r_hat.dotp(&v);
+            if r_hat_v == T::zero() {
+                return IterativeResult::new(x, k - 1, residuals, false);
+            }
+            alpha = rho / r_hat_v;
+
+            let s: Vector<T> = &r - &(&v * &alpha);
+            let s_norm: T = s.eucl_norm();
+            if s_norm <= threshold {
+                x += p_hat * alpha;
+                residuals.push(s_norm);
+                return IterativeResult::new(x, k, residuals, true);
+            }
+
+            let s_hat: Vector<T> = m.precondition(&s);
+            let t: Vector<T> = a.mul_vec(&s_hat);
+            let tt: T = t.dotp(&t);
+            omega = if tt == T::zero() {
+                T::zero()
+            } else {
+                t.dotp(&s) / tt
+            };
+
+            x += p_hat * alpha + s_hat * omega;
+            r = s - t * omega;
+
+            let r_norm: T = r.eucl_norm();
+            residuals.push(r_norm);
+            if r_norm <= threshold {
+                return IterativeResult::new(x, k, residuals, true);
+            }
+        }
+
+        IterativeResult::new(x, self.max_iter, residuals, false)
+    }
+}
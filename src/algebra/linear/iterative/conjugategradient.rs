@@ -0,0 +1,123 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        iterative::{
+            IdentityPreconditioner, IterativeResult, LinearOperator, Preconditioner,
+        },
+        vector::Vector,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Conjugate gradient method
+///
+/// Solves $Ax = b$ for a symmetric positive definite matrix $A$. A
+/// preconditioner has to be symmetric positive definite as well.
+///
+/// The iteration stops as soon as $\lVert b - Ax_k \rVert_2 \leq \epsilon
+/// \lVert b \rVert_2$ or the maximum number of iterations is reached.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{iterative::{ConjugateGradient, IterativeResult}, matrix::General, vector::Vector};
+/// use mathru::{assert_relative_eq, matrix, vector};
+///
+/// let a: General<f64> = matrix![4.0, 1.0;
+///                               1.0, 3.0];
+/// let b: Vector<f64> = vector![1.0; 2.0];
+///
+/// let cg: ConjugateGradient<f64> = ConjugateGradient::new(10, 1.0e-12);
+/// let result: IterativeResult<f64> = cg.solve(&a, &b, &Vector::zero(2));
+///
+/// assert_relative_eq!(*result.x(), vector![1.0 / 11.0; 7.0 / 11.0], epsilon = 1.0e-10);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct ConjugateGradient<T> {
+    max_iter: usize,
+    tolerance: T,
+}
+
+impl<T> ConjugateGradient<T> {
+    /// Creates an instance of the conjugate gradient method
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iter`: Maximum number of iterations
+    /// * `tolerance`: Relative residual tolerance
+    pub fn new(max_iter: usize, tolerance: T) -> ConjugateGradient<T> {
+        ConjugateGradient {
+            max_iter,
+            tolerance,
+        }
+    }
+}
+
+impl<T> ConjugateGradient<T>
+where
+    T: Real,
+{
+    /// Solves $Ax = b$ starting with the initial approximation x_0
+    pub fn solve<A>(&self, a: &A, b: &Vector<T>, x_0: &Vector<T>) -> IterativeResult<T>
+    where
+        A: LinearOperator<T>,
+    {
+        self.solve_preconditioned(a, b, x_0, &IdentityPreconditioner)
+    }
+
+    /// Solves $Ax = b$ starting with the initial approximation x_0 using the
+    /// preconditioner m
+    pub fn solve_preconditioned<A, M>(
+        &self,
+        a: &A,
+        b: &Vector<T>,
+        x_0: &Vector<T>,
+        m: &M,
+    ) -> IterativeResult<T>
+    where
+        A: LinearOperator<T>,
+        M: Preconditioner<T>,
+    {
+        let threshold: T = self.tolerance * b.eucl_norm();
+
+        let mut x: Vector<T> = x_0.clone();
+        let mut r: Vector<T> = b - &a.mul_vec(&x);
+        let mut residuals: Vec<T> = vec![r.eucl_norm()];
+
+        if residuals[0] <= threshold {
+            return IterativeResult::new(x, 0, residuals, true);
+        }
+
+        let mut z: Vector<T> = m.precondition(&r);
+        let mut p: Vector<T> = z.clone();
+        let mut rz: T = r.dotp(&z);
+
+        for k in 1..=self.max_iter {
+            let q: Vector<T> = a.mul_vec(&p);
+            let pq: T = p.dotp(&q);
+            if pq == T::zero() {
+                return IterativeResult::new(x, k - 1, residuals, false);
+            }
+
+            let alpha: T = rz / pq;
+            x += &p * &alpha;
+            r -= q * alpha;
+
+            let r_norm: T = r.eucl_norm();
+            residuals.push(r_norm);
+            if r_norm <= threshold {
+                return IterativeResult::new(x, k, residuals, true);
+            }
+
+            z = m.precondition(&r);
+            let rz_next: T = r.dotp(&z);
+            let beta: T = rz_next / rz;
+            p = z + p * beta;
+            rz = rz_next;
+        }
+
+        IterativeResult::new(x, self.max_iter, residuals, false)
+    }
+}
@@ -0,0 +1,190 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        iterative::{
+            IdentityPreconditioner, IterativeResult, LinearOperator, Preconditioner,
+        },
+        vector::Vector,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Restarted generalized minimal residual method, GMRES(m)
+///
+/// Solves $Ax = b$ for a general square matrix $A$. After `restart`
+/// iterations the Krylov basis is discarded and the method starts again with
+/// the current approximation. The preconditioner is applied from the right,
+/// so the residual norms are the norms of the unpreconditioned residual
+/// $b - Ax_k$.
+///
+/// The iteration stops as soon as $\lVert b - Ax_k \rVert_2 \leq \epsilon
+/// \lVert b \rVert_2$ or the maximum number of iterations is reached.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{iterative::{Gmres, IterativeResult}, matrix::General, vector::Vector};
+/// use mathru::{assert_relative_eq, matrix, vector};
+///
+/// let a: General<f64> = matrix![4.0, 1.0, 0.0;
+///                               -2.0, 3.0, 1.0;
+///                               0.0, 1.0, 5.0];
+/// let b: Vector<f64> = vector![5.0; 2.0; 6.0];
+///
+/// let gmres: Gmres<f64> = Gmres::new(20, 1.0e-12, 3);
+/// let result: IterativeResult<f64> = gmres.solve(&a, &b, &Vector::zero(3));
+///
+/// assert_relative_eq!(*result.x(), vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Gmres<T> {
+    max_iter: usize,
+    tolerance: T,
+    restart: usize,
+}
+
+impl<T> Gmres<T> {
+    /// Creates an instance of the restarted GMRES method
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iter`: Maximum number of iterations
+    /// * `tolerance`: Relative residual tolerance
+    /// * `restart`: Number of iterations after which the method is restarted
+    ///
+    /// # Panics
+    ///
+    /// if restart is zero
+    pub fn new(max_iter: usize, tolerance: T, restart: usize) -> Gmres<T> {
+        assert!(restart > 0, "restart has to be greater than zero");
+        Gmres {
+            max_iter,
+            tolerance,
+            restart,
+        }
+    }
+}
+
+impl<T> Gmres<T>
+where
+    T: Real,
+{
+    /// Solves $Ax = b$ starting with the initial approximation x_0
+    pub fn solve<A>(&self, a: &A, b: &Vector<T>, x_0: &Vector<T>) -> IterativeResult<T>
+    where
+        A: LinearOperator<T>,
+    {
+        self.solve_preconditioned(a, b, x_0, &IdentityPreconditioner)
+    }
+
+    /// Solves $Ax = b$ starting with the initial approximation x_0 using the
+    /// preconditioner m
+    pub fn solve_preconditioned<A, M>(
+        &self,
+        a: &A,
+        b: &Vector<T>,
+        x_0: &Vector<T>,
+        m: &M,
+    ) -> IterativeResult<T>
+    where
+        A: LinearOperator<T>,
+        M: Preconditioner<T>,
+    {
+        let n: usize = b.dim().0;
+        let threshold: T = self.tolerance * b.eucl_norm();
+
+        let mut x: Vector<T> = x_0.clone();
+        let mut r: Vector<T> = b - &a.mul_vec(&x);
+        let mut beta: T = r.eucl_norm();
+        let mut residuals: Vec<T> = vec![beta];
+        let mut k: usize = 0;
+
+        loop {
+            if beta <= threshold {
+                return IterativeResult::new(x, k, residuals, true);
+            }
+            if k == self.max_iter {
+                return IterativeResult::new(x, k, residuals, false);
+            }
+
+            let restart: usize = self.restart.min(self.max_iter - k);
+
+            // Arnoldi basis and Hessenberg matrix, h[j] is the j-th column
+            let mut v: Vec<Vector<T>> = Vec::with_capacity(restart + 1);
+            let mut h: Vec<Vec<T>> = Vec::with_capacity(restart);
+            let mut c: Vec<T> = Vec::with_capacity(restart);
+            let mut s: Vec<T> = Vec::with_capacity(restart);
+            let mut g: Vec<T> = vec![T::zero(); restart + 1];
+            g[0] = beta;
+            v.push(r / beta);
+
+            for j in 0..restart {
+                let mut w: Vector<T> = a.mul_vec(&m.precondition(&v[j]));
+                let mut h_j: Vec<T> = vec![T::zero(); j + 2];
+
+                // modified Gram-Schmidt
+                for (i, v_i) in v.iter().enumerate() {
+                    h_j[i] = w.dotp(v_i);
+                    w -= v_i * &h_j[i];
+                }
+                h_j[j + 1] = w.eucl_norm();
+                let h_next: T = h_j[j + 1];
+
+                // Apply the previous Givens rotations to the new column
+                for i in 0..j {
+                    let temp: T = c[i] * h_j[i] + s[i] * h_j[i + 1];
+                    h_j[i + 1] = -s[i] * h_j[i] + c[i] * h_j[i + 1];
+                    h_j[i] = temp;
+                }
+
+                // Compute the rotation which eliminates h_{j+1, j}
+                let rho: T = (h_j[j] * h_j[j] + h_j[j + 1] * h_j[j + 1]).sqrt();
+                let (c_j, s_j) = if rho == T::zero() {
+                    (T::one(), T::zero())
+                } else {
+                    (h_j[j] / rho, h_j[j + 1] / rho)
+                };
+                c.push(c_j);
+                s.push(s_j);
+                h_j[j] = rho;
+                h_j[j + 1] = T::zero();
+                g[j + 1] = -s_j * g[j];
+                g[j] *= c_j;
+                h.push(h_j);
+
+                k += 1;
+                residuals.push(g[j + 1].abs());
+
+                if g[j + 1].abs() <= threshold || h_next == T::zero() {
+                    break;
+                }
+                v.push(w / h_next);
+            }
+
+            // Solve the upper triangular system H y = g
+            let l: usize = h.len();
+            let mut y: Vec<T> = g[..l].to_vec();
+            for i in (0..l).rev() {
+                for (j, h_col) in h.iter().enumerate().skip(i + 1) {
+                    let y_j: T = y[j];
+                    y[i] -= h_col[i] * y_j;
+                }
+                if h[i][i] == T::zero() {
+                    return IterativeResult::new(x, k, residuals, false);
+                }
+                y[i] /= h[i][i];
+            }
+
+            let mut z: Vector<T> = Vector::zero(n);
+            for (v_i, y_i) in v.iter().zip(y.iter()) {
+                z += v_i * y_i;
+            }
+            x += m.precondition(&z);
+
+            r = b - &a.mul_vec(&x);
+            beta = r.eucl_norm();
+        }
+    }
+}
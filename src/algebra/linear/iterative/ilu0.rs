@@ -0,0 +1,126 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{iterative::Preconditioner, matrix::CsrMatrix, vector::Vector},
+};
+
+/// Incomplete LU factorization without fill-in, ILU(0)
+///
+/// The factors $L$ and $U$ have the same sparsity pattern as the system
+/// matrix $A$. $L$ is unit lower triangular, both factors are stored
+/// together in one CSR matrix.
+#[derive(Clone, Debug)]
+pub struct Ilu0<T> {
+    lu: CsrMatrix<T>,
+    diag: Vec<usize>,
+}
+
+impl<T> Ilu0<T>
+where
+    T: Field + Scalar,
+{
+    /// Computes the incomplete LU factorization of the square matrix a
+    ///
+    /// # Errors
+    ///
+    /// if a diagonal entry is missing or a zero pivot occurs
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{iterative::{Ilu0, Preconditioner}, matrix::{CsrMatrix, General}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// // The factorization of a tridiagonal matrix is exact
+    /// let a: General<f64> = matrix![4.0, 1.0, 0.0;
+    ///                               1.0, 4.0, 1.0;
+    ///                               0.0, 1.0, 4.0];
+    /// let m: Ilu0<f64> = Ilu0::new(&CsrMatrix::from(&a)).unwrap();
+    /// let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+    ///
+    /// assert_relative_eq!(m.precondition(&(&a * &b)), b);
+    /// ```
+    pub fn new(a: &CsrMatrix<T>) -> Result<Ilu0<T>, LinalgError> {
+        debug_assert_eq!(a.nrows(), a.ncols());
+        let n: usize = a.nrows();
+        let mut lu: CsrMatrix<T> = a.clone();
+
+        let mut diag: Vec<usize> = Vec::with_capacity(n);
+        for i in 0..n {
+            let (s, e) = (lu.row_offsets[i], lu.row_offsets[i + 1]);
+            match lu.col_indices[s..e].binary_search(&i) {
+                Ok(p) => diag.push(s + p),
+                Err(_) => return Err(LinalgError::Singular),
+            }
+        }
+
+        for i in 1..n {
+            let (s, e) = (lu.row_offsets[i], lu.row_offsets[i + 1]);
+            for p in s..diag[i] {
+                let k: usize = lu.col_indices[p];
+                let pivot: T = lu.values[diag[k]];
+                if pivot == T::zero() {
+                    return Err(LinalgError::Singular);
+                }
+                let l_ik: T = lu.values[p] / pivot;
+                lu.values[p] = l_ik;
+
+                // a_ij -= l_ik * u_kj for all j > k in the pattern of row i
+                let mut q: usize = diag[k] + 1;
+                let q_end: usize = lu.row_offsets[k + 1];
+                for r in (p + 1)..e {
+                    let j: usize = lu.col_indices[r];
+                    while q < q_end && lu.col_indices[q] < j {
+                        q += 1;
+                    }
+                    if q == q_end {
+                        break;
+                    }
+                    if lu.col_indices[q] == j {
+                        let u_kj: T = lu.values[q];
+                        lu.values[r] -= l_ik * u_kj;
+                    }
+                }
+            }
+        }
+
+        if diag.iter().any(|p| lu.values[*p] == T::zero()) {
+            return Err(LinalgError::Singular);
+        }
+
+        Ok(Ilu0 { lu, diag })
+    }
+}
+
+impl<T> Preconditioner<T> for Ilu0<T>
+where
+    T: Field + Scalar,
+{
+    fn precondition(&self, r: &Vector<T>) -> Vector<T> {
+        let n: usize = self.diag.len();
+        debug_assert_eq!(r.dim().0, n);
+        let mut z: Vector<T> = r.clone();
+
+        // L y = r
+        for i in 0..n {
+            let s: usize = self.lu.row_offsets[i];
+            let mut sum: T = z[i];
+            for p in s..self.diag[i] {
+                sum -= self.lu.values[p] * z[self.lu.col_indices[p]];
+            }
+            z[i] = sum;
+        }
+
+        // U z = y
+        for i in (0..n).rev() {
+            let e: usize = self.lu.row_offsets[i + 1];
+            let mut sum: T = z[i];
+            for p in (self.diag[i] + 1)..e {
+                sum -= self.lu.values[p] * z[self.lu.col_indices[p]];
+            }
+            z[i] = sum / self.lu.values[self.diag[i]];
+        }
+
+        z
+    }
+}
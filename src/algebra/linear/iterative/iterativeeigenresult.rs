@@ -0,0 +1,66 @@
+use crate::algebra::linear::matrix::General;
+
+/// Result of an iterative eigensolver
+///
+/// Besides the approximate eigenpairs, the result reports the number of
+/// iterations and the residual norms $\lVert Ax_j - \lambda_j x_j \rVert_2$
+/// of the eigenpairs. The eigenpairs are ordered starting with the most
+/// wanted one according to [`Which`](super::Which), the eigenvectors have
+/// unit euclidean norm.
+#[derive(Clone, Debug)]
+pub struct IterativeEigenResult<R, T = R> {
+    values: Vec<T>,
+    vectors: General<T>,
+    iterations: usize,
+    residuals: Vec<R>,
+    converged: bool,
+}
+
+impl<R, T> IterativeEigenResult<R, T> {
+    pub(super) fn new(
+        values: Vec<T>,
+        vectors: General<T>,
+        iterations: usize,
+        residuals: Vec<R>,
+        converged: bool,
+    ) -> IterativeEigenResult<R, T> {
+        IterativeEigenResult {
+            values,
+            vectors,
+            iterations,
+            residuals,
+            converged,
+        }
+    }
+
+    /// Returns the eigenvalues
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the eigenvectors, the j-th column belongs to the j-th
+    /// eigenvalue
+    pub fn vectors(&self) -> &General<T> {
+        &self.vectors
+    }
+
+    /// Returns the eigenvalues and eigenvectors and consumes the result
+    pub fn pair(self) -> (Vec<T>, General<T>) {
+        (self.values, self.vectors)
+    }
+
+    /// Returns the number of performed iterations
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns the residual norms of the eigenpairs
+    pub fn residuals(&self) -> &[R] {
+        &self.residuals
+    }
+
+    /// Returns true if all eigenpairs reached the tolerance
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+}
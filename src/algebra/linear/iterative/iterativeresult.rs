@@ -0,0 +1,56 @@
+use crate::algebra::linear::vector::Vector;
+
+/// Result of an iterative linear solver
+///
+/// Besides the approximate solution, the result reports the number of
+/// iterations and the history of the residual norms. The first entry of the
+/// history is the residual norm of the initial approximation, followed by one
+/// entry per iteration.
+#[derive(Clone, Debug)]
+pub struct IterativeResult<T> {
+    x: Vector<T>,
+    iterations: usize,
+    residuals: Vec<T>,
+    converged: bool,
+}
+
+impl<T> IterativeResult<T> {
+    pub(super) fn new(
+        x: Vector<T>,
+        iterations: usize,
+        residuals: Vec<T>,
+        converged: bool,
+    ) -> IterativeResult<T> {
+        IterativeResult {
+            x,
+            iterations,
+            residuals,
+            converged,
+        }
+    }
+
+    /// Returns the approximate solution
+    pub fn x(&self) -> &Vector<T> {
+        &self.x
+    }
+
+    /// Returns the approximate solution and consumes the result
+    pub fn into_x(self) -> Vector<T> {
+        self.x
+    }
+
+    /// Returns the number of performed iterations
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns the residual norms
+    pub fn residuals(&self) -> &[T] {
+        &self.residuals
+    }
+
+    /// Returns true if the tolerance has been reached
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+}
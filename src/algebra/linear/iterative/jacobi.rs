@@ -0,0 +1,63 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{iterative::Preconditioner, matrix::CsrMatrix, vector::Vector},
+};
+
+/// Jacobi preconditioner
+///
+/// The preconditioner is the diagonal of the system matrix, $M = D$.
+#[derive(Clone, Debug)]
+pub struct Jacobi<T> {
+    inv_diag: Vec<T>,
+}
+
+impl<T> Jacobi<T>
+where
+    T: Field + Scalar,
+{
+    /// Creates the Jacobi preconditioner of the square matrix a
+    ///
+    /// # Errors
+    ///
+    /// if a diagonal entry is zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{iterative::{Jacobi, Preconditioner}, matrix::{CsrMatrix, General}, vector::Vector};
+    /// use mathru::{matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![2.0, 1.0; 1.0, 4.0];
+    /// let m: Jacobi<f64> = Jacobi::new(&CsrMatrix::from(&a)).unwrap();
+    ///
+    /// assert_eq!(m.precondition(&vector![1.0; 1.0]), vector![0.5; 0.25]);
+    /// ```
+    pub fn new(a: &CsrMatrix<T>) -> Result<Jacobi<T>, LinalgError> {
+        debug_assert_eq!(a.nrows(), a.ncols());
+
+        let mut inv_diag: Vec<T> = a.diagonal();
+        for d in inv_diag.iter_mut() {
+            if *d == T::zero() {
+                return Err(LinalgError::Singular);
+            }
+            *d = T::one() / *d;
+        }
+
+        Ok(Jacobi { inv_diag })
+    }
+}
+
+impl<T> Preconditioner<T> for Jacobi<T>
+where
+    T: Field + Scalar,
+{
+    fn precondition(&self, r: &Vector<T>) -> Vector<T> {
+        debug_assert_eq!(r.dim().0, self.inv_diag.len());
+        let mut z: Vector<T> = r.clone();
+        for (i, d) in self.inv_diag.iter().enumerate() {
+            z[i] *= *d;
+        }
+        z
+    }
+}
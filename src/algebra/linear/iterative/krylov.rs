@@ -0,0 +1,181 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        iterative::LinearOperator,
+        matrix::{General, Transpose},
+        vector::Vector,
+    },
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Krylov decomposition $AV_m = V_mH_m + fe_m^T$ with orthonormal columns
+/// $V_m$ and a residual $f$ orthogonal to them
+///
+/// The basis is expanded with the Arnoldi process with full
+/// reorthogonalization. A restart keeps an invariant subspace of $H_m$,
+/// which preserves the form of the decomposition (thick restart).
+pub(super) struct KrylovDecomposition<T> {
+    v: Vec<Vector<T>>,
+    h: General<T>,
+    f: Vector<T>,
+    n: usize,
+    m: usize,
+}
+
+impl<T> KrylovDecomposition<T>
+where
+    T: Real,
+{
+    /// # Panics
+    ///
+    /// if v_0 is zero
+    pub fn new(v_0: &Vector<T>, m: usize) -> KrylovDecomposition<T> {
+        let n: usize = v_0.dim().0;
+        let norm: T = v_0.eucl_norm();
+        assert!(norm > T::zero(), "The start vector must not be zero");
+
+        KrylovDecomposition {
+            v: vec![v_0 / &norm],
+            h: General::zero(m, m),
+            f: Vector::zero(n),
+            n,
+            m,
+        }
+    }
+
+    /// Expands the basis to $m$ vectors
+    pub fn expand<A>(&mut self, a: &A)
+    where
+        A: LinearOperator<T>,
+    {
+        for j in self.v.len() - 1..self.m {
+            let mut w: Vector<T> = a.mul_vec(&self.v[j]);
+            let w_norm: T = w.eucl_norm();
+            let h_j: Vec<T> = orthogonalize(&self.v, &mut w);
+            for (i, h_ij) in h_j.into_iter().enumerate() {
+                self.h[[i, j]] = h_ij;
+            }
+
+            if j + 1 == self.m {
+                self.f = w;
+                break;
+            }
+            let beta: T = w.eucl_norm();
+            if beta <= T::default_epsilon() * w_norm || beta == T::zero() {
+                // The subspace is invariant, the basis is continued with a
+                // vector orthogonal to it
+                self.h[[j + 1, j]] = T::zero();
+                let v_next: Vector<T> = self.orthogonal_vector();
+                self.v.push(v_next);
+            } else {
+                self.h[[j + 1, j]] = beta;
+                self.v.push(w / beta);
+            }
+        }
+    }
+
+    pub fn h(&self) -> &General<T> {
+        &self.h
+    }
+
+    pub fn residual_norm(&self) -> T {
+        self.f.eucl_norm()
+    }
+
+    /// Computes $V_my$
+    pub fn combine(&self, y: &[T]) -> Vector<T> {
+        let mut x: Vector<T> = Vector::zero(self.n);
+        for (v_i, y_i) in self.v.iter().zip(y.iter()) {
+            x += v_i * y_i;
+        }
+        x
+    }
+
+    /// Restarts with the subspace spanned by $V_mU$
+    ///
+    /// The columns of u have to be orthonormal and span an invariant
+    /// subspace of $H_m$.
+    pub fn restart(&mut self, u: &General<T>) {
+        let (m, r): (usize, usize) = u.dim();
+        debug_assert_eq!(m, self.m);
+        debug_assert!(r < m);
+
+        let mut v: Vec<Vector<T>> = (0..r)
+            .map(|j| {
+                let u_j: Vec<T> = u.get_column(j).convert_to_vec();
+                self.combine(&u_j)
+            })
+            .collect();
+        let s: General<T> = &(&u.clone().transpose() * &self.h) * u;
+
+        let beta: T = self.residual_norm();
+        let mut h: General<T> = General::zero(m, m);
+        for j in 0..r {
+            for i in 0..r {
+                h[[i, j]] = s[[i, j]];
+            }
+        }
+
+        if beta > T::zero() {
+            for j in 0..r {
+                h[[r, j]] = beta * u[[m - 1, j]];
+            }
+            v.push(&self.f / &beta);
+        }
+        self.v = v;
+        self.h = h;
+        if beta == T::zero() {
+            let v_next: Vector<T> = self.orthogonal_vector();
+            self.v.push(v_next);
+        }
+        self.f = Vector::zero(self.n);
+    }
+
+    /// Returns a unit vector orthogonal to the basis
+    ///
+    /// A random vector is orthogonalized, the generator is seeded
+    /// deterministically, such that the results are reproducible.
+    fn orthogonal_vector(&self) -> Vector<T> {
+        let n: usize = self.n;
+        let j: usize = self.v.len();
+        let threshold: T = T::from_f64(0.1 * ((n - j) as f64 / n as f64).sqrt());
+        let mut rng: StdRng = StdRng::seed_from_u64(j as u64);
+
+        loop {
+            let mut w: Vector<T> =
+                Vector::new_column((0..n).map(|_| T::from_f64(rng.gen::<f64>() - 0.5)).collect());
+            let norm: T = w.eucl_norm();
+            orthogonalize(&self.v, &mut w);
+            let w_norm: T = w.eucl_norm();
+            if w_norm > threshold * norm {
+                return w / w_norm;
+            }
+        }
+    }
+}
+
+/// Orthogonalizes w against the orthonormal vectors v with two passes of
+/// classical Gram-Schmidt and returns the coefficients
+pub(super) fn orthogonalize<T>(v: &[Vector<T>], w: &mut Vector<T>) -> Vec<T>
+where
+    T: Real,
+{
+    let mut h: Vec<T> = vec![T::zero(); v.len()];
+    for _ in 0..2 {
+        let c: Vec<T> = v.iter().map(|v_i| v_i.dotp(w)).collect();
+        for ((v_i, c_i), h_i) in v.iter().zip(c).zip(h.iter_mut()) {
+            *w -= v_i * &c_i;
+            *h_i += c_i;
+        }
+    }
+    h
+}
+
+/// Tolerance of an eigenpair with the eigenvalue of magnitude lambda
+pub(super) fn converged<T>(residual: T, lambda: T, tolerance: T) -> bool
+where
+    T: Real,
+{
+    let floor: T = T::default_epsilon().pow(T::from_f64(2.0 / 3.0));
+    residual <= tolerance * lambda.max(floor)
+}
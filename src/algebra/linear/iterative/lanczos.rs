@@ -0,0 +1,165 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        iterative::{
+            krylov::{self, KrylovDecomposition},
+            IterativeEigenResult, LinearOperator, Which,
+        },
+        matrix::{General, SymmetricEigenDecomposition},
+        vector::Vector,
+        LinalgError,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Restarted Lanczos method
+///
+/// Computes $k$ eigenpairs of a symmetric matrix $A$. The Lanczos process
+/// with full reorthogonalization builds an orthonormal basis $V_m$ of the
+/// Krylov subspace $\mathcal{K}_m(A, v_0)$, the eigenpairs of the projected
+/// matrix $T_m = V_m^TAV_m$ are the Ritz pairs.
+///
+/// The dimension of the subspace is $m = \max(2k + 1, 20)$, limited by the
+/// dimension of $A$. If not all wanted Ritz pairs have converged, the method
+/// is restarted with the $k + (m - k) / 2$ most wanted Ritz vectors (thick
+/// restart), which is mathematically equivalent to the implicit restart with
+/// exact shifts.
+///
+/// A Ritz pair $(\theta, x)$ has converged, if $\lVert Ax - \theta x
+/// \rVert_2 \leq \epsilon \max(|\theta|, \epsilon_{mach}^{2/3})$.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{
+///     iterative::{IterativeEigenResult, Lanczos, Which},
+///     matrix::General,
+///     vector::Vector,
+/// };
+/// use mathru::{assert_relative_eq, matrix};
+///
+/// let a: General<f64> = matrix![  2.0, -1.0, 0.0, 0.0;
+///                                 -1.0, 2.0, -1.0, 0.0;
+///                                 0.0, -1.0, 2.0, -1.0;
+///                                 0.0, 0.0, -1.0, 2.0];
+///
+/// let lanczos: Lanczos<f64> = Lanczos::new(10, 1.0e-10);
+/// let result: IterativeEigenResult<f64> = lanczos.solve(&a, &Vector::one(4), 1, Which::LargestReal).unwrap();
+///
+/// assert_relative_eq!(result.values()[0], 2.0 + 2.0 * (std::f64::consts::PI / 5.0).cos(), epsilon = 1.0e-10);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Lanczos<T> {
+    max_iter: usize,
+    tolerance: T,
+}
+
+impl<T> Lanczos<T> {
+    /// Creates an instance of the restarted Lanczos method
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iter`: Maximum number of restarts
+    /// * `tolerance`: Relative residual tolerance of the eigenpairs
+    pub fn new(max_iter: usize, tolerance: T) -> Lanczos<T> {
+        Lanczos {
+            max_iter,
+            tolerance,
+        }
+    }
+}
+
+impl<T> Lanczos<T>
+where
+    T: Real,
+{
+    /// Computes $k$ eigenpairs of the symmetric matrix $A$ starting with the
+    /// vector v_0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the eigenvalues of the projected matrix can not
+    /// be computed. If the tolerance is not reached within the maximum
+    /// number of restarts, the current approximations are returned and the
+    /// result is marked as not converged.
+    ///
+    /// # Panics
+    ///
+    /// if $k$ is zero or larger than the dimension of $A$ or v_0 is zero
+    pub fn solve<A>(
+        &self,
+        a: &A,
+        v_0: &Vector<T>,
+        k: usize,
+        which: Which,
+    ) -> Result<IterativeEigenResult<T>, LinalgError>
+    where
+        A: LinearOperator<T>,
+    {
+        let n: usize = v_0.dim().0;
+        let m: usize = subspace_dim(n, k);
+        let mut basis: KrylovDecomposition<T> = KrylovDecomposition::new(v_0, m);
+
+        let mut iteration: usize = 0;
+        loop {
+            basis.expand(a);
+
+            // T_m is symmetric up to rounding errors
+            let h: &General<T> = basis.h();
+            let mut t: General<T> = General::zero(m, m);
+            for j in 0..m {
+                for i in 0..m {
+                    t[[i, j]] = (h[[i, j]] + h[[j, i]]) / T::from_f64(2.0);
+                }
+            }
+            let (theta, y): (Vec<T>, General<T>) = t.dec_symmetric_eigen()?.pair();
+
+            let values: Vec<(T, T)> = theta.iter().map(|t| (*t, T::zero())).collect();
+            let order: Vec<usize> = which.ordering(&values);
+            let beta: T = basis.residual_norm();
+            let residuals: Vec<T> = order[..k]
+                .iter()
+                .map(|i| beta * y[[m - 1, *i]].abs())
+                .collect();
+            let converged: bool = order[..k]
+                .iter()
+                .zip(residuals.iter())
+                .all(|(i, r)| krylov::converged(*r, theta[*i].abs(), self.tolerance));
+
+            if converged || iteration == self.max_iter || m == n {
+                let mut vectors: General<T> = General::zero(n, k);
+                for (j, i) in order[..k].iter().enumerate() {
+                    let y_i: Vec<T> = y.get_column(*i).convert_to_vec();
+                    let x: Vector<T> = basis.combine(&y_i);
+                    let norm: T = x.eucl_norm();
+                    vectors.set_column(&(x / norm), j);
+                }
+                let values: Vec<T> = order[..k].iter().map(|i| theta[*i]).collect();
+                return Ok(IterativeEigenResult::new(
+                    values, vectors, iteration, residuals, converged,
+                ));
+            }
+
+            let keep: usize = restart_dim(m, k);
+            let mut u: General<T> = General::zero(m, keep);
+            for (j, i) in order[..keep].iter().enumerate() {
+                u.set_column(&y.get_column(*i), j);
+            }
+            basis.restart(&u);
+            iteration += 1;
+        }
+    }
+}
+
+/// Dimension of the Krylov subspace
+pub(super) fn subspace_dim(n: usize, k: usize) -> usize {
+    assert!(0 < k && k <= n, "The number of eigenpairs must be in 1..=n");
+    (2 * k + 1).max(20).min(n)
+}
+
+/// Number of Ritz vectors kept at a restart
+pub(super) fn restart_dim(m: usize, k: usize) -> usize {
+    k + (m - k) / 2
+}
@@ -0,0 +1,94 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{CscMatrix, CsrMatrix, Diagonal, General},
+        vector::Vector,
+    },
+};
+
+/// Linear operator
+///
+/// A linear operator only has to evaluate the product $Ax$, the matrix $A$
+/// does not need to be stored explicitly.
+///
+/// The trait is implemented for closures `Fn(&Vector<T>) -> Vector<T>`.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{iterative::LinearOperator, vector::Vector};
+/// use mathru::vector;
+///
+/// // Finite difference approximation of the second derivative
+/// let laplace = |x: &Vector<f64>| -> Vector<f64> {
+///     let n: usize = x.dim().0;
+///     let mut y: Vector<f64> = Vector::zero(n);
+///     for i in 0..n {
+///         y[i] = 2.0 * x[i];
+///         if i > 0 {
+///             y[i] -= x[i - 1];
+///         }
+///         if i + 1 < n {
+///             y[i] -= x[i + 1];
+///         }
+///     }
+///     y
+/// };
+///
+/// assert_eq!(laplace.mul_vec(&vector![1.0; 1.0; 1.0]), vector![1.0; 0.0; 1.0]);
+/// ```
+pub trait LinearOperator<T> {
+    /// Computes the product $Ax$
+    fn mul_vec(&self, x: &Vector<T>) -> Vector<T>;
+}
+
+impl<T, F> LinearOperator<T> for F
+where
+    F: Fn(&Vector<T>) -> Vector<T>,
+{
+    fn mul_vec(&self, x: &Vector<T>) -> Vector<T> {
+        self(x)
+    }
+}
+
+impl<T> LinearOperator<T> for General<T>
+where
+    T: Field + Scalar,
+{
+    fn mul_vec(&self, x: &Vector<T>) -> Vector<T> {
+        self * x
+    }
+}
+
+impl<T> LinearOperator<T> for Diagonal<T>
+where
+    T: Field + Scalar,
+{
+    fn mul_vec(&self, x: &Vector<T>) -> Vector<T> {
+        let (m, n) = self.dim();
+        debug_assert_eq!(n, x.dim().0);
+        let mut y: Vector<T> = Vector::zero(m);
+        for i in 0..m.min(n) {
+            y[i] = self[[i, i]] * x[i];
+        }
+        y
+    }
+}
+
+impl<T> LinearOperator<T> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    fn mul_vec(&self, x: &Vector<T>) -> Vector<T> {
+        self * x
+    }
+}
+
+impl<T> LinearOperator<T> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    fn mul_vec(&self, x: &Vector<T>) -> Vector<T> {
+        self * x
+    }
+}
@@ -0,0 +1,251 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        iterative::{
+            krylov, IdentityPreconditioner, IterativeEigenResult, LinearOperator, Preconditioner,
+            Which,
+        },
+        matrix::{General, SymmetricEigenDecomposition},
+        vector::Vector,
+        LinalgError,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Locally optimal block preconditioned conjugate gradient method (LOBPCG)
+///
+/// Computes the $k$ smallest or largest eigenpairs of a symmetric matrix
+/// $A$, where $k$ is the number of columns of the initial approximation. In
+/// every iteration the Rayleigh-Ritz method is applied to the subspace
+/// spanned by the current approximations $X$, the preconditioned residuals
+/// $W = M^{-1}(AX - X\Theta)$ and the previous search directions $P$. The
+/// preconditioner has to be symmetric positive definite.
+///
+/// A Ritz pair $(\theta, x)$ has converged, if $\lVert Ax - \theta x
+/// \rVert_2 \leq \epsilon \max(|\theta|, \epsilon_{mach}^{2/3})$.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{
+///     iterative::{IterativeEigenResult, Lobpcg, Which},
+///     matrix::{Diagonal, General},
+/// };
+/// use mathru::assert_relative_eq;
+///
+/// let a: Diagonal<f64> = Diagonal::new(&(1..=100).map(|i| i as f64).collect::<Vec<f64>>());
+/// let x_0: General<f64> = General::new(100, 2, (0..200).map(|i| ((i * 7 % 13) as f64) - 6.0).collect());
+///
+/// let lobpcg: Lobpcg<f64> = Lobpcg::new(200, 1.0e-8);
+/// let result: IterativeEigenResult<f64> = lobpcg.solve(&a, &x_0, Which::SmallestReal).unwrap();
+///
+/// assert_relative_eq!(result.values()[0], 1.0, epsilon = 1.0e-8);
+/// assert_relative_eq!(result.values()[1], 2.0, epsilon = 1.0e-8);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Lobpcg<T> {
+    max_iter: usize,
+    tolerance: T,
+}
+
+impl<T> Lobpcg<T> {
+    /// Creates an instance of the LOBPCG method
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iter`: Maximum number of iterations
+    /// * `tolerance`: Relative residual tolerance of the eigenpairs
+    pub fn new(max_iter: usize, tolerance: T) -> Lobpcg<T> {
+        Lobpcg {
+            max_iter,
+            tolerance,
+        }
+    }
+}
+
+impl<T> Lobpcg<T>
+where
+    T: Real,
+{
+    /// Computes the eigenpairs of the symmetric matrix $A$ starting with the
+    /// columns of x_0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Rayleigh-Ritz problem can not be solved. If
+    /// the tolerance is not reached within the maximum number of iterations,
+    /// the current approximations are returned and the result is marked as
+    /// not converged.
+    ///
+    /// # Panics
+    ///
+    /// if `which` selects the eigenvalues by magnitude or the columns of x_0
+    /// are linearly dependent
+    pub fn solve<A>(
+        &self,
+        a: &A,
+        x_0: &General<T>,
+        which: Which,
+    ) -> Result<IterativeEigenResult<T>, LinalgError>
+    where
+        A: LinearOperator<T>,
+    {
+        self.solve_preconditioned(a, x_0, which, &IdentityPreconditioner)
+    }
+
+    /// Computes the eigenpairs of the symmetric matrix $A$ starting with the
+    /// columns of x_0 using the preconditioner m
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Rayleigh-Ritz problem can not be solved. If
+    /// the tolerance is not reached within the maximum number of iterations,
+    /// the current approximations are returned and the result is marked as
+    /// not converged.
+    ///
+    /// # Panics
+    ///
+    /// if `which` selects the eigenvalues by magnitude or the columns of x_0
+    /// are linearly dependent
+    pub fn solve_preconditioned<A, M>(
+        &self,
+        a: &A,
+        x_0: &General<T>,
+        which: Which,
+        m: &M,
+    ) -> Result<IterativeEigenResult<T>, LinalgError>
+    where
+        A: LinearOperator<T>,
+        M: Preconditioner<T>,
+    {
+        assert!(
+            matches!(which, Which::LargestReal | Which::SmallestReal),
+            "LOBPCG computes the smallest or largest eigenvalues"
+        );
+        let (n, k): (usize, usize) = x_0.dim();
+
+        let mut x: Vec<Vector<T>> = Vec::with_capacity(k);
+        orthonormal_extend(&mut x, (0..k).map(|j| x_0.get_column(j)));
+        assert_eq!(
+            x.len(),
+            k,
+            "The initial approximations must be linearly independent"
+        );
+        let ax: Vec<Vector<T>> = x.iter().map(|x_j| a.mul_vec(x_j)).collect();
+
+        let (mut theta, mut x, mut ax, _) = rayleigh_ritz(&x, &ax, k, k, which)?;
+        let mut p: Vec<Vector<T>> = Vec::new();
+
+        let mut iteration: usize = 0;
+        loop {
+            let r: Vec<Vector<T>> = (0..k).map(|j| &ax[j] - &(&x[j] * &theta[j])).collect();
+            let residuals: Vec<T> = r.iter().map(|r_j| r_j.eucl_norm()).collect();
+            let active: Vec<bool> = (0..k)
+                .map(|j| !krylov::converged(residuals[j], theta[j].abs(), self.tolerance))
+                .collect();
+
+            if active.iter().all(|a_j| !a_j) || iteration == self.max_iter {
+                let mut vectors: General<T> = General::zero(n, k);
+                for (j, x_j) in x.iter().enumerate() {
+                    vectors.set_column(x_j, j);
+                }
+                let converged: bool = active.iter().all(|a_j| !a_j);
+                return Ok(IterativeEigenResult::new(
+                    theta, vectors, iteration, residuals, converged,
+                ));
+            }
+            iteration += 1;
+
+            // Orthonormal basis of [X, W, P], the converged columns do not
+            // contribute to W
+            let mut s: Vec<Vector<T>> = x.clone();
+            let w = r
+                .iter()
+                .zip(active.iter())
+                .filter(|(_, a_j)| **a_j)
+                .map(|(r_j, _)| m.precondition(r_j));
+            orthonormal_extend(&mut s, w.chain(p));
+            let mut a_s: Vec<Vector<T>> = ax;
+            for s_j in s[k..].iter() {
+                a_s.push(a.mul_vec(s_j));
+            }
+
+            let (theta_next, x_next, ax_next, p_next) = rayleigh_ritz(&s, &a_s, k, k, which)?;
+            theta = theta_next;
+            x = x_next;
+            ax = ax_next;
+            p = p_next;
+        }
+    }
+}
+
+/// Appends the candidates to the orthonormal vectors s, candidates which are
+/// numerically contained in the span of s are dropped
+fn orthonormal_extend<T, I>(s: &mut Vec<Vector<T>>, candidates: I)
+where
+    T: Real,
+    I: Iterator<Item = Vector<T>>,
+{
+    let threshold: T = T::default_epsilon().sqrt();
+    for mut w in candidates {
+        let norm: T = w.eucl_norm();
+        krylov::orthogonalize(s, &mut w);
+        let w_norm: T = w.eucl_norm();
+        if w_norm > threshold * norm {
+            s.push(w / w_norm);
+        }
+    }
+}
+
+type RitzPairs<T> = (Vec<T>, Vec<Vector<T>>, Vec<Vector<T>>, Vec<Vector<T>>);
+
+/// Rayleigh-Ritz method on the orthonormal basis s with a_s = As
+///
+/// Returns the k wanted Ritz values, the Ritz vectors X, the products AX and
+/// the components of X in the span of the basis vectors starting with
+/// index `split`.
+fn rayleigh_ritz<T>(
+    s: &[Vector<T>],
+    a_s: &[Vector<T>],
+    split: usize,
+    k: usize,
+    which: Which,
+) -> Result<RitzPairs<T>, LinalgError>
+where
+    T: Real,
+{
+    let d: usize = s.len();
+    let mut g: General<T> = General::zero(d, d);
+    for j in 0..d {
+        for i in 0..=j {
+            let g_ij: T = (s[i].dotp(&a_s[j]) + s[j].dotp(&a_s[i])) / T::from_f64(2.0);
+            g[[i, j]] = g_ij;
+            g[[j, i]] = g_ij;
+        }
+    }
+    let (values, c): (Vec<T>, General<T>) = g.dec_symmetric_eigen()?.pair();
+
+    let pairs: Vec<(T, T)> = values.iter().map(|v| (*v, T::zero())).collect();
+    let order: Vec<usize> = which.ordering(&pairs);
+
+    let combine = |vectors: &[Vector<T>], j: usize, from: usize| -> Vector<T> {
+        let mut x: Vector<T> = Vector::zero(vectors[0].dim().0);
+        for (l, v_l) in vectors.iter().enumerate().skip(from) {
+            x += v_l * &c[[l, j]];
+        }
+        x
+    };
+
+    let theta: Vec<T> = order[..k].iter().map(|j| values[*j]).collect();
+    let x: Vec<Vector<T>> = order[..k].iter().map(|j| combine(s, *j, 0)).collect();
+    let ax: Vec<Vector<T>> = order[..k].iter().map(|j| combine(a_s, *j, 0)).collect();
+    let p: Vec<Vector<T>> = if split < d {
+        order[..k].iter().map(|j| combine(s, *j, split)).collect()
+    } else {
+        Vec::new()
+    };
+
+    Ok((theta, x, ax, p))
+}
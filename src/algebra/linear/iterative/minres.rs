@@ -0,0 +1,166 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        iterative::{
+            IdentityPreconditioner, IterativeResult, LinearOperator, Preconditioner,
+        },
+        vector::Vector,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Minimal residual method (MINRES)
+///
+/// Solves $Ax = b$ for a symmetric, possibly indefinite matrix $A$. A
+/// preconditioner has to be symmetric positive definite.
+///
+/// The residual norms are not computed explicitly, but are updated by the
+/// recurrence of the method. With a preconditioner $M$ the recurrence yields
+/// the norm $\lVert r_k \rVert_{M^{-1}}$ instead of $\lVert r_k \rVert_2$.
+/// The iteration stops as soon as the residual norm is smaller than
+/// $\epsilon \lVert b \rVert_2$ or the maximum number of iterations is
+/// reached.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{iterative::{IterativeResult, Minres}, matrix::General, vector::Vector};
+/// use mathru::{assert_relative_eq, matrix, vector};
+///
+/// // symmetric, but indefinite
+/// let a: General<f64> = matrix![1.0, 2.0;
+///                               2.0, 1.0];
+/// let b: Vector<f64> = vector![3.0; 3.0];
+///
+/// let minres: Minres<f64> = Minres::new(10, 1.0e-12);
+/// let result: IterativeResult<f64> = minres.solve(&a, &b, &Vector::zero(2));
+///
+/// assert_relative_eq!(*result.x(), vector![1.0; 1.0], epsilon = 1.0e-10);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Minres<T> {
+    max_iter: usize,
+    tolerance: T,
+}
+
+impl<T> Minres<T> {
+    /// Creates an instance of the minimal residual method
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iter`: Maximum number of iterations
+    /// * `tolerance`: Relative residual tolerance
+    pub fn new(max_iter: usize, tolerance: T) -> Minres<T> {
+        Minres {
+            max_iter,
+            tolerance,
+        }
+    }
+}
+
+impl<T> Minres<T>
+where
+    T: Real,
+{
+    /// Solves $Ax = b$ starting with the initial approximation x_0
+    pub fn solve<A>(&self, a: &A, b: &Vector<T>, x_0: &Vector<T>) -> IterativeResult<T>
+    where
+        A: LinearOperator<T>,
+    {
+        self.solve_preconditioned(a, b, x_0, &IdentityPreconditioner)
+    }
+
+    /// Solves $Ax = b$ starting with the initial approximation x_0 using the
+    /// preconditioner m
+    pub fn solve_preconditioned<A, M>(
+        &self,
+        a: &A,
+        b: &Vector<T>,
+        x_0: &Vector<T>,
+        m: &M,
+    ) -> IterativeResult<T>
+    where
+        A: LinearOperator<T>,
+        M: Preconditioner<T>,
+    {
+        let n: usize = b.dim().0;
+        let threshold: T = self.tolerance * b.eucl_norm();
+
+        let mut x: Vector<T> = x_0.clone();
+        let mut r_1: Vector<T> = b - &a.mul_vec(&x);
+        let mut y: Vector<T> = m.precondition(&r_1);
+        let mut r_2: Vector<T> = r_1.clone();
+
+        let beta_1: T = r_1.dotp(&y).sqrt();
+        let mut residuals: Vec<T> = vec![beta_1];
+        if beta_1 <= threshold {
+            return IterativeResult::new(x, 0, residuals, true);
+        }
+
+        let mut beta: T = beta_1;
+        let mut beta_old: T = T::zero();
+        let mut d_bar: T = T::zero();
+        let mut epsilon: T = T::zero();
+        let mut phi_bar: T = beta_1;
+        let mut c: T = -T::one();
+        let mut s: T = T::zero();
+
+        let mut w: Vector<T> = Vector::zero(n);
+        let mut w_2: Vector<T> = Vector::zero(n);
+
+        for k in 1..=self.max_iter {
+            // Lanczos step
+            let v: Vector<T> = &y / &beta;
+            y = a.mul_vec(&v);
+            if k >= 2 {
+                y -= &r_1 * &(beta / beta_old);
+            }
+            let alpha: T = v.dotp(&y);
+            y -= &r_2 * &(alpha / beta);
+            r_1 = r_2;
+            r_2 = y;
+            y = m.precondition(&r_2);
+            beta_old = beta;
+            beta = r_2.dotp(&y);
+            if beta < T::zero() {
+                // preconditioner is not positive definite
+                return IterativeResult::new(x, k - 1, residuals, false);
+            }
+            beta = beta.sqrt();
+
+            // Apply previous rotation and compute the next one
+            let epsilon_old: T = epsilon;
+            let delta: T = c * d_bar + s * alpha;
+            let gamma_bar: T = s * d_bar - c * alpha;
+            epsilon = s * beta;
+            d_bar = -c * beta;
+
+            let gamma: T = (gamma_bar * gamma_bar + beta * beta)
+                .sqrt()
+                .max(T::default_epsilon());
+            c = gamma_bar / gamma;
+            s = beta / gamma;
+            let phi: T = c * phi_bar;
+            phi_bar = s * phi_bar;
+
+            // Update the solution
+            let w_1: Vector<T> = w_2;
+            w_2 = w;
+            w = (v - w_1 * epsilon_old - &w_2 * &delta) / gamma;
+            x += &w * &phi;
+
+            residuals.push(phi_bar);
+            if phi_bar <= threshold {
+                return IterativeResult::new(x, k, residuals, true);
+            }
+            if beta == T::zero() {
+                // the Krylov subspace is invariant
+                return IterativeResult::new(x, k, residuals, false);
+            }
+        }
+
+        IterativeResult::new(x, self.max_iter, residuals, false)
+    }
+}
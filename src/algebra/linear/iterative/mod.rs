@@ -0,0 +1,84 @@
+//! Iterative solvers for linear systems and eigenvalue problems
+//!
+//! The Krylov subspace methods solve $Ax = b$ by only evaluating matrix
+//! vector products $Ax$. The matrix is therefore passed as a
+//! [`LinearOperator`], which is implemented for the dense and sparse matrix
+//! types as well as for closures.
+//!
+//! - [`ConjugateGradient`]: symmetric positive definite matrices
+//! - [`Minres`]: symmetric (possibly indefinite) matrices
+//! - [`BiCgStab`]: general matrices
+//! - [`Gmres`]: general matrices, restarted
+//!
+//! Every solver can be combined with a [`Preconditioner`]:
+//!
+//! - [`Jacobi`]: diagonal scaling
+//! - [`Ssor`]: symmetric successive over-relaxation
+//! - [`Ilu0`]: incomplete LU factorization without fill-in
+//!
+//! A few eigenpairs of large matrices, selected by [`Which`], are computed
+//! by
+//!
+//! - [`Lanczos`]: symmetric matrices, restarted
+//! - [`Arnoldi`]: general matrices, restarted
+//! - [`Lobpcg`]: smallest or largest eigenpairs of symmetric matrices with
+//!   a preconditioner
+//!
+//! # Example
+//!
+//! ```
+//! use mathru::algebra::linear::{
+//!     iterative::{ConjugateGradient, IterativeResult, Jacobi},
+//!     matrix::{CsrMatrix, General},
+//!     vector::Vector,
+//! };
+//! use mathru::{matrix, vector};
+//!
+//! let a: General<f64> = matrix![4.0, 1.0, 0.0;
+//!                               1.0, 3.0, 1.0;
+//!                               0.0, 1.0, 2.0];
+//! let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+//! let x_0: Vector<f64> = Vector::zero(3);
+//!
+//! let jacobi: Jacobi<f64> = Jacobi::new(&CsrMatrix::from(&a)).unwrap();
+//! let cg: ConjugateGradient<f64> = ConjugateGradient::new(100, 1.0e-10);
+//!
+//! let result: IterativeResult<f64> = cg.solve_preconditioned(&a, &b, &x_0, &jacobi);
+//!
+//! assert!(result.converged());
+//! ```
+
+mod arnoldi;
+mod bicgstab;
+mod conjugategradient;
+mod gmres;
+mod ilu0;
+mod iterativeeigenresult;
+mod iterativeresult;
+mod jacobi;
+mod krylov;
+mod lanczos;
+mod linearoperator;
+mod lobpcg;
+mod minres;
+mod preconditioner;
+mod ssor;
+mod which;
+
+pub use self::{
+    arnoldi::Arnoldi,
+    bicgstab::BiCgStab,
+    conjugategradient::ConjugateGradient,
+    gmres::Gmres,
+    ilu0::Ilu0,
+    iterativeeigenresult::IterativeEigenResult,
+    iterativeresult::IterativeResult,
+    jacobi::Jacobi,
+    lanczos::Lanczos,
+    linearoperator::LinearOperator,
+    lobpcg::Lobpcg,
+    minres::Minres,
+    preconditioner::{IdentityPreconditioner, Preconditioner},
+    ssor::Ssor,
+    which::Which,
+};
@@ -0,0 +1,23 @@
+use crate::algebra::linear::vector::Vector;
+
+/// Preconditioner
+///
+/// A preconditioner $M$ approximates the system matrix $A$ such that systems
+/// $Mz = r$ are cheap to solve.
+pub trait Preconditioner<T> {
+    /// Computes $z = M^{-1}r$
+    fn precondition(&self, r: &Vector<T>) -> Vector<T>;
+}
+
+/// Identity preconditioner $M = I$, i.e. no preconditioning at all
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IdentityPreconditioner;
+
+impl<T> Preconditioner<T> for IdentityPreconditioner
+where
+    T: Clone,
+{
+    fn precondition(&self, r: &Vector<T>) -> Vector<T> {
+        r.clone()
+    }
+}
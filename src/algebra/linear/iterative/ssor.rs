@@ -0,0 +1,101 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{iterative::Preconditioner, matrix::CsrMatrix, vector::Vector},
+};
+
+/// Symmetric successive over-relaxation (SSOR) preconditioner
+///
+/// With the splitting $A = L + D + U$ into the strictly lower triangular part,
+/// the diagonal and the strictly upper triangular part, the preconditioner is
+///
+/// ```math
+/// M = \frac{\omega}{2 - \omega} \left(\frac{D}{\omega} + L\right)
+/// \left(\frac{D}{\omega}\right)^{-1} \left(\frac{D}{\omega} + U\right)
+/// ```
+///
+/// For symmetric positive definite $A$ and $0 < \omega < 2$ the preconditioner
+/// is symmetric positive definite as well.
+#[derive(Clone, Debug)]
+pub struct Ssor<T> {
+    a: CsrMatrix<T>,
+    diag: Vec<T>,
+    omega: T,
+}
+
+impl<T> Ssor<T>
+where
+    T: Field + Scalar,
+{
+    /// Creates the SSOR preconditioner of the square matrix a
+    ///
+    /// # Arguments
+    ///
+    /// * `a`: system matrix
+    /// * `omega`: relaxation parameter, 0 < omega < 2
+    ///
+    /// # Errors
+    ///
+    /// if a diagonal entry is zero
+    ///
+    /// # Panics
+    ///
+    /// if omega is not inside (0, 2)
+    pub fn new(a: &CsrMatrix<T>, omega: T) -> Result<Ssor<T>, LinalgError> {
+        debug_assert_eq!(a.nrows(), a.ncols());
+        assert!(
+            omega > T::zero() && omega < T::from_f64(2.0),
+            "relaxation parameter has to be inside (0, 2)"
+        );
+
+        let diag: Vec<T> = a.diagonal();
+        if diag.iter().any(|d| *d == T::zero()) {
+            return Err(LinalgError::Singular);
+        }
+
+        Ok(Ssor {
+            a: a.clone(),
+            diag,
+            omega,
+        })
+    }
+}
+
+impl<T> Preconditioner<T> for Ssor<T>
+where
+    T: Field + Scalar,
+{
+    fn precondition(&self, r: &Vector<T>) -> Vector<T> {
+        let n: usize = self.diag.len();
+        debug_assert_eq!(r.dim().0, n);
+        let mut z: Vector<T> = r.clone();
+
+        // (D / omega + L) y = r
+        for i in 0..n {
+            let (cols, values) = self.a.row(i);
+            let mut sum: T = z[i];
+            for (j, a_ij) in cols.iter().zip(values.iter()).take_while(|(j, _)| **j < i) {
+                sum -= *a_ij * z[*j];
+            }
+            z[i] = sum * self.omega / self.diag[i];
+        }
+
+        // w = D / omega y
+        for i in 0..n {
+            z[i] = z[i] * self.diag[i] / self.omega;
+        }
+
+        // (D / omega + U) z = w
+        for i in (0..n).rev() {
+            let (cols, values) = self.a.row(i);
+            let mut sum: T = z[i];
+            for (j, a_ij) in cols.iter().zip(values.iter()).filter(|(j, _)| **j > i) {
+                sum -= *a_ij * z[*j];
+            }
+            z[i] = sum * self.omega / self.diag[i];
+        }
+
+        let scale: T = (T::from_f64(2.0) - self.omega) / self.omega;
+        z * scale
+    }
+}
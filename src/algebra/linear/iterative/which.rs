@@ -0,0 +1,50 @@
+use crate::algebra::abstr::Real;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Part of the spectrum computed by the iterative eigensolvers
+///
+/// The solvers converge fastest to eigenvalues at the boundary of the
+/// spectrum. Eigenvalues of smallest magnitude are interior eigenvalues of
+/// indefinite matrices and may require many restarts.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Which {
+    /// Eigenvalues of largest magnitude
+    LargestMagnitude,
+    /// Eigenvalues of smallest magnitude
+    SmallestMagnitude,
+    /// Eigenvalues with the largest real part, i.e. the largest eigenvalues
+    /// of a symmetric matrix
+    LargestReal,
+    /// Eigenvalues with the smallest real part, i.e. the smallest eigenvalues
+    /// of a symmetric matrix
+    SmallestReal,
+}
+
+impl Which {
+    /// Returns the indices of the eigenvalues `(re, im)`, starting with the
+    /// most wanted one
+    pub(super) fn ordering<T>(&self, values: &[(T, T)]) -> Vec<usize>
+    where
+        T: Real,
+    {
+        let key = |(re, im): (T, T)| -> T {
+            match self {
+                Which::LargestMagnitude => -(re * re + im * im).sqrt(),
+                Which::SmallestMagnitude => (re * re + im * im).sqrt(),
+                Which::LargestReal => -re,
+                Which::SmallestReal => re,
+            }
+        };
+
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.sort_by(|i, j| {
+            key(values[*i])
+                .partial_cmp(&key(values[*j]))
+                .unwrap_or(Ordering::Equal)
+        });
+        indices
+    }
+}
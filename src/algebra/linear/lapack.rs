@@ -4,8 +4,8 @@ use lapack_sys as ffi;
 use std::os::raw::c_char;
 
 macro_rules! lapack_real (
-    ($T: ty, $xgehrd: path, $xorghr: path, $xgeev: path, $xgetrf: path, $xgeqrf: path, $xorgqr: path, $xgetri: path, $xtrtri: path, $xpotrf: path,
-    $xgetrs: path)
+    ($T: ty, $xgehrd: path, $xorghr: path, $xgeev: path, $xsyevd: path, $xgesdd: path, $xgelsd: path, $xgetrf: path, $xgeqrf: path, $xgeqp3: path, $xorgqr: path, $xgetri: path, $xtrtri: path,
+    $xpotrf: path, $xgetrs: path, $xggev: path, $xsygv: path)
     => (
         impl Lapack for $T
        	{
@@ -44,27 +44,79 @@ macro_rules! lapack_real (
             }
 
             fn xgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [Self], lda: i32,
-                     w: &mut [Self],
+                     w: &mut [Self], wi: &mut [Self],
                      vl: &mut [Self], ldvl: i32, vr: &mut [Self], ldvr: i32,
                      work: &mut [Self], lwork: i32, info: &mut i32)
           	{
-          		let mut wi = Vec::with_capacity(n as usize);
-                unsafe { $xgeev(jobvl, jobvr, n, a, lda, w, &mut wi, vl, ldvl, vr, ldvr, work, lwork, info) }
+                unsafe { $xgeev(jobvl, jobvr, n, a, lda, w, wi, vl, ldvl, vr, ldvr, work, lwork, info) }
             }
 
 
             fn xgeev_work_size(jobvl: u8, jobvr: u8, n: i32, a: &mut [Self], lda: i32,
-                               w: &mut [Self], vl: &mut [Self], ldvl: i32,
+                               w: &mut [Self], wi: &mut [Self], vl: &mut [Self], ldvl: i32,
                                vr: &mut [Self], ldvr: i32, info: &mut i32) -> i32
           	{
                 let mut work = [<$T>::zero()];
-                let mut wi = Vec::with_capacity(n as usize);
                 let lwork = -1 as i32;
 
-                unsafe { $xgeev(jobvl, jobvr, n, a, lda, w, &mut wi, vl, ldvl, vr, ldvr, &mut work, lwork, info) };
+                unsafe { $xgeev(jobvl, jobvr, n, a, lda, w, wi, vl, ldvl, vr, ldvr, &mut work, lwork, info) };
                 work[0] as i32
 			}
 
+			//Symmetric eigen decomposition
+			fn xsyevd(jobz: u8, uplo: u8, n: i32, a: &mut [Self], lda: i32, w: &mut [Self],
+					  work: &mut [Self], lwork: i32, iwork: &mut [i32], liwork: i32, info: &mut i32)
+			{
+				unsafe { $xsyevd(jobz, uplo, n, a, lda, w, work, lwork, iwork, liwork, info) }
+			}
+
+			fn xsyevd_work_size(jobz: u8, uplo: u8, n: i32, a: &mut [Self], lda: i32, w: &mut [Self],
+								info: &mut i32) -> (i32, i32)
+			{
+				let mut work = [<$T>::zero()];
+				let mut iwork = [0];
+				let lwork = -1 as i32;
+				let liwork = -1 as i32;
+
+				unsafe { $xsyevd(jobz, uplo, n, a, lda, w, &mut work, lwork, &mut iwork, liwork, info) };
+				(work[0] as i32, iwork[0])
+			}
+
+			//Singular value decomposition
+			fn xgesdd(jobz: u8, m: i32, n: i32, a: &mut [Self], lda: i32, s: &mut [Self], u: &mut [Self], ldu: i32,
+					  vt: &mut [Self], ldvt: i32, work: &mut [Self], lwork: i32, iwork: &mut [i32], info: &mut i32)
+			{
+				unsafe { $xgesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, iwork, info) }
+			}
+
+			fn xgesdd_work_size(jobz: u8, m: i32, n: i32, a: &mut [Self], lda: i32, s: &mut [Self], u: &mut [Self],
+								ldu: i32, vt: &mut [Self], ldvt: i32, iwork: &mut [i32], info: &mut i32) -> i32
+			{
+				let mut work = [<$T>::zero()];
+				let lwork = -1 as i32;
+
+				unsafe { $xgesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt, &mut work, lwork, iwork, info) };
+				work[0] as i32
+			}
+
+			//Least squares
+			fn xgelsd(m: i32, n: i32, nrhs: i32, a: &mut [Self], lda: i32, b: &mut [Self], ldb: i32, s: &mut [Self],
+					  rcond: Self, rank: &mut i32, work: &mut [Self], lwork: i32, iwork: &mut [i32], info: &mut i32)
+			{
+				unsafe { $xgelsd(m, n, nrhs, a, lda, b, ldb, s, rcond, rank, work, lwork, iwork, info) }
+			}
+
+			fn xgelsd_work_size(m: i32, n: i32, nrhs: i32, a: &mut [Self], lda: i32, b: &mut [Self], ldb: i32,
+								s: &mut [Self], rcond: Self, rank: &mut i32, info: &mut i32) -> (i32, i32)
+			{
+				let mut work = [<$T>::zero()];
+				let mut iwork = [0];
+				let lwork = -1 as i32;
+
+				unsafe { $xgelsd(m, n, nrhs, a, lda, b, ldb, s, rcond, rank, &mut work, lwork, &mut iwork, info) };
+				(work[0] as i32, iwork[0])
+			}
+
 			//LU decomposition
 			fn xgetrf(m: i32, n: i32, a: &mut [Self], lda: i32, ipiv: &mut [i32], info: &mut i32)
 			{
@@ -86,6 +138,22 @@ macro_rules! lapack_real (
                 work[0] as i32
 			}
 
+			fn xgeqp3(m: i32, n: i32, a: &mut [Self], lda: i32, jpvt: &mut [i32], tau: &mut [Self], work: &mut [Self],
+					  lwork: i32, info: &mut i32)
+			{
+				unsafe { $xgeqp3(m, n, a, lda, jpvt, tau, work, lwork, info) };
+			}
+
+			fn xgeqp3_work_size(m: i32, n: i32, a: &mut [Self], lda: i32, jpvt: &mut [i32], tau: &mut [Self],
+								info: &mut i32) -> i32
+			{
+				let mut work = [<$T>::zero()];
+				let lwork = -1 as i32;
+
+				unsafe { $xgeqp3(m, n, a, lda, jpvt, tau, &mut work, lwork, info) };
+				work[0] as i32
+			}
+
 			fn xorgqr(m: i32, n: i32, k: i32, a: &mut [Self], lda: i32, tau: &mut [Self], work: &mut [Self], lwork:
 			i32,
 			info: &mut i32)
@@ -134,6 +202,43 @@ macro_rules! lapack_real (
 					$xgetrs('N' as u8, n, nrhs, a, lda, ipiv, b, ldb, info);
 				}
 			}
+
+			//Generalized eigen decomposition
+			fn xggev(jobvl: u8, jobvr: u8, n: i32, a: &mut [Self], lda: i32, b: &mut [Self], ldb: i32,
+					 alphar: &mut [Self], alphai: &mut [Self], beta: &mut [Self], vl: &mut [Self], ldvl: i32,
+					 vr: &mut [Self], ldvr: i32, work: &mut [Self], lwork: i32, info: &mut i32)
+			{
+				unsafe { $xggev(jobvl, jobvr, n, a, lda, b, ldb, alphar, alphai, beta, vl, ldvl, vr, ldvr, work, lwork, info) }
+			}
+
+			fn xggev_work_size(jobvl: u8, jobvr: u8, n: i32, a: &mut [Self], lda: i32, b: &mut [Self], ldb: i32,
+							   alphar: &mut [Self], alphai: &mut [Self], beta: &mut [Self], vl: &mut [Self], ldvl: i32,
+							   vr: &mut [Self], ldvr: i32, info: &mut i32) -> i32
+			{
+				let mut work = [<$T>::zero()];
+				let lwork = -1 as i32;
+
+				unsafe { $xggev(jobvl, jobvr, n, a, lda, b, ldb, alphar, alphai, beta, vl, ldvl, vr, ldvr, &mut work, lwork, info) };
+
+				work[0] as i32
+			}
+
+			fn xsygv(itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [Self], lda: i32, b: &mut [Self], ldb: i32,
+					 w: &mut [Self], work: &mut [Self], lwork: i32, info: &mut i32)
+			{
+				unsafe { $xsygv(&[itype], jobz, uplo, n, a, lda, b, ldb, w, work, lwork, info) }
+			}
+
+			fn xsygv_work_size(itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [Self], lda: i32, b: &mut [Self],
+							   ldb: i32, w: &mut [Self], info: &mut i32) -> i32
+			{
+				let mut work = [<$T>::zero()];
+				let lwork = -1 as i32;
+
+				unsafe { $xsygv(&[itype], jobz, uplo, n, a, lda, b, ldb, w, &mut work, lwork, info) };
+
+				work[0] as i32
+			}
       	}
     )
 );
@@ -143,13 +248,19 @@ lapack_real!(
     lapack::sgehrd,
     lapack::sorghr,
     lapack::sgeev,
+    lapack::ssyevd,
+    lapack::sgesdd,
+    lapack::sgelsd,
     lapack::sgetrf,
     lapack::sgeqrf,
+    lapack::sgeqp3,
     lapack::sorgqr,
     lapack::sgetri,
     lapack::strtri,
     lapack::spotrf,
-    lapack::sgetrs
+    lapack::sgetrs,
+    lapack::sggev,
+    lapack::ssygv
 );
 
 lapack_real!(
@@ -157,17 +268,23 @@ lapack_real!(
     lapack::dgehrd,
     lapack::dorghr,
     lapack::dgeev,
+    lapack::dsyevd,
+    lapack::dgesdd,
+    lapack::dgelsd,
     lapack::dgetrf,
     lapack::dgeqrf,
+    lapack::dgeqp3,
     lapack::dorgqr,
     lapack::dgetri,
     lapack::dtrtri,
     lapack::dpotrf,
-    lapack::dgetrs
+    lapack::dgetrs,
+    lapack::dggev,
+    lapack::dsygv
 );
 
 macro_rules! lapack_complex (
-    ($T: ty, $xgehrd: path, $xorghr: path, $xgeev: path, $xgetrf: path, $xgeqrf: path, $xorgqr: path, $xgetri: path, $xtrtri: path, $xpotrf: path, $xgetrs: path)
+    ($T: ty, $xgehrd: path, $xorghr: path, $xgeev: path, $xsyevd: path, $xgesdd: path, $xgelsd: path, $xgetrf: path, $xgeqrf: path, $xgeqp3: path, $xorgqr: path, $xgetri: path, $xtrtri: path, $xpotrf: path, $xgetrs: path, $xggev: path, $xsygv: path)
     => (
 		impl Lapack for Complex<$T>
 		{
@@ -208,6 +325,7 @@ macro_rules! lapack_complex (
 					 a: &mut [Self],
 					 lda: i32,
 					 w: &mut [Self],
+					 _wi: &mut [Self],
 					 vl: &mut [Self],
 					 ldvl: i32,
 					 vr: &mut [Self],
@@ -216,7 +334,7 @@ macro_rules! lapack_complex (
 					 lwork: i32,
 					 info: &mut i32)
 			{
-				let mut rwork: Vec<$T> = Vec::with_capacity(2*n as usize);
+				let mut rwork: Vec<$T> = vec![0.0; 2 * n as usize];
 				unsafe
 				{
 					$xgeev(&(jobvl as c_char), &(jobvr as c_char), &n, a.as_mut_ptr() as *mut _, &lda, w.as_mut_ptr() as *mut _, vl.as_mut_ptr() as *mut _, &ldvl, vr.as_mut_ptr() as *mut _, &ldvr, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr() as *mut _, info as *mut _)
@@ -229,6 +347,7 @@ macro_rules! lapack_complex (
 							   a: &mut [Self],
 							   lda: i32,
 							   w: &mut [Self],
+							   _wi: &mut [Self],
 							   vl: &mut [Self],
 							   ldvl: i32,
 							   vr: &mut [Self],
@@ -238,7 +357,7 @@ macro_rules! lapack_complex (
 			{
 				let mut work = [Self::zero()];
 				let lwork = -1 as i32;
-				let mut rwork: Vec<$T> = Vec::with_capacity(2*n as usize);
+				let mut rwork: Vec<$T> = vec![0.0; 2 * n as usize];
 
 				unsafe
 				{
@@ -247,6 +366,174 @@ macro_rules! lapack_complex (
 				work[0].re as i32
 			}
 
+			fn xsyevd(jobz: u8,
+					  uplo: u8,
+					  n: i32,
+					  a: &mut [Self],
+					  lda: i32,
+					  w: &mut [Self],
+					  work: &mut [Self],
+					  lwork: i32,
+					  iwork: &mut [i32],
+					  liwork: i32,
+					  info: &mut i32)
+			{
+				let n_usize: usize = n as usize;
+				let lrwork: i32 = if jobz == 'V' as u8 { 1 + 5 * n + 2 * n * n } else { n.max(1) };
+				let mut rwork: Vec<$T> = vec![0.0; lrwork as usize];
+				let mut w_re: Vec<$T> = vec![0.0; n_usize];
+				unsafe
+				{
+					$xsyevd(&(jobz as c_char), &(uplo as c_char), &n, a.as_mut_ptr() as *mut _, &lda, w_re.as_mut_ptr(), work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), &lrwork, iwork.as_mut_ptr(), &liwork, info as *mut _)
+				};
+				for (w_i, w_re_i) in w.iter_mut().zip(w_re.iter()) {
+					*w_i = Complex { re: *w_re_i, im: 0.0 };
+				}
+			}
+
+			fn xsyevd_work_size(jobz: u8,
+								uplo: u8,
+								n: i32,
+								a: &mut [Self],
+								lda: i32,
+								_w: &mut [Self],
+								info: &mut i32)
+								-> (i32, i32)
+			{
+				let mut work = [Self::zero()];
+				let mut rwork: [$T; 1] = [0.0];
+				let mut iwork = [0];
+				let mut w_re: Vec<$T> = vec![0.0; n as usize];
+				let lwork = -1 as i32;
+				unsafe
+				{
+					$xsyevd(&(jobz as c_char), &(uplo as c_char), &n, a.as_mut_ptr() as *mut _, &lda, w_re.as_mut_ptr(), work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), &lwork, iwork.as_mut_ptr(), &lwork, info as *mut _)
+				};
+
+				(work[0].re as i32, iwork[0])
+			}
+
+			fn xgesdd(jobz: u8,
+					  m: i32,
+					  n: i32,
+					  a: &mut [Self],
+					  lda: i32,
+					  s: &mut [Self],
+					  u: &mut [Self],
+					  ldu: i32,
+					  vt: &mut [Self],
+					  ldvt: i32,
+					  work: &mut [Self],
+					  lwork: i32,
+					  iwork: &mut [i32],
+					  info: &mut i32)
+			{
+				let min: usize = m.min(n) as usize;
+				let max: usize = m.max(n) as usize;
+				let lrwork: usize = if jobz == 'N' as u8 {
+					7 * min
+				} else {
+					(min * (5 * min + 7)).max(min * (2 * max + 2 * min + 1))
+				};
+				let mut rwork: Vec<$T> = vec![0.0; lrwork.max(1)];
+				let mut s_re: Vec<$T> = vec![0.0; min];
+				unsafe
+				{
+					$xgesdd(&(jobz as c_char), &m, &n, a.as_mut_ptr() as *mut _, &lda, s_re.as_mut_ptr(), u.as_mut_ptr() as *mut _, &ldu, vt.as_mut_ptr() as *mut _, &ldvt, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), iwork.as_mut_ptr(), info as *mut _)
+				};
+				for (s_i, s_re_i) in s.iter_mut().zip(s_re.iter()) {
+					*s_i = Complex { re: *s_re_i, im: 0.0 };
+				}
+			}
+
+			fn xgesdd_work_size(jobz: u8,
+								m: i32,
+								n: i32,
+								a: &mut [Self],
+								lda: i32,
+								_s: &mut [Self],
+								u: &mut [Self],
+								ldu: i32,
+								vt: &mut [Self],
+								ldvt: i32,
+								iwork: &mut [i32],
+								info: &mut i32)
+								-> i32
+			{
+				let mut work = [Self::zero()];
+				let lwork = -1 as i32;
+				let mut rwork: [$T; 1] = [0.0];
+				let mut s_re: Vec<$T> = vec![0.0; m.min(n) as usize];
+				unsafe
+				{
+					$xgesdd(&(jobz as c_char), &m, &n, a.as_mut_ptr() as *mut _, &lda, s_re.as_mut_ptr(), u.as_mut_ptr() as *mut _, &ldu, vt.as_mut_ptr() as *mut _, &ldvt, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), iwork.as_mut_ptr(), info as *mut _)
+				};
+
+				work[0].re as i32
+			}
+
+			fn xgelsd(m: i32,
+					  n: i32,
+					  nrhs: i32,
+					  a: &mut [Self],
+					  lda: i32,
+					  b: &mut [Self],
+					  ldb: i32,
+					  s: &mut [Self],
+					  rcond: Self,
+					  rank: &mut i32,
+					  work: &mut [Self],
+					  lwork: i32,
+					  iwork: &mut [i32],
+					  info: &mut i32)
+			{
+				let mut s_re: Vec<$T> = vec![0.0; m.min(n) as usize];
+
+				// query the size of the real workspace
+				let mut work_query = [Self::zero()];
+				let mut rwork_query: [$T; 1] = [0.0];
+				let mut iwork_query = [0];
+				unsafe
+				{
+					$xgelsd(&m, &n, &nrhs, a.as_mut_ptr() as *mut _, &lda, b.as_mut_ptr() as *mut _, &ldb, s_re.as_mut_ptr(), &rcond.re, rank as *mut _, work_query.as_mut_ptr() as *mut _, &(-1), rwork_query.as_mut_ptr(), iwork_query.as_mut_ptr(), info as *mut _)
+				};
+				let mut rwork: Vec<$T> = vec![0.0; (rwork_query[0] as usize).max(1)];
+
+				unsafe
+				{
+					$xgelsd(&m, &n, &nrhs, a.as_mut_ptr() as *mut _, &lda, b.as_mut_ptr() as *mut _, &ldb, s_re.as_mut_ptr(), &rcond.re, rank as *mut _, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), iwork.as_mut_ptr(), info as *mut _)
+				};
+				for (s_i, s_re_i) in s.iter_mut().zip(s_re.iter()) {
+					*s_i = Complex { re: *s_re_i, im: 0.0 };
+				}
+			}
+
+			fn xgelsd_work_size(m: i32,
+								n: i32,
+								nrhs: i32,
+								a: &mut [Self],
+								lda: i32,
+								b: &mut [Self],
+								ldb: i32,
+								_s: &mut [Self],
+								rcond: Self,
+								rank: &mut i32,
+								info: &mut i32)
+								-> (i32, i32)
+			{
+				let mut work = [Self::zero()];
+				let mut rwork: [$T; 1] = [0.0];
+				let mut iwork = [0];
+				let lwork = -1 as i32;
+				let mut s_re: Vec<$T> = vec![0.0; m.min(n) as usize];
+				unsafe
+				{
+					$xgelsd(&m, &n, &nrhs, a.as_mut_ptr() as *mut _, &lda, b.as_mut_ptr() as *mut _, &ldb, s_re.as_mut_ptr(), &rcond.re, rank as *mut _, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), iwork.as_mut_ptr(), info as *mut _)
+				};
+
+				(work[0].re as i32, iwork[0])
+			}
+
 			fn xgetrf(m: i32, n: i32, a: &mut [Self], lda: i32, ipiv: &mut [i32], info: &mut i32)
 			{
 				unsafe
@@ -274,6 +561,28 @@ macro_rules! lapack_complex (
                 work[0].re as i32
 			}
 
+			fn xgeqp3(m: i32, n: i32, a: &mut [Self], lda: i32, jpvt: &mut [i32], tau: &mut [Self], work: &mut [Self], lwork: i32, info: &mut i32)
+			{
+				let mut rwork: Vec<$T> = vec![0.0; 2 * n as usize];
+				unsafe
+				{
+					$xgeqp3(&m, &n, a.as_mut_ptr() as *mut _, &lda, jpvt.as_mut_ptr(), tau.as_mut_ptr() as *mut _, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), info as *mut _)
+				};
+			}
+
+			fn xgeqp3_work_size(m: i32, n: i32, a: &mut [Self], lda: i32, jpvt: &mut [i32], tau: &mut [Self], info: &mut i32) -> i32
+			{
+				let mut work = [Self::zero()];
+				let mut rwork: Vec<$T> = vec![0.0; 2 * n as usize];
+				let lwork = -1 as i32;
+
+				unsafe
+				{
+					$xgeqp3(&m, &n, a.as_mut_ptr() as *mut _, &lda, jpvt.as_mut_ptr(), tau.as_mut_ptr() as *mut _, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), info as *mut _)
+				};
+				work[0].re as i32
+			}
+
 			fn xorgqr(m: i32, n: i32, k: i32, a: &mut [Self], lda: i32, tau: &mut [Self], work: &mut [Self], lwork: i32, info: &mut i32)
 			{
 				unsafe
@@ -347,6 +656,109 @@ macro_rules! lapack_complex (
 					$xgetrs(&('N' as c_char), &n, &nrhs, a.as_ptr() as *const _, &lda, ipiv.as_ptr() as *const _, b.as_mut_ptr() as *mut _, &ldb, info as *mut _);
 				}
 			}
+
+			/// The eigenvalues are returned in alphar, alphai is not used
+			fn xggev(jobvl: u8,
+					 jobvr: u8,
+					 n: i32,
+					 a: &mut [Self],
+					 lda: i32,
+					 b: &mut [Self],
+					 ldb: i32,
+					 alphar: &mut [Self],
+					 _alphai: &mut [Self],
+					 beta: &mut [Self],
+					 vl: &mut [Self],
+					 ldvl: i32,
+					 vr: &mut [Self],
+					 ldvr: i32,
+					 work: &mut [Self],
+					 lwork: i32,
+					 info: &mut i32)
+			{
+				let mut rwork: Vec<$T> = vec![0.0; 8 * n as usize];
+				unsafe
+				{
+					$xggev(&(jobvl as c_char), &(jobvr as c_char), &n, a.as_mut_ptr() as *mut _, &lda, b.as_mut_ptr() as *mut _, &ldb, alphar.as_mut_ptr() as *mut _, beta.as_mut_ptr() as *mut _, vl.as_mut_ptr() as *mut _, &ldvl, vr.as_mut_ptr() as *mut _, &ldvr, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), info as *mut _)
+				};
+			}
+
+			fn xggev_work_size(jobvl: u8,
+							   jobvr: u8,
+							   n: i32,
+							   a: &mut [Self],
+							   lda: i32,
+							   b: &mut [Self],
+							   ldb: i32,
+							   alphar: &mut [Self],
+							   _alphai: &mut [Self],
+							   beta: &mut [Self],
+							   vl: &mut [Self],
+							   ldvl: i32,
+							   vr: &mut [Self],
+							   ldvr: i32,
+							   info: &mut i32)
+							   -> i32
+			{
+				let mut work = [Self::zero()];
+				let lwork = -1 as i32;
+				let mut rwork: Vec<$T> = vec![0.0; 8 * n as usize];
+
+				unsafe
+				{
+					$xggev(&(jobvl as c_char), &(jobvr as c_char), &n, a.as_mut_ptr() as *mut _, &lda, b.as_mut_ptr() as *mut _, &ldb, alphar.as_mut_ptr() as *mut _, beta.as_mut_ptr() as *mut _, vl.as_mut_ptr() as *mut _, &ldvl, vr.as_mut_ptr() as *mut _, &ldvr, work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), info as *mut _)
+				};
+				work[0].re as i32
+			}
+
+			fn xsygv(itype: i32,
+					 jobz: u8,
+					 uplo: u8,
+					 n: i32,
+					 a: &mut [Self],
+					 lda: i32,
+					 b: &mut [Self],
+					 ldb: i32,
+					 w: &mut [Self],
+					 work: &mut [Self],
+					 lwork: i32,
+					 info: &mut i32)
+			{
+				let n_usize: usize = n as usize;
+				let mut rwork: Vec<$T> = vec![0.0; (3 * n_usize).max(3) - 2];
+				let mut w_re: Vec<$T> = vec![0.0; n_usize];
+				unsafe
+				{
+					$xsygv(&itype, &(jobz as c_char), &(uplo as c_char), &n, a.as_mut_ptr() as *mut _, &lda, b.as_mut_ptr() as *mut _, &ldb, w_re.as_mut_ptr(), work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), info as *mut _)
+				};
+				for (w_i, w_re_i) in w.iter_mut().zip(w_re.iter()) {
+					*w_i = Complex { re: *w_re_i, im: 0.0 };
+				}
+			}
+
+			fn xsygv_work_size(itype: i32,
+							   jobz: u8,
+							   uplo: u8,
+							   n: i32,
+							   a: &mut [Self],
+							   lda: i32,
+							   b: &mut [Self],
+							   ldb: i32,
+							   _w: &mut [Self],
+							   info: &mut i32)
+							   -> i32
+			{
+				let mut work = [Self::zero()];
+				let lwork = -1 as i32;
+				let mut rwork: Vec<$T> = vec![0.0; (3 * n as usize).max(3) - 2];
+				let mut w_re: Vec<$T> = vec![0.0; n as usize];
+
+				unsafe
+				{
+					$xsygv(&itype, &(jobz as c_char), &(uplo as c_char), &n, a.as_mut_ptr() as *mut _, &lda, b.as_mut_ptr() as *mut _, &ldb, w_re.as_mut_ptr(), work.as_mut_ptr() as *mut _, &lwork, rwork.as_mut_ptr(), info as *mut _)
+				};
+				work[0].re as i32
+			}
 		}
 	)
 );
@@ -356,13 +768,19 @@ lapack_complex!(
     ffi::cgehrd_,
     ffi::cunghr_,
     ffi::cgeev_,
+    ffi::cheevd_,
+    ffi::cgesdd_,
+    ffi::cgelsd_,
     ffi::cgetrf_,
     ffi::cgeqrf_,
+    ffi::cgeqp3_,
     ffi::cungqr_,
     ffi::cgetri_,
     ffi::ctrtri_,
     ffi::cpotrf_,
-    ffi::cgetrs_
+    ffi::cgetrs_,
+    ffi::cggev_,
+    ffi::chegv_
 );
 
 lapack_complex!(
@@ -370,11 +788,17 @@ lapack_complex!(
     ffi::zgehrd_,
     ffi::zunghr_,
     ffi::zgeev_,
+    ffi::zheevd_,
+    ffi::zgesdd_,
+    ffi::zgelsd_,
     ffi::zgetrf_,
     ffi::zgeqrf_,
+    ffi::zgeqp3_,
     ffi::zungqr_,
     ffi::zgetri_,
     ffi::ztrtri_,
     ffi::zpotrf_,
-    ffi::zgetrs_
+    ffi::zgetrs_,
+    ffi::zggev_,
+    ffi::zhegv_
 );
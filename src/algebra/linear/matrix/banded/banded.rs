@@ -0,0 +1,104 @@
+use crate::algebra::abstr::{Field, Scalar};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Square band matrix with kl subdiagonals and ku superdiagonals
+///
+/// The band is stored column by column in the LAPACK band storage, i.e. the
+/// element $a_{ij}$ with $\max(0, j - k_u) \leq i \leq \min(n - 1, j + k_l)$
+/// is located in row $k_u + i - j$ of a $(k_l + k_u + 1) \times n$ array.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::matrix::{Banded, General};
+/// use mathru::matrix;
+///
+/// let mut a: Banded<f64> = Banded::zero(3, 1, 0);
+/// a[[0, 0]] = 1.0;
+/// a[[1, 0]] = 2.0;
+/// a[[1, 1]] = 3.0;
+/// a[[2, 1]] = 4.0;
+/// a[[2, 2]] = 5.0;
+///
+/// let a_ref: General<f64> = matrix![  1.0, 0.0, 0.0;
+///                                     2.0, 3.0, 0.0;
+///                                     0.0, 4.0, 5.0];
+///
+/// assert_eq!(General::from(a), a_ref);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Banded<T> {
+    pub(crate) n: usize,
+    pub(crate) kl: usize,
+    pub(crate) ku: usize,
+    pub(crate) data: Vec<T>,
+}
+
+impl<T> Banded<T> {
+    /// Creates a band matrix from the band storage
+    ///
+    /// # Arguments
+    ///
+    /// * `n`: number of rows and columns
+    /// * `kl`: number of subdiagonals
+    /// * `ku`: number of superdiagonals
+    /// * `data`: $(k_l + k_u + 1) \times n$ array in column-major order
+    ///
+    /// # Panics
+    ///
+    /// If data does not have $(k_l + k_u + 1) n$ entries
+    pub fn new(n: usize, kl: usize, ku: usize, data: Vec<T>) -> Banded<T> {
+        assert_eq!(
+            data.len(),
+            (kl + ku + 1) * n,
+            "The band storage must have (kl + ku + 1) * n entries"
+        );
+        Banded { n, kl, ku, data }
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    /// Returns the number of subdiagonals and superdiagonals
+    pub fn bandwidth(&self) -> (usize, usize) {
+        (self.kl, self.ku)
+    }
+
+    /// Returns true if the element $a_{ij}$ lies inside of the band
+    pub fn in_band(&self, i: usize, j: usize) -> bool {
+        i < self.n && j < self.n && i + self.ku >= j && j + self.kl >= i
+    }
+
+    /// Position of the element $a_{ij}$ in the band storage
+    pub(crate) fn offset(&self, i: usize, j: usize) -> usize {
+        j * (self.kl + self.ku + 1) + self.ku + i - j
+    }
+}
+
+impl<T> Banded<T>
+where
+    T: Field + Scalar,
+{
+    /// Creates a band matrix with all entries being zero
+    pub fn zero(n: usize, kl: usize, ku: usize) -> Banded<T> {
+        Banded {
+            n,
+            kl,
+            ku,
+            data: vec![T::zero(); (kl + ku + 1) * n],
+        }
+    }
+
+    /// Returns the element $a_{ij}$, which is zero outside of the band
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if self.in_band(i, j) {
+            self.data[self.offset(i, j)]
+        } else {
+            T::zero()
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{Banded, General, Solve},
+        vector::Vector,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Result of a Cholesky decomposition of a band matrix
+///
+/// ```math
+/// A = LL^T
+/// ```
+/// $L$ is lower triangular with the same lower bandwidth as $A$.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BandedCholeskyDec<T> {
+    l: Banded<T>,
+}
+
+impl<T> BandedCholeskyDec<T> {
+    /// Return the lower triangular band matrix $L$
+    pub fn l(self) -> Banded<T> {
+        self.l
+    }
+}
+
+impl<T> Banded<T>
+where
+    T: Real,
+{
+    /// Decomposes a symmetric, positive definite band matrix in
+    /// O(n k^2) operations
+    ///
+    /// Only the diagonal and the subdiagonals of the matrix are read, the
+    /// matrix is assumed to be symmetric.
+    ///
+    /// # Errors
+    ///
+    /// If the matrix is not positive definite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Banded, General, Solve};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  2.0, -1.0, 0.0;
+    ///                                 -1.0, 2.0, -1.0;
+    ///                                 0.0, -1.0, 2.0];
+    /// let b: Vector<f64> = vector![1.0; 0.0; 1.0];
+    ///
+    /// let x: Vector<f64> = Banded::from_general(&a, 1, 1).dec_cholesky().unwrap().solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    pub fn dec_cholesky(&self) -> Result<BandedCholeskyDec<T>, LinalgError> {
+        let n: usize = self.n;
+        let kd: usize = self.kl;
+        let mut l: Banded<T> = Banded::zero(n, kd, 0);
+
+        for j in 0..n {
+            let k_start: usize = j.saturating_sub(kd);
+            let mut s: T = self[[j, j]];
+            for k in k_start..j {
+                s -= l[[j, k]] * l[[j, k]];
+            }
+            if s <= T::zero() {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            let l_jj: T = s.sqrt();
+            l[[j, j]] = l_jj;
+
+            for i in (j + 1)..n.min(j + kd + 1) {
+                let mut s: T = self[[i, j]];
+                for k in i.saturating_sub(kd)..j {
+                    s -= l[[i, k]] * l[[j, k]];
+                }
+                l[[i, j]] = s / l_jj;
+            }
+        }
+
+        Ok(BandedCholeskyDec { l })
+    }
+}
+
+impl<T> BandedCholeskyDec<T>
+where
+    T: Real,
+{
+    /// Calculates the determinant of the decomposed matrix
+    pub fn det(&self) -> T {
+        let (n, _): (usize, usize) = self.l.dim();
+        (0..n).fold(T::one(), |det, j| det * self.l[[j, j]] * self.l[[j, j]])
+    }
+}
+
+impl<T> Solve<General<T>> for BandedCholeskyDec<T>
+where
+    T: Real,
+{
+    /// Solves $AX = B$
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        let (n, _): (usize, usize) = self.l.dim();
+        let (m, k): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the right-hand side do not match"
+        );
+        let (kd, _): (usize, usize) = self.l.bandwidth();
+
+        let mut b: General<T> = rhs.clone();
+        for c in 0..k {
+            // L y = b
+            for j in 0..n {
+                let y_j: T = b[[j, c]] / self.l[[j, j]];
+                b[[j, c]] = y_j;
+                for i in (j + 1)..n.min(j + kd + 1) {
+                    b[[i, c]] -= self.l[[i, j]] * y_j;
+                }
+            }
+
+            // L^T x = y
+            for j in (0..n).rev() {
+                let mut x_j: T = b[[j, c]];
+                for i in (j + 1)..n.min(j + kd + 1) {
+                    x_j -= self.l[[i, j]] * b[[i, c]];
+                }
+                b[[j, c]] = x_j / self.l[[j, j]];
+            }
+        }
+
+        Ok(b)
+    }
+}
+
+impl<T> Solve<Vector<T>> for BandedCholeskyDec<T>
+where
+    T: Real,
+{
+    /// Solves $Ax = b$
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let x: General<T> = self.solve(&rhs.data)?;
+
+        Ok(Vector { data: x })
+    }
+}
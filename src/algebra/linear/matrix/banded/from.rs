@@ -0,0 +1,62 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{Banded, General},
+};
+
+impl<T> From<Banded<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a band matrix into a general matrix
+    fn from(a: Banded<T>) -> General<T> {
+        let (n, _): (usize, usize) = a.dim();
+        let mut g: General<T> = General::zero(n, n);
+        for j in 0..n {
+            for i in j.saturating_sub(a.ku)..n.min(j + a.kl + 1) {
+                g[[i, j]] = a[[i, j]];
+            }
+        }
+        g
+    }
+}
+
+impl<T> Banded<T>
+where
+    T: Field + Scalar,
+{
+    /// Extracts the band of a square matrix. Entries outside of the band are
+    /// ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `a`: square matrix
+    /// * `kl`: number of subdiagonals
+    /// * `ku`: number of superdiagonals
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Banded, General};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  4.0, 1.0, 0.0;
+    ///                                 1.0, 4.0, 1.0;
+    ///                                 0.0, 1.0, 4.0];
+    ///
+    /// let b: Banded<f64> = Banded::from_general(&a, 1, 1);
+    ///
+    /// assert_eq!(General::from(b), a);
+    /// ```
+    pub fn from_general(a: &General<T>, kl: usize, ku: usize) -> Banded<T> {
+        let (m, n): (usize, usize) = a.dim();
+        assert_eq!(m, n, "A band matrix has to be square");
+
+        let mut b: Banded<T> = Banded::zero(n, kl, ku);
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..n.min(j + kl + 1) {
+                b[[i, j]] = a[[i, j]];
+            }
+        }
+        b
+    }
+}
@@ -0,0 +1,41 @@
+use crate::algebra::linear::matrix::Banded;
+use std::ops::{Index, IndexMut};
+
+impl<T> Index<[usize; 2]> for Banded<T> {
+    type Output = T;
+
+    /// Gets the element in the matrix
+    ///
+    /// # Panics
+    ///
+    /// If the element lies outside of the band
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        let [i, j] = index;
+        assert!(
+            self.in_band(i, j),
+            "The element [{}, {}] is not inside of the band",
+            i,
+            j
+        );
+        &self.data[self.offset(i, j)]
+    }
+}
+
+impl<T> IndexMut<[usize; 2]> for Banded<T> {
+    /// Sets the element in the matrix
+    ///
+    /// # Panics
+    ///
+    /// If the element lies outside of the band
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        let [i, j] = index;
+        assert!(
+            self.in_band(i, j),
+            "The element [{}, {}] is not inside of the band",
+            i,
+            j
+        );
+        let offset: usize = self.offset(i, j);
+        &mut self.data[offset]
+    }
+}
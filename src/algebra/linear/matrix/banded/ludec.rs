@@ -0,0 +1,186 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{Banded, General, Solve},
+        vector::Vector,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Result of a LU decomposition of a band matrix
+///
+/// ```math
+/// PA = LU
+/// ```
+/// The multipliers of $L$ are stored below the diagonal and $U$, whose upper
+/// bandwidth grows to $k_l + k_u$ due to the row interchanges, on and above
+/// the diagonal.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BandedLUDec<T> {
+    lu: Banded<T>,
+    pivots: Vec<usize>,
+}
+
+impl<T> Banded<T>
+where
+    T: Field + Scalar,
+{
+    /// Decomposes the band matrix with partial pivoting in
+    /// O(n k_l (k_l + k_u)) operations
+    ///
+    /// # Errors
+    ///
+    /// If the matrix is singular
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Banded, General, Solve};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0, 0.0, 0.0;
+    ///                                 3.0, 1.0, 2.0, 0.0;
+    ///                                 1.0, 3.0, 1.0, 2.0;
+    ///                                 0.0, 1.0, 3.0, 1.0];
+    /// let b: Vector<f64> = vector![3.0; 6.0; 7.0; 5.0];
+    ///
+    /// let x: Vector<f64> = Banded::from_general(&a, 2, 1).dec_lu().unwrap().solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![1.0; 1.0; 1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    pub fn dec_lu(&self) -> Result<BandedLUDec<T>, LinalgError> {
+        let n: usize = self.n;
+        let kl: usize = self.kl;
+        let ku: usize = kl + self.ku;
+
+        let mut lu: Banded<T> = Banded::zero(n, kl, ku);
+        for j in 0..n {
+            for i in j.saturating_sub(self.ku)..n.min(j + kl + 1) {
+                lu[[i, j]] = self[[i, j]];
+            }
+        }
+        let mut pivots: Vec<usize> = (0..n).collect();
+
+        for j in 0..n {
+            let i_end: usize = n.min(j + kl + 1);
+            let c_end: usize = n.min(j + ku + 1);
+
+            let mut p: usize = j;
+            let mut max: T = lu[[j, j]].abs();
+            for i in (j + 1)..i_end {
+                if lu[[i, j]].abs() > max {
+                    max = lu[[i, j]].abs();
+                    p = i;
+                }
+            }
+            if max == T::zero() {
+                return Err(LinalgError::Singular);
+            }
+
+            pivots[j] = p;
+            if p != j {
+                for c in j..c_end {
+                    let temp: T = lu[[j, c]];
+                    lu[[j, c]] = lu[[p, c]];
+                    lu[[p, c]] = temp;
+                }
+            }
+
+            let u_jj: T = lu[[j, j]];
+            for i in (j + 1)..i_end {
+                lu[[i, j]] /= u_jj;
+            }
+            for c in (j + 1)..c_end {
+                let u_jc: T = lu[[j, c]];
+                if u_jc != T::zero() {
+                    for i in (j + 1)..i_end {
+                        let l_ij: T = lu[[i, j]];
+                        lu[[i, c]] -= l_ij * u_jc;
+                    }
+                }
+            }
+        }
+
+        Ok(BandedLUDec { lu, pivots })
+    }
+}
+
+impl<T> BandedLUDec<T>
+where
+    T: Field + Scalar,
+{
+    /// Calculates the determinant of the decomposed matrix
+    pub fn det(&self) -> T {
+        self.pivots
+            .iter()
+            .enumerate()
+            .fold(T::one(), |det, (j, p)| {
+                let u_jj: T = self.lu[[j, j]];
+                if *p != j {
+                    -det * u_jj
+                } else {
+                    det * u_jj
+                }
+            })
+    }
+}
+
+impl<T> Solve<General<T>> for BandedLUDec<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $AX = B$
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        let (n, _): (usize, usize) = self.lu.dim();
+        let (m, k): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the right-hand side do not match"
+        );
+        let (kl, ku): (usize, usize) = self.lu.bandwidth();
+
+        let mut b: General<T> = rhs.clone();
+        for l in 0..k {
+            // L y = P b
+            for j in 0..n {
+                let p: usize = self.pivots[j];
+                if p != j {
+                    let temp: T = b[[j, l]];
+                    b[[j, l]] = b[[p, l]];
+                    b[[p, l]] = temp;
+                }
+                let b_jl: T = b[[j, l]];
+                for i in (j + 1)..n.min(j + kl + 1) {
+                    b[[i, l]] -= self.lu[[i, j]] * b_jl;
+                }
+            }
+
+            // U x = y
+            for i in (0..n).rev() {
+                let mut b_il: T = b[[i, l]];
+                for c in (i + 1)..n.min(i + ku + 1) {
+                    b_il -= self.lu[[i, c]] * b[[c, l]];
+                }
+                b[[i, l]] = b_il / self.lu[[i, i]];
+            }
+        }
+
+        Ok(b)
+    }
+}
+
+impl<T> Solve<Vector<T>> for BandedLUDec<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $Ax = b$
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let x: General<T> = self.solve(&rhs.data)?;
+
+        Ok(Vector { data: x })
+    }
+}
@@ -0,0 +1,10 @@
+mod banded;
+mod choleskydec;
+mod from;
+mod index;
+mod ludec;
+mod mul;
+
+pub use banded::Banded;
+pub use choleskydec::BandedCholeskyDec;
+pub use ludec::BandedLUDec;
@@ -0,0 +1,75 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{Banded, General},
+        vector::Vector,
+    },
+};
+use std::ops::Mul;
+
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a Banded<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    /// Multiplies a band matrix with a vector in O((kl + ku + 1) n)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Banded, General};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  4.0, 1.0, 0.0;
+    ///                                 1.0, 4.0, 1.0;
+    ///                                 0.0, 1.0, 4.0];
+    /// let b: Banded<f64> = Banded::from_general(&a, 1, 1);
+    /// let x: Vector<f64> = vector![1.0; 2.0; 3.0];
+    ///
+    /// assert_eq!(&b * &x, &a * &x);
+    /// ```
+    fn mul(self, rhs: &'b Vector<T>) -> Self::Output {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, _): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the vector do not match"
+        );
+
+        let mut y: Vector<T> = Vector::zero(n);
+        for j in 0..n {
+            let x_j: T = rhs[j];
+            for i in j.saturating_sub(self.ku)..n.min(j + self.kl + 1) {
+                y[i] += self[[i, j]] * x_j;
+            }
+        }
+        y
+    }
+}
+
+impl<'a, 'b, T> Mul<&'b General<T>> for &'a Banded<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    /// Multiplies a band matrix with a general matrix
+    fn mul(self, rhs: &'b General<T>) -> Self::Output {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, k): (usize, usize) = rhs.dim();
+        assert_eq!(n, m, "The dimensions of the matrices do not match");
+
+        let mut c: General<T> = General::zero(n, k);
+        for l in 0..k {
+            for j in 0..n {
+                let b_jl: T = rhs[[j, l]];
+                for i in j.saturating_sub(self.ku)..n.min(j + self.kl + 1) {
+                    c[[i, l]] += self[[i, j]] * b_jl;
+                }
+            }
+        }
+        c
+    }
+}
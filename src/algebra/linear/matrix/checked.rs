@@ -0,0 +1,97 @@
+use crate::algebra::linear::LinalgError;
+
+/// Addition, which returns an error instead of panicking if the dimensions
+/// of the operands do not match
+pub trait CheckedAdd<Rhs = Self> {
+    type Output;
+
+    /// Computes self + rhs
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the dimensions of self and rhs
+    /// differ
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{CheckedAdd, General}, LinalgError};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    /// let b: General<f64> = matrix![1.0, 2.0, 3.0];
+    ///
+    /// assert_eq!(a.checked_add(&a), Ok(matrix![2.0, 4.0; 6.0, 8.0]));
+    /// assert_eq!(a.checked_add(&b), Err(LinalgError::DimensionMismatch));
+    /// ```
+    fn checked_add(&self, rhs: &Rhs) -> Result<Self::Output, LinalgError>;
+}
+
+/// Subtraction, which returns an error instead of panicking if the
+/// dimensions of the operands do not match
+pub trait CheckedSub<Rhs = Self> {
+    type Output;
+
+    /// Computes self - rhs
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the dimensions of self and rhs
+    /// differ
+    fn checked_sub(&self, rhs: &Rhs) -> Result<Self::Output, LinalgError>;
+}
+
+/// Multiplication, which returns an error instead of panicking if the
+/// dimensions of the operands do not match
+pub trait CheckedMul<Rhs = Self> {
+    type Output;
+
+    /// Computes self * rhs
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the number of columns of self
+    /// differs from the number of rows of rhs
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{CheckedMul, General}, vector::Vector, LinalgError};
+    /// use mathru::{matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    /// let x: Vector<f64> = vector![1.0; 1.0];
+    /// let y: Vector<f64> = vector![1.0; 1.0; 1.0];
+    ///
+    /// assert_eq!(a.checked_mul(&x), Ok(vector![3.0; 7.0]));
+    /// assert_eq!(a.checked_mul(&y), Err(LinalgError::DimensionMismatch));
+    /// ```
+    fn checked_mul(&self, rhs: &Rhs) -> Result<Self::Output, LinalgError>;
+}
+
+/// Solution of a linear system, which returns an error instead of panicking
+/// if the dimensions of the operands do not match
+pub trait CheckedSolve<T> {
+    /// Solves self * x = rhs
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if self is not square or the
+    /// number of rows of self and rhs differ, and the errors of the
+    /// underlying solver otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{CheckedSolve, General}, vector::Vector, LinalgError};
+    /// use mathru::{matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![2.0, 0.0; 0.0, 4.0];
+    /// let b: Vector<f64> = vector![2.0; 4.0];
+    /// let c: Vector<f64> = vector![2.0; 4.0; 6.0];
+    ///
+    /// assert_eq!(a.checked_solve(&b), Ok(vector![1.0; 1.0]));
+    /// assert_eq!(a.checked_solve(&c), Err(LinalgError::DimensionMismatch));
+    /// ```
+    fn checked_solve(&self, rhs: &T) -> Result<T, LinalgError>;
+}
@@ -15,13 +15,20 @@
 //!                                 0.0, -1.0,  2.0];
 //! let l: LowerTriangular<f64> = a.dec_cholesky().unwrap().l();
 //! ```
-use super::LowerTriangular;
+use super::{
+    condest, General, LowerTriangular, SubstituteBackward, SubstituteForward, Transpose,
+    UpperTriangular,
+};
+use crate::algebra::{
+    abstr::Real,
+    linear::{vector::Vector, LinalgError},
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::clone::Clone;
 
 pub trait CholeskyDecomposition<T> {
-    fn dec_cholesky(&self) -> Result<CholeskyDec<T>, String>;
+    fn dec_cholesky(&self) -> Result<CholeskyDec<T>, LinalgError>;
 }
 
 /// Result of a Cholesky decomposition
@@ -43,3 +50,249 @@ impl<T> CholeskyDec<T> {
         self.l
     }
 }
+
+impl<T> CholeskyDec<T>
+where
+    T: Real,
+{
+    /// Estimates the condition number with respect to the 1-norm
+    ///
+    /// ```math
+    /// \kappa_1(A) = \lVert A \rVert_1 \lVert A^{-1} \rVert_1
+    /// ```
+    /// $\lVert A^{-1} \rVert_1$ is estimated with Hager's method from a few
+    /// solves with $L$ and $L^T$.
+    ///
+    /// # Arguments
+    ///
+    /// * `norm_1`: $\lVert A \rVert_1$ of the decomposed matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CholeskyDecomposition, General, MatrixNorm};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  2.0, -1.0;
+    ///                                -1.0, 2.0];
+    ///
+    /// let cond: f64 = a.dec_cholesky().unwrap().cond_1_estimate(a.norm_1());
+    ///
+    /// assert_relative_eq!(cond, 3.0, epsilon = 1.0e-10);
+    /// ```
+    pub fn cond_1_estimate(&self, norm_1: T) -> T {
+        let (n, _): (usize, usize) = self.l.dim();
+        let l_t: UpperTriangular<T> = self.l.clone().transpose();
+        // A is symmetric, hence A^{-T} = A^{-1}
+        let solve = |x: Vector<T>| -> Result<Vector<T>, LinalgError> {
+            let c: Vector<T> = self.l.substitute_forward(x)?;
+            l_t.substitute_backward(c)
+        };
+
+        norm_1 * condest::inv_norm_1_estimate(n, solve, solve)
+    }
+}
+
+impl<T> CholeskyDec<T>
+where
+    T: Real,
+{
+    /// Updates the decomposition of $A$ to the decomposition of $A + xx^T$
+    ///
+    /// The update is computed with $O(n^2)$ operations instead of the
+    /// $O(n^3)$ of a new decomposition.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the length of $x$ differs from
+    /// the dimension of $A$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CholeskyDecomposition, General, Transpose};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  4.0, 2.0;
+    ///                                 2.0, 3.0];
+    /// let x: Vector<f64> = vector![1.0; 2.0];
+    ///
+    /// let mut dec = a.dec_cholesky().unwrap();
+    /// dec.update(&x).unwrap();
+    ///
+    /// let l: General<f64> = dec.l().into();
+    /// assert_relative_eq!(&l * &l.clone().transpose(), matrix![5.0, 4.0; 4.0, 7.0], epsilon = 1.0e-10);
+    /// ```
+    pub fn update(&mut self, x: &Vector<T>) -> Result<(), LinalgError> {
+        self.rank_one(x, T::one())
+    }
+
+    /// Updates the decomposition of $A$ to the decomposition of $A - xx^T$
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the length of $x$ differs from
+    /// the dimension of $A$ and [`LinalgError::NotPositiveDefinite`] if
+    /// $A - xx^T$ is not positive definite. The decomposition is left
+    /// unchanged in both cases.
+    pub fn downdate(&mut self, x: &Vector<T>) -> Result<(), LinalgError> {
+        self.rank_one(x, -T::one())
+    }
+
+    /// Updates the decomposition of $A$ to the decomposition of the matrix
+    /// with the row and column `a` inserted at the index `k`
+    ///
+    /// `a` has the dimension of the enlarged matrix and `a[k]` is its new
+    /// diagonal entry.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if `k` is greater than the
+    /// dimension of $A$ or `a` has not the dimension of the enlarged matrix
+    /// and [`LinalgError::NotPositiveDefinite`] if the enlarged matrix is not
+    /// positive definite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CholeskyDecomposition, General, Transpose};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  4.0, 2.0;
+    ///                                 2.0, 3.0];
+    ///
+    /// let mut dec = a.dec_cholesky().unwrap();
+    /// dec.insert_row_column(1, &vector![1.0; 5.0; 1.0]).unwrap();
+    ///
+    /// let l: General<f64> = dec.l().into();
+    /// let a_ref: General<f64> = matrix![  4.0, 1.0, 2.0;
+    ///                                     1.0, 5.0, 1.0;
+    ///                                     2.0, 1.0, 3.0];
+    /// assert_relative_eq!(&l * &l.clone().transpose(), a_ref, epsilon = 1.0e-10);
+    /// ```
+    pub fn insert_row_column(&mut self, k: usize, a: &Vector<T>) -> Result<(), LinalgError> {
+        let n: usize = self.l.matrix.nrows();
+        if k > n || a.dim() != (n + 1, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        let l: &General<T> = &self.l.matrix;
+
+        // L_11 l_1 = a_1
+        let mut l_1: Vec<T> = Vec::with_capacity(k);
+        for i in 0..k {
+            let sum: T = (0..i).fold(T::zero(), |s, j| s + l[[i, j]] * l_1[j]);
+            l_1.push((a[i] - sum) / l[[i, i]]);
+        }
+
+        let lambda_sq: T = a[k] - l_1.iter().fold(T::zero(), |s, l_1j| s + *l_1j * *l_1j);
+        if lambda_sq <= T::zero() {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        let lambda: T = lambda_sq.sqrt();
+
+        let mut l_new: General<T> = General::zero(n + 1, n + 1);
+        for j in 0..n {
+            let j_new: usize = if j < k { j } else { j + 1 };
+            for i in j..n {
+                let i_new: usize = if i < k { i } else { i + 1 };
+                l_new[[i_new, j_new]] = l[[i, j]];
+            }
+        }
+        for (j, l_1j) in l_1.iter().enumerate() {
+            l_new[[k, j]] = *l_1j;
+        }
+        l_new[[k, k]] = lambda;
+
+        // l_2 = (a_2 - L_21 l_1) / lambda
+        let mut w: Vec<T> = vec![T::zero(); n + 1];
+        for i in k..n {
+            let sum: T = (0..k).fold(T::zero(), |s, j| s + l[[i, j]] * l_1[j]);
+            w[i + 1] = (a[i + 1] - sum) / lambda;
+            l_new[[i + 1, k]] = w[i + 1];
+        }
+
+        // L_22' L_22'^T = L_22 L_22^T - l_2 l_2^T
+        rank_one_update(&mut l_new, &mut w, k + 1, -T::one())?;
+        self.l.matrix = l_new;
+        Ok(())
+    }
+
+    /// Updates the decomposition of $A$ to the decomposition of the matrix
+    /// with the row and column `k` removed
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if `k` is not less than the
+    /// dimension of $A$
+    pub fn remove_row_column(&mut self, k: usize) -> Result<(), LinalgError> {
+        let n: usize = self.l.matrix.nrows();
+        if k >= n {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        let l: &General<T> = &self.l.matrix;
+
+        let mut l_new: General<T> = General::zero(n - 1, n - 1);
+        for j in (0..n).filter(|j| *j != k) {
+            let j_new: usize = if j < k { j } else { j - 1 };
+            for i in (j..n).filter(|i| *i != k) {
+                let i_new: usize = if i < k { i } else { i - 1 };
+                l_new[[i_new, j_new]] = l[[i, j]];
+            }
+        }
+
+        // L_33' L_33'^T = L_33 L_33^T + l_3 l_3^T
+        let mut w: Vec<T> = vec![T::zero(); n - 1];
+        for i in k + 1..n {
+            w[i - 1] = l[[i, k]];
+        }
+        rank_one_update(&mut l_new, &mut w, k, T::one())?;
+        self.l.matrix = l_new;
+        Ok(())
+    }
+
+    fn rank_one(&mut self, x: &Vector<T>, sigma: T) -> Result<(), LinalgError> {
+        let n: usize = self.l.matrix.nrows();
+        if x.dim() != (n, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        let mut l: General<T> = self.l.matrix.clone();
+        let mut w: Vec<T> = (0..n).map(|i| x[i]).collect();
+        rank_one_update(&mut l, &mut w, 0, sigma)?;
+        self.l.matrix = l;
+        Ok(())
+    }
+}
+
+/// Updates the trailing block $L_{k:n,k:n}$ to the factor of
+/// $L L^T + \sigma w w^T$, where $w$ is zero above the index `k`
+fn rank_one_update<T>(
+    l: &mut General<T>,
+    w: &mut [T],
+    k: usize,
+    sigma: T,
+) -> Result<(), LinalgError>
+where
+    T: Real,
+{
+    let n: usize = l.nrows();
+    for j in k..n {
+        let l_jj: T = l[[j, j]];
+        let r_sq: T = l_jj * l_jj + sigma * w[j] * w[j];
+        if r_sq <= T::zero() {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        let r: T = r_sq.sqrt();
+        let c: T = r / l_jj;
+        let s: T = w[j] / l_jj;
+        l[[j, j]] = r;
+
+        for i in j + 1..n {
+            l[[i, j]] = (l[[i, j]] + sigma * s * w[i]) / c;
+            w[i] = c * w[i] - s * l[[i, j]];
+        }
+    }
+    Ok(())
+}
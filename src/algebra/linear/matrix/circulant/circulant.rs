@@ -0,0 +1,107 @@
+use super::fft::fft;
+use crate::algebra::abstr::{Complex, Real};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Circulant matrix
+///
+/// A square matrix in which each column is the previous column rotated down
+/// by one element, $a_{ij} = c_{(i - j) \bmod n}$.
+///
+/// ```math
+/// \begin{pmatrix}
+/// c_0 & c_{n-1} & \cdots & c_1 \\
+/// c_1 & c_0 & \ddots & \vdots \\
+/// \vdots & \ddots & \ddots & c_{n-1} \\
+/// c_{n-1} & \cdots & c_1 & c_0
+/// \end{pmatrix}
+/// ```
+/// Circulant matrices are diagonalized by the discrete Fourier
+/// transformation, hence products and solves take O(n log n) operations.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Circulant<T> {
+    pub(crate) column: Vec<T>,
+}
+
+impl<T> Circulant<T> {
+    /// Creates a circulant matrix from its first column
+    ///
+    /// # Panics
+    ///
+    /// If the column is empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Circulant, General};
+    /// use mathru::matrix;
+    ///
+    /// let c: Circulant<f64> = Circulant::new(vec![1.0, 2.0, 3.0]);
+    ///
+    /// let c_ref: General<f64> = matrix![  1.0, 3.0, 2.0;
+    ///                                     2.0, 1.0, 3.0;
+    ///                                     3.0, 2.0, 1.0];
+    ///
+    /// assert_eq!(General::from(c), c_ref);
+    /// ```
+    pub fn new(column: Vec<T>) -> Circulant<T> {
+        assert!(
+            !column.is_empty(),
+            "A circulant matrix needs at least one element"
+        );
+        Circulant { column }
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(&self) -> (usize, usize) {
+        (self.column.len(), self.column.len())
+    }
+
+    /// Returns the first column
+    pub fn column(&self) -> &[T] {
+        &self.column
+    }
+}
+
+impl<T> Circulant<T>
+where
+    T: Real,
+{
+    /// Returns the element $a_{ij}$
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let n: usize = self.column.len();
+        self.column[(n + i - j) % n]
+    }
+
+    /// Computes the eigenvalues, which are the discrete Fourier
+    /// transformation of the first column
+    ///
+    /// The k-th eigenvalue belongs to the eigenvector
+    /// $(1, \omega^k, \omega^{2k}, \dots, \omega^{(n-1)k})^T$ with
+    /// $\omega = e^{2 \pi i / n}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::Circulant;
+    /// use mathru::assert_relative_eq;
+    ///
+    /// let c: Circulant<f64> = Circulant::new(vec![2.0, -1.0, 0.0, -1.0]);
+    ///
+    /// let values: Vec<Complex<f64>> = c.eigenvalues();
+    ///
+    /// assert_relative_eq!(values[0], Complex::new(0.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// assert_relative_eq!(values[2], Complex::new(4.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// ```
+    pub fn eigenvalues(&self) -> Vec<Complex<T>> {
+        let c: Vec<Complex<T>> = self
+            .column
+            .iter()
+            .map(|c_k| Complex::new(*c_k, T::zero()))
+            .collect();
+
+        fft(&c)
+    }
+}
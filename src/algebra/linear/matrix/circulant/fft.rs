@@ -0,0 +1,127 @@
+//! Fast Fourier transformation of arbitrary length
+//!
+//! Lengths which are a power of two are transformed with the iterative
+//! radix-2 algorithm, all other lengths with Bluestein's algorithm, which
+//! expresses the transformation as a convolution of power of two length.
+use crate::algebra::abstr::{Complex, Real, Zero};
+
+/// Computes the discrete Fourier transformation
+/// ```math
+/// X_k = \sum_{j=0}^{n-1} x_j e^{-2 \pi i jk / n}
+/// ```
+pub(super) fn fft<T>(x: &[Complex<T>]) -> Vec<Complex<T>>
+where
+    T: Real,
+{
+    let n: usize = x.len();
+    if n.is_power_of_two() {
+        let mut a: Vec<Complex<T>> = x.to_vec();
+        radix2(&mut a, false);
+        a
+    } else {
+        bluestein(x)
+    }
+}
+
+/// Computes the inverse discrete Fourier transformation
+/// ```math
+/// x_j = \frac{1}{n} \sum_{k=0}^{n-1} X_k e^{2 \pi i jk / n}
+/// ```
+pub(super) fn ifft<T>(x: &[Complex<T>]) -> Vec<Complex<T>>
+where
+    T: Real,
+{
+    let n: usize = x.len();
+    let scale: T = T::one() / T::from_f64(n as f64);
+    let x_conj: Vec<Complex<T>> = x.iter().map(|x_k| x_k.conj()).collect();
+
+    fft(&x_conj)
+        .iter()
+        .map(|y_k| Complex::new(y_k.re * scale, -y_k.im * scale))
+        .collect()
+}
+
+/// In-place radix-2 transformation, the length of a has to be a power of two
+fn radix2<T>(a: &mut [Complex<T>], inverse: bool)
+where
+    T: Real,
+{
+    let n: usize = a.len();
+    if n <= 1 {
+        return;
+    }
+
+    // bit reversal permutation
+    let mut j: usize = 0;
+    for i in 1..n {
+        let mut bit: usize = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let sign: T = if inverse { T::one() } else { -T::one() };
+    let mut len: usize = 2;
+    while len <= n {
+        let phi: T = sign * T::from_f64(2.0) * T::pi() / T::from_f64(len as f64);
+        let w_len: Complex<T> = Complex::new(phi.cos(), phi.sin());
+        for start in (0..n).step_by(len) {
+            let mut w: Complex<T> = Complex::new(T::one(), T::zero());
+            for k in 0..(len / 2) {
+                let u: Complex<T> = a[start + k];
+                let v: Complex<T> = a[start + k + len / 2] * w;
+                a[start + k] = u + v;
+                a[start + k + len / 2] = u - v;
+                w *= w_len;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Bluestein's algorithm for an arbitrary length
+fn bluestein<T>(x: &[Complex<T>]) -> Vec<Complex<T>>
+where
+    T: Real,
+{
+    let n: usize = x.len();
+    let m: usize = (2 * n - 1).next_power_of_two();
+
+    // chirp w_k = e^{-\pi i k^2 / n}, k^2 is reduced modulo 2n to keep the
+    // argument small
+    let chirp: Vec<Complex<T>> = (0..n)
+        .map(|k| {
+            let k_2: usize = (k * k) % (2 * n);
+            let phi: T = -T::pi() * T::from_f64(k_2 as f64) / T::from_f64(n as f64);
+            Complex::new(phi.cos(), phi.sin())
+        })
+        .collect();
+
+    let mut a: Vec<Complex<T>> = vec![Complex::zero(); m];
+    for k in 0..n {
+        a[k] = x[k] * chirp[k];
+    }
+    let mut b: Vec<Complex<T>> = vec![Complex::zero(); m];
+    b[0] = chirp[0].conj();
+    for k in 1..n {
+        b[k] = chirp[k].conj();
+        b[m - k] = chirp[k].conj();
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for k in 0..m {
+        a[k] *= b[k];
+    }
+    radix2(&mut a, true);
+
+    let scale: T = T::one() / T::from_f64(m as f64);
+    (0..n)
+        .map(|k| a[k] * chirp[k] * Complex::new(scale, T::zero()))
+        .collect()
+}
@@ -0,0 +1,21 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::matrix::{Circulant, General},
+};
+
+impl<T> From<Circulant<T>> for General<T>
+where
+    T: Real,
+{
+    /// Converts a circulant matrix into a general matrix
+    fn from(c: Circulant<T>) -> General<T> {
+        let (n, _): (usize, usize) = c.dim();
+        let mut g: General<T> = General::zero(n, n);
+        for j in 0..n {
+            for i in 0..n {
+                g[[i, j]] = c.get(i, j);
+            }
+        }
+        g
+    }
+}
@@ -0,0 +1,7 @@
+mod circulant;
+mod fft;
+mod from;
+mod mul;
+mod solve;
+
+pub use circulant::Circulant;
@@ -0,0 +1,53 @@
+use super::fft::{fft, ifft};
+use crate::algebra::{
+    abstr::{Complex, Real},
+    linear::{matrix::Circulant, vector::Vector},
+};
+use std::ops::Mul;
+
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a Circulant<T>
+where
+    T: Real,
+{
+    type Output = Vector<T>;
+
+    /// Multiplies a circulant matrix with a vector in O(n log n) operations
+    ///
+    /// The product is the cyclic convolution of the first column with the
+    /// vector, which is evaluated with the fast Fourier transformation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::Circulant;
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, vector};
+    ///
+    /// let c: Circulant<f64> = Circulant::new(vec![1.0, 2.0, 3.0]);
+    /// let x: Vector<f64> = vector![1.0; 0.0; -1.0];
+    ///
+    /// assert_relative_eq!(&c * &x, vector![-1.0; -1.0; 2.0], epsilon = 1.0e-10);
+    /// ```
+    fn mul(self, rhs: &'b Vector<T>) -> Self::Output {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, _): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the vector do not match"
+        );
+
+        let x: Vec<Complex<T>> = rhs
+            .iter()
+            .map(|x_i| Complex::new(*x_i, T::zero()))
+            .collect();
+        let x_hat: Vec<Complex<T>> = fft(&x);
+        let y_hat: Vec<Complex<T>> = self
+            .eigenvalues()
+            .iter()
+            .zip(x_hat.iter())
+            .map(|(lambda, x_k)| *lambda * *x_k)
+            .collect();
+
+        Vector::new_column(ifft(&y_hat).iter().map(|y_i| y_i.re).collect())
+    }
+}
@@ -0,0 +1,112 @@
+use super::fft::{fft, ifft};
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Complex, Real},
+    linear::{
+        matrix::{Circulant, General, Solve},
+        vector::Vector,
+    },
+};
+
+impl<T> Circulant<T>
+where
+    T: Real,
+{
+    /// Solves $Cx = b$ for one right-hand side
+    fn solve_fft(eigenvalues: &[Complex<T>], b: &[T]) -> Vec<T> {
+        let b: Vec<Complex<T>> = b.iter().map(|b_i| Complex::new(*b_i, T::zero())).collect();
+        let b_hat: Vec<Complex<T>> = fft(&b);
+        let x_hat: Vec<Complex<T>> = eigenvalues
+            .iter()
+            .zip(b_hat.iter())
+            .map(|(lambda, b_k)| *b_k / *lambda)
+            .collect();
+
+        ifft(&x_hat).iter().map(|x_i| x_i.re).collect()
+    }
+
+    /// Checks that no eigenvalue vanishes relative to the largest one
+    fn check_singular(eigenvalues: &[Complex<T>]) -> Result<(), LinalgError> {
+        let modulus = |z: &Complex<T>| (z.re * z.re + z.im * z.im).sqrt();
+        let max: T =
+            eigenvalues.iter().fold(
+                T::zero(),
+                |m, z| if modulus(z) > m { modulus(z) } else { m },
+            );
+        let tolerance: T = T::from_f64(eigenvalues.len() as f64) * T::default_epsilon() * max;
+
+        if max == T::zero() || eigenvalues.iter().any(|z| modulus(z) <= tolerance) {
+            return Err(LinalgError::Singular);
+        }
+        Ok(())
+    }
+}
+
+impl<T> Solve<Vector<T>> for Circulant<T>
+where
+    T: Real,
+{
+    /// Solves $Cx = b$ in O(n log n) operations
+    ///
+    /// # Errors
+    ///
+    /// If the matrix is numerically singular, i.e. an eigenvalue is
+    /// negligible compared to the largest one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Circulant, Solve};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, vector};
+    ///
+    /// let c: Circulant<f64> = Circulant::new(vec![4.0, 1.0, 0.0, 1.0, 2.0]);
+    /// let b: Vector<f64> = vector![8.0; 8.0; 8.0; 8.0; 8.0];
+    ///
+    /// let x: Vector<f64> = c.solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![1.0; 1.0; 1.0; 1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, _): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the vector do not match"
+        );
+
+        let eigenvalues: Vec<Complex<T>> = self.eigenvalues();
+        Circulant::check_singular(&eigenvalues)?;
+
+        let b: Vec<T> = rhs.iter().copied().collect();
+        Ok(Vector::new_column(Circulant::solve_fft(&eigenvalues, &b)))
+    }
+}
+
+impl<T> Solve<General<T>> for Circulant<T>
+where
+    T: Real,
+{
+    /// Solves $CX = B$ column by column
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, k): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the right-hand side do not match"
+        );
+
+        let eigenvalues: Vec<Complex<T>> = self.eigenvalues();
+        Circulant::check_singular(&eigenvalues)?;
+
+        let mut x: General<T> = General::zero(n, k);
+        for j in 0..k {
+            let b_j: Vec<T> = (0..n).map(|i| rhs[[i, j]]).collect();
+            let x_j: Vec<T> = Circulant::solve_fft(&eigenvalues, &b_j);
+            for (i, x_ij) in x_j.iter().enumerate() {
+                x[[i, j]] = *x_ij;
+            }
+        }
+        Ok(x)
+    }
+}
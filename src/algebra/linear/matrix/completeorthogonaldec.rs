@@ -0,0 +1,147 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{General, Solve, Transpose, UpperTriangular},
+        vector::Vector,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Complete orthogonal decomposition
+///
+/// ```math
+/// AP = Q \begin{pmatrix} T & 0 \\ 0 & 0 \end{pmatrix} Z^T
+/// ```
+/// with orthogonal matrices $Q \in T^{m \times m}$ and $Z \in T^{n \times n}$,
+/// a permutation matrix $P$ and an upper triangular matrix
+/// $T \in T^{r \times r}$, where $r$ is the numerical rank of $A$.
+pub trait CompleteOrthogonalDecomposition<T> {
+    /// Computes the decomposition, diagonal entries of the pivoted QR
+    /// decomposition with $|r_{ii}| \leq \text{tolerance} \cdot |r_{00}|$ are
+    /// treated as zero
+    fn dec_cod(&self, tolerance: T) -> Result<CompleteOrthogonalDec<T>, LinalgError>;
+}
+
+/// Result of a complete orthogonal decomposition
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CompleteOrthogonalDec<T> {
+    q: General<T>,
+    t: UpperTriangular<T>,
+    z: General<T>,
+    p: General<T>,
+}
+
+impl<T> CompleteOrthogonalDec<T> {
+    pub(super) fn new(
+        q: General<T>,
+        t: UpperTriangular<T>,
+        z: General<T>,
+        p: General<T>,
+    ) -> CompleteOrthogonalDec<T> {
+        CompleteOrthogonalDec { q, t, z, p }
+    }
+
+    /// Return the orthogonal matrix $Q \in T^{m \times m}$
+    pub fn q(self) -> General<T> {
+        self.q
+    }
+
+    /// Return the upper triangular matrix $T \in T^{r \times r}$
+    pub fn t(self) -> UpperTriangular<T> {
+        self.t
+    }
+
+    /// Return the orthogonal matrix $Z \in T^{n \times n}$
+    pub fn z(self) -> General<T> {
+        self.z
+    }
+
+    /// Return the permutation matrix $P \in T^{n \times n}$
+    pub fn p(self) -> General<T> {
+        self.p
+    }
+
+    pub fn qtzp(self) -> (General<T>, UpperTriangular<T>, General<T>, General<T>) {
+        (self.q, self.t, self.z, self.p)
+    }
+
+    /// Return the numerical rank
+    pub fn rank(&self) -> usize {
+        self.t.dim().0
+    }
+}
+
+impl<T> CompleteOrthogonalDec<T>
+where
+    T: Real,
+{
+    /// Returns an orthonormal basis of the null space of $A$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CompleteOrthogonalDecomposition, General};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+    ///                                 2.0, 4.0, 6.0];
+    ///
+    /// let n: General<f64> = a.dec_cod(1.0e-12).unwrap().null_space();
+    ///
+    /// assert_eq!(n.dim(), (3, 2));
+    /// assert_relative_eq!(&a * &n, General::zero(2, 2), epsilon = 1.0e-10);
+    /// ```
+    pub fn null_space(&self) -> General<T> {
+        let (_, n): (usize, usize) = self.z.dim();
+        let r: usize = self.rank();
+        if r == n {
+            return General::zero(n, 0);
+        }
+
+        &self.p * &self.z.get_slice(0, n - 1, r, n - 1)
+    }
+}
+
+impl<T> Solve<Vector<T>> for CompleteOrthogonalDec<T>
+where
+    T: Real,
+{
+    /// Computes the minimum norm solution of the least squares problem
+    /// $\min_x \lVert Ax - b \rVert_2$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{CompleteOrthogonalDecomposition, General, Solve}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 1.0;
+    ///                                 1.0, 1.0];
+    /// let b: Vector<f64> = vector![2.0; 2.0];
+    ///
+    /// let x: Vector<f64> = a.dec_cod(1.0e-12).unwrap().solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let (_, n): (usize, usize) = self.z.dim();
+        let r: usize = self.rank();
+
+        let c: Vector<T> = self.q.clone().transpose() * b.clone();
+
+        // Back substitution with T
+        let mut y: Vector<T> = Vector::zero(n);
+        for i in (0..r).rev() {
+            let mut s: T = c[i];
+            for k in (i + 1)..r {
+                s -= self.t[[i, k]] * y[k];
+            }
+            y[i] = s / self.t[[i, i]];
+        }
+
+        Ok(&self.p * &(&self.z * &y))
+    }
+}
@@ -0,0 +1,273 @@
+//! Helpers shared by the compressed sparse row and column formats.
+//!
+//! Both formats store a sequence of "lanes" (rows for CSR, columns for CSC).
+//! The pointer array `ptr` has one entry more than there are lanes, the
+//! entries of lane `k` are `idx[ptr[k]..ptr[k + 1]]` and
+//! `values[ptr[k]..ptr[k + 1]]`. Indices within a lane are strictly
+//! increasing.
+use crate::algebra::abstr::{Field, Scalar};
+
+/// Compressed lanes: (pointer, indices, values)
+pub(crate) type Compressed<T> = (Vec<usize>, Vec<usize>, Vec<T>);
+
+/// Panics if the compressed structure is malformed
+pub(crate) fn check_compressed(
+    lanes: usize,
+    lane_len: usize,
+    ptr: &[usize],
+    idx: &[usize],
+    values_len: usize,
+) {
+    assert_eq!(ptr.len(), lanes + 1, "pointer array has the wrong length");
+    assert_eq!(ptr[0], 0, "pointer array has to start with zero");
+    assert_eq!(
+        idx.len(),
+        values_len,
+        "number of indices and values do not match"
+    );
+    assert_eq!(
+        ptr[lanes],
+        values_len,
+        "last pointer does not match the number of values"
+    );
+
+    for k in 0..lanes {
+        assert!(ptr[k] <= ptr[k + 1], "pointer array is not sorted");
+        let lane: &[usize] = &idx[ptr[k]..ptr[k + 1]];
+        for (p, i) in lane.iter().enumerate() {
+            assert!(*i < lane_len, "index out of bounds");
+            if p > 0 {
+                assert!(lane[p - 1] < *i, "indices are not strictly increasing");
+            }
+        }
+    }
+}
+
+/// Converts lanes of one orientation to lanes of the other orientation,
+/// i.e. CSR to CSC and vice versa.
+///
+/// `lane_len` is the number of lanes of the result.
+pub(crate) fn transpose_compressed<T>(
+    lane_len: usize,
+    ptr: &[usize],
+    idx: &[usize],
+    values: &[T],
+) -> Compressed<T>
+where
+    T: Copy,
+{
+    let lanes: usize = ptr.len() - 1;
+    let nnz: usize = values.len();
+
+    let mut t_ptr: Vec<usize> = vec![0; lane_len + 1];
+    for i in idx.iter() {
+        t_ptr[*i + 1] += 1;
+    }
+    for k in 0..lane_len {
+        t_ptr[k + 1] += t_ptr[k];
+    }
+
+    let mut next: Vec<usize> = t_ptr[..lane_len].to_vec();
+    let mut t_idx: Vec<usize> = vec![0; nnz];
+    let mut t_values: Vec<T> = Vec::with_capacity(nnz);
+    let mut pos: Vec<usize> = vec![0; nnz];
+
+    for k in 0..lanes {
+        for (p, i) in idx.iter().enumerate().take(ptr[k + 1]).skip(ptr[k]) {
+            let q: usize = next[*i];
+            next[*i] += 1;
+            t_idx[q] = k;
+            pos[q] = p;
+        }
+    }
+    t_values.extend(pos.iter().map(|p| values[*p]));
+
+    (t_ptr, t_idx, t_values)
+}
+
+/// Builds compressed lanes out of triplets. Entries at the same position are
+/// summed up.
+pub(crate) fn compress_triplets<T>(
+    lanes: usize,
+    lane_idx: &[usize],
+    idx: &[usize],
+    values: &[T],
+) -> Compressed<T>
+where
+    T: Field + Scalar,
+{
+    let nnz: usize = values.len();
+    let mut order: Vec<usize> = (0..nnz).collect();
+    order.sort_by_key(|k| (lane_idx[*k], idx[*k]));
+
+    let mut ptr: Vec<usize> = vec![0; lanes + 1];
+    let mut c_idx: Vec<usize> = Vec::with_capacity(nnz);
+    let mut c_values: Vec<T> = Vec::with_capacity(nnz);
+
+    let mut last: Option<(usize, usize)> = None;
+    for k in order {
+        let key = (lane_idx[k], idx[k]);
+        if last == Some(key) {
+            let v = c_values.last_mut().unwrap();
+            *v += values[k];
+        } else {
+            ptr[key.0 + 1] += 1;
+            c_idx.push(key.1);
+            c_values.push(values[k]);
+            last = Some(key);
+        }
+    }
+
+    for k in 0..lanes {
+        ptr[k + 1] += ptr[k];
+    }
+
+    (ptr, c_idx, c_values)
+}
+
+/// Combines two compressed matrices of equal dimension entry wise.
+///
+/// Entries which are present in only one of the operands are combined with
+/// zero. Exact zeros in the result are dropped.
+pub(crate) fn combine_compressed<T, F>(
+    a_ptr: &[usize],
+    a_idx: &[usize],
+    a_values: &[T],
+    b_ptr: &[usize],
+    b_idx: &[usize],
+    b_values: &[T],
+    f: F,
+) -> Compressed<T>
+where
+    T: Field + Scalar,
+    F: Fn(T, T) -> T,
+{
+    let lanes: usize = a_ptr.len() - 1;
+    let mut ptr: Vec<usize> = Vec::with_capacity(lanes + 1);
+    let mut idx: Vec<usize> = Vec::with_capacity(a_idx.len().max(b_idx.len()));
+    let mut values: Vec<T> = Vec::with_capacity(a_idx.len().max(b_idx.len()));
+    ptr.push(0);
+
+    for k in 0..lanes {
+        let (mut p, p_end) = (a_ptr[k], a_ptr[k + 1]);
+        let (mut q, q_end) = (b_ptr[k], b_ptr[k + 1]);
+
+        while p < p_end || q < q_end {
+            let (i, v) = if q == q_end || (p < p_end && a_idx[p] < b_idx[q]) {
+                p += 1;
+                (a_idx[p - 1], f(a_values[p - 1], T::zero()))
+            } else if p == p_end || b_idx[q] < a_idx[p] {
+                q += 1;
+                (b_idx[q - 1], f(T::zero(), b_values[q - 1]))
+            } else {
+                p += 1;
+                q += 1;
+                (a_idx[p - 1], f(a_values[p - 1], b_values[q - 1]))
+            };
+
+            if v != T::zero() {
+                idx.push(i);
+                values.push(v);
+            }
+        }
+        ptr.push(idx.len());
+    }
+
+    (ptr, idx, values)
+}
+
+/// Checks if two compressed matrices of equal dimension have the same
+/// entries. Missing entries are treated as zero.
+pub(crate) fn eq_compressed<T>(
+    a_ptr: &[usize],
+    a_idx: &[usize],
+    a_values: &[T],
+    b_ptr: &[usize],
+    b_idx: &[usize],
+    b_values: &[T],
+) -> bool
+where
+    T: Field + Scalar,
+{
+    let lanes: usize = a_ptr.len() - 1;
+
+    for k in 0..lanes {
+        let (mut p, p_end) = (a_ptr[k], a_ptr[k + 1]);
+        let (mut q, q_end) = (b_ptr[k], b_ptr[k + 1]);
+
+        while p < p_end || q < q_end {
+            let equal: bool = if q == q_end || (p < p_end && a_idx[p] < b_idx[q]) {
+                p += 1;
+                a_values[p - 1] == T::zero()
+            } else if p == p_end || b_idx[q] < a_idx[p] {
+                q += 1;
+                b_values[q - 1] == T::zero()
+            } else {
+                p += 1;
+                q += 1;
+                a_values[p - 1] == b_values[q - 1]
+            };
+
+            if !equal {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Multiplies two compressed matrices lane by lane (Gustavson's algorithm).
+///
+/// The lanes of the result are the lanes of `a`, `lane_len` is the length of
+/// the lanes of `b`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn mul_compressed<T>(
+    lane_len: usize,
+    a_ptr: &[usize],
+    a_idx: &[usize],
+    a_values: &[T],
+    b_ptr: &[usize],
+    b_idx: &[usize],
+    b_values: &[T],
+) -> Compressed<T>
+where
+    T: Field + Scalar,
+{
+    let lanes: usize = a_ptr.len() - 1;
+    let mut ptr: Vec<usize> = Vec::with_capacity(lanes + 1);
+    let mut idx: Vec<usize> = Vec::new();
+    let mut values: Vec<T> = Vec::new();
+    ptr.push(0);
+
+    let mut acc: Vec<T> = vec![T::zero(); lane_len];
+    let mut marker: Vec<usize> = vec![usize::MAX; lane_len];
+    let mut pattern: Vec<usize> = Vec::new();
+
+    for k in 0..lanes {
+        pattern.clear();
+        for p in a_ptr[k]..a_ptr[k + 1] {
+            let j: usize = a_idx[p];
+            let a_kj: T = a_values[p];
+            for q in b_ptr[j]..b_ptr[j + 1] {
+                let i: usize = b_idx[q];
+                if marker[i] != k {
+                    marker[i] = k;
+                    acc[i] = T::zero();
+                    pattern.push(i);
+                }
+                acc[i] += a_kj * b_values[q];
+            }
+        }
+        pattern.sort_unstable();
+        for i in pattern.iter() {
+            if acc[*i] != T::zero() {
+                idx.push(*i);
+                values.push(acc[*i]);
+            }
+        }
+        ptr.push(idx.len());
+    }
+
+    (ptr, idx, values)
+}
@@ -0,0 +1,99 @@
+//! 1-norm condition estimation
+//!
+//! Hager's method with Higham's refinements (LAPACK xLACON) estimates
+//! $\lVert A^{-1} \rVert_1$ from a handful of solves with $A$ and $A^T$
+//! instead of forming the inverse.
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{abstr::Real, linear::vector::Vector};
+
+const MAX_ITERATIONS: usize = 5;
+
+/// Estimates $\lVert A^{-1} \rVert_1$ of a square matrix of dimension n
+///
+/// `solve` returns $A^{-1}x$ and `solve_transpose` returns $A^{-T}x$. A
+/// failing or non-finite solve means that $A$ is singular and yields an
+/// infinite estimate.
+pub(super) fn inv_norm_1_estimate<T, F, G>(n: usize, solve: F, solve_transpose: G) -> T
+where
+    T: Real,
+    F: Fn(Vector<T>) -> Result<Vector<T>, LinalgError>,
+    G: Fn(Vector<T>) -> Result<Vector<T>, LinalgError>,
+{
+    if n == 0 {
+        return T::zero();
+    }
+
+    let finite = |v: Result<Vector<T>, LinalgError>| -> Option<Vector<T>> {
+        v.ok()
+            .filter(|v| v.iter().all(|v_i| v_i.abs() < T::infinity()))
+    };
+    let norm_1 = |v: &Vector<T>| -> T { v.iter().fold(T::zero(), |s, v_i| s + v_i.abs()) };
+
+    let n_t: T = T::from_u64(n as u64);
+    let mut x: Vector<T> = Vector::new_column(vec![T::one() / n_t; n]);
+    let mut estimate: T = T::zero();
+
+    for k in 0..MAX_ITERATIONS {
+        let y: Vector<T> = match finite(solve(x.clone())) {
+            Some(y) => y,
+            None => return T::infinity(),
+        };
+        let y_norm: T = norm_1(&y);
+        if k > 0 && y_norm <= estimate {
+            break;
+        }
+        estimate = y_norm;
+
+        let xi: Vector<T> = Vector::new_column(
+            y.iter()
+                .map(|y_i| {
+                    if *y_i >= T::zero() {
+                        T::one()
+                    } else {
+                        -T::one()
+                    }
+                })
+                .collect(),
+        );
+        let z: Vector<T> = match finite(solve_transpose(xi)) {
+            Some(z) => z,
+            None => return T::infinity(),
+        };
+
+        let (j, z_max): (usize, T) =
+            z.iter()
+                .enumerate()
+                .fold((0, -T::one()), |(j, m), (i, z_i)| {
+                    if z_i.abs() > m {
+                        (i, z_i.abs())
+                    } else {
+                        (j, m)
+                    }
+                });
+        let z_x: T = z
+            .iter()
+            .zip(x.iter())
+            .fold(T::zero(), |s, (z_i, x_i)| s + *z_i * *x_i);
+        if z_max <= z_x {
+            break;
+        }
+
+        x = Vector::zero(n);
+        x[j] = T::one();
+    }
+
+    // Higham's alternative estimate guards against the rare matrices for
+    // which the iteration gets stuck in a poor local maximum.
+    let mut b: Vector<T> = Vector::zero(n);
+    for i in 0..n {
+        let t: T = T::one() + T::from_u64(i as u64) / T::from_u64(n.max(2) as u64 - 1);
+        b[i] = if i % 2 == 0 { t } else { -t };
+    }
+    match finite(solve(b)) {
+        Some(y) => {
+            let alternative: T = T::from_u64(2) * norm_1(&y) / (T::from_u64(3) * n_t);
+            estimate.max(alternative)
+        }
+        None => T::infinity(),
+    }
+}
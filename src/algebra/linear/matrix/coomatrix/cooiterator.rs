@@ -0,0 +1,31 @@
+use crate::algebra::linear::matrix::CooMatrix;
+
+/// Iterator over the entries of a coordinate matrix
+pub struct CooIterator<'a, T> {
+    matrix: &'a CooMatrix<T>,
+    pos: usize,
+}
+
+impl<'a, T> CooIterator<'a, T> {
+    pub fn new(matrix: &'a CooMatrix<T>) -> CooIterator<'a, T> {
+        CooIterator { matrix, pos: 0 }
+    }
+}
+
+impl<'a, T> Iterator for CooIterator<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.matrix.values.len() {
+            let k = self.pos;
+            self.pos += 1;
+            Some((
+                self.matrix.rows[k],
+                self.matrix.cols[k],
+                &self.matrix.values[k],
+            ))
+        } else {
+            None
+        }
+    }
+}
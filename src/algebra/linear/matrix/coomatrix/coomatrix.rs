@@ -0,0 +1,112 @@
+use crate::algebra::linear::matrix::coomatrix::CooIterator;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Sparse matrix in coordinate format
+///
+/// A coordinate matrix stores the triplets (row, column, value) of all
+/// entries. It is meant to assemble sparse matrices entry by entry and to
+/// be converted afterwards into a [`CsrMatrix`](crate::algebra::linear::matrix::CsrMatrix)
+/// or a [`CscMatrix`](crate::algebra::linear::matrix::CscMatrix).
+/// Entries with the same position are summed up during the conversion.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::matrix::{CooMatrix, CsrMatrix, General};
+/// use mathru::matrix;
+///
+/// let mut coo: CooMatrix<f64> = CooMatrix::new(2, 3);
+/// coo.push(0, 0, 1.0);
+/// coo.push(1, 2, 2.0);
+/// coo.push(1, 2, 3.0);
+///
+/// let csr: CsrMatrix<f64> = CsrMatrix::from(&coo);
+/// let a: General<f64> = General::from(&csr);
+///
+/// assert_eq!(a, matrix![1.0, 0.0, 0.0; 0.0, 0.0, 5.0]);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CooMatrix<T> {
+    /// Num of rows which the matrix has
+    pub(crate) m: usize,
+    /// Num of columns which the matrix has
+    pub(crate) n: usize,
+    /// Row indices of the entries
+    pub(crate) rows: Vec<usize>,
+    /// Column indices of the entries
+    pub(crate) cols: Vec<usize>,
+    /// Values of the entries
+    pub(crate) values: Vec<T>,
+}
+
+impl<T> CooMatrix<T> {
+    /// Creates an empty m x n coordinate matrix
+    pub fn new(m: usize, n: usize) -> CooMatrix<T> {
+        CooMatrix {
+            m,
+            n,
+            rows: Vec::new(),
+            cols: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Creates an empty m x n coordinate matrix with space for `nnz` entries
+    pub fn with_capacity(m: usize, n: usize, nnz: usize) -> CooMatrix<T> {
+        CooMatrix {
+            m,
+            n,
+            rows: Vec::with_capacity(nnz),
+            cols: Vec::with_capacity(nnz),
+            values: Vec::with_capacity(nnz),
+        }
+    }
+
+    /// Appends the entry `value` at position (i, j)
+    ///
+    /// # Panics
+    ///
+    /// if (i, j) is not inside the matrix
+    pub fn push(&mut self, i: usize, j: usize, value: T) {
+        assert!(
+            i < self.m && j < self.n,
+            "index out of bounds: is a {}x{} matrix but the index is {}x{}",
+            self.m,
+            self.n,
+            i,
+            j
+        );
+        self.rows.push(i);
+        self.cols.push(j);
+        self.values.push(value);
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(&self) -> (usize, usize) {
+        (self.m, self.n)
+    }
+
+    /// Returns the number of rows
+    pub fn nrows(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the number of columns
+    pub fn ncols(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of stored entries, duplicates included
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Iterates over the stored entries in insertion order
+    ///
+    /// The items are triplets (row, column, value).
+    pub fn iter(&self) -> CooIterator<'_, T> {
+        CooIterator::new(self)
+    }
+}
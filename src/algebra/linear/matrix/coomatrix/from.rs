@@ -0,0 +1,49 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{CooMatrix, General},
+};
+
+impl<T> From<&General<T>> for CooMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a dense matrix into a coordinate matrix. Zero entries are not
+    /// stored.
+    fn from(matrix: &General<T>) -> Self {
+        let (m, n): (usize, usize) = matrix.dim();
+        let mut coo: CooMatrix<T> = CooMatrix::new(m, n);
+        for j in 0..n {
+            for i in 0..m {
+                let a_ij: T = matrix[[i, j]];
+                if a_ij != T::zero() {
+                    coo.push(i, j, a_ij);
+                }
+            }
+        }
+        coo
+    }
+}
+
+impl<T> From<&CooMatrix<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a coordinate matrix into a dense matrix. Entries at the same
+    /// position are summed up.
+    fn from(matrix: &CooMatrix<T>) -> Self {
+        let mut dense: General<T> = General::zero(matrix.m, matrix.n);
+        for (i, j, a_ij) in matrix.iter() {
+            dense[[i, j]] += *a_ij;
+        }
+        dense
+    }
+}
+
+impl<T> From<CooMatrix<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: CooMatrix<T>) -> Self {
+        General::from(&matrix)
+    }
+}
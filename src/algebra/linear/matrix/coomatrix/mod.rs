@@ -0,0 +1,6 @@
+mod coomatrix;
+mod cooiterator;
+mod from;
+
+pub use coomatrix::CooMatrix;
+pub use cooiterator::CooIterator;
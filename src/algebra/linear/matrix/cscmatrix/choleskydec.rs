@@ -0,0 +1,369 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{CscMatrix, General, Solve, SparseOrdering},
+        vector::Vector,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Symbolic analysis of a sparse Cholesky decomposition
+///
+/// It contains the fill-reducing permutation $p$, the elimination tree and
+/// the sparsity pattern of the factor $L$ of $PAP^T = LL^T$. It depends
+/// only on the pattern of $A$, such that matrices with the same pattern are
+/// factorized without repeating the analysis.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CscCholeskySymbolic {
+    n: usize,
+    perm: Vec<usize>,
+    perm_inv: Vec<usize>,
+    parent: Vec<Option<usize>>,
+    /// Pattern of L column by column, the diagonal entry comes first
+    col_offsets: Vec<usize>,
+    row_indices: Vec<usize>,
+    /// Pattern of the strict lower triangle of L row by row
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+}
+
+/// Result of a sparse Cholesky decomposition
+///
+/// ```math
+/// PAP^T = LL^T
+/// ```
+/// $P$ is the fill-reducing permutation of the symbolic analysis and $L$ is
+/// a sparse lower triangular matrix.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CscCholeskyDec<T> {
+    symbolic: CscCholeskySymbolic,
+    l: CscMatrix<T>,
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Real,
+{
+    /// Analyzes the pattern of a symmetric matrix for a Cholesky
+    /// decomposition
+    ///
+    /// Only the entries on and below the diagonal are read.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the matrix is not square
+    pub fn symbolic_cholesky(
+        &self,
+        ordering: SparseOrdering,
+    ) -> Result<CscCholeskySymbolic, LinalgError> {
+        if self.m != self.n {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        let n: usize = self.n;
+        let perm: Vec<usize> = ordering.permutation(self);
+        let mut perm_inv: Vec<usize> = vec![0; n];
+        for (k, p_k) in perm.iter().enumerate() {
+            perm_inv[*p_k] = k;
+        }
+
+        // Upper triangle of PAP^T column by column
+        let mut upper: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for (r, c, _) in self.iter().filter(|(r, c, _)| r >= c) {
+            let (i, j): (usize, usize) = (perm_inv[r], perm_inv[c]);
+            upper[i.max(j)].push(i.min(j));
+        }
+
+        // Elimination tree
+        let mut parent: Vec<Option<usize>> = vec![None; n];
+        let mut ancestor: Vec<Option<usize>> = vec![None; n];
+        for (k, upper_k) in upper.iter().enumerate() {
+            for i in upper_k.iter() {
+                let mut i: Option<usize> = Some(*i);
+                while let Some(node) = i.filter(|node| *node < k) {
+                    i = ancestor[node];
+                    ancestor[node] = Some(k);
+                    if i.is_none() {
+                        parent[node] = Some(k);
+                    }
+                }
+            }
+        }
+
+        // The pattern of the row k of L is the set of nodes reached from the
+        // upper entries of column k in the elimination tree
+        let mut columns: Vec<Vec<usize>> = (0..n).map(|j| vec![j]).collect();
+        let mut row_offsets: Vec<usize> = Vec::with_capacity(n + 1);
+        let mut col_indices: Vec<usize> = Vec::new();
+        let mut mark: Vec<usize> = vec![usize::MAX; n];
+        row_offsets.push(0);
+        for k in 0..n {
+            mark[k] = k;
+            for i in upper[k].iter() {
+                let mut node: usize = *i;
+                while mark[node] != k {
+                    mark[node] = k;
+                    col_indices.push(node);
+                    columns[node].push(k);
+                    node = parent[node].unwrap();
+                }
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        let mut col_offsets: Vec<usize> = Vec::with_capacity(n + 1);
+        col_offsets.push(0);
+        for column in columns.iter() {
+            col_offsets.push(col_offsets.last().unwrap() + column.len());
+        }
+
+        Ok(CscCholeskySymbolic {
+            n,
+            perm,
+            perm_inv,
+            parent,
+            col_offsets,
+            row_indices: columns.concat(),
+            row_offsets,
+            col_indices,
+        })
+    }
+
+    /// Decomposes a symmetric, positive definite sparse matrix
+    ///
+    /// The decomposition is a left-looking column Cholesky decomposition,
+    /// which computes only the entries of the pattern of the symbolic
+    /// analysis. Only the entries on and below the diagonal are read.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the matrix is not square and
+    /// [`LinalgError::NotPositiveDefinite`] if it is not positive definite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CscMatrix, General, Solve, SparseOrdering};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::from(&matrix![   4.0, -1.0, 0.0, -1.0;
+    ///                                                     -1.0, 4.0, -1.0, 0.0;
+    ///                                                     0.0, -1.0, 4.0, -1.0;
+    ///                                                     -1.0, 0.0, -1.0, 4.0]);
+    /// let b: Vector<f64> = vector![2.0; 2.0; 2.0; 2.0];
+    ///
+    /// let x: Vector<f64> = a.dec_cholesky(SparseOrdering::Amd).unwrap().solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![1.0; 1.0; 1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    pub fn dec_cholesky(&self, ordering: SparseOrdering) -> Result<CscCholeskyDec<T>, LinalgError> {
+        self.symbolic_cholesky(ordering)?.factor(self)
+    }
+}
+
+impl CscCholeskySymbolic {
+    /// Return the fill-reducing permutation
+    ///
+    /// The row and column $k$ of $PAP^T$ are the row and column `perm[k]` of
+    /// $A$.
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Return the parents of the nodes in the elimination tree of $PAP^T$
+    pub fn elimination_tree(&self) -> &[Option<usize>] {
+        &self.parent
+    }
+
+    /// Return the number of entries of $L$
+    pub fn nnz(&self) -> usize {
+        self.row_indices.len()
+    }
+
+    /// Computes the numeric decomposition of a symmetric, positive definite
+    /// matrix with the analyzed pattern
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the dimension of $A$ differs or
+    /// the lower triangle of $A$ has an entry outside of the analyzed
+    /// pattern and [`LinalgError::NotPositiveDefinite`] if $A$ is not
+    /// positive definite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CscMatrix, General, Solve, SparseOrdering};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::from(&matrix![   2.0, -1.0, 0.0;
+    ///                                                     -1.0, 2.0, -1.0;
+    ///                                                     0.0, -1.0, 2.0]);
+    /// let symbolic = a.symbolic_cholesky(SparseOrdering::ReverseCuthillMcKee).unwrap();
+    ///
+    /// // Same pattern, other values
+    /// let b: CscMatrix<f64> = a.apply(&|a_ij| 2.0 * a_ij);
+    /// let x: Vector<f64> = symbolic.factor(&b).unwrap().solve(&vector![2.0; 0.0; 2.0]).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    pub fn factor<T>(&self, a: &CscMatrix<T>) -> Result<CscCholeskyDec<T>, LinalgError>
+    where
+        T: Real,
+    {
+        let values: Vec<T> = self.factor_values(a)?;
+        Ok(CscCholeskyDec {
+            symbolic: self.clone(),
+            l: CscMatrix {
+                m: self.n,
+                n: self.n,
+                col_offsets: self.col_offsets.clone(),
+                row_indices: self.row_indices.clone(),
+                values,
+            },
+        })
+    }
+
+    fn factor_values<T>(&self, a: &CscMatrix<T>) -> Result<Vec<T>, LinalgError>
+    where
+        T: Real,
+    {
+        let n: usize = self.n;
+        if a.dim() != (n, n) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        // Lower triangle of PAP^T column by column
+        let mut lower: Vec<Vec<(usize, T)>> = vec![Vec::new(); n];
+        for (r, c, a_rc) in a.iter().filter(|(r, c, _)| r >= c) {
+            let (i, j): (usize, usize) = (self.perm_inv[r], self.perm_inv[c]);
+            lower[i.min(j)].push((i.max(j), *a_rc));
+        }
+
+        let (lp, li): (&[usize], &[usize]) = (&self.col_offsets, &self.row_indices);
+        let mut lx: Vec<T> = vec![T::zero(); li.len()];
+        let mut x: Vec<T> = vec![T::zero(); n];
+        let mut mark: Vec<usize> = vec![usize::MAX; n];
+        // Position of the next entry of the columns, which updates a column
+        let mut next: Vec<usize> = (0..n).map(|k| lp[k] + 1).collect();
+
+        for j in 0..n {
+            for i in li[lp[j]..lp[j + 1]].iter() {
+                mark[*i] = j;
+            }
+            for (i, a_ij) in lower[j].iter() {
+                if mark[*i] != j {
+                    return Err(LinalgError::DimensionMismatch);
+                }
+                x[*i] += *a_ij;
+            }
+
+            // x -= L_{j:n,k} L_{j,k} for all columns k with L_{j,k} != 0
+            for k in self.col_indices[self.row_offsets[j]..self.row_offsets[j + 1]].iter() {
+                let l_jk: T = lx[next[*k]];
+                for p in next[*k]..lp[*k + 1] {
+                    x[li[p]] -= lx[p] * l_jk;
+                }
+                next[*k] += 1;
+            }
+
+            let d: T = x[j];
+            x[j] = T::zero();
+            if d <= T::zero() {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            let l_jj: T = d.sqrt();
+            lx[lp[j]] = l_jj;
+            for p in lp[j] + 1..lp[j + 1] {
+                lx[p] = x[li[p]] / l_jj;
+                x[li[p]] = T::zero();
+            }
+        }
+
+        Ok(lx)
+    }
+}
+
+impl<T> CscCholeskyDec<T>
+where
+    T: Real,
+{
+    /// Return the lower triangular factor $L$ of $PAP^T$
+    pub fn l(self) -> CscMatrix<T> {
+        self.l
+    }
+
+    /// Return the symbolic analysis
+    pub fn symbolic(&self) -> &CscCholeskySymbolic {
+        &self.symbolic
+    }
+
+    /// Replaces the decomposition by the one of a matrix with the same
+    /// pattern, without repeating the symbolic analysis
+    ///
+    /// # Errors
+    ///
+    /// See [`CscCholeskySymbolic::factor`]. The decomposition is left
+    /// unchanged.
+    pub fn refactor(&mut self, a: &CscMatrix<T>) -> Result<(), LinalgError> {
+        self.l.values = self.symbolic.factor_values(a)?;
+        Ok(())
+    }
+}
+
+impl<T> Solve<Vector<T>> for CscCholeskyDec<T>
+where
+    T: Real,
+{
+    /// Solves $Ax = b$
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let n: usize = self.symbolic.n;
+        if rhs.dim() != (n, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        let (lp, li, lx): (&[usize], &[usize], &[T]) =
+            (&self.l.col_offsets, &self.l.row_indices, &self.l.values);
+
+        let mut y: Vec<T> = self.symbolic.perm.iter().map(|p_k| rhs[*p_k]).collect();
+        for j in 0..n {
+            y[j] /= lx[lp[j]];
+            let y_j: T = y[j];
+            for p in lp[j] + 1..lp[j + 1] {
+                y[li[p]] -= lx[p] * y_j;
+            }
+        }
+        for j in (0..n).rev() {
+            let mut y_j: T = y[j];
+            for p in lp[j] + 1..lp[j + 1] {
+                y_j -= lx[p] * y[li[p]];
+            }
+            y[j] = y_j / lx[lp[j]];
+        }
+
+        let mut x: Vector<T> = Vector::zero(n);
+        for (k, p_k) in self.symbolic.perm.iter().enumerate() {
+            x[*p_k] = y[k];
+        }
+        Ok(x)
+    }
+}
+
+impl<T> Solve<General<T>> for CscCholeskyDec<T>
+where
+    T: Real,
+{
+    /// Solves $AX = B$
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        let (m, k): (usize, usize) = rhs.dim();
+        let mut x: General<T> = General::zero(m, k);
+        for j in 0..k {
+            x.set_column(&self.solve(&rhs.get_column(j))?, j);
+        }
+        Ok(x)
+    }
+}
@@ -0,0 +1,66 @@
+use crate::algebra::linear::matrix::CscMatrix;
+
+/// Iterator over the stored entries of a CSC matrix
+pub struct CscIterator<'a, T> {
+    matrix: &'a CscMatrix<T>,
+    column: usize,
+    pos: usize,
+}
+
+impl<'a, T> CscIterator<'a, T> {
+    pub fn new(matrix: &'a CscMatrix<T>) -> CscIterator<'a, T> {
+        CscIterator {
+            matrix,
+            column: 0,
+            pos: 0,
+        }
+    }
+}
+
+impl<'a, T> Iterator for CscIterator<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.matrix.values.len() {
+            return None;
+        }
+
+        while self.matrix.col_offsets[self.column + 1] <= self.pos {
+            self.column += 1;
+        }
+
+        let k = self.pos;
+        self.pos += 1;
+        Some((
+            self.matrix.row_indices[k],
+            self.column,
+            &self.matrix.values[k],
+        ))
+    }
+}
+
+/// Iterator over the columns of a CSC matrix
+pub struct CscColumnIterator<'a, T> {
+    matrix: &'a CscMatrix<T>,
+    column: usize,
+}
+
+impl<'a, T> CscColumnIterator<'a, T> {
+    pub fn new(matrix: &'a CscMatrix<T>) -> CscColumnIterator<'a, T> {
+        CscColumnIterator { matrix, column: 0 }
+    }
+}
+
+impl<'a, T> Iterator for CscColumnIterator<'a, T> {
+    type Item = (&'a [usize], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.column < self.matrix.n {
+            let column = self.matrix.column(self.column);
+            self.column += 1;
+            Some(column)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{
+        compressed,
+        cscmatrix::{CscColumnIterator, CscIterator},
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Sparse matrix in compressed sparse column (CSC) format
+///
+/// The entries of column j are stored in `values[col_offsets[j]..col_offsets[j + 1]]`
+/// and their row indices in `row_indices[col_offsets[j]..col_offsets[j + 1]]`.
+/// The row indices within a column are strictly increasing.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{matrix::{CscMatrix, General}, vector::Vector};
+/// use mathru::{matrix, vector};
+///
+/// // a = [1.0 0.0 2.0]
+/// //     [0.0 0.0 3.0]
+/// let a: CscMatrix<f64> = CscMatrix::new(2, 3, vec![0, 1, 1, 3], vec![0, 0, 1], vec![1.0, 2.0, 3.0]);
+/// let x: Vector<f64> = vector![1.0; 1.0; 1.0];
+///
+/// assert_eq!(&a * &x, vector![3.0; 3.0]);
+/// assert_eq!(General::from(&a), matrix![1.0, 0.0, 2.0; 0.0, 0.0, 3.0]);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CscMatrix<T> {
+    /// Num of rows which the matrix has
+    pub(crate) m: usize,
+    /// Num of columns which the matrix has
+    pub(crate) n: usize,
+    /// Offsets of the columns in `row_indices` and `values`
+    pub(crate) col_offsets: Vec<usize>,
+    /// Row indices of the entries
+    pub(crate) row_indices: Vec<usize>,
+    /// Values of the entries
+    pub(crate) values: Vec<T>,
+}
+
+impl<T> CscMatrix<T> {
+    /// Creates a new CSC matrix from its raw parts
+    ///
+    /// # Arguments
+    ///
+    /// * `m`: number of rows
+    /// * `n`: number of columns
+    /// * `col_offsets`: n + 1 non decreasing offsets, starting with 0 and
+    ///   ending with the number of entries
+    /// * `row_indices`: row index of every entry
+    /// * `values`: value of every entry
+    ///
+    /// # Panics
+    ///
+    /// if the parts do not describe a valid CSC matrix
+    pub fn new(
+        m: usize,
+        n: usize,
+        col_offsets: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> CscMatrix<T> {
+        compressed::check_compressed(n, m, &col_offsets, &row_indices, values.len());
+        CscMatrix {
+            m,
+            n,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(&self) -> (usize, usize) {
+        (self.m, self.n)
+    }
+
+    /// Returns the number of rows
+    pub fn nrows(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the number of columns
+    pub fn ncols(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the column offsets
+    pub fn col_offsets(&self) -> &[usize] {
+        &self.col_offsets
+    }
+
+    /// Returns the row indices of the stored entries
+    pub fn row_indices(&self) -> &[usize] {
+        &self.row_indices
+    }
+
+    /// Returns the values of the stored entries
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the values of the stored entries
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    /// Returns the row indices and values of column j
+    pub fn column(&self, j: usize) -> (&[usize], &[T]) {
+        let (s, e) = (self.col_offsets[j], self.col_offsets[j + 1]);
+        (&self.row_indices[s..e], &self.values[s..e])
+    }
+
+    /// Iterates over the stored entries column by column
+    ///
+    /// The items are triplets (row, column, value).
+    pub fn iter(&self) -> CscIterator<'_, T> {
+        CscIterator::new(self)
+    }
+
+    /// Iterates over the columns
+    ///
+    /// Every column is returned as a pair of its row indices and values.
+    pub fn column_iter(&self) -> CscColumnIterator<'_, T> {
+        CscColumnIterator::new(self)
+    }
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Creates a m x n matrix without any entries
+    pub fn zero(m: usize, n: usize) -> CscMatrix<T> {
+        CscMatrix {
+            m,
+            n,
+            col_offsets: vec![0; n + 1],
+            row_indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Returns the identity matrix
+    pub fn one(size: usize) -> CscMatrix<T> {
+        CscMatrix {
+            m: size,
+            n: size,
+            col_offsets: (0..=size).collect(),
+            row_indices: (0..size).collect(),
+            values: vec![T::one(); size],
+        }
+    }
+
+    /// Returns the entry at position (i, j)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::CscMatrix;
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::new(2, 2, vec![0, 1, 2], vec![1, 0], vec![4.0, 5.0]);
+    ///
+    /// assert_eq!(a.get(1, 0), 4.0);
+    /// assert_eq!(a.get(0, 0), 0.0);
+    /// ```
+    pub fn get(&self, i: usize, j: usize) -> T {
+        debug_assert!(i < self.m && j < self.n);
+        let (rows, values) = self.column(j);
+        match rows.binary_search(&i) {
+            Ok(p) => values[p],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// Returns the diagonal entries
+    pub fn diagonal(&self) -> Vec<T> {
+        (0..self.m.min(self.n)).map(|i| self.get(i, i)).collect()
+    }
+
+    /// Applies the function f on every stored entry
+    pub fn apply(&self, f: &dyn Fn(&T) -> T) -> CscMatrix<T> {
+        CscMatrix {
+            m: self.m,
+            n: self.n,
+            col_offsets: self.col_offsets.clone(),
+            row_indices: self.row_indices.clone(),
+            values: self.values.iter().map(f).collect(),
+        }
+    }
+}
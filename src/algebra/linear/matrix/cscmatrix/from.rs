@@ -0,0 +1,140 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{compressed, CooMatrix, CscMatrix, CsrMatrix, General},
+};
+
+impl<T> From<&General<T>> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a dense matrix into a CSC matrix. Zero entries are not stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CscMatrix, General};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![1.0, 0.0; 0.0, 2.0];
+    /// let b: CscMatrix<f64> = CscMatrix::from(&a);
+    ///
+    /// assert_eq!(b.nnz(), 2);
+    /// ```
+    fn from(matrix: &General<T>) -> Self {
+        let (m, n): (usize, usize) = matrix.dim();
+        let mut col_offsets: Vec<usize> = Vec::with_capacity(n + 1);
+        let mut row_indices: Vec<usize> = Vec::new();
+        let mut values: Vec<T> = Vec::new();
+
+        col_offsets.push(0);
+        for j in 0..n {
+            for i in 0..m {
+                let a_ij: T = matrix[[i, j]];
+                if a_ij != T::zero() {
+                    row_indices.push(i);
+                    values.push(a_ij);
+                }
+            }
+            col_offsets.push(values.len());
+        }
+
+        CscMatrix {
+            m,
+            n,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+}
+
+impl<T> From<General<T>> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: General<T>) -> Self {
+        CscMatrix::from(&matrix)
+    }
+}
+
+impl<T> From<&CscMatrix<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: &CscMatrix<T>) -> Self {
+        let mut dense: General<T> = General::zero(matrix.m, matrix.n);
+        for (i, j, a_ij) in matrix.iter() {
+            dense[[i, j]] = *a_ij;
+        }
+        dense
+    }
+}
+
+impl<T> From<CscMatrix<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: CscMatrix<T>) -> Self {
+        General::from(&matrix)
+    }
+}
+
+impl<T> From<&CooMatrix<T>> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a coordinate matrix into a CSC matrix. Entries at the same
+    /// position are summed up.
+    fn from(matrix: &CooMatrix<T>) -> Self {
+        let (col_offsets, row_indices, values) =
+            compressed::compress_triplets(matrix.n, &matrix.cols, &matrix.rows, &matrix.values);
+
+        CscMatrix {
+            m: matrix.m,
+            n: matrix.n,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+}
+
+impl<T> From<CooMatrix<T>> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: CooMatrix<T>) -> Self {
+        CscMatrix::from(&matrix)
+    }
+}
+
+impl<T> From<&CsrMatrix<T>> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: &CsrMatrix<T>) -> Self {
+        let (col_offsets, row_indices, values) = compressed::transpose_compressed(
+            matrix.n,
+            &matrix.row_offsets,
+            &matrix.col_indices,
+            &matrix.values,
+        );
+
+        CscMatrix {
+            m: matrix.m,
+            n: matrix.n,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+}
+
+impl<T> From<CsrMatrix<T>> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: CsrMatrix<T>) -> Self {
+        CscMatrix::from(&matrix)
+    }
+}
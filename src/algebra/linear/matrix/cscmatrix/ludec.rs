@@ -0,0 +1,499 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{CscMatrix, General, Solve, SparseOrdering},
+        vector::Vector,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Symbolic analysis of a sparse LU decomposition
+///
+/// It contains the fill-reducing column permutation $q$. As the row
+/// permutation is determined by partial pivoting, the pattern of the
+/// factors is only known after the numeric decomposition.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CscLUSymbolic {
+    n: usize,
+    q: Vec<usize>,
+}
+
+/// Result of a sparse LU decomposition with partial pivoting
+///
+/// ```math
+/// PAQ = LU
+/// ```
+/// $Q$ is the fill-reducing column permutation of the symbolic analysis,
+/// $P$ the row permutation of the partial pivoting, $L$ is a sparse unit
+/// lower triangular matrix and $U$ a sparse upper triangular matrix.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CscLUDec<T> {
+    l: CscMatrix<T>,
+    u: CscMatrix<T>,
+    p: Vec<usize>,
+    p_inv: Vec<usize>,
+    q: Vec<usize>,
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Computes the fill-reducing column ordering of a square matrix for a
+    /// LU decomposition
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the matrix is not square
+    pub fn symbolic_lu(&self, ordering: SparseOrdering) -> Result<CscLUSymbolic, LinalgError> {
+        if self.m != self.n {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(CscLUSymbolic {
+            n: self.n,
+            q: ordering.permutation(self),
+        })
+    }
+
+    /// Decomposes a square sparse matrix with partial pivoting
+    ///
+    /// Every column of the factors is computed by a sparse triangular solve
+    /// with the already computed columns of $L$ (Gilbert-Peierls), whose
+    /// costs are proportional to the number of floating point operations.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the matrix is not square and
+    /// [`LinalgError::Singular`] if it is singular
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CscMatrix, General, Solve, SparseOrdering};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::from(&matrix![   0.0, 2.0, 0.0;
+    ///                                                     1.0, 0.0, 3.0;
+    ///                                                     0.0, 1.0, 1.0]);
+    /// let b: Vector<f64> = vector![2.0; 4.0; 2.0];
+    ///
+    /// let x: Vector<f64> = a.dec_lu(SparseOrdering::Colamd).unwrap().solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    pub fn dec_lu(&self, ordering: SparseOrdering) -> Result<CscLUDec<T>, LinalgError> {
+        self.symbolic_lu(ordering)?.factor(self)
+    }
+}
+
+impl CscLUSymbolic {
+    /// Return the fill-reducing column permutation
+    ///
+    /// The column $k$ of $AQ$ is the column `q[k]` of $A$.
+    pub fn column_permutation(&self) -> &[usize] {
+        &self.q
+    }
+
+    /// Computes the numeric decomposition with partial pivoting
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the dimension of $A$ differs
+    /// and [`LinalgError::Singular`] if $A$ is singular
+    pub fn factor<T>(&self, a: &CscMatrix<T>) -> Result<CscLUDec<T>, LinalgError>
+    where
+        T: Field + Scalar,
+    {
+        let n: usize = self.n;
+        if a.dim() != (n, n) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        // The rows of L are the original row indices during the
+        // decomposition, they are permuted at the end
+        let mut l_ptr: Vec<usize> = vec![0];
+        let mut l_idx: Vec<usize> = Vec::new();
+        let mut l_val: Vec<T> = Vec::new();
+        let mut u_ptr: Vec<usize> = vec![0];
+        let mut u_idx: Vec<usize> = Vec::new();
+        let mut u_val: Vec<T> = Vec::new();
+
+        let mut p_inv: Vec<Option<usize>> = vec![None; n];
+        let mut x: Vec<T> = vec![T::zero(); n];
+        let mut reach: Reach = Reach::new(n);
+
+        for k in 0..n {
+            let col: usize = self.q[k];
+            let (a_rows, a_values) = a.column(col);
+
+            // x = L \ A[:, col]
+            let pattern: &[usize] = reach.compute(&l_ptr, &l_idx, &p_inv, a_rows, k);
+            for i in pattern.iter() {
+                x[*i] = T::zero();
+            }
+            for (i, a_i) in a_rows.iter().zip(a_values.iter()) {
+                x[*i] += *a_i;
+            }
+            for j in pattern.iter() {
+                if let Some(j_col) = p_inv[*j] {
+                    let x_j: T = x[*j];
+                    for p in l_ptr[j_col] + 1..l_ptr[j_col + 1] {
+                        x[l_idx[p]] -= l_val[p] * x_j;
+                    }
+                }
+            }
+
+            // Partial pivoting, the diagonal entry is preferred on ties
+            let mut pivot: Option<usize> = None;
+            let mut max: T = T::zero();
+            for i in pattern.iter() {
+                match p_inv[*i] {
+                    Some(i_col) => {
+                        u_idx.push(i_col);
+                        u_val.push(x[*i]);
+                    }
+                    None => {
+                        let abs: T = x[*i].abs();
+                        if abs > max {
+                            max = abs;
+                            pivot = Some(*i);
+                        }
+                    }
+                }
+            }
+            if p_inv[col].is_none() && max != T::zero() && x[col].abs() >= max {
+                pivot = Some(col);
+            }
+            let pivot: usize = pivot.ok_or(LinalgError::Singular)?;
+            let u_kk: T = x[pivot];
+
+            u_idx.push(k);
+            u_val.push(u_kk);
+            u_ptr.push(u_idx.len());
+
+            p_inv[pivot] = Some(k);
+            l_idx.push(pivot);
+            l_val.push(T::one());
+            for i in pattern.iter() {
+                if p_inv[*i].is_none() {
+                    l_idx.push(*i);
+                    l_val.push(x[*i] / u_kk);
+                }
+            }
+            l_ptr.push(l_idx.len());
+        }
+
+        let p_inv: Vec<usize> = p_inv.into_iter().map(|k| k.unwrap()).collect();
+        for i in l_idx.iter_mut() {
+            *i = p_inv[*i];
+        }
+        let mut p: Vec<usize> = vec![0; n];
+        for (i, k) in p_inv.iter().enumerate() {
+            p[*k] = i;
+        }
+
+        Ok(CscLUDec {
+            l: sorted_csc(n, l_ptr, l_idx, l_val),
+            u: sorted_csc(n, u_ptr, u_idx, u_val),
+            p,
+            p_inv,
+            q: self.q.clone(),
+        })
+    }
+}
+
+impl<T> CscLUDec<T>
+where
+    T: Field + Scalar,
+{
+    /// Return the unit lower triangular factor $L$
+    pub fn l(self) -> CscMatrix<T> {
+        self.l
+    }
+
+    /// Return the upper triangular factor $U$
+    pub fn u(self) -> CscMatrix<T> {
+        self.u
+    }
+
+    /// Return the row permutation
+    ///
+    /// The row $k$ of $PA$ is the row `p[k]` of $A$.
+    pub fn row_permutation(&self) -> &[usize] {
+        &self.p
+    }
+
+    /// Return the column permutation
+    ///
+    /// The column $k$ of $AQ$ is the column `q[k]` of $A$.
+    pub fn column_permutation(&self) -> &[usize] {
+        &self.q
+    }
+
+    /// Replaces the decomposition by the one of a matrix with the same
+    /// pattern
+    ///
+    /// The permutations and the patterns of the factors are reused and only
+    /// the values are computed, i.e. no new pivoting is done. Refactoring
+    /// is therefore cheaper than a new decomposition, but only stable as
+    /// long as the values do not change too much.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the dimension of $A$ differs or
+    /// $A$ has an entry outside of the pattern of the factors and
+    /// [`LinalgError::Singular`] if a pivot vanishes. The decomposition is
+    /// left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CscMatrix, General, Solve, SparseOrdering};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::from(&matrix![   4.0, 1.0, 0.0;
+    ///                                                     1.0, 4.0, 1.0;
+    ///                                                     0.0, 1.0, 4.0]);
+    /// let mut dec = a.dec_lu(SparseOrdering::Amd).unwrap();
+    ///
+    /// dec.refactor(&a.apply(&|a_ij| 2.0 * a_ij)).unwrap();
+    /// let x: Vector<f64> = dec.solve(&vector![10.0; 12.0; 10.0]).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    pub fn refactor(&mut self, a: &CscMatrix<T>) -> Result<(), LinalgError> {
+        let n: usize = self.q.len();
+        if a.dim() != (n, n) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        let (lp, li): (&[usize], &[usize]) = (&self.l.col_offsets, &self.l.row_indices);
+        let (up, ui): (&[usize], &[usize]) = (&self.u.col_offsets, &self.u.row_indices);
+        let mut l_val: Vec<T> = vec![T::zero(); li.len()];
+        let mut u_val: Vec<T> = vec![T::zero(); ui.len()];
+        let mut x: Vec<T> = vec![T::zero(); n];
+        let mut mark: Vec<usize> = vec![usize::MAX; n];
+
+        for k in 0..n {
+            for i in ui[up[k]..up[k + 1]]
+                .iter()
+                .chain(li[lp[k]..lp[k + 1]].iter())
+            {
+                mark[*i] = k;
+            }
+            let (a_rows, a_values) = a.column(self.q[k]);
+            for (r, a_r) in a_rows.iter().zip(a_values.iter()) {
+                let i: usize = self.p_inv[*r];
+                if mark[i] != k {
+                    return Err(LinalgError::DimensionMismatch);
+                }
+                x[i] += *a_r;
+            }
+
+            // The rows of U[:, k] are sorted, which is a topological order
+            // of the triangular solve
+            for p in up[k]..up[k + 1] - 1 {
+                let j: usize = ui[p];
+                let x_j: T = x[j];
+                x[j] = T::zero();
+                u_val[p] = x_j;
+                for pp in lp[j] + 1..lp[j + 1] {
+                    x[li[pp]] -= l_val[pp] * x_j;
+                }
+            }
+
+            let u_kk: T = x[k];
+            x[k] = T::zero();
+            if u_kk == T::zero() {
+                return Err(LinalgError::Singular);
+            }
+            u_val[up[k + 1] - 1] = u_kk;
+            l_val[lp[k]] = T::one();
+            for p in lp[k] + 1..lp[k + 1] {
+                l_val[p] = x[li[p]] / u_kk;
+                x[li[p]] = T::zero();
+            }
+        }
+
+        self.l.values = l_val;
+        self.u.values = u_val;
+        Ok(())
+    }
+}
+
+impl<T> Solve<Vector<T>> for CscLUDec<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $Ax = b$
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let n: usize = self.q.len();
+        if rhs.dim() != (n, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        let (lp, li, lx): (&[usize], &[usize], &[T]) =
+            (&self.l.col_offsets, &self.l.row_indices, &self.l.values);
+        let (up, ui, ux): (&[usize], &[usize], &[T]) =
+            (&self.u.col_offsets, &self.u.row_indices, &self.u.values);
+
+        let mut y: Vec<T> = self.p.iter().map(|p_k| rhs[*p_k]).collect();
+        for j in 0..n {
+            let y_j: T = y[j];
+            for p in lp[j] + 1..lp[j + 1] {
+                y[li[p]] -= lx[p] * y_j;
+            }
+        }
+        for j in (0..n).rev() {
+            y[j] /= ux[up[j + 1] - 1];
+            let y_j: T = y[j];
+            for p in up[j]..up[j + 1] - 1 {
+                y[ui[p]] -= ux[p] * y_j;
+            }
+        }
+
+        let mut x: Vector<T> = Vector::zero(n);
+        for (k, q_k) in self.q.iter().enumerate() {
+            x[*q_k] = y[k];
+        }
+        Ok(x)
+    }
+}
+
+impl<T> Solve<General<T>> for CscLUDec<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $AX = B$
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        let (m, k): (usize, usize) = rhs.dim();
+        let mut x: General<T> = General::zero(m, k);
+        for j in 0..k {
+            x.set_column(&self.solve(&rhs.get_column(j))?, j);
+        }
+        Ok(x)
+    }
+}
+
+impl<T> Solve<Vector<T>> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $Ax = b$ with a sparse LU decomposition and the
+    /// [`SparseOrdering::Colamd`] ordering
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CscMatrix, General, Solve};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::from(&matrix![   1.0, 0.0, 2.0;
+    ///                                                     0.0, 3.0, 0.0;
+    ///                                                     4.0, 0.0, 5.0]);
+    ///
+    /// let x: Vector<f64> = a.solve(&vector![3.0; 3.0; 9.0]).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        self.dec_lu(SparseOrdering::Colamd)?.solve(rhs)
+    }
+}
+
+/// Depth first search in the graph of L, which determines the nonzero
+/// pattern of the solution of $Lx = b$ in topological order
+struct Reach {
+    mark: Vec<usize>,
+    pattern: Vec<usize>,
+    stack: Vec<(usize, usize)>,
+}
+
+impl Reach {
+    fn new(n: usize) -> Reach {
+        Reach {
+            mark: vec![usize::MAX; n],
+            pattern: Vec::with_capacity(n),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Returns the rows reachable from the rows of b, every row is listed
+    /// before the rows it updates
+    fn compute(
+        &mut self,
+        l_ptr: &[usize],
+        l_idx: &[usize],
+        p_inv: &[Option<usize>],
+        b_rows: &[usize],
+        stamp: usize,
+    ) -> &[usize] {
+        self.pattern.clear();
+        // The children of a pivotal row are the off-diagonal rows of its
+        // column of L
+        let children = |j: usize| match p_inv[j] {
+            Some(j_col) => (l_ptr[j_col] + 1, l_ptr[j_col + 1]),
+            None => (0, 0),
+        };
+
+        for root in b_rows.iter() {
+            if self.mark[*root] == stamp {
+                continue;
+            }
+            self.mark[*root] = stamp;
+            self.stack.push((*root, children(*root).0));
+
+            while let Some((j, p)) = self.stack.pop() {
+                let end: usize = children(j).1;
+                match (p..end).find(|p| self.mark[l_idx[*p]] != stamp) {
+                    Some(p) => {
+                        let i: usize = l_idx[p];
+                        self.mark[i] = stamp;
+                        self.stack.push((j, p + 1));
+                        self.stack.push((i, children(i).0));
+                    }
+                    None => self.pattern.push(j),
+                }
+            }
+        }
+
+        // Post order reversed
+        self.pattern.reverse();
+        &self.pattern
+    }
+}
+
+/// Sorts the row indices of every column
+fn sorted_csc<T>(n: usize, ptr: Vec<usize>, idx: Vec<usize>, val: Vec<T>) -> CscMatrix<T>
+where
+    T: Copy,
+{
+    let mut row_indices: Vec<usize> = Vec::with_capacity(idx.len());
+    let mut values: Vec<T> = Vec::with_capacity(val.len());
+    for k in 0..n {
+        let mut column: Vec<(usize, T)> = idx[ptr[k]..ptr[k + 1]]
+            .iter()
+            .copied()
+            .zip(val[ptr[k]..ptr[k + 1]].iter().copied())
+            .collect();
+        column.sort_unstable_by_key(|(i, _)| *i);
+        for (i, v) in column {
+            row_indices.push(i);
+            values.push(v);
+        }
+    }
+
+    CscMatrix {
+        m: n,
+        n,
+        col_offsets: ptr,
+        row_indices,
+        values,
+    }
+}
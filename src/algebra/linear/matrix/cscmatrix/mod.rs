@@ -0,0 +1,17 @@
+mod add;
+mod choleskydec;
+mod csciterator;
+mod cscmatrix;
+mod from;
+mod ludec;
+mod mul;
+mod ordering;
+mod partial_eq;
+mod sub;
+mod transpose;
+
+pub use choleskydec::{CscCholeskyDec, CscCholeskySymbolic};
+pub use csciterator::{CscColumnIterator, CscIterator};
+pub use cscmatrix::CscMatrix;
+pub use ludec::{CscLUDec, CscLUSymbolic};
+pub use ordering::SparseOrdering;
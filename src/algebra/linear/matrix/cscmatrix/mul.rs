@@ -0,0 +1,198 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{compressed, CscMatrix, General},
+        vector::Vector,
+    },
+};
+use std::ops::Mul;
+
+/// Multiplies a CSC matrix with a vector
+impl<'b, T> Mul<&'b Vector<T>> for &CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    /// Multiplies a CSC matrix with a column vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{CscMatrix, General}, vector::Vector};
+    /// use mathru::{matrix, vector};
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::from(&matrix![1.0, 0.0; 3.0, -7.0]);
+    /// let x: Vector<f64> = vector![2.0; 1.0];
+    ///
+    /// assert_eq!(&a * &x, vector![2.0; -1.0]);
+    /// ```
+    fn mul(self, v: &'b Vector<T>) -> Vector<T> {
+        let (v_m, _v_n): (usize, usize) = v.dim();
+        debug_assert_eq!(self.n, v_m);
+
+        let mut prod: Vector<T> = Vector::zero(self.m);
+        for j in 0..self.n {
+            let v_j: T = v[j];
+            for p in self.col_offsets[j]..self.col_offsets[j + 1] {
+                prod[self.row_indices[p]] += self.values[p] * v_j;
+            }
+        }
+
+        prod
+    }
+}
+
+impl<T> Mul<Vector<T>> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        &self * &v
+    }
+}
+
+/// Multiplies a CSC matrix with a dense matrix
+impl<'b, T> Mul<&'b General<T>> for &CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    /// Multiplies a CSC matrix with a dense matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CscMatrix, General};
+    /// use mathru::matrix;
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::from(&matrix![1.0, 0.0; 3.0, -7.0]);
+    /// let b: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    ///
+    /// assert_eq!(&a * &b, matrix![1.0, 2.0; -18.0, -22.0]);
+    /// ```
+    fn mul(self, rhs: &'b General<T>) -> General<T> {
+        let (rhs_m, rhs_n): (usize, usize) = rhs.dim();
+        debug_assert_eq!(self.n, rhs_m);
+
+        let mut prod: General<T> = General::zero(self.m, rhs_n);
+        for k in 0..rhs_n {
+            for j in 0..self.n {
+                let b_jk: T = rhs[[j, k]];
+                for p in self.col_offsets[j]..self.col_offsets[j + 1] {
+                    prod[[self.row_indices[p], k]] += self.values[p] * b_jk;
+                }
+            }
+        }
+
+        prod
+    }
+}
+
+impl<T> Mul<General<T>> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn mul(self, rhs: General<T>) -> General<T> {
+        &self * &rhs
+    }
+}
+
+/// Multiplies a dense matrix with a CSC matrix
+impl<'b, T> Mul<&'b CscMatrix<T>> for &General<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn mul(self, rhs: &'b CscMatrix<T>) -> General<T> {
+        let (m, n): (usize, usize) = self.dim();
+        debug_assert_eq!(n, rhs.m);
+
+        let mut prod: General<T> = General::zero(m, rhs.n);
+        for k in 0..rhs.n {
+            for p in rhs.col_offsets[k]..rhs.col_offsets[k + 1] {
+                let j: usize = rhs.row_indices[p];
+                let b_jk: T = rhs.values[p];
+                for i in 0..m {
+                    prod[[i, k]] += self[[i, j]] * b_jk;
+                }
+            }
+        }
+
+        prod
+    }
+}
+
+/// Multiplies two CSC matrices
+impl<'b, T> Mul<&'b CscMatrix<T>> for &CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CscMatrix<T>;
+
+    /// Multiplies two CSC matrices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CscMatrix, General};
+    /// use mathru::matrix;
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::from(&matrix![1.0, 0.0; 3.0, -7.0]);
+    /// let b: CscMatrix<f64> = CscMatrix::from(&matrix![0.0, 2.0; 1.0, 0.0]);
+    ///
+    /// assert_eq!(General::from(&a * &b), matrix![0.0, 2.0; -7.0, 6.0]);
+    /// ```
+    fn mul(self, rhs: &'b CscMatrix<T>) -> CscMatrix<T> {
+        debug_assert_eq!(self.n, rhs.m);
+
+        // column k of the product is a linear combination of the columns of self
+        let (col_offsets, row_indices, values) = compressed::mul_compressed(
+            self.m,
+            &rhs.col_offsets,
+            &rhs.row_indices,
+            &rhs.values,
+            &self.col_offsets,
+            &self.row_indices,
+            &self.values,
+        );
+
+        CscMatrix {
+            m: self.m,
+            n: rhs.n,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+}
+
+/// Multiplies a CSC matrix with a scalar
+impl<'b, T> Mul<&'b T> for &CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CscMatrix<T>;
+
+    fn mul(self, k: &'b T) -> CscMatrix<T> {
+        self.apply(&|x: &T| *x * *k)
+    }
+}
+
+impl<T> Mul<T> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CscMatrix<T>;
+
+    fn mul(mut self, k: T) -> CscMatrix<T> {
+        self.values.iter_mut().for_each(|x| *x *= k);
+        self
+    }
+}
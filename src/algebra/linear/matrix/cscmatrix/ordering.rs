@@ -0,0 +1,252 @@
+use crate::algebra::linear::matrix::CscMatrix;
+use std::{cmp::Reverse, collections::BinaryHeap, collections::VecDeque};
+
+/// Fill-reducing orderings of sparse matrices
+///
+/// An ordering is a permutation $p$ of the columns, the new column $k$ is
+/// the old column $p_k$. Symmetric factorizations apply it to the rows as
+/// well.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::matrix::{CscMatrix, General, SparseOrdering};
+/// use mathru::matrix;
+///
+/// // Arrow matrix, eliminating the dense column first fills the whole matrix
+/// let a: CscMatrix<f64> = CscMatrix::from(&matrix![   4.0, 1.0, 1.0, 1.0;
+///                                                     1.0, 4.0, 0.0, 0.0;
+///                                                     1.0, 0.0, 4.0, 0.0;
+///                                                     1.0, 0.0, 0.0, 4.0]);
+///
+/// let p: Vec<usize> = SparseOrdering::Amd.permutation(&a);
+///
+/// assert!(!p[..2].contains(&0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseOrdering {
+    /// No reordering
+    Natural,
+    /// Reverse Cuthill-McKee ordering of the pattern of $A + A^T$, which
+    /// reduces the bandwidth
+    ReverseCuthillMcKee,
+    /// Approximate minimum degree ordering of the pattern of $A + A^T$ for
+    /// Cholesky and LU decompositions of matrices with a symmetric pattern
+    Amd,
+    /// Approximate minimum degree ordering of the pattern of $A^TA$, which
+    /// is not formed explicitly, for LU decompositions with partial pivoting
+    Colamd,
+}
+
+impl SparseOrdering {
+    /// Computes the permutation of the columns of $A$
+    ///
+    /// Only the pattern of $A$ is read. `Natural`, `ReverseCuthillMcKee`
+    /// and `Amd` require a square matrix.
+    ///
+    /// # Panics
+    ///
+    /// if the ordering requires a square matrix and $A$ is not square
+    pub fn permutation<T>(&self, a: &CscMatrix<T>) -> Vec<usize> {
+        let (m, n): (usize, usize) = a.dim();
+        match self {
+            SparseOrdering::Natural => (0..n).collect(),
+            SparseOrdering::ReverseCuthillMcKee => {
+                assert_eq!(m, n, "The ordering requires a square matrix");
+                reverse_cuthill_mckee(&symmetric_adjacency(a))
+            }
+            SparseOrdering::Amd => {
+                assert_eq!(m, n, "The ordering requires a square matrix");
+                minimum_degree(symmetric_adjacency(a), Vec::new())
+            }
+            SparseOrdering::Colamd => {
+                // Every row of A is an element, which connects its columns
+                let mut rows: Vec<Vec<usize>> = vec![Vec::new(); m];
+                for j in 0..n {
+                    for i in a.row_indices[a.col_offsets[j]..a.col_offsets[j + 1]].iter() {
+                        rows[*i].push(j);
+                    }
+                }
+                minimum_degree(vec![Vec::new(); n], rows)
+            }
+        }
+    }
+}
+
+/// Adjacency lists of the graph of $A + A^T$ without self loops
+fn symmetric_adjacency<T>(a: &CscMatrix<T>) -> Vec<Vec<usize>> {
+    let n: usize = a.ncols();
+    let mut adj: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for j in 0..n {
+        for i in a.row_indices[a.col_offsets[j]..a.col_offsets[j + 1]].iter() {
+            if *i != j {
+                adj[*i].push(j);
+                adj[j].push(*i);
+            }
+        }
+    }
+    for a_i in adj.iter_mut() {
+        a_i.sort_unstable();
+        a_i.dedup();
+    }
+    adj
+}
+
+/// Breadth first search restricted to the unvisited nodes
+///
+/// Returns the visited nodes in the order of the search, where the
+/// neighbours of a node are visited in the order of increasing degree, the
+/// index of the first node of the last level and the number of levels.
+fn breadth_first_search(
+    adj: &[Vec<usize>],
+    root: usize,
+    visited: &[bool],
+) -> (Vec<usize>, usize, usize) {
+    let mut seen: Vec<bool> = visited.to_vec();
+    let mut order: Vec<usize> = vec![root];
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+    let (mut last_start, mut depth): (usize, usize) = (0, 0);
+
+    seen[root] = true;
+    queue.push_back((root, 0));
+    while let Some((node, level)) = queue.pop_front() {
+        let mut neighbours: Vec<usize> = adj[node].iter().copied().filter(|i| !seen[*i]).collect();
+        neighbours.sort_by_key(|i| (adj[*i].len(), *i));
+        for i in neighbours {
+            seen[i] = true;
+            if level + 1 > depth {
+                last_start = order.len();
+                depth = level + 1;
+            }
+            order.push(i);
+            queue.push_back((i, level + 1));
+        }
+    }
+
+    (order, last_start, depth)
+}
+
+fn reverse_cuthill_mckee(adj: &[Vec<usize>]) -> Vec<usize> {
+    let n: usize = adj.len();
+    let mut visited: Vec<bool> = vec![false; n];
+    let mut order: Vec<usize> = Vec::with_capacity(n);
+
+    while order.len() < n {
+        // Search of a pseudo-peripheral node of the component, starting with
+        // an unvisited node of minimum degree
+        let root: usize = (0..n)
+            .filter(|i| !visited[*i])
+            .min_by_key(|i| adj[*i].len())
+            .unwrap();
+        let (mut component, mut last_start, mut depth) = breadth_first_search(adj, root, &visited);
+        loop {
+            let candidate: usize = component[last_start..]
+                .iter()
+                .copied()
+                .min_by_key(|i| adj[*i].len())
+                .unwrap();
+            let (c_component, c_last_start, c_depth) =
+                breadth_first_search(adj, candidate, &visited);
+            if c_depth <= depth {
+                break;
+            }
+            component = c_component;
+            last_start = c_last_start;
+            depth = c_depth;
+        }
+
+        for i in component.iter() {
+            visited[*i] = true;
+        }
+        order.extend(component);
+    }
+
+    order.reverse();
+    order
+}
+
+/// Minimum degree ordering on the quotient graph
+///
+/// The graph consists of the variables with the adjacency `var_adj` and
+/// the elements `elements`, i.e. cliques of variables. Eliminating a
+/// variable turns it into a new element, which absorbs the elements
+/// adjacent to it. The degree of a variable is approximated by the upper
+/// bound $|A_i| + \sum_{e \in E_i} (|L_e| - 1)$.
+fn minimum_degree(mut var_adj: Vec<Vec<usize>>, mut elements: Vec<Vec<usize>>) -> Vec<usize> {
+    let n: usize = var_adj.len();
+    let mut elem_adj: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (e, vars) in elements.iter().enumerate() {
+        for i in vars.iter() {
+            elem_adj[*i].push(e);
+        }
+    }
+    let mut absorbed: Vec<bool> = vec![false; elements.len()];
+    let mut eliminated: Vec<bool> = vec![false; n];
+    let mut mark: Vec<usize> = vec![0; n];
+
+    let approximate_degree =
+        |i: usize, var_adj: &[Vec<usize>], elem_adj: &[Vec<usize>], elements: &[Vec<usize>]| {
+            var_adj[i].len()
+                + elem_adj[i]
+                    .iter()
+                    .map(|e| elements[*e].len().saturating_sub(1))
+                    .sum::<usize>()
+        };
+
+    let mut degree: Vec<usize> = (0..n)
+        .map(|i| approximate_degree(i, &var_adj, &elem_adj, &elements))
+        .collect();
+    let mut heap: BinaryHeap<Reverse<(usize, usize)>> =
+        (0..n).map(|i| Reverse((degree[i], i))).collect();
+    let mut order: Vec<usize> = Vec::with_capacity(n);
+
+    while let Some(Reverse((d, p))) = heap.pop() {
+        if eliminated[p] || d != degree[p] {
+            continue;
+        }
+        eliminated[p] = true;
+        order.push(p);
+        let stamp: usize = order.len();
+
+        // L_p = (A_p \cup \bigcup_{e \in E_p} L_e) \ {p}
+        let mut l_p: Vec<usize> = Vec::new();
+        for i in var_adj[p].iter() {
+            if !eliminated[*i] && mark[*i] != stamp {
+                mark[*i] = stamp;
+                l_p.push(*i);
+            }
+        }
+        for e in elem_adj[p].iter() {
+            if absorbed[*e] {
+                continue;
+            }
+            for i in elements[*e].iter() {
+                if !eliminated[*i] && mark[*i] != stamp {
+                    mark[*i] = stamp;
+                    l_p.push(*i);
+                }
+            }
+            absorbed[*e] = true;
+            elements[*e] = Vec::new();
+        }
+        var_adj[p] = Vec::new();
+        elem_adj[p] = Vec::new();
+
+        let e_p: usize = elements.len();
+        elements.push(l_p.clone());
+        absorbed.push(false);
+
+        let remaining: usize = n - order.len();
+        for i in l_p.iter().copied() {
+            // The edges within L_p are covered by the new element
+            var_adj[i].retain(|j| !eliminated[*j] && mark[*j] != stamp);
+            elem_adj[i].retain(|e| !absorbed[*e]);
+            elem_adj[i].push(e_p);
+
+            degree[i] = approximate_degree(i, &var_adj, &elem_adj, &elements).min(remaining - 1);
+            heap.push(Reverse((degree[i], i)));
+        }
+    }
+
+    order
+}
@@ -0,0 +1,26 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{compressed, CscMatrix},
+};
+
+impl<T> PartialEq for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Checks if two CSC matrices are equal. Entries which are not stored are
+    /// treated as zero.
+    fn eq(&self, other: &Self) -> bool {
+        if self.dim() != other.dim() {
+            return false;
+        }
+
+        compressed::eq_compressed(
+            &self.col_offsets,
+            &self.row_indices,
+            &self.values,
+            &other.col_offsets,
+            &other.row_indices,
+            &other.values,
+        )
+    }
+}
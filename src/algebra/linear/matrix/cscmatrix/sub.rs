@@ -0,0 +1,58 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{compressed, CscMatrix},
+};
+use std::ops::Sub;
+
+impl<'b, T> Sub<&'b CscMatrix<T>> for &CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CscMatrix<T>;
+
+    /// Subtracts two CSC matrices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CscMatrix, General};
+    /// use mathru::matrix;
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::from(&matrix![1.0, 0.0; 0.0, 2.0]);
+    /// let b: CscMatrix<f64> = CscMatrix::from(&matrix![0.0, 3.0; 0.0, -2.0]);
+    ///
+    /// assert_eq!(General::from(&a - &b), matrix![1.0, -3.0; 0.0, 4.0]);
+    /// ```
+    fn sub(self, rhs: &'b CscMatrix<T>) -> Self::Output {
+        debug_assert_eq!(self.dim(), rhs.dim());
+
+        let (col_offsets, row_indices, values) = compressed::combine_compressed(
+            &self.col_offsets,
+            &self.row_indices,
+            &self.values,
+            &rhs.col_offsets,
+            &rhs.row_indices,
+            &rhs.values,
+            |a, b| a - b,
+        );
+
+        CscMatrix {
+            m: self.m,
+            n: self.n,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+}
+
+impl<T> Sub<CscMatrix<T>> for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CscMatrix<T>;
+
+    fn sub(self, rhs: CscMatrix<T>) -> Self::Output {
+        &self - &rhs
+    }
+}
@@ -0,0 +1,41 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{compressed, CscMatrix, Transpose},
+};
+
+impl<T> Transpose for CscMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CscMatrix<T>;
+
+    /// Transposes a CSC matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CscMatrix, General, Transpose};
+    /// use mathru::matrix;
+    ///
+    /// let a: CscMatrix<f64> = CscMatrix::from(&matrix![1.0, 2.0, 0.0; 0.0, 0.0, 3.0]);
+    /// let a_t: General<f64> = a.transpose().into();
+    ///
+    /// assert_eq!(a_t, matrix![1.0, 0.0; 2.0, 0.0; 0.0, 3.0]);
+    /// ```
+    fn transpose(self) -> CscMatrix<T> {
+        let (col_offsets, row_indices, values) = compressed::transpose_compressed(
+            self.m,
+            &self.col_offsets,
+            &self.row_indices,
+            &self.values,
+        );
+
+        CscMatrix {
+            m: self.n,
+            n: self.m,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{compressed, CsrMatrix},
+};
+use std::ops::Add;
+
+impl<'b, T> Add<&'b CsrMatrix<T>> for &CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CsrMatrix<T>;
+
+    /// Adds two CSR matrices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CsrMatrix, General};
+    /// use mathru::matrix;
+    ///
+    /// let a: CsrMatrix<f64> = CsrMatrix::from(&matrix![1.0, 0.0; 0.0, 2.0]);
+    /// let b: CsrMatrix<f64> = CsrMatrix::from(&matrix![0.0, 3.0; 0.0, -2.0]);
+    ///
+    /// assert_eq!(General::from(&a + &b), matrix![1.0, 3.0; 0.0, 0.0]);
+    /// ```
+    fn add(self, rhs: &'b CsrMatrix<T>) -> Self::Output {
+        debug_assert_eq!(self.dim(), rhs.dim());
+
+        let (row_offsets, col_indices, values) = compressed::combine_compressed(
+            &self.row_offsets,
+            &self.col_indices,
+            &self.values,
+            &rhs.row_offsets,
+            &rhs.col_indices,
+            &rhs.values,
+            |a, b| a + b,
+        );
+
+        CsrMatrix {
+            m: self.m,
+            n: self.n,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+impl<T> Add<CsrMatrix<T>> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CsrMatrix<T>;
+
+    fn add(self, rhs: CsrMatrix<T>) -> Self::Output {
+        &self + &rhs
+    }
+}
@@ -0,0 +1,66 @@
+use crate::algebra::linear::matrix::CsrMatrix;
+
+/// Iterator over the stored entries of a CSR matrix
+pub struct CsrIterator<'a, T> {
+    matrix: &'a CsrMatrix<T>,
+    row: usize,
+    pos: usize,
+}
+
+impl<'a, T> CsrIterator<'a, T> {
+    pub fn new(matrix: &'a CsrMatrix<T>) -> CsrIterator<'a, T> {
+        CsrIterator {
+            matrix,
+            row: 0,
+            pos: 0,
+        }
+    }
+}
+
+impl<'a, T> Iterator for CsrIterator<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.matrix.values.len() {
+            return None;
+        }
+
+        while self.matrix.row_offsets[self.row + 1] <= self.pos {
+            self.row += 1;
+        }
+
+        let k = self.pos;
+        self.pos += 1;
+        Some((
+            self.row,
+            self.matrix.col_indices[k],
+            &self.matrix.values[k],
+        ))
+    }
+}
+
+/// Iterator over the rows of a CSR matrix
+pub struct CsrRowIterator<'a, T> {
+    matrix: &'a CsrMatrix<T>,
+    row: usize,
+}
+
+impl<'a, T> CsrRowIterator<'a, T> {
+    pub fn new(matrix: &'a CsrMatrix<T>) -> CsrRowIterator<'a, T> {
+        CsrRowIterator { matrix, row: 0 }
+    }
+}
+
+impl<'a, T> Iterator for CsrRowIterator<'a, T> {
+    type Item = (&'a [usize], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row < self.matrix.m {
+            let row = self.matrix.row(self.row);
+            self.row += 1;
+            Some(row)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{
+        compressed,
+        csrmatrix::{CsrIterator, CsrRowIterator},
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Sparse matrix in compressed sparse row (CSR) format
+///
+/// The entries of row i are stored in `values[row_offsets[i]..row_offsets[i + 1]]`
+/// and their column indices in `col_indices[row_offsets[i]..row_offsets[i + 1]]`.
+/// The column indices within a row are strictly increasing.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::{matrix::{CsrMatrix, General}, vector::Vector};
+/// use mathru::{matrix, vector};
+///
+/// // a = [1.0 0.0 2.0]
+/// //     [0.0 0.0 3.0]
+/// let a: CsrMatrix<f64> = CsrMatrix::new(2, 3, vec![0, 2, 3], vec![0, 2, 2], vec![1.0, 2.0, 3.0]);
+/// let x: Vector<f64> = vector![1.0; 1.0; 1.0];
+///
+/// assert_eq!(&a * &x, vector![3.0; 3.0]);
+/// assert_eq!(General::from(&a), matrix![1.0, 0.0, 2.0; 0.0, 0.0, 3.0]);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CsrMatrix<T> {
+    /// Num of rows which the matrix has
+    pub(crate) m: usize,
+    /// Num of columns which the matrix has
+    pub(crate) n: usize,
+    /// Offsets of the rows in `col_indices` and `values`
+    pub(crate) row_offsets: Vec<usize>,
+    /// Column indices of the entries
+    pub(crate) col_indices: Vec<usize>,
+    /// Values of the entries
+    pub(crate) values: Vec<T>,
+}
+
+impl<T> CsrMatrix<T> {
+    /// Creates a new CSR matrix from its raw parts
+    ///
+    /// # Arguments
+    ///
+    /// * `m`: number of rows
+    /// * `n`: number of columns
+    /// * `row_offsets`: m + 1 non decreasing offsets, starting with 0 and
+    ///   ending with the number of entries
+    /// * `col_indices`: column index of every entry
+    /// * `values`: value of every entry
+    ///
+    /// # Panics
+    ///
+    /// if the parts do not describe a valid CSR matrix
+    pub fn new(
+        m: usize,
+        n: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> CsrMatrix<T> {
+        compressed::check_compressed(m, n, &row_offsets, &col_indices, values.len());
+        CsrMatrix {
+            m,
+            n,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(&self) -> (usize, usize) {
+        (self.m, self.n)
+    }
+
+    /// Returns the number of rows
+    pub fn nrows(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the number of columns
+    pub fn ncols(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the row offsets
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.row_offsets
+    }
+
+    /// Returns the column indices of the stored entries
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_indices
+    }
+
+    /// Returns the values of the stored entries
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the values of the stored entries
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    /// Returns the column indices and values of row i
+    pub fn row(&self, i: usize) -> (&[usize], &[T]) {
+        let (s, e) = (self.row_offsets[i], self.row_offsets[i + 1]);
+        (&self.col_indices[s..e], &self.values[s..e])
+    }
+
+    /// Iterates over the stored entries row by row
+    ///
+    /// The items are triplets (row, column, value).
+    pub fn iter(&self) -> CsrIterator<'_, T> {
+        CsrIterator::new(self)
+    }
+
+    /// Iterates over the rows
+    ///
+    /// Every row is returned as a pair of its column indices and values.
+    pub fn row_iter(&self) -> CsrRowIterator<'_, T> {
+        CsrRowIterator::new(self)
+    }
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Creates a m x n matrix without any entries
+    pub fn zero(m: usize, n: usize) -> CsrMatrix<T> {
+        CsrMatrix {
+            m,
+            n,
+            row_offsets: vec![0; m + 1],
+            col_indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Returns the identity matrix
+    pub fn one(size: usize) -> CsrMatrix<T> {
+        CsrMatrix {
+            m: size,
+            n: size,
+            row_offsets: (0..=size).collect(),
+            col_indices: (0..size).collect(),
+            values: vec![T::one(); size],
+        }
+    }
+
+    /// Returns the entry at position (i, j)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::CsrMatrix;
+    ///
+    /// let a: CsrMatrix<f64> = CsrMatrix::new(2, 2, vec![0, 1, 2], vec![1, 0], vec![4.0, 5.0]);
+    ///
+    /// assert_eq!(a.get(0, 1), 4.0);
+    /// assert_eq!(a.get(0, 0), 0.0);
+    /// ```
+    pub fn get(&self, i: usize, j: usize) -> T {
+        debug_assert!(i < self.m && j < self.n);
+        let (cols, values) = self.row(i);
+        match cols.binary_search(&j) {
+            Ok(p) => values[p],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// Returns the diagonal entries
+    pub fn diagonal(&self) -> Vec<T> {
+        (0..self.m.min(self.n)).map(|i| self.get(i, i)).collect()
+    }
+
+    /// Applies the function f on every stored entry
+    pub fn apply(&self, f: &dyn Fn(&T) -> T) -> CsrMatrix<T> {
+        CsrMatrix {
+            m: self.m,
+            n: self.n,
+            row_offsets: self.row_offsets.clone(),
+            col_indices: self.col_indices.clone(),
+            values: self.values.iter().map(f).collect(),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{compressed, CooMatrix, CscMatrix, CsrMatrix, General},
+};
+
+impl<T> From<&General<T>> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a dense matrix into a CSR matrix. Zero entries are not stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CsrMatrix, General};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![1.0, 0.0; 0.0, 2.0];
+    /// let b: CsrMatrix<f64> = CsrMatrix::from(&a);
+    ///
+    /// assert_eq!(b.nnz(), 2);
+    /// ```
+    fn from(matrix: &General<T>) -> Self {
+        let (m, n): (usize, usize) = matrix.dim();
+        let mut row_offsets: Vec<usize> = Vec::with_capacity(m + 1);
+        let mut col_indices: Vec<usize> = Vec::new();
+        let mut values: Vec<T> = Vec::new();
+
+        row_offsets.push(0);
+        for i in 0..m {
+            for j in 0..n {
+                let a_ij: T = matrix[[i, j]];
+                if a_ij != T::zero() {
+                    col_indices.push(j);
+                    values.push(a_ij);
+                }
+            }
+            row_offsets.push(values.len());
+        }
+
+        CsrMatrix {
+            m,
+            n,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+impl<T> From<General<T>> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: General<T>) -> Self {
+        CsrMatrix::from(&matrix)
+    }
+}
+
+impl<T> From<&CsrMatrix<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: &CsrMatrix<T>) -> Self {
+        let mut dense: General<T> = General::zero(matrix.m, matrix.n);
+        for (i, j, a_ij) in matrix.iter() {
+            dense[[i, j]] = *a_ij;
+        }
+        dense
+    }
+}
+
+impl<T> From<CsrMatrix<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: CsrMatrix<T>) -> Self {
+        General::from(&matrix)
+    }
+}
+
+impl<T> From<&CooMatrix<T>> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a coordinate matrix into a CSR matrix. Entries at the same
+    /// position are summed up.
+    fn from(matrix: &CooMatrix<T>) -> Self {
+        let (row_offsets, col_indices, values) =
+            compressed::compress_triplets(matrix.m, &matrix.rows, &matrix.cols, &matrix.values);
+
+        CsrMatrix {
+            m: matrix.m,
+            n: matrix.n,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+impl<T> From<CooMatrix<T>> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: CooMatrix<T>) -> Self {
+        CsrMatrix::from(&matrix)
+    }
+}
+
+impl<T> From<&CscMatrix<T>> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: &CscMatrix<T>) -> Self {
+        let (row_offsets, col_indices, values) = compressed::transpose_compressed(
+            matrix.m,
+            &matrix.col_offsets,
+            &matrix.row_indices,
+            &matrix.values,
+        );
+
+        CsrMatrix {
+            m: matrix.m,
+            n: matrix.n,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+impl<T> From<CscMatrix<T>> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    fn from(matrix: CscMatrix<T>) -> Self {
+        CsrMatrix::from(&matrix)
+    }
+}
@@ -0,0 +1,11 @@
+mod add;
+mod csriterator;
+mod csrmatrix;
+mod from;
+mod mul;
+mod partial_eq;
+mod sub;
+mod transpose;
+
+pub use csriterator::{CsrIterator, CsrRowIterator};
+pub use csrmatrix::CsrMatrix;
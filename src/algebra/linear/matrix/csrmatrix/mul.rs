@@ -0,0 +1,195 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{compressed, CsrMatrix, General},
+        vector::Vector,
+    },
+};
+use std::ops::Mul;
+
+/// Multiplies a CSR matrix with a vector
+impl<'b, T> Mul<&'b Vector<T>> for &CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    /// Multiplies a CSR matrix with a column vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{CsrMatrix, General}, vector::Vector};
+    /// use mathru::{matrix, vector};
+    ///
+    /// let a: CsrMatrix<f64> = CsrMatrix::from(&matrix![1.0, 0.0; 3.0, -7.0]);
+    /// let x: Vector<f64> = vector![2.0; 1.0];
+    ///
+    /// assert_eq!(&a * &x, vector![2.0; -1.0]);
+    /// ```
+    fn mul(self, v: &'b Vector<T>) -> Vector<T> {
+        let (v_m, _v_n): (usize, usize) = v.dim();
+        debug_assert_eq!(self.n, v_m);
+
+        let mut prod_data: Vec<T> = Vec::with_capacity(self.m);
+        for i in 0..self.m {
+            let mut sum: T = T::zero();
+            for p in self.row_offsets[i]..self.row_offsets[i + 1] {
+                sum += self.values[p] * v[self.col_indices[p]];
+            }
+            prod_data.push(sum);
+        }
+
+        Vector::new_column(prod_data)
+    }
+}
+
+impl<T> Mul<Vector<T>> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        &self * &v
+    }
+}
+
+/// Multiplies a CSR matrix with a dense matrix
+impl<'b, T> Mul<&'b General<T>> for &CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    /// Multiplies a CSR matrix with a dense matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CsrMatrix, General};
+    /// use mathru::matrix;
+    ///
+    /// let a: CsrMatrix<f64> = CsrMatrix::from(&matrix![1.0, 0.0; 3.0, -7.0]);
+    /// let b: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    ///
+    /// assert_eq!(&a * &b, matrix![1.0, 2.0; -18.0, -22.0]);
+    /// ```
+    fn mul(self, rhs: &'b General<T>) -> General<T> {
+        let (rhs_m, rhs_n): (usize, usize) = rhs.dim();
+        debug_assert_eq!(self.n, rhs_m);
+
+        let mut prod: General<T> = General::zero(self.m, rhs_n);
+        for k in 0..rhs_n {
+            for i in 0..self.m {
+                let mut sum: T = T::zero();
+                for p in self.row_offsets[i]..self.row_offsets[i + 1] {
+                    sum += self.values[p] * rhs[[self.col_indices[p], k]];
+                }
+                prod[[i, k]] = sum;
+            }
+        }
+
+        prod
+    }
+}
+
+impl<T> Mul<General<T>> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn mul(self, rhs: General<T>) -> General<T> {
+        &self * &rhs
+    }
+}
+
+/// Multiplies a dense matrix with a CSR matrix
+impl<'b, T> Mul<&'b CsrMatrix<T>> for &General<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn mul(self, rhs: &'b CsrMatrix<T>) -> General<T> {
+        let (m, n): (usize, usize) = self.dim();
+        debug_assert_eq!(n, rhs.m);
+
+        let mut prod: General<T> = General::zero(m, rhs.n);
+        for (j, k, a_jk) in rhs.iter() {
+            for i in 0..m {
+                prod[[i, k]] += self[[i, j]] * *a_jk;
+            }
+        }
+
+        prod
+    }
+}
+
+/// Multiplies two CSR matrices
+impl<'b, T> Mul<&'b CsrMatrix<T>> for &CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CsrMatrix<T>;
+
+    /// Multiplies two CSR matrices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CsrMatrix, General};
+    /// use mathru::matrix;
+    ///
+    /// let a: CsrMatrix<f64> = CsrMatrix::from(&matrix![1.0, 0.0; 3.0, -7.0]);
+    /// let b: CsrMatrix<f64> = CsrMatrix::from(&matrix![0.0, 2.0; 1.0, 0.0]);
+    ///
+    /// assert_eq!(General::from(&a * &b), matrix![0.0, 2.0; -7.0, 6.0]);
+    /// ```
+    fn mul(self, rhs: &'b CsrMatrix<T>) -> CsrMatrix<T> {
+        debug_assert_eq!(self.n, rhs.m);
+
+        let (row_offsets, col_indices, values) = compressed::mul_compressed(
+            rhs.n,
+            &self.row_offsets,
+            &self.col_indices,
+            &self.values,
+            &rhs.row_offsets,
+            &rhs.col_indices,
+            &rhs.values,
+        );
+
+        CsrMatrix {
+            m: self.m,
+            n: rhs.n,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+/// Multiplies a CSR matrix with a scalar
+impl<'b, T> Mul<&'b T> for &CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CsrMatrix<T>;
+
+    fn mul(self, k: &'b T) -> CsrMatrix<T> {
+        self.apply(&|x: &T| *x * *k)
+    }
+}
+
+impl<T> Mul<T> for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CsrMatrix<T>;
+
+    fn mul(mut self, k: T) -> CsrMatrix<T> {
+        self.values.iter_mut().for_each(|x| *x *= k);
+        self
+    }
+}
@@ -0,0 +1,26 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{compressed, CsrMatrix},
+};
+
+impl<T> PartialEq for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    /// Checks if two CSR matrices are equal. Entries which are not stored are
+    /// treated as zero.
+    fn eq(&self, other: &Self) -> bool {
+        if self.dim() != other.dim() {
+            return false;
+        }
+
+        compressed::eq_compressed(
+            &self.row_offsets,
+            &self.col_indices,
+            &self.values,
+            &other.row_offsets,
+            &other.col_indices,
+            &other.values,
+        )
+    }
+}
@@ -0,0 +1,41 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{compressed, CsrMatrix, Transpose},
+};
+
+impl<T> Transpose for CsrMatrix<T>
+where
+    T: Field + Scalar,
+{
+    type Output = CsrMatrix<T>;
+
+    /// Transposes a CSR matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CsrMatrix, General, Transpose};
+    /// use mathru::matrix;
+    ///
+    /// let a: CsrMatrix<f64> = CsrMatrix::from(&matrix![1.0, 2.0, 0.0; 0.0, 0.0, 3.0]);
+    /// let a_t: General<f64> = a.transpose().into();
+    ///
+    /// assert_eq!(a_t, matrix![1.0, 0.0; 2.0, 0.0; 0.0, 3.0]);
+    /// ```
+    fn transpose(self) -> CsrMatrix<T> {
+        let (row_offsets, col_indices, values) = compressed::transpose_compressed(
+            self.n,
+            &self.row_offsets,
+            &self.col_indices,
+            &self.values,
+        );
+
+        CsrMatrix {
+            m: self.n,
+            n: self.m,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
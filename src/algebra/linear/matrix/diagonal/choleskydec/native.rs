@@ -1,6 +1,7 @@
 use crate::algebra::abstr::{Complex, Real, Scalar};
 use crate::algebra::linear::matrix::choleskydec::CholeskyDecomposition;
 use crate::algebra::linear::matrix::{CholeskyDec, Diagonal};
+use crate::algebra::linear::LinalgError;
 
 impl<T> CholeskyDecomposition<T> for Diagonal<T>
 where
@@ -25,7 +26,7 @@ where
     ///
     /// let l = a.dec_cholesky().unwrap().l();
     /// ```
-    fn dec_cholesky(&self) -> Result<CholeskyDec<T>, String> {
+    fn dec_cholesky(&self) -> Result<CholeskyDec<T>, LinalgError> {
         self.matrix.dec_cholesky()
     }
 }
@@ -58,7 +59,7 @@ where
     ///
     /// let l: LowerTriangular<f64> = a.dec_cholesky().unwrap().l();
     /// ```
-    fn dec_cholesky(&self) -> Result<CholeskyDec<Complex<T>>, String> {
+    fn dec_cholesky(&self) -> Result<CholeskyDec<Complex<T>>, LinalgError> {
         self.matrix.dec_cholesky()
     }
 }
@@ -1,5 +1,6 @@
 use crate::algebra::abstr::{AbsDiffEq, Field, Scalar};
 use crate::algebra::linear::matrix::{Diagonal, EigenDec, EigenDecomposition, General};
+use crate::algebra::linear::LinalgError;
 use crate::elementary::Power;
 
 impl<T> EigenDecomposition<T> for Diagonal<T>
@@ -26,7 +27,7 @@ where
     ///    epsilon = 1.0e-5
     /// );
     /// ```
-    fn dec_eigen(&self) -> Result<EigenDec<T>, String> {
+    fn dec_eigen(&self) -> Result<EigenDec<T>, LinalgError> {
         let (m, _): (usize, usize) = self.dim();
 
         let values: Diagonal<T> = self.clone();
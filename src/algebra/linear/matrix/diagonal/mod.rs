@@ -8,6 +8,7 @@ mod eigendec;
 mod from;
 mod index;
 mod mul;
+mod norm;
 mod partial_eq;
 mod relative_eq;
 mod sub;
@@ -0,0 +1,60 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::matrix::{Diagonal, MatrixNorm},
+};
+
+impl<T> MatrixNorm<T> for Diagonal<T>
+where
+    T: Real,
+{
+    /// Largest absolute diagonal entry
+    fn norm_1(&self) -> T {
+        self.norm_max_diagonal()
+    }
+
+    /// Largest absolute diagonal entry
+    fn norm_inf(&self) -> T {
+        self.norm_max_diagonal()
+    }
+
+    /// Square root of the sum of the squared diagonal entries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Diagonal, MatrixNorm};
+    ///
+    /// let d: Diagonal<f64> = Diagonal::new(&[3.0, -4.0]);
+    ///
+    /// assert_eq!(d.norm_frobenius(), 5.0);
+    /// ```
+    fn norm_frobenius(&self) -> T {
+        (0..self.matrix.m)
+            .fold(T::zero(), |s, i| s + self[[i, i]] * self[[i, i]])
+            .sqrt()
+    }
+
+    /// Largest absolute diagonal entry
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Diagonal, MatrixNorm};
+    ///
+    /// let d: Diagonal<f64> = Diagonal::new(&[3.0, -4.0]);
+    ///
+    /// assert_eq!(d.norm_2(), 4.0);
+    /// ```
+    fn norm_2(&self) -> T {
+        self.norm_max_diagonal()
+    }
+}
+
+impl<T> Diagonal<T>
+where
+    T: Real,
+{
+    fn norm_max_diagonal(&self) -> T {
+        (0..self.matrix.m).fold(T::zero(), |acc, i| acc.max(self[[i, i]].abs()))
+    }
+}
@@ -1,10 +1,19 @@
-use crate::algebra::linear::matrix::{Diagonal, General};
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Complex,
+    linear::matrix::{Diagonal, General},
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::clone::Clone;
 
+/// Eigen decomposition with real eigenvalues
+///
+/// For a real matrix the decomposition can only be represented if all
+/// eigenvalues are real. Use [`ComplexEigenDecomposition`] for real matrices
+/// with complex conjugate eigenvalue pairs.
 pub trait EigenDecomposition<T> {
-    fn dec_eigen(&self) -> Result<EigenDec<T>, String>;
+    fn dec_eigen(&self) -> Result<EigenDec<T>, LinalgError>;
 }
 
 /// Result of a Eigen decomposition
@@ -32,3 +41,59 @@ impl<T> EigenDec<T> {
         (self.values, self.vectors)
     }
 }
+
+/// Eigen decomposition of a real matrix with complex eigenvalues and
+/// eigenvectors
+pub trait ComplexEigenDecomposition<T> {
+    fn dec_eigen_complex(&self) -> Result<ComplexEigenDec<T>, LinalgError>;
+}
+
+/// Result of a complex Eigen decomposition
+///
+/// ```math
+/// A v_j = \lambda_j v_j \\\\
+/// u_j^* A = \lambda_j u_j^*
+/// ```
+/// The right eigenvectors $v_j$ and the left eigenvectors $u_j$ are the
+/// columns of the respective matrices and have unit euclidean norm.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ComplexEigenDec<T> {
+    values: Vec<Complex<T>>,
+    left_vectors: General<Complex<T>>,
+    right_vectors: General<Complex<T>>,
+}
+
+impl<T> ComplexEigenDec<T> {
+    pub(super) fn new(
+        values: Vec<Complex<T>>,
+        left_vectors: General<Complex<T>>,
+        right_vectors: General<Complex<T>>,
+    ) -> ComplexEigenDec<T> {
+        ComplexEigenDec {
+            values,
+            left_vectors,
+            right_vectors,
+        }
+    }
+
+    /// Return the eigenvalues
+    pub fn values(self) -> Vec<Complex<T>> {
+        self.values
+    }
+
+    /// Return the left eigenvectors
+    pub fn left_vectors(self) -> General<Complex<T>> {
+        self.left_vectors
+    }
+
+    /// Return the right eigenvectors
+    pub fn right_vectors(self) -> General<Complex<T>> {
+        self.right_vectors
+    }
+
+    /// Return the eigenvalues and the right eigenvectors
+    pub fn pair(self) -> (Vec<Complex<T>>, General<Complex<T>>) {
+        (self.values, self.right_vectors)
+    }
+}
@@ -25,7 +25,11 @@ where
     /// assert_eq!(sum, c);
     /// ```
     fn add(self, rhs: Self) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
 
         let (m, n): (usize, usize) = rhs.dim();
 
@@ -67,7 +71,11 @@ where
     /// assert_eq!(sum, c)
     /// ```
     fn add(self, rhs: &'b General<T>) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
 
         let (m, n): (usize, usize) = rhs.dim();
 
@@ -109,7 +117,11 @@ where
     /// assert_eq!(&sum, c)
     /// ```
     fn add(self, rhs: &'b General<T>) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
 
         let (m, n): (usize, usize) = rhs.dim();
 
@@ -25,6 +25,11 @@ where
     /// assert_eq!(sum, c);
     /// ```
     fn add(mut self, rhs: Self) -> Self::Output {
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
         self.data
             .iter_mut()
             .zip(rhs.data.iter())
@@ -54,7 +59,11 @@ where
     /// assert_eq!(sum, c)
     /// ```
     fn add(self, rhs: &'b General<T>) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
         let (m, n) = self.dim();
         General {
             m,
@@ -90,7 +99,11 @@ where
     /// assert_eq!(&sum, c)
     /// ```
     fn add(self, rhs: &'b General<T>) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
         self.data
             .iter_mut()
             .zip(rhs.data.iter())
@@ -0,0 +1,92 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{CheckedAdd, CheckedMul, CheckedSolve, CheckedSub, General, Solve},
+        vector::Vector,
+        LinalgError,
+    },
+};
+
+impl<T> CheckedAdd<General<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn checked_add(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        if self.dim() != rhs.dim() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(self + rhs)
+    }
+}
+
+impl<T> CheckedSub<General<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn checked_sub(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        if self.dim() != rhs.dim() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(self - rhs)
+    }
+}
+
+impl<T> CheckedMul<General<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn checked_mul(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        if self.ncols() != rhs.nrows() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(self * rhs)
+    }
+}
+
+impl<T> CheckedMul<Vector<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    fn checked_mul(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        if rhs.dim() != (self.ncols(), 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(self * rhs)
+    }
+}
+
+impl<T> CheckedSolve<Vector<T>> for General<T>
+where
+    T: Field + Scalar,
+    General<T>: Solve<Vector<T>>,
+{
+    fn checked_solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        if m != n || rhs.dim() != (m, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        self.solve(rhs)
+    }
+}
+
+impl<T> CheckedSolve<General<T>> for General<T>
+where
+    T: Field + Scalar,
+    General<T>: Solve<General<T>>,
+{
+    fn checked_solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        if m != n || rhs.nrows() != m {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        self.solve(rhs)
+    }
+}
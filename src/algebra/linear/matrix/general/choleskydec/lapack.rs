@@ -1,3 +1,4 @@
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{
     abstr::{Field, Scalar},
     linear::matrix::{CholeskyDec, CholeskyDecomposition, General, LowerTriangular},
@@ -36,7 +37,7 @@ where
     ///
     /// assert_abs_diff_eq!(l_ref, l, epsilon=0.001);
     /// ```
-    fn dec_cholesky(&self) -> Result<CholeskyDec<T>, String> {
+    fn dec_cholesky(&self) -> Result<CholeskyDec<T>, LinalgError> {
         let (m, n) = self.dim();
         debug_assert_eq!(m, n);
         debug_assert_ne!(m, 0);
@@ -51,7 +52,11 @@ where
         T::xpotrf('L', n_i32, l_data.as_mut_slice(), n_i32, &mut info);
 
         if info < 0 {
-            return Err(String::from("LAPACK reported illegal argument."));
+            return Err(LinalgError::BackendError { info });
+        }
+
+        if info > 0 {
+            return Err(LinalgError::NotPositiveDefinite);
         }
 
         let mut l: General<T> = General::new(n, n, l_data);
@@ -1,10 +1,8 @@
 use crate::algebra::abstr::Zero;
 use crate::algebra::abstr::{Complex, Real, Scalar};
 use crate::algebra::linear::matrix::choleskydec::CholeskyDecomposition;
-use crate::{
-    algebra::linear::matrix::{CholeskyDec, General, LowerTriangular},
-    elementary::Power,
-};
+use crate::algebra::linear::LinalgError;
+use crate::algebra::linear::matrix::{CholeskyDec, General, LowerTriangular};
 
 impl<T> CholeskyDecomposition<T> for General<T>
 where
@@ -15,7 +13,11 @@ where
     ///
     /// # Panics
     ///
-    /// If the matrix $A$ is not quadratic or not positive definite.
+    /// If the matrix $A$ is not quadratic.
+    ///
+    /// # Errors
+    ///
+    /// If the matrix $A$ is not positive definite.
     ///
     /// For efficiency reasons, the function may not check, if the matrix is
     /// symmetric, but just assume so.
@@ -38,7 +40,7 @@ where
     ///
     /// assert_abs_diff_eq!(l_ref, l, epsilon=0.001);
     /// ```
-    fn dec_cholesky(&self) -> Result<CholeskyDec<T>, String> {
+    fn dec_cholesky(&self) -> Result<CholeskyDec<T>, LinalgError> {
         let (m, n) = self.dim();
         debug_assert_eq!(m, n);
         debug_assert_ne!(m, 0);
@@ -53,13 +55,11 @@ where
                 }
 
                 if i == j {
-                    assert!(
-                        self[[i, i]] - sum >= T::zero(),
-                        "The matrix is not positive definite."
-                    );
+                    if self[[i, i]] - sum <= T::zero() {
+                        return Err(LinalgError::NotPositiveDefinite);
+                    }
                     l[[i, j]] = (self[[i, i]] - sum).sqrt();
                 } else {
-                    assert_ne!(l[[j, j]], T::zero(), "The matrix is not positive definite");
                     l[[i, j]] = (self[[i, j]] - sum) / l[[j, j]];
                 }
             }
@@ -77,9 +77,12 @@ where
     /// a lower triangular matrix $L$ and its conjugate transpose, such that
     /// $A = LL^*$.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// If the matrix $A$ is not quadratic or not positive definite.
+    /// [`LinalgError::DimensionMismatch`] if the matrix $A$ is not quadratic
+    /// and [`LinalgError::NotPositiveDefinite`] if the matrix $A$ is not
+    /// positive definite or has a diagonal entry with a nonzero imaginary
+    /// part.
     ///
     /// For efficiency reasons, the function may not check if the matrix is
     /// Hermitian, but just assume so.
@@ -87,20 +90,23 @@ where
     /// # Example
     ///
     /// ```
-    /// use mathru::algebra::linear::matrix::{General, LowerTriangular, CholeskyDecomposition};
-    /// use mathru::matrix;
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{CholeskyDecomposition, ConjugateTranspose, General};
+    /// use mathru::{assert_relative_eq, matrix};
     ///
-    /// let a: General<f64> = matrix![2.0, -1.0, 0.0;
-    ///                               -1.0, 2.0, -1.0;
-    ///                               0.0, -1.0, 2.0];
+    /// let a: General<Complex<f64>> = matrix![Complex::new(4.0, 0.0), Complex::new(1.0, 1.0);
+    ///                                        Complex::new(1.0, -1.0), Complex::new(3.0, 0.0)];
     ///
-    /// let l: LowerTriangular<f64> = a.dec_cholesky().unwrap().l();
+    /// let l: General<Complex<f64>> = a.dec_cholesky().unwrap().l().into();
+    ///
+    /// assert_relative_eq!(&l * &l.clone().conj_transpose(), a, epsilon = Complex::new(1.0e-10, 1.0e-10));
     /// ```
-    fn dec_cholesky(&self) -> Result<CholeskyDec<Complex<T>>, String> {
+    fn dec_cholesky(&self) -> Result<CholeskyDec<Complex<T>>, LinalgError> {
         let (m, n) = self.dim();
-        debug_assert_eq!(m, n);
+        if m != n {
+            return Err(LinalgError::DimensionMismatch);
+        }
 
-        let (m, n) = self.dim();
         let mut l: General<Complex<T>> = General::zero(m, n);
 
         for j in 0..n {
@@ -111,21 +117,12 @@ where
                 }
 
                 if i == j {
-                    assert!(
-                        (self[[i, i]] - sum).re >= T::zero(),
-                        "The matrix is not positive definite."
-                    );
-                    assert!(
-                        (self[[i, i]] - sum).im == T::zero(),
-                        "The matrix is not Hermitian."
-                    );
-                    l[[i, j]] = (self[[i, i]] - sum).sqrt();
+                    let d: Complex<T> = self[[i, i]] - sum;
+                    if self[[i, i]].im != T::zero() || d.re <= T::zero() {
+                        return Err(LinalgError::NotPositiveDefinite);
+                    }
+                    l[[i, j]] = Complex::new(d.re.sqrt(), T::zero());
                 } else {
-                    assert_ne!(
-                        l[[j, j]],
-                        Complex::new(T::zero(), T::zero()),
-                        "The matrix is not positive definite"
-                    );
                     l[[i, j]] = (self[[i, j]] - sum) / l[[j, j]];
                 }
             }
@@ -0,0 +1,91 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::matrix::{
+        CompleteOrthogonalDec, CompleteOrthogonalDecomposition, General, QRPDec, QRPDecomposition,
+        UpperTriangular,
+    },
+};
+
+impl<T> CompleteOrthogonalDecomposition<T> for General<T>
+where
+    T: Real,
+{
+    /// Computes the complete orthogonal decomposition from the QR
+    /// decomposition with column pivoting
+    ///
+    /// The leading rows $R_1 = (R_{11}\ R_{12})$ of the pivoted QR
+    /// decomposition are reduced to $(T\ 0)$ with Householder reflections
+    /// from the right, which are accumulated in $Z$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{CompleteOrthogonalDecomposition, General, Transpose, UpperTriangular};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+    ///                                 2.0, 4.0, 6.0;
+    ///                                 1.0, 0.0, 1.0];
+    ///
+    /// let (q, t, z, p): (General<f64>, UpperTriangular<f64>, General<f64>, General<f64>) = a.dec_cod(1.0e-12).unwrap().qtzp();
+    ///
+    /// let t_0: General<f64> = General::zero(3, 3).set_slice(&General::from(t), 0, 0);
+    ///
+    /// assert_relative_eq!(&a * &p, &(&q * &t_0) * &z.transpose(), epsilon = 1.0e-10);
+    /// ```
+    fn dec_cod(&self, tolerance: T) -> Result<CompleteOrthogonalDec<T>, LinalgError> {
+        let (_, n): (usize, usize) = self.dim();
+
+        let qrp: QRPDec<T> = self.dec_qrp()?;
+        let rank: usize = qrp.rank(tolerance);
+        let (q, r, p): (General<T>, UpperTriangular<T>, General<T>) = qrp.qrp();
+
+        let mut r: General<T> = General::from(r);
+        let mut z: General<T> = General::one(n);
+
+        for k in (0..rank).rev() {
+            // Reflection acting on the column k and the columns rank..n
+            let mut v: Vec<T> = vec![r[[k, k]]];
+            v.extend((rank..n).map(|c| r[[k, c]]));
+
+            let norm: T = v.iter().fold(T::zero(), |s, v_i| s + *v_i * *v_i).sqrt();
+            let alpha: T = if v[0] > T::zero() { -norm } else { norm };
+            v[0] -= alpha;
+            let v_v: T = v.iter().fold(T::zero(), |s, v_i| s + *v_i * *v_i);
+            if v_v == T::zero() {
+                continue;
+            }
+
+            General::reflect_columns(&mut r, k, rank, &v, v_v, k + 1);
+            General::reflect_columns(&mut z, k, rank, &v, v_v, n);
+        }
+
+        let t: General<T> = if rank == 0 {
+            General::zero(0, 0)
+        } else {
+            r.get_slice(0, rank - 1, 0, rank - 1)
+        };
+
+        Ok(CompleteOrthogonalDec::new(q, UpperTriangular::new(t), z, p))
+    }
+}
+
+impl<T> General<T>
+where
+    T: Real,
+{
+    /// Applies the reflection $I - 2 v v^T / (v^T v)$ from the right on the
+    /// rows 0..rows, where v acts on the column k and the columns rank..n
+    fn reflect_columns(a: &mut General<T>, k: usize, rank: usize, v: &[T], v_v: T, rows: usize) {
+        let n: usize = a.ncols();
+        for i in 0..rows {
+            let dot: T = (rank..n).fold(a[[i, k]] * v[0], |s, c| s + a[[i, c]] * v[c - rank + 1]);
+            let f: T = T::from_f64(2.0) * dot / v_v;
+            a[[i, k]] -= f * v[0];
+            for c in rank..n {
+                a[[i, c]] -= f * v[c - rank + 1];
+            }
+        }
+    }
+}
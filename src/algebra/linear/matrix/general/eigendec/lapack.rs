@@ -1,7 +1,11 @@
+use crate::algebra::linear::LinalgError;
 use crate::{
     algebra::{
-        abstr::{Field, Scalar},
-        linear::matrix::{Diagonal, EigenDec, EigenDecomposition, General},
+        abstr::{Complex, Field, Lapack, Real, Scalar, Zero},
+        linear::matrix::{
+            ComplexEigenDec, ComplexEigenDecomposition, Diagonal, EigenDec, EigenDecomposition,
+            General,
+        },
     },
     elementary::Power,
 };
@@ -26,7 +30,7 @@ where
     ///
     /// let eigen: EigenDec<f64> = a.dec_eigen().unwrap();
     /// ```
-    fn dec_eigen(&self) -> Result<EigenDec<T>, String> {
+    fn dec_eigen(&self) -> Result<EigenDec<T>, LinalgError> {
         let (m, n): (usize, usize) = self.dim();
         debug_assert_eq!(
             m, n,
@@ -45,6 +49,7 @@ where
         let mut info: i32 = 0;
 
         let mut w: Vec<T> = vec![T::zero(); n];
+        let mut wi: Vec<T> = vec![T::zero(); n];
 
         let mut temp1 = [T::zero()];
         let mut temp2 = vec![T::zero(); n * n];
@@ -56,6 +61,7 @@ where
             &mut self_data[..],
             n_i32,
             w.as_mut_slice(),
+            wi.as_mut_slice(),
             &mut temp1,
             n_i32,
             &mut temp2,
@@ -72,6 +78,7 @@ where
             &mut self_data[..],
             n_i32,
             w.as_mut_slice(),
+            wi.as_mut_slice(),
             &mut temp1,
             1 as i32,
             &mut temp2,
@@ -82,9 +89,103 @@ where
         );
 
         if info != 0 {
-            return Err("".to_string());
+            return Err(LinalgError::BackendError { info });
+        }
+        if wi.iter().any(|wi_i| *wi_i != T::zero()) {
+            return Err(LinalgError::ComplexEigenvalues);
         }
 
         Ok(EigenDec::new(Diagonal::new(&w), General::new(n, n, temp2)))
     }
 }
+
+impl<T> ComplexEigenDecomposition<T> for General<T>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the complex eigenvalues and the left and right eigenvectors
+    /// of a real matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{ComplexEigenDec, ComplexEigenDecomposition, General};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// // rotation by 90 degrees
+    /// let a: General<f64> = matrix![0.0, -1.0;
+    ///                               1.0, 0.0];
+    ///
+    /// let mut values: Vec<Complex<f64>> = a.dec_eigen_complex().unwrap().values();
+    /// values.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+    ///
+    /// assert_relative_eq!(values[0], Complex::new(0.0, -1.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// assert_relative_eq!(values[1], Complex::new(0.0, 1.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// ```
+    fn dec_eigen_complex(&self) -> Result<ComplexEigenDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        debug_assert_eq!(
+            m, n,
+            "Unable to compute the eigen value of a non-square matrix"
+        );
+        debug_assert_ne!(
+            m, 0,
+            "Unable to compute the eigen value of an empty matrix."
+        );
+
+        let mut self_data: Vec<Complex<T>> = General::<Complex<T>>::from(self).data;
+        let n_i32: i32 = n as i32;
+
+        let mut info: i32 = 0;
+
+        let mut w: Vec<Complex<T>> = vec![Complex::zero(); n];
+        let mut vl: Vec<Complex<T>> = vec![Complex::zero(); n * n];
+        let mut vr: Vec<Complex<T>> = vec![Complex::zero(); n * n];
+
+        let lwork = Complex::<T>::xgeev_work_size(
+            'V' as u8,
+            'V' as u8,
+            n_i32,
+            &mut self_data[..],
+            n_i32,
+            w.as_mut_slice(),
+            &mut [],
+            &mut vl,
+            n_i32,
+            &mut vr,
+            n_i32,
+            &mut info,
+        );
+
+        let mut work: Vec<Complex<T>> = vec![Complex::zero(); lwork as usize];
+
+        Complex::<T>::xgeev(
+            'V' as u8,
+            'V' as u8,
+            n_i32,
+            &mut self_data[..],
+            n_i32,
+            w.as_mut_slice(),
+            &mut [],
+            &mut vl,
+            n_i32,
+            &mut vr,
+            n_i32,
+            &mut work,
+            lwork,
+            &mut info,
+        );
+
+        if info != 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+
+        Ok(ComplexEigenDec::new(
+            w,
+            General::new(n, n, vl),
+            General::new(n, n, vr),
+        ))
+    }
+}
@@ -1,11 +1,12 @@
-use crate::algebra::abstr::{AbsDiffEq, Field, RelativeEq, Scalar};
+use crate::algebra::abstr::{AbsDiffEq, Complex, Field, One, Real, RelativeEq, Scalar, Zero};
 use crate::algebra::linear::matrix::Diagonal;
 use crate::algebra::linear::{
     matrix::{
-        EigenDec, EigenDecomposition, General, HessenbergDecomposition, SchurDecomposition, Solve,
-        UpperHessenberg,
+        ComplexEigenDec, ComplexEigenDecomposition, EigenDec, EigenDecomposition, General, HessenbergDecomposition,
+        SchurDecomposition, Solve, UpperHessenberg,
     },
     vector::Vector,
+    LinalgError,
 };
 use crate::elementary::Power;
 
@@ -19,7 +20,7 @@ use crate::elementary::Power;
 /// ```
 impl<T> EigenDecomposition<T> for General<T>
 where
-    T: Field + Scalar + Power + AbsDiffEq<Epsilon = T> + RelativeEq,
+    T: Real,
 {
     /// Computes the eigenvalues of a real matrix
     ///
@@ -37,7 +38,7 @@ where
     ///
     /// let eigen: EigenDec<f64> = a.dec_eigen().unwrap();
     /// ```
-    fn dec_eigen(&self) -> Result<EigenDec<T>, String> {
+    fn dec_eigen(&self) -> Result<EigenDec<T>, LinalgError> {
         let (m, n): (usize, usize) = self.dim();
         debug_assert_eq!(
             m, n,
@@ -59,17 +60,12 @@ impl<T> General<T>
 where
     T: Field + Scalar + Power + AbsDiffEq<Epsilon = T> + RelativeEq,
 {
-    pub fn eigenvalue_r(&self) -> Result<Vec<T>, String> {
+    pub fn eigenvalue_r(&self) -> Result<Vec<T>, LinalgError> {
         let (m, n): (usize, usize) = self.dim();
 
         let h: UpperHessenberg<T> = self.dec_hessenberg().h();
 
-        let dec_schur_res = h.dec_schur();
-
-        if dec_schur_res.is_err() {
-            return Result::Err("".to_string());
-        }
-        let u = dec_schur_res.unwrap().u();
+        let u = h.dec_schur()?.u();
 
         let mut eig = Vec::with_capacity(m);
 
@@ -84,7 +80,7 @@ where
                 let a_i1i = u[[i + 1, i]];
                 let a_i1i1 = u[[i + 1, i + 1]];
 
-                let (l1, l2) = General::eigen_2by2(a_ii, a_ii1, a_i1i, a_i1i1);
+                let (l1, l2) = General::eigen_2by2(a_ii, a_ii1, a_i1i, a_i1i1)?;
                 eig.push(l1);
                 eig.push(l2);
                 i += 2;
@@ -117,14 +113,211 @@ where
         v
     }
 
-    fn eigen_2by2(a11: T, a12: T, a21: T, a22: T) -> (T, T) {
+    fn eigen_2by2(a11: T, a12: T, a21: T, a22: T) -> Result<(T, T), LinalgError> {
         let m = (a11 + a22) / T::from_f32(2.0);
         let p = a11 * a22 - a12 * a21;
 
-        let k = (m * m - p).sqrt();
+        let d = m * m - p;
+        if d < T::zero() {
+            return Err(LinalgError::ComplexEigenvalues);
+        }
+        let k = d.sqrt();
         let l1 = m + k;
         let l2 = m - k;
 
-        (l1, l2)
+        Ok((l1, l2))
+    }
+}
+
+impl<T> EigenDecomposition<Complex<T>> for General<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the eigenvalues and eigenvectors of a complex matrix
+    ///
+    /// The matrix is reduced to the complex Schur form $A = QUQ^*$ with the
+    /// shifted QR algorithm. The eigenvalues are the diagonal entries of $U$,
+    /// the eigenvectors are normalized to unit length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{Diagonal, EigenDecomposition, General};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<Complex<f64>> = matrix![Complex::new(2.0, 0.0), Complex::new(1.0, 1.0);
+    ///                                        Complex::new(1.0, -1.0), Complex::new(3.0, 0.0)];
+    ///
+    /// let (values, vectors): (Diagonal<Complex<f64>>, General<Complex<f64>>) = a.dec_eigen().unwrap().pair();
+    ///
+    /// assert_relative_eq!(&a * &vectors, &vectors * &General::from(values), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// ```
+    fn dec_eigen(&self) -> Result<EigenDec<Complex<T>>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        debug_assert_eq!(
+            m, n,
+            "Unable to compute the eigen value of a non-square matrix"
+        );
+        debug_assert_ne!(
+            m, 0,
+            "Unable to compute the eigen value of an empty matrix."
+        );
+
+        let (q, u) = self.dec_schur()?.qu();
+        let u: General<Complex<T>> = u.into();
+
+        let values: Vec<Complex<T>> = (0..n).map(|i| u[[i, i]]).collect();
+        let vectors: General<Complex<T>> = &q * &General::eigenvector_triangular(&u);
+
+        Ok(EigenDec::new(Diagonal::new(&values), vectors))
+    }
+}
+
+impl<T> ComplexEigenDecomposition<T> for General<T>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the complex eigenvalues and the left and right eigenvectors
+    /// of a real matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{ComplexEigenDec, ComplexEigenDecomposition, General};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// // rotation by 90 degrees
+    /// let a: General<f64> = matrix![0.0, -1.0;
+    ///                               1.0, 0.0];
+    ///
+    /// let mut values: Vec<Complex<f64>> = a.dec_eigen_complex().unwrap().values();
+    /// values.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+    ///
+    /// assert_relative_eq!(values[0], Complex::new(0.0, -1.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// assert_relative_eq!(values[1], Complex::new(0.0, 1.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// ```
+    fn dec_eigen_complex(&self) -> Result<ComplexEigenDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        debug_assert_eq!(
+            m, n,
+            "Unable to compute the eigen value of a non-square matrix"
+        );
+        debug_assert_ne!(
+            m, 0,
+            "Unable to compute the eigen value of an empty matrix."
+        );
+
+        let a: General<Complex<T>> = General::from(self);
+        let (q, u) = a.dec_schur()?.qu();
+        let u: General<Complex<T>> = u.into();
+
+        let values: Vec<Complex<T>> = (0..n).map(|i| u[[i, i]]).collect();
+        let left_vectors: General<Complex<T>> = &q * &General::left_eigenvector_triangular(&u);
+        let right_vectors: General<Complex<T>> = &q * &General::eigenvector_triangular(&u);
+
+        Ok(ComplexEigenDec::new(values, left_vectors, right_vectors))
+    }
+}
+
+impl<T> General<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the eigenvectors of the upper triangular matrix u by back
+    /// substitution
+    fn eigenvector_triangular(u: &General<Complex<T>>) -> General<Complex<T>> {
+        let n: usize = u.m;
+        let norm: T = u
+            .data
+            .iter()
+            .map(|u_ij| General::modulus(*u_ij))
+            .fold(T::zero(), |acc, a| acc.max(a));
+        let norm: T = if norm == T::zero() { T::one() } else { norm };
+        let small: Complex<T> = Complex::new(T::default_epsilon() * norm, T::zero());
+
+        let mut y: General<Complex<T>> = General::zero(n, n);
+
+        for k in 0..n {
+            let lambda: Complex<T> = u[[k, k]];
+            y[[k, k]] = Complex::one();
+
+            for i in (0..k).rev() {
+                let mut sum: Complex<T> = Complex::zero();
+                for j in (i + 1)..=k {
+                    sum += u[[i, j]] * y[[j, k]];
+                }
+                let mut d: Complex<T> = u[[i, i]] - lambda;
+                if General::modulus(d) < small.re {
+                    d = small;
+                }
+                y[[i, k]] = -sum / d;
+            }
+
+            let col_norm: T = (0..=k)
+                .map(|i| {
+                    let a: T = General::modulus(y[[i, k]]);
+                    a * a
+                })
+                .fold(T::zero(), |acc, a| acc + a)
+                .sqrt();
+            let col_norm: Complex<T> = Complex::new(col_norm, T::zero());
+            for i in 0..=k {
+                y[[i, k]] /= col_norm;
+            }
+        }
+
+        y
+    }
+
+    /// Computes the left eigenvectors $w_k^* U = \lambda_k w_k^*$ of the
+    /// upper triangular matrix u by forward substitution
+    fn left_eigenvector_triangular(u: &General<Complex<T>>) -> General<Complex<T>> {
+        let n: usize = u.m;
+        let norm: T = u
+            .data
+            .iter()
+            .map(|u_ij| General::modulus(*u_ij))
+            .fold(T::zero(), |acc, a| acc.max(a));
+        let norm: T = if norm == T::zero() { T::one() } else { norm };
+        let small: Complex<T> = Complex::new(T::default_epsilon() * norm, T::zero());
+
+        let mut w: General<Complex<T>> = General::zero(n, n);
+
+        for k in 0..n {
+            let lambda: Complex<T> = u[[k, k]];
+            // w[[j, k]] holds the conjugate of the entries until normalization
+            w[[k, k]] = Complex::one();
+
+            for j in (k + 1)..n {
+                let mut sum: Complex<T> = Complex::zero();
+                for i in k..j {
+                    sum += w[[i, k]] * u[[i, j]];
+                }
+                let mut d: Complex<T> = lambda - u[[j, j]];
+                if General::modulus(d) < small.re {
+                    d = small;
+                }
+                w[[j, k]] = sum / d;
+            }
+
+            let col_norm: T = (k..n)
+                .map(|i| {
+                    let a: T = General::modulus(w[[i, k]]);
+                    a * a
+                })
+                .fold(T::zero(), |acc, a| acc + a)
+                .sqrt();
+            let col_norm: Complex<T> = Complex::new(col_norm, T::zero());
+            for i in k..n {
+                w[[i, k]] = w[[i, k]].conj() / col_norm;
+            }
+        }
+
+        w
     }
 }
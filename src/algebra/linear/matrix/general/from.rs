@@ -1,9 +1,9 @@
 use crate::algebra::{
-    abstr::{Field, Scalar},
+    abstr::{Complex, Field, Real, Scalar},
     linear::{
         matrix::{
-            Diagonal, LowerTriangular, UnitLowerTriangular, UnitUpperTriangular, UpperHessenberg,
-            UpperTriangular,
+            Diagonal, LowerTriangular, MatrixView, MatrixViewMut, UnitLowerTriangular,
+            UnitUpperTriangular, UpperHessenberg, UpperTriangular,
         },
         vector::Vector,
     },
@@ -52,8 +52,58 @@ impl<T> From<UpperHessenberg<T>> for General<T> {
     }
 }
 
+impl<'a, T> From<MatrixView<'a, T>> for General<T>
+where
+    T: Copy,
+{
+    /// Copies the elements of the view into a new matrix
+    fn from(view: MatrixView<'a, T>) -> Self {
+        let (m, n): (usize, usize) = view.dim();
+        let mut data: Vec<T> = Vec::with_capacity(m * n);
+        for j in 0..n {
+            for i in 0..m {
+                data.push(view[[i, j]]);
+            }
+        }
+        General::new(m, n, data)
+    }
+}
+
+impl<'a, 'b, T> From<&'b MatrixViewMut<'a, T>> for General<T>
+where
+    T: Copy,
+{
+    /// Copies the elements of the view into a new matrix
+    fn from(view: &'b MatrixViewMut<'a, T>) -> Self {
+        General::from(view.as_view())
+    }
+}
+
 impl<T> From<Diagonal<T>> for General<T> {
     fn from(ut: Diagonal<T>) -> Self {
         ut.matrix
     }
 }
+
+impl<T> From<&General<T>> for General<Complex<T>>
+where
+    T: Real,
+{
+    /// Embeds a real matrix into the complex matrices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![1.0, -2.0];
+    ///
+    /// assert_eq!(General::from(&a), matrix![Complex::new(1.0, 0.0), Complex::new(-2.0, 0.0)]);
+    /// ```
+    fn from(m: &General<T>) -> Self {
+        let data: Vec<Complex<T>> = m.data.iter().map(|a| Complex::new(*a, T::zero())).collect();
+        General::new(m.m, m.n, data)
+    }
+}
@@ -0,0 +1,431 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Complex, Real, Scalar, Zero},
+    linear::matrix::{
+        ComplexEigenDecomposition, ConjugateTranspose, Diagonal, General, Inverse, MatrixNorm,
+        SchurDec, SchurDecomposition, Solve,
+    },
+};
+use crate::elementary::Power;
+use std::cmp::Ordering;
+
+/// Padé coefficients of the degrees 3, 5, 7, 9 and 13 for the exponential
+const EXP_PADE_3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const EXP_PADE_5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const EXP_PADE_7: [f64; 8] = [
+    17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+];
+const EXP_PADE_9: [f64; 10] = [
+    17643225600.0,
+    8821612800.0,
+    2075673600.0,
+    302702400.0,
+    30270240.0,
+    2162160.0,
+    110880.0,
+    3960.0,
+    90.0,
+    1.0,
+];
+const EXP_PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+/// Maximal 1-norms for which the Padé approximants of degree 3, 5, 7 and 9
+/// of the exponential are accurate without scaling
+const EXP_THETA: [f64; 4] = [
+    1.495585217958292e-2,
+    2.53939833006323e-1,
+    9.504178996162932e-1,
+    2.097847961257068e0,
+];
+/// Maximal 1-norm for the Padé approximant of degree 13
+const EXP_THETA_13: f64 = 5.371920351148152e0;
+
+/// Gauss-Legendre nodes and weights on [0, 1] for the Padé approximant of
+/// log(I + X)
+const LOG_NODES: [f64; 7] = [
+    0.025446043828620757,
+    0.12923440720030277,
+    0.2970774243113014,
+    0.5,
+    0.7029225756886986,
+    0.8707655927996972,
+    0.9745539561713792,
+];
+const LOG_WEIGHTS: [f64; 7] = [
+    0.06474248308443485,
+    0.13985269574463834,
+    0.19091502525255946,
+    0.2089795918367347,
+    0.19091502525255946,
+    0.13985269574463834,
+    0.06474248308443485,
+];
+
+impl<T> General<T>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the matrix exponential
+    ///
+    /// ```math
+    /// e^A = \sum_{k=0}^{\infty} \frac{1}{k!} A^k
+    /// ```
+    ///
+    /// The exponential is evaluated with the scaling and squaring method and a
+    /// Padé approximant of degree 3, 5, 7, 9 or 13, see N. J. Higham, The
+    /// scaling and squaring method for the matrix exponential revisited, 2005.
+    /// If the 1-norm of the matrix is not finite, e.g. because of an infinite
+    /// entry or an overflow, all entries of the result are NaN.
+    ///
+    /// # Panics
+    ///
+    /// if the matrix is not square
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![0.0, 1.0;
+    ///                               0.0, 0.0];
+    ///
+    /// assert_relative_eq!(a.exp(), matrix![1.0, 1.0; 0.0, 1.0], epsilon = 1.0e-12);
+    /// ```
+    pub fn exp(&self) -> General<T> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(m, n, "matrix is not square");
+
+        let norm: T = self.norm_1();
+        if norm.partial_cmp(&T::infinity()) != Some(Ordering::Less) {
+            let nan: T = T::infinity() - T::infinity();
+            return General::new(m, n, vec![nan; m * n]);
+        }
+
+        for (i, theta) in EXP_THETA.iter().enumerate() {
+            if norm <= T::from_f64(*theta) {
+                let b: &[f64] = match i {
+                    0 => &EXP_PADE_3,
+                    1 => &EXP_PADE_5,
+                    2 => &EXP_PADE_7,
+                    _ => &EXP_PADE_9,
+                };
+                return self.exp_pade(b);
+            }
+        }
+
+        // Scale A such that the norm is smaller than theta_13
+        let mut s: i32 = 0;
+        let theta_13: T = T::from_f64(EXP_THETA_13);
+        let mut scaled_norm: T = norm;
+        while scaled_norm > theta_13 {
+            scaled_norm /= T::from_f64(2.0);
+            s += 1;
+        }
+        let a: General<T> = self * &T::from_f64(2.0).pow(T::from_f64(-s as f64));
+
+        let mut r: General<T> = a.exp_pade_13();
+        for _ in 0..s {
+            r = &r * &r;
+        }
+
+        r
+    }
+
+    /// Computes the principal matrix logarithm
+    ///
+    /// The logarithm is computed from the complex Schur form with the inverse
+    /// scaling and squaring method. Square roots are taken until the
+    /// triangular factor is close to the identity, the logarithm of the
+    /// remainder is evaluated with a Padé approximant.
+    ///
+    /// # Errors
+    ///
+    /// if the matrix has an eigenvalue on the closed negative real axis, in
+    /// this case there is no real principal logarithm
+    ///
+    /// # Panics
+    ///
+    /// if the matrix is not square
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![1.0, 1.0;
+    ///                               0.0, 1.0];
+    ///
+    /// assert_relative_eq!(a.logm().unwrap(), matrix![0.0, 1.0; 0.0, 0.0], epsilon = 1.0e-12);
+    /// ```
+    pub fn logm(&self) -> Result<General<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(m, n, "matrix is not square");
+
+        let (q, u) = self.schur_function()?.qu();
+        let u: General<Complex<T>> = u.into();
+
+        for i in 0..n {
+            if General::on_negative_real_axis(u[[i, i]], true) {
+                return Err(LinalgError::OutOfDomain);
+            }
+        }
+
+        // Take square roots until the triangular factor is close to the identity
+        let one: General<Complex<T>> = General::one(n);
+        let mut r: General<Complex<T>> = u;
+        let mut s: i32 = 0;
+        while s < 64 && (&r - &one).norm_1_complex() > T::from_f64(0.25) {
+            r = General::sqrt_triangular(&r)?;
+            s += 1;
+        }
+
+        // Padé approximant of log(I + X) as sum of partial fractions
+        let x: General<Complex<T>> = &r - &one;
+        let mut l: General<Complex<T>> = General::zero(n, n);
+        for (node, weight) in LOG_NODES.iter().zip(LOG_WEIGHTS.iter()) {
+            let node: Complex<T> = Complex::new(T::from_f64(*node), T::zero());
+            let weight: Complex<T> = Complex::new(T::from_f64(*weight), T::zero());
+            let y: General<Complex<T>> = (&one + &(&x * &node)).solve(&x)?;
+            l = &l + &(&y * &weight);
+        }
+        let scale: Complex<T> =
+            Complex::new(T::from_f64(2.0).pow(T::from_f64(s as f64)), T::zero());
+        let l: General<Complex<T>> = &l * &scale;
+
+        Ok(General::real_part(
+            &(&(&q * &l) * &q.clone().conj_transpose()),
+        ))
+    }
+
+    /// Computes the principal square root $X$ with $X^2 = A$
+    ///
+    /// The square root is computed from the complex Schur form $A = QUQ^*$,
+    /// the square root of the triangular factor $U$ is computed column by
+    /// column.
+    ///
+    /// # Errors
+    ///
+    /// if the matrix has a negative real eigenvalue, in this case there is no
+    /// real principal square root, or if the matrix is singular and the
+    /// square root does not exist
+    ///
+    /// # Panics
+    ///
+    /// if the matrix is not square
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![4.0, 1.0;
+    ///                               0.0, 9.0];
+    ///
+    /// let x: General<f64> = a.sqrtm().unwrap();
+    ///
+    /// assert_relative_eq!(x, matrix![2.0, 0.2; 0.0, 3.0], epsilon = 1.0e-12);
+    /// ```
+    pub fn sqrtm(&self) -> Result<General<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(m, n, "matrix is not square");
+
+        let (q, u) = self.schur_function()?.qu();
+        let u: General<Complex<T>> = u.into();
+
+        for i in 0..n {
+            if General::on_negative_real_axis(u[[i, i]], false) {
+                return Err(LinalgError::OutOfDomain);
+            }
+        }
+
+        let r: General<Complex<T>> = General::sqrt_triangular(&u)?;
+
+        Ok(General::real_part(
+            &(&(&q * &r) * &q.clone().conj_transpose()),
+        ))
+    }
+
+    /// Applies the scalar function f to the matrix
+    ///
+    /// ```math
+    /// f(A) = V f(\Lambda) V^{-1}
+    /// ```
+    /// with the eigen decomposition $A = V \Lambda V^{-1}$. The function has to
+    /// map complex conjugated eigenvalues to complex conjugated values, that
+    /// is it has to be real on the real axis, such that $f(A)$ is real.
+    ///
+    /// # Errors
+    ///
+    /// if the eigen decomposition fails or the matrix is not diagonalizable
+    ///
+    /// # Panics
+    ///
+    /// if the matrix is not square
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::elementary::Trigonometry;
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let t: f64 = 0.5;
+    /// let a: General<f64> = matrix![0.0, -t;
+    ///                               t, 0.0];
+    ///
+    /// let c: General<f64> = a.funm(|z: Complex<f64>| z.cos()).unwrap();
+    ///
+    /// assert_relative_eq!(c, matrix![t.cosh(), 0.0; 0.0, t.cosh()], epsilon = 1.0e-12);
+    /// ```
+    pub fn funm<F>(&self, f: F) -> Result<General<T>, LinalgError>
+    where
+        F: Fn(Complex<T>) -> Complex<T>,
+    {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(m, n, "matrix is not square");
+
+        let (values, vectors): (Vec<Complex<T>>, General<Complex<T>>) =
+            self.dec_eigen_complex()?.pair();
+
+        let f_values: Vec<Complex<T>> = values.into_iter().map(f).collect();
+        let vectors_inv: General<Complex<T>> = vectors.inv()?;
+
+        let f_a: General<Complex<T>> =
+            &(&vectors * &General::from(Diagonal::new(&f_values))) * &vectors_inv;
+
+        Ok(General::real_part(&f_a))
+    }
+
+    /// Evaluates the Padé approximant with the coefficients b of degree 3, 5,
+    /// 7 or 9
+    fn exp_pade(&self, b: &[f64]) -> General<T> {
+        let n: usize = self.m;
+        let a_2: General<T> = self * self;
+
+        let mut power: General<T> = General::one(n);
+        let mut u: General<T> = General::zero(n, n);
+        let mut v: General<T> = General::zero(n, n);
+        for k in (0..b.len()).step_by(2) {
+            v = &v + &(&power * &T::from_f64(b[k]));
+            u = &u + &(&power * &T::from_f64(b[k + 1]));
+            power = &power * &a_2;
+        }
+        let u: General<T> = self * &u;
+
+        General::exp_pade_quotient(u, v)
+    }
+
+    /// Evaluates the Padé approximant of degree 13
+    fn exp_pade_13(&self) -> General<T> {
+        let n: usize = self.m;
+        let b: Vec<T> = EXP_PADE_13.iter().map(|b_i| T::from_f64(*b_i)).collect();
+
+        let ident: General<T> = General::one(n);
+        let a_2: General<T> = self * self;
+        let a_4: General<T> = &a_2 * &a_2;
+        let a_6: General<T> = &a_2 * &a_4;
+
+        let u_1: General<T> = &(&(&a_6 * &b[13]) + &(&a_4 * &b[11])) + &(&a_2 * &b[9]);
+        let u_2: General<T> =
+            &(&(&a_6 * &b[7]) + &(&a_4 * &b[5])) + &(&(&a_2 * &b[3]) + &(&ident * &b[1]));
+        let u: General<T> = self * &(&(&a_6 * &u_1) + &u_2);
+
+        let v_1: General<T> = &(&(&a_6 * &b[12]) + &(&a_4 * &b[10])) + &(&a_2 * &b[8]);
+        let v_2: General<T> =
+            &(&(&a_6 * &b[6]) + &(&a_4 * &b[4])) + &(&(&a_2 * &b[2]) + &(&ident * &b[0]));
+        let v: General<T> = &(&a_6 * &v_1) + &v_2;
+
+        General::exp_pade_quotient(u, v)
+    }
+
+    /// Solves $(V - U) R = V + U$
+    fn exp_pade_quotient(u: General<T>, v: General<T>) -> General<T> {
+        let p: General<T> = &v + &u;
+        let q: General<T> = &v - &u;
+
+        q.solve(&p)
+            .expect("denominator of the Padé approximant is singular")
+    }
+
+    /// Computes the complex Schur form of the matrix
+    fn schur_function(&self) -> Result<SchurDec<Complex<T>>, LinalgError> {
+        let a: General<Complex<T>> = General::from(self);
+        a.dec_schur()
+    }
+
+    fn real_part(a: &General<Complex<T>>) -> General<T> {
+        let (m, n): (usize, usize) = a.dim();
+        General::new(m, n, a.data.iter().map(|a_ij| a_ij.re).collect())
+    }
+}
+
+impl<T> General<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the principal square root of the upper triangular matrix u
+    fn sqrt_triangular(u: &General<Complex<T>>) -> Result<General<Complex<T>>, LinalgError> {
+        let n: usize = u.m;
+        let mut r: General<Complex<T>> = General::zero(n, n);
+
+        for j in 0..n {
+            r[[j, j]] = u[[j, j]].sqrt();
+            for i in (0..j).rev() {
+                let mut s: Complex<T> = u[[i, j]];
+                for k in (i + 1)..j {
+                    s -= r[[i, k]] * r[[k, j]];
+                }
+
+                let d: Complex<T> = r[[i, i]] + r[[j, j]];
+                if d == Complex::zero() {
+                    if s != Complex::zero() {
+                        return Err(LinalgError::OutOfDomain);
+                    }
+                    continue;
+                }
+                r[[i, j]] = s / d;
+            }
+        }
+
+        Ok(r)
+    }
+
+    /// Checks if z is on the negative real axis, including zero if
+    /// with_zero is set
+    fn on_negative_real_axis(z: Complex<T>, with_zero: bool) -> bool {
+        let modulus: T = General::modulus(z);
+        if modulus == T::zero() {
+            return with_zero;
+        }
+
+        z.re < T::zero() && z.im.abs() <= T::default_epsilon().sqrt() * modulus
+    }
+
+    /// Maximum absolute column sum
+    fn norm_1_complex(&self) -> T {
+        (0..self.n)
+            .map(|j| (0..self.m).fold(T::zero(), |s, i| s + General::modulus(self[[i, j]])))
+            .fold(T::zero(), |acc, s| acc.max(s))
+    }
+}
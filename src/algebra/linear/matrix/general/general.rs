@@ -1,23 +1,18 @@
 //! General
 use super::super::{
-    //MatrixColumnIterator,
-    //MatrixColumnIteratorMut,
-    MatrixColumnIntoIterator,
-    MatrixIntoIterator,
-    MatrixIterator,
-    MatrixIteratorMut,
-    //MatrixRowIterator,
-    //MatrixRowIteratorMut,
-    MatrixRowIntoIterator,
+    MatrixColumnIntoIterator, MatrixColumnIterator, MatrixColumnIteratorMut, MatrixIntoIterator,
+    MatrixIterator, MatrixIteratorMut, MatrixRowIntoIterator, MatrixRowIterator,
+    MatrixRowIteratorMut,
 };
 use crate::algebra::abstr::Zero;
-use crate::algebra::linear::matrix::substitute::{SubstituteBackward, SubstituteForward};
+use crate::algebra::linear::LinalgError;
 use crate::{
     algebra::{
-        abstr::AbsDiffEq,
-        abstr::{Addition, Field, Identity, Multiplication, Scalar},
+        abstr::{Addition, Field, Identity, Multiplication, Real, Scalar},
         linear::{
-            matrix::{QRDecomposition, Transpose, UpperTriangular},
+            matrix::{
+                LUDec, LUDecomposition, MatrixNorm, SingularValueDecomposition, Solve, Transpose,
+            },
             vector::Vector,
         },
     },
@@ -27,6 +22,7 @@ use rand::{self, Rng};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::clone::Clone;
+use std::cmp::Ordering;
 use std::{fmt, fmt::Display};
 
 /// Macro to construct matrices
@@ -168,22 +164,237 @@ where
 
         sum
     }
+
+    /// Checks if the matrix is square and equal to its transpose
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  2.0, 1.0;
+    ///                                 1.0, 3.0];
+    ///
+    /// assert!(a.is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool {
+        let (m, n): (usize, usize) = self.dim();
+        if m != n {
+            return false;
+        }
+
+        (0..n).all(|j| ((j + 1)..n).all(|i| self[[i, j]] == self[[j, i]]))
+    }
 }
 
 impl<T> General<T>
 where
-    T: Field + Scalar + Power + AbsDiffEq,
+    T: Real,
 {
-    /// Calculates the pseudo inverse matrix
+    /// Computes the singular value decomposition
+    ///
+    /// M = U * S * V*
+    ///
+    /// # Return
+    ///
+    /// (u, s, v) with $U \in T^{m \times m}$, $S \in T^{m \times n}$ and
+    /// $V \in T^{n \times n}$
+    ///
+    /// # Panics
+    ///
+    /// if the QR algorithm does not converge
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    ///
+    /// let a: General<f64> = General::new(4,
+    ///                                  4,
+    ///                                  vec![4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, -2.0, 0.0, 3.0,
+    ///                                       -2.0, 2.0, 2.0, 1.0, -2.0, -1.0]);
+    ///
+    /// let (u, s, v): (General<f64>, General<f64>, General<f64>) = a.dec_sv();
+    /// ```
+    pub fn dec_sv(&self) -> (Self, Self, Self) {
+        let (m, n): (usize, usize) = self.dim();
+        let (u, s, v): (General<T>, Vector<T>, General<T>) = self.dec_svd().unwrap().usv();
+
+        let mut sigma: General<T> = General::zero(m, n);
+        for i in 0..m.min(n) {
+            sigma[[i, i]] = s[i];
+        }
+
+        (u, sigma, v)
+    }
+
+    /// Calculates the Moore-Penrose pseudo inverse matrix
+    ///
+    /// ```math
+    /// A^+ = V \Sigma^+ U^T
+    /// ```
+    /// Singular values smaller than $\max(m, n) \epsilon \sigma_{max}$ are
+    /// treated as zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// // rank deficient
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 2.0, 4.0];
+    ///
+    /// let a_pinv: General<f64> = a.pinv().unwrap();
+    ///
+    /// assert_relative_eq!(&(&a * &a_pinv) * &a, a, epsilon = 1.0e-10);
+    /// ```
+    pub fn pinv(&self) -> Result<General<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        let (u, s, v): (General<T>, Vector<T>, General<T>) = self.dec_svd_thin()?.usv();
+
+        let k: usize = m.min(n);
+        let tolerance: T = self.singular_value_tolerance(&s);
+        let mut v_s: General<T> = v;
+        for j in 0..k {
+            let s_j: T = if s[j] > tolerance {
+                T::one() / s[j]
+            } else {
+                T::zero()
+            };
+            for i in 0..n {
+                v_s[[i, j]] *= s_j;
+            }
+        }
+
+        Ok(&v_s * &u.transpose())
+    }
+
+    /// Calculates the numerical rank
+    ///
+    /// The rank is the number of singular values greater than
+    /// $\max(m, n) \epsilon \sigma_{max}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+    ///                                 2.0, 4.0, 6.0;
+    ///                                 1.0, 0.0, 1.0];
+    ///
+    /// assert_eq!(a.rank().unwrap(), 2);
+    /// ```
+    pub fn rank(&self) -> Result<usize, LinalgError> {
+        let s: Vector<T> = self.singular_values()?;
+        let tolerance: T = self.singular_value_tolerance(&s);
+
+        Ok(s.iter().filter(|s_i| **s_i > tolerance).count())
+    }
+
+    /// Calculates the condition number with respect to the 2-norm
+    ///
+    /// ```math
+    /// \kappa_2(A) = \frac{\sigma_{max}}{\sigma_{min}}
+    /// ```
+    /// The condition number of a singular matrix is infinite, where the
+    /// singular values are compared with the tolerance of
+    /// [`rank`](General::rank). The condition number of an empty matrix is
+    /// zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  2.0, 0.0;
+    ///                                 0.0, 0.5];
+    ///
+    /// assert_relative_eq!(a.cond().unwrap(), 4.0, epsilon = 1.0e-10);
+    /// ```
+    pub fn cond(&self) -> Result<T, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        let k: usize = m.min(n);
+        if k == 0 {
+            return Ok(T::zero());
+        }
+
+        let s: Vector<T> = self.singular_values()?;
+        let tolerance: T = self.singular_value_tolerance(&s);
+        if s[k - 1] <= tolerance {
+            return Ok(T::infinity());
+        }
+
+        Ok(s[0] / s[k - 1])
+    }
+
+    /// Solves $Ax = b$ and estimates the condition number of $A$
+    ///
+    /// The 1-norm condition number is estimated from the LU decomposition
+    /// that is used to solve the system, which is much cheaper than
+    /// [`cond`](General::cond). Returns the solution together with the
+    /// estimate so that the caller can warn about ill-conditioned systems.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_cond`: upper bound on the estimated condition number
+    ///
+    /// # Errors
+    ///
+    /// if $A$ is singular or the estimate exceeds `max_cond`
     ///
-    /// A^+ = (A^TA)^-1A^T
-    pub fn pinv(&self) -> Result<General<T>, ()> {
-        let r: UpperTriangular<T> = self.dec_qr()?.r();
-        let x: General<T> = r
-            .clone()
-            .transpose()
-            .substitute_forward(self.clone().transpose())?;
-        r.substitute_backward(x)
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::General, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  4.0, 1.0;
+    ///                                 2.0, 3.0];
+    /// let b: Vector<f64> = vector![1.0; 2.0];
+    ///
+    /// let (x, cond): (Vector<f64>, f64) = a.solve_with_cond(&b, Some(1.0e8)).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![0.1; 0.6], epsilon = 1.0e-10);
+    /// assert_relative_eq!(cond, 3.0, epsilon = 1.0e-10);
+    ///
+    /// let singular: General<f64> = matrix![   1.0, 2.0;
+    ///                                         2.0, 4.0];
+    /// assert!(singular.solve_with_cond(&b, Some(1.0e8)).is_err());
+    /// ```
+    pub fn solve_with_cond(
+        &self,
+        b: &Vector<T>,
+        max_cond: Option<T>,
+    ) -> Result<(Vector<T>, T), LinalgError> {
+        let lu: LUDec<T> = self.dec_lu()?;
+        let cond: T = lu.cond_1_estimate(self.norm_1());
+
+        if cond.partial_cmp(&T::infinity()) != Some(Ordering::Less) {
+            return Err(LinalgError::Singular);
+        }
+        if let Some(max_cond) = max_cond {
+            if cond > max_cond {
+                return Err(LinalgError::IllConditioned);
+            }
+        }
+
+        let x: Vector<T> = lu.solve(b)?;
+
+        Ok((x, cond))
+    }
+
+    pub(crate) fn singular_value_tolerance(&self, s: &Vector<T>) -> T {
+        let (m, n): (usize, usize) = self.dim();
+        if m.min(n) == 0 {
+            return T::zero();
+        }
+        T::from_u64(m.max(n) as u64) * T::default_epsilon() * s[0]
     }
 }
 
@@ -301,7 +512,7 @@ where
 {
     pub fn new_random(m: usize, n: usize) -> General<T> {
         let mut rng = rand::thread_rng();
-        let data: Vec<T> = vec![T::from_f64(rng.gen()); m * n];
+        let data: Vec<T> = (0..m * n).map(|_| T::from_f64(rng.gen())).collect();
         General::new(m, n, data)
     }
 }
@@ -348,31 +559,75 @@ impl<T> General<T> {
     pub fn row_into_iter(&self) -> MatrixRowIntoIterator<T> {
         MatrixRowIntoIterator::new(self)
     }
-    // pub fn row_iter(&self) -> MatrixRowIterator<T>
-    //     where T: Zero
-    // {
-    //     MatrixRowIterator::new(self.data.iter())
-    // }
 
-    // pub fn row_iter_mut(&mut self) -> MatrixRowIteratorMut<T>
-    //     where T: Zero
-    // {
-    //     MatrixRowIteratorMut::new(self.data.iter_mut())
-    // }
+    /// Returns an iterator over the rows as 1 x n views
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// let sums: Vec<f64> = a.row_iter().map(|row| row[[0, 0]] + row[[0, 1]]).collect();
+    ///
+    /// assert_eq!(sums, vec![3.0, 7.0]);
+    /// ```
+    pub fn row_iter(&self) -> MatrixRowIterator<'_, T> {
+        MatrixRowIterator::new(self.view())
+    }
+
+    /// Returns an iterator over the rows as mutable 1 x n views
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let mut a: General<f64> = General::zero(2, 2);
+    ///
+    /// for (i, mut row) in a.row_iter_mut().enumerate() {
+    ///     row.fill(i as f64);
+    /// }
+    ///
+    /// assert_eq!(a, matrix![0.0, 0.0; 1.0, 1.0]);
+    /// ```
+    pub fn row_iter_mut(&mut self) -> MatrixRowIteratorMut<'_, T> {
+        MatrixRowIteratorMut::new(self.view_mut())
+    }
 
     pub fn column_into_iter(&self) -> MatrixColumnIntoIterator<T> {
         MatrixColumnIntoIterator::new(self)
     }
 
-    // pub fn column_iter(&self) -> MatrixColumnIterator<T>
-    // {
-    //     MatrixColumnIterator::new(self.data.iter())
-    // }
+    /// Returns an iterator over the columns as m x 1 views
+    pub fn column_iter(&self) -> MatrixColumnIterator<'_, T> {
+        MatrixColumnIterator::new(self.view())
+    }
 
-    // pub fn column_iter_mut(&mut self) -> MatrixColumnIteratorMut<T>
-    // {
-    //     MatrixColumnIteratorMut::new(self.data.iter_mut())
-    // }
+    /// Returns an iterator over the columns as mutable m x 1 views
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let mut a: General<f64> = matrix![  1.0, 2.0;
+    ///                                     3.0, 4.0];
+    ///
+    /// for mut column in a.column_iter_mut().rev().skip(1) {
+    ///     column.scale(2.0);
+    /// }
+    ///
+    /// assert_eq!(a, matrix![2.0, 2.0; 6.0, 4.0]);
+    /// ```
+    pub fn column_iter_mut(&mut self) -> MatrixColumnIteratorMut<'_, T> {
+        MatrixColumnIteratorMut::new(self.view_mut())
+    }
 }
 
 impl<T> General<T>
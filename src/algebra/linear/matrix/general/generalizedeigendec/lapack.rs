@@ -0,0 +1,257 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Complex, Real, Scalar},
+    linear::matrix::{
+        General, GeneralizedEigenDec, GeneralizedEigenDecomposition, SymmetricEigenDec,
+        SymmetricGeneralizedEigenDecomposition,
+    },
+};
+
+impl<T> GeneralizedEigenDecomposition<T> for General<T>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the generalized eigenvalues and right eigenvectors of the
+    /// square matrices $A$ and $B$
+    /// ```math
+    /// Ax = \lambda Bx
+    /// ```
+    ///
+    /// If $A$ is symmetric and $B$ is symmetric positive definite, xsygv is
+    /// used. Otherwise the problem is solved with xggev, which also handles a
+    /// singular $B$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{General, GeneralizedEigenDecomposition};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 3.0, 4.0];
+    /// let b: General<f64> = matrix![  2.0, 0.0;
+    ///                                 1.0, 1.0];
+    ///
+    /// let mut values: Vec<Complex<f64>> = a.dec_eigen_generalized(&b).unwrap().values();
+    /// values.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+    ///
+    /// // det(A - λB) = 2λ² - 7λ - 2
+    /// let d: f64 = 65.0f64.sqrt();
+    /// assert_relative_eq!(values[0], Complex::new((7.0 - d) / 4.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// assert_relative_eq!(values[1], Complex::new((7.0 + d) / 4.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// ```
+    fn dec_eigen_generalized(&self, b: &General<T>) -> Result<GeneralizedEigenDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(
+            m, n,
+            "Unable to compute the generalized eigenvalues of a non-square matrix"
+        );
+        assert_eq!(b.dim(), (m, n), "The matrices must have the same dimension");
+
+        if self.is_symmetric() && b.is_symmetric() {
+            if let Ok((values, x)) = General::xsygv(self, b) {
+                return Ok(GeneralizedEigenDec::new(
+                    values.iter().map(|v| Complex::new(*v, T::zero())).collect(),
+                    vec![T::one(); n],
+                    General::from(&x),
+                ));
+            }
+        }
+
+        General::xggev(self, b)
+    }
+}
+
+impl<T> SymmetricGeneralizedEigenDecomposition<T> for General<T>
+where
+    T: Real,
+{
+    /// Computes the eigenvalues and eigenvectors of the symmetric-definite
+    /// problem
+    /// ```math
+    /// Ax = \lambda Bx
+    /// ```
+    /// with a symmetric matrix $A$ and a symmetric positive definite matrix
+    /// $B$.
+    ///
+    /// # Errors
+    ///
+    /// If $B$ is not positive definite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, SymmetricGeneralizedEigenDecomposition, Transpose};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let k: General<f64> = matrix![  2.0, -1.0;
+    ///                                 -1.0, 2.0];
+    /// let m: General<f64> = matrix![  2.0, 0.0;
+    ///                                 0.0, 1.0];
+    ///
+    /// let (values, x): (Vec<f64>, General<f64>) = k.dec_symmetric_eigen_generalized(&m).unwrap().pair();
+    ///
+    /// assert_relative_eq!(&(&x.clone().transpose() * &m) * &x, General::one(2), epsilon = 1.0e-10);
+    /// assert_relative_eq!(values[0], 0.6339745962155614, epsilon = 1.0e-10);
+    /// ```
+    fn dec_symmetric_eigen_generalized(
+        &self,
+        b: &General<T>,
+    ) -> Result<SymmetricEigenDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(
+            m, n,
+            "Unable to compute the generalized eigenvalues of a non-square matrix"
+        );
+        assert_eq!(b.dim(), (m, n), "The matrices must have the same dimension");
+
+        let (values, x): (Vec<T>, General<T>) = General::xsygv(self, b)?;
+
+        Ok(SymmetricEigenDec::new(values, x))
+    }
+}
+
+impl<T> General<T>
+where
+    T: Real,
+{
+    /// Calls xsygv and returns the eigenvalues and the B-orthonormal
+    /// eigenvectors
+    fn xsygv(a: &General<T>, b: &General<T>) -> Result<(Vec<T>, General<T>), LinalgError> {
+        let n: usize = a.m;
+        let n_i32: i32 = n as i32;
+        let mut a_data: Vec<T> = a.data.clone();
+        let mut b_data: Vec<T> = b.data.clone();
+        let mut w: Vec<T> = vec![T::zero(); n];
+        let mut info: i32 = 0;
+
+        let lwork: i32 = T::xsygv_work_size(
+            1,
+            'V' as u8,
+            'L' as u8,
+            n_i32,
+            &mut a_data[..],
+            n_i32,
+            &mut b_data[..],
+            n_i32,
+            &mut w[..],
+            &mut info,
+        );
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+
+        T::xsygv(
+            1,
+            'V' as u8,
+            'L' as u8,
+            n_i32,
+            &mut a_data[..],
+            n_i32,
+            &mut b_data[..],
+            n_i32,
+            &mut w[..],
+            &mut work[..],
+            lwork,
+            &mut info,
+        );
+
+        if info > n_i32 {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        if info != 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+
+        Ok((w, General::new(n, n, a_data)))
+    }
+
+    /// Calls xggev and combines the complex conjugate eigenvector pairs
+    fn xggev(a: &General<T>, b: &General<T>) -> Result<GeneralizedEigenDec<T>, LinalgError>
+    where
+        Complex<T>: Scalar,
+    {
+        let n: usize = a.m;
+        let n_i32: i32 = n as i32;
+        let mut a_data: Vec<T> = a.data.clone();
+        let mut b_data: Vec<T> = b.data.clone();
+        let mut alphar: Vec<T> = vec![T::zero(); n];
+        let mut alphai: Vec<T> = vec![T::zero(); n];
+        let mut beta: Vec<T> = vec![T::zero(); n];
+        let mut vl: Vec<T> = vec![T::zero(); 1];
+        let mut vr: Vec<T> = vec![T::zero(); n * n];
+        let mut info: i32 = 0;
+
+        let lwork: i32 = T::xggev_work_size(
+            'N' as u8,
+            'V' as u8,
+            n_i32,
+            &mut a_data[..],
+            n_i32,
+            &mut b_data[..],
+            n_i32,
+            &mut alphar[..],
+            &mut alphai[..],
+            &mut beta[..],
+            &mut vl[..],
+            1,
+            &mut vr[..],
+            n_i32,
+            &mut info,
+        );
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+
+        T::xggev(
+            'N' as u8,
+            'V' as u8,
+            n_i32,
+            &mut a_data[..],
+            n_i32,
+            &mut b_data[..],
+            n_i32,
+            &mut alphar[..],
+            &mut alphai[..],
+            &mut beta[..],
+            &mut vl[..],
+            1,
+            &mut vr[..],
+            n_i32,
+            &mut work[..],
+            lwork,
+            &mut info,
+        );
+
+        if info != 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+
+        let mut vectors: General<Complex<T>> = General::zero(n, n);
+        let mut j: usize = 0;
+        while j < n {
+            if alphai[j] == T::zero() {
+                for i in 0..n {
+                    vectors[[i, j]] = Complex::new(vr[j * n + i], T::zero());
+                }
+                j += 1;
+            } else {
+                for i in 0..n {
+                    let re: T = vr[j * n + i];
+                    let im: T = vr[(j + 1) * n + i];
+                    vectors[[i, j]] = Complex::new(re, im);
+                    vectors[[i, j + 1]] = Complex::new(re, -im);
+                }
+                j += 2;
+            }
+        }
+
+        let alpha: Vec<Complex<T>> = alphar
+            .iter()
+            .zip(alphai.iter())
+            .map(|(re, im)| Complex::new(*re, *im))
+            .collect();
+
+        Ok(GeneralizedEigenDec::new(alpha, beta, vectors))
+    }
+}
@@ -0,0 +1,4 @@
+#[cfg(feature = "lapack")]
+mod lapack;
+#[cfg(feature = "native")]
+mod native;
@@ -0,0 +1,237 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Complex, Real, Scalar, Zero},
+    linear::matrix::{
+        CholeskyDecomposition, ComplexEigenDecomposition, General, GeneralizedEigenDec,
+        GeneralizedEigenDecomposition, LowerTriangular, SubstituteBackward, SubstituteForward,
+        SymmetricEigenDec, SymmetricEigenDecomposition, SymmetricGeneralizedEigenDecomposition,
+        Transpose, UpperTriangular,
+    },
+};
+
+impl<T> GeneralizedEigenDecomposition<T> for General<T>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the generalized eigenvalues and right eigenvectors of the
+    /// square matrices $A$ and $B$
+    /// ```math
+    /// Ax = \lambda Bx
+    /// ```
+    ///
+    /// If $B$ is symmetric positive definite, the problem is reduced to a
+    /// standard eigenvalue problem with the Cholesky decomposition of $B$.
+    /// Otherwise the QZ algorithm is used, which also handles a singular $B$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{General, GeneralizedEigenDecomposition};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 3.0, 4.0];
+    /// let b: General<f64> = matrix![  2.0, 0.0;
+    ///                                 1.0, 1.0];
+    ///
+    /// let mut values: Vec<Complex<f64>> = a.dec_eigen_generalized(&b).unwrap().values();
+    /// values.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+    ///
+    /// // det(A - λB) = 2λ² - 7λ - 2
+    /// let d: f64 = 65.0f64.sqrt();
+    /// assert_relative_eq!(values[0], Complex::new((7.0 - d) / 4.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// assert_relative_eq!(values[1], Complex::new((7.0 + d) / 4.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// ```
+    fn dec_eigen_generalized(&self, b: &General<T>) -> Result<GeneralizedEigenDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(
+            m, n,
+            "Unable to compute the generalized eigenvalues of a non-square matrix"
+        );
+        assert_eq!(b.dim(), (m, n), "The matrices must have the same dimension");
+
+        if b.is_symmetric() {
+            if let Ok(dec) = b.dec_cholesky() {
+                return self.eigen_generalized_cholesky(dec.l());
+            }
+        }
+
+        let a_c: General<Complex<T>> = General::from(self);
+        let b_c: General<Complex<T>> = General::from(b);
+        let (_, z, s, t) = a_c.qz(&b_c)?.qzst();
+
+        Ok(General::eigen_triangular_pencil(
+            z,
+            General::from(s),
+            General::from(t),
+        ))
+    }
+}
+
+impl<T> SymmetricGeneralizedEigenDecomposition<T> for General<T>
+where
+    T: Real,
+{
+    /// Computes the eigenvalues and eigenvectors of the symmetric-definite
+    /// problem
+    /// ```math
+    /// Ax = \lambda Bx
+    /// ```
+    /// with a symmetric matrix $A$ and a symmetric positive definite matrix
+    /// $B$.
+    ///
+    /// # Errors
+    ///
+    /// If $B$ is not positive definite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, SymmetricGeneralizedEigenDecomposition, Transpose};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let k: General<f64> = matrix![  2.0, -1.0;
+    ///                                 -1.0, 2.0];
+    /// let m: General<f64> = matrix![  2.0, 0.0;
+    ///                                 0.0, 1.0];
+    ///
+    /// let (values, x): (Vec<f64>, General<f64>) = k.dec_symmetric_eigen_generalized(&m).unwrap().pair();
+    ///
+    /// assert_relative_eq!(&(&x.clone().transpose() * &m) * &x, General::one(2), epsilon = 1.0e-10);
+    /// assert_relative_eq!(values[0], 0.6339745962155614, epsilon = 1.0e-10);
+    /// ```
+    fn dec_symmetric_eigen_generalized(
+        &self,
+        b: &General<T>,
+    ) -> Result<SymmetricEigenDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(
+            m, n,
+            "Unable to compute the generalized eigenvalues of a non-square matrix"
+        );
+        assert_eq!(b.dim(), (m, n), "The matrices must have the same dimension");
+
+        let l: LowerTriangular<T> = b.dec_cholesky()?.l();
+        let mut c: General<T> = General::reduce_cholesky(self, &l)?;
+
+        // Remove the rounding errors in the symmetry of C
+        for j in 0..n {
+            for i in (j + 1)..n {
+                let c_ij: T = (c[[i, j]] + c[[j, i]]) / T::from_f64(2.0);
+                c[[i, j]] = c_ij;
+                c[[j, i]] = c_ij;
+            }
+        }
+
+        let (values, y): (Vec<T>, General<T>) = c.dec_symmetric_eigen()?.pair();
+        let l_t: UpperTriangular<T> = l.transpose();
+        let x: General<T> = l_t.substitute_backward(y)?;
+
+        Ok(SymmetricEigenDec::new(values, x))
+    }
+}
+
+impl<T> General<T>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Solves the generalized eigenvalue problem with $B = LL^T$ as the
+    /// standard eigenvalue problem of $L^{-1}AL^{-T}$
+    fn eigen_generalized_cholesky(
+        &self,
+        l: LowerTriangular<T>,
+    ) -> Result<GeneralizedEigenDec<T>, LinalgError> {
+        let n: usize = self.m;
+        let c: General<T> = General::reduce_cholesky(self, &l)?;
+        let l_t: UpperTriangular<T> = l.transpose();
+
+        let (values, y): (Vec<Complex<T>>, General<Complex<T>>) = if self.is_symmetric() {
+            let (values, y): (Vec<T>, General<T>) = c.dec_symmetric_eigen()?.pair();
+            (
+                values.iter().map(|v| Complex::new(*v, T::zero())).collect(),
+                General::from(&y),
+            )
+        } else {
+            c.dec_eigen_complex()?.pair()
+        };
+
+        // x = L^{-T} y for the real and the imaginary part
+        let y_re: General<T> = General::new(n, n, y.data.iter().map(|y| y.re).collect());
+        let y_im: General<T> = General::new(n, n, y.data.iter().map(|y| y.im).collect());
+        let x_re: General<T> = l_t.substitute_backward(y_re)?;
+        let x_im: General<T> = l_t.substitute_backward(y_im)?;
+        let x: General<Complex<T>> = General::new(
+            n,
+            n,
+            x_re.data
+                .iter()
+                .zip(x_im.data.iter())
+                .map(|(re, im)| Complex::new(*re, *im))
+                .collect(),
+        );
+
+        Ok(GeneralizedEigenDec::new(values, vec![T::one(); n], x))
+    }
+
+    /// Computes the eigenvalues and right eigenvectors of the upper triangular
+    /// pencil (S, T) and transforms the eigenvectors with Z
+    fn eigen_triangular_pencil(
+        z: General<Complex<T>>,
+        s: General<Complex<T>>,
+        t: General<Complex<T>>,
+    ) -> GeneralizedEigenDec<T> {
+        let n: usize = s.m;
+        let eps: T = T::default_epsilon();
+        let mut y: General<Complex<T>> = General::zero(n, n);
+        let mut alpha: Vec<Complex<T>> = Vec::with_capacity(n);
+        let mut beta: Vec<T> = Vec::with_capacity(n);
+
+        for k in 0..n {
+            let a_k: Complex<T> = s[[k, k]];
+            let b_k: Complex<T> = t[[k, k]];
+            let small: T = eps * (General::modulus(a_k) + General::modulus(b_k));
+
+            // solve (b_k S - a_k T) y = 0 with y_k = 1
+            y[[k, k]] = Complex::new(T::one(), T::zero());
+            for i in (0..k).rev() {
+                let mut r: Complex<T> = Complex::zero();
+                for j in (i + 1)..=k {
+                    r -= (b_k * s[[i, j]] - a_k * t[[i, j]]) * y[[j, k]];
+                }
+                let mut d: Complex<T> = b_k * s[[i, i]] - a_k * t[[i, i]];
+                if General::modulus(d) <= small {
+                    let d_re: T = if small == T::zero() { eps } else { small };
+                    d = Complex::new(d_re, T::zero());
+                }
+                y[[i, k]] = r / d;
+            }
+
+            // normalize beta to be real and not negative
+            let b_abs: T = General::modulus(b_k);
+            if b_abs == T::zero() {
+                alpha.push(a_k);
+            } else {
+                alpha.push(a_k * b_k.conj() / Complex::new(b_abs, T::zero()));
+            }
+            beta.push(b_abs);
+        }
+
+        GeneralizedEigenDec::new(alpha, beta, &z * &y)
+    }
+}
+
+impl<T> General<T>
+where
+    T: Real,
+{
+    /// Computes $L^{-1}AL^{-T}$
+    fn reduce_cholesky(a: &General<T>, l: &LowerTriangular<T>) -> Result<General<T>, LinalgError> {
+        let x: General<T> = l.substitute_forward(a.clone())?;
+        let c_t: General<T> = l.substitute_forward(x.transpose())?;
+
+        Ok(c_t.transpose())
+    }
+}
@@ -0,0 +1,266 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Complex, One, Real, Scalar, Zero},
+    linear::matrix::{
+        General, GeneralizedSchurDec, GeneralizedSchurDecomposition, UpperTriangular,
+    },
+};
+
+impl<T> GeneralizedSchurDecomposition<Complex<T>> for General<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the complex generalized Schur decomposition
+    /// ```math
+    /// A = QSZ^* \\\\
+    /// B = QTZ^*
+    /// ```
+    /// of the square matrices $A$ and $B$ with the QZ algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{ConjugateTranspose, General, GeneralizedSchurDecomposition};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<Complex<f64>> = matrix![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0);
+    ///                                        Complex::new(3.0, 0.0), Complex::new(4.0, 0.0)];
+    /// let b: General<Complex<f64>> = matrix![Complex::new(2.0, 0.0), Complex::new(0.0, 0.0);
+    ///                                        Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)];
+    ///
+    /// let (q, z, s, t) = a.dec_generalized_schur(&b).unwrap().qzst();
+    /// let z_h: General<Complex<f64>> = z.conj_transpose();
+    ///
+    /// assert_relative_eq!(&(&q * &General::from(s)) * &z_h, a, epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// assert_relative_eq!(&(&q * &General::from(t)) * &z_h, b, epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// ```
+    fn dec_generalized_schur(
+        &self,
+        b: &General<Complex<T>>,
+    ) -> Result<GeneralizedSchurDec<Complex<T>>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(
+            m, n,
+            "Unable to compute the generalized Schur decomposition of a non-square matrix"
+        );
+        assert_eq!(b.dim(), (m, n), "The matrices must have the same dimension");
+
+        self.qz(b)
+    }
+}
+
+impl<T> General<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the generalized Schur form $(A, B) = (QSZ^*, QTZ^*)$ with the
+    /// single shift QZ algorithm
+    pub(crate) fn qz(
+        &self,
+        b: &General<Complex<T>>,
+    ) -> Result<GeneralizedSchurDec<Complex<T>>, LinalgError> {
+        let n: usize = self.m;
+        let mut h: General<Complex<T>> = self.clone();
+        let mut t: General<Complex<T>> = b.clone();
+        let mut q: General<Complex<T>> = General::one(n);
+        let mut z: General<Complex<T>> = General::one(n);
+
+        General::reduce_hessenberg_triangular(&mut h, &mut t, &mut q, &mut z);
+
+        let eps: T = T::default_epsilon();
+        let norm_h: T = General::norm_complex(&h);
+        let norm_t: T = General::norm_complex(&t);
+        let max_iter: usize = 30 * n;
+        let mut iter: usize = 0;
+        let mut hi: usize = n.saturating_sub(1);
+
+        while hi > 0 {
+            // Find the lowest negligible subdiagonal entry
+            let mut lo: usize = hi;
+            while lo > 0 {
+                let mut s: T =
+                    General::modulus(h[[lo - 1, lo - 1]]) + General::modulus(h[[lo, lo]]);
+                if s == T::zero() {
+                    s = norm_h;
+                }
+                if General::modulus(h[[lo, lo - 1]]) <= eps * s {
+                    h[[lo, lo - 1]] = Complex::zero();
+                    break;
+                }
+                lo -= 1;
+            }
+
+            if lo == hi {
+                hi -= 1;
+                iter = 0;
+                continue;
+            }
+
+            // A zero on the diagonal of T is an infinite eigenvalue, which is
+            // chased to the bottom of the active block and deflated
+            if let Some(j) = (lo..=hi).find(|j| General::modulus(t[[*j, *j]]) <= eps * norm_t) {
+                t[[j, j]] = Complex::zero();
+                General::chase_zero(&mut h, &mut t, &mut q, &mut z, j, lo, hi);
+                continue;
+            }
+
+            iter += 1;
+            if iter > max_iter {
+                return Err(LinalgError::NoConvergence { iters: max_iter });
+            }
+
+            let shift: Complex<T> = General::shift_pencil(&h, &t, hi, iter);
+
+            // Implicit single shift QZ step on the active block lo..=hi
+            let mut x: Complex<T> = h[[lo, lo]] / t[[lo, lo]] - shift;
+            let mut y: Complex<T> = h[[lo + 1, lo]] / t[[lo, lo]];
+            for k in lo..hi {
+                let (c, s) = General::rotation(x, y);
+                let col_start: usize = if k > lo { k - 1 } else { k };
+                General::rotate_left(&mut h, k, c, s, col_start);
+                General::rotate_left(&mut t, k, c, s, k);
+                General::rotate_right(&mut q, k, c, s, n - 1);
+                if k > lo {
+                    h[[k + 1, k - 1]] = Complex::zero();
+                }
+
+                let (c, s) = General::rotation(t[[k + 1, k + 1]], -t[[k + 1, k]]);
+                General::rotate_right(&mut t, k, c, s, k + 1);
+                General::rotate_right(&mut h, k, c, s, (k + 2).min(hi));
+                General::rotate_right(&mut z, k, c, s, n - 1);
+                t[[k + 1, k]] = Complex::zero();
+
+                if k + 1 < hi {
+                    x = h[[k + 1, k]];
+                    y = h[[k + 2, k]];
+                }
+            }
+        }
+
+        // Clear the entries below the diagonal
+        for j in 0..n {
+            for i in (j + 1)..n {
+                h[[i, j]] = Complex::zero();
+                t[[i, j]] = Complex::zero();
+            }
+        }
+
+        Ok(GeneralizedSchurDec::new(
+            q,
+            z,
+            UpperTriangular::new(h),
+            UpperTriangular::new(t),
+        ))
+    }
+
+    /// Reduces h to upper Hessenberg and t to upper triangular form with
+    /// Givens rotations and accumulates them in q and z
+    fn reduce_hessenberg_triangular(
+        h: &mut General<Complex<T>>,
+        t: &mut General<Complex<T>>,
+        q: &mut General<Complex<T>>,
+        z: &mut General<Complex<T>>,
+    ) {
+        let n: usize = h.m;
+
+        for j in 0..n {
+            for i in ((j + 1)..n).rev() {
+                let (c, s) = General::rotation(t[[i - 1, j]], t[[i, j]]);
+                General::rotate_left(t, i - 1, c, s, j);
+                General::rotate_left(h, i - 1, c, s, 0);
+                General::rotate_right(q, i - 1, c, s, n - 1);
+                t[[i, j]] = Complex::zero();
+            }
+        }
+
+        for j in 0..n.saturating_sub(2) {
+            for i in ((j + 2)..n).rev() {
+                let (c, s) = General::rotation(h[[i - 1, j]], h[[i, j]]);
+                General::rotate_left(h, i - 1, c, s, j);
+                General::rotate_left(t, i - 1, c, s, i - 1);
+                General::rotate_right(q, i - 1, c, s, n - 1);
+                h[[i, j]] = Complex::zero();
+
+                let (c, s) = General::rotation(t[[i, i]], -t[[i, i - 1]]);
+                General::rotate_right(t, i - 1, c, s, i);
+                General::rotate_right(h, i - 1, c, s, n - 1);
+                General::rotate_right(z, i - 1, c, s, n - 1);
+                t[[i, i - 1]] = Complex::zero();
+            }
+        }
+    }
+
+    /// Moves the zero diagonal entry t[j, j] to t[hi, hi] and zeros h[hi, hi -
+    /// 1]
+    fn chase_zero(
+        h: &mut General<Complex<T>>,
+        t: &mut General<Complex<T>>,
+        q: &mut General<Complex<T>>,
+        z: &mut General<Complex<T>>,
+        j: usize,
+        lo: usize,
+        hi: usize,
+    ) {
+        let n: usize = h.m;
+
+        for k in j..hi {
+            let (c, s) = General::rotation(t[[k, k + 1]], t[[k + 1, k + 1]]);
+            General::rotate_left(t, k, c, s, k + 1);
+            General::rotate_left(h, k, c, s, if k > lo { k - 1 } else { k });
+            General::rotate_right(q, k, c, s, n - 1);
+            t[[k + 1, k + 1]] = Complex::zero();
+
+            if k > lo {
+                let (c, s) = General::rotation(h[[k + 1, k]], -h[[k + 1, k - 1]]);
+                General::rotate_right(h, k - 1, c, s, k + 1);
+                General::rotate_right(t, k - 1, c, s, k);
+                General::rotate_right(z, k - 1, c, s, n - 1);
+                h[[k + 1, k - 1]] = Complex::zero();
+            }
+        }
+
+        let (c, s) = General::rotation(h[[hi, hi]], -h[[hi, hi - 1]]);
+        General::rotate_right(h, hi - 1, c, s, hi);
+        General::rotate_right(t, hi - 1, c, s, hi);
+        General::rotate_right(z, hi - 1, c, s, n - 1);
+        h[[hi, hi - 1]] = Complex::zero();
+    }
+
+    /// Eigenvalue of the trailing 2x2 pencil, with an exceptional shift every
+    /// tenth iteration
+    fn shift_pencil(
+        h: &General<Complex<T>>,
+        t: &General<Complex<T>>,
+        hi: usize,
+        iter: usize,
+    ) -> Complex<T> {
+        // M = H_22 T_22^{-1}
+        let t_00: Complex<T> = Complex::one() / t[[hi - 1, hi - 1]];
+        let t_11: Complex<T> = Complex::one() / t[[hi, hi]];
+        let t_01: Complex<T> = -t[[hi - 1, hi]] * t_00 * t_11;
+
+        let m_00: Complex<T> = h[[hi - 1, hi - 1]] * t_00;
+        let m_01: Complex<T> = h[[hi - 1, hi - 1]] * t_01 + h[[hi - 1, hi]] * t_11;
+        let m_10: Complex<T> = h[[hi, hi - 1]] * t_00;
+        let m_11: Complex<T> = h[[hi, hi - 1]] * t_01 + h[[hi, hi]] * t_11;
+
+        if iter.is_multiple_of(10) {
+            m_11 + Complex::new(General::modulus(m_10), T::zero())
+        } else {
+            General::wilkinson_shift(m_00, m_01, m_10, m_11)
+        }
+    }
+
+    /// Frobenius norm of a complex matrix
+    fn norm_complex(a: &General<Complex<T>>) -> T {
+        a.data
+            .iter()
+            .fold(T::zero(), |s, a_ij| {
+                s + a_ij.re * a_ij.re + a_ij.im * a_ij.im
+            })
+            .sqrt()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{
     abstr::{Field, Scalar, Zero},
     linear::matrix::{General, Inverse, Transpose},
@@ -18,7 +19,7 @@ where
     /// let a: General<f64> = General::new(2, 2, vec![1.0, 0.0, 3.0, -7.0]);
     /// let b_inv: General<f64> = a.inv().unwrap();
     /// ```
-    fn inv(&self) -> Result<General<T>, ()> {
+    fn inv(&self) -> Result<General<T>, LinalgError> {
         self.inv_r()
     }
 }
@@ -27,7 +28,7 @@ impl<T> General<T>
 where
     T: Field + Scalar,
 {
-    pub fn inv_r(&self) -> Result<General<T>, ()> {
+    pub fn inv_r(&self) -> Result<General<T>, LinalgError> {
         let (m, n): (usize, usize) = self.dim();
         let m_i32: i32 = m as i32;
         let n_i32: i32 = n as i32;
@@ -48,15 +49,18 @@ where
             &mut info,
         );
 
-        if info != 0 {
-            return Err(());
+        if info < 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+        if info > 0 {
+            return Err(LinalgError::Singular);
         }
 
         let lwork: i32 =
             T::xgetri_work_size(n_i32, &mut self_data[..], n_i32, &mut ipiv, &mut info);
 
         if info != 0 {
-            return Err(());
+            return Err(LinalgError::BackendError { info });
         }
 
         let mut work: Vec<T> = vec![T::zero(); lwork as usize];
@@ -71,8 +75,11 @@ where
             &mut info,
         );
 
-        if info != 0 {
-            return Err(());
+        if info < 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+        if info > 0 {
+            return Err(LinalgError::Singular);
         }
 
         let self_inv: General<T> = General::new(n, m, self_data);
@@ -1,3 +1,4 @@
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{
     abstr::{AbsDiffEq, Field, Scalar},
     linear::matrix::{General, Inverse, LUDec, LUDecomposition},
@@ -18,7 +19,7 @@ where
     /// let a: General<f64> = General::new(2, 2, vec![1.0, 0.0, 3.0, -7.0]);
     /// let b_inv: General<f64> = a.inv().unwrap();
     /// ```
-    fn inv(&self) -> Result<General<T>, ()> {
+    fn inv(&self) -> Result<General<T>, LinalgError> {
         self.inv_r()
     }
 }
@@ -27,7 +28,7 @@ impl<T> General<T>
 where
     T: Field + Scalar + AbsDiffEq,
 {
-    pub fn inv_r(&self) -> Result<General<T>, ()> {
+    pub fn inv_r(&self) -> Result<General<T>, LinalgError> {
         let lu_dec: LUDec<T> = self.dec_lu()?;
         lu_dec.inv()
     }
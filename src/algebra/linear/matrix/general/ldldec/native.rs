@@ -0,0 +1,186 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::matrix::{General, LDLDec, LDLDecomposition, UnitLowerTriangular},
+};
+
+impl<T> LDLDecomposition<T> for General<T>
+where
+    T: Real,
+{
+    /// Decomposes a symmetric matrix $A$ into
+    /// ```math
+    /// PAP^T = LDL^T
+    /// ```
+    /// with the Bunch-Kaufman pivoting strategy. $D$ is block diagonal with
+    /// $1 \times 1$ and $2 \times 2$ blocks, hence $A$ may be indefinite or
+    /// singular.
+    ///
+    /// Only the lower triangle of $A$ is read, the matrix is assumed to be
+    /// symmetric.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix $A$ is not quadratic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, LDLDecomposition, Transpose, UnitLowerTriangular};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+    ///                                 2.0, -4.0, 1.0;
+    ///                                 3.0, 1.0, 0.0];
+    ///
+    /// let (l, d, p): (UnitLowerTriangular<f64>, General<f64>, General<f64>) = a.dec_ldl().unwrap().ldp();
+    /// let l: General<f64> = l.into();
+    ///
+    /// assert_relative_eq!(&(&l * &d) * &l.clone().transpose(), &(&p * &a) * &p.clone().transpose(), epsilon = 1.0e-10);
+    /// ```
+    fn dec_ldl(&self) -> Result<LDLDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(
+            m, n,
+            "Unable to compute the LDL decomposition of a non-square matrix"
+        );
+
+        // growth factor bound (1 + sqrt(17)) / 8
+        let alpha: T = (T::one() + T::from_f64(17.0).sqrt()) / T::from_f64(8.0);
+
+        let mut a: General<T> = self.clone();
+        for j in 0..n {
+            for i in (j + 1)..n {
+                a[[j, i]] = a[[i, j]];
+            }
+        }
+        let mut l: General<T> = General::one(n);
+        let mut d: General<T> = General::zero(n, n);
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        let mut k: usize = 0;
+        while k < n {
+            let abs_akk: T = a[[k, k]].abs();
+            let (r, col_max): (usize, T) = General::abs_max(((k + 1)..n).map(|i| (i, a[[i, k]])));
+
+            if abs_akk == T::zero() && col_max == T::zero() {
+                // zero column, the matrix is singular
+                k += 1;
+                continue;
+            }
+
+            let mut size: usize = 1;
+            if abs_akk < alpha * col_max {
+                let (_, row_max): (usize, T) =
+                    General::abs_max((k..n).filter(|j| *j != r).map(|j| (j, a[[r, j]])));
+
+                if abs_akk * row_max >= alpha * col_max * col_max {
+                    // no interchange, 1x1 pivot a_kk
+                } else if a[[r, r]].abs() >= alpha * row_max {
+                    // 1x1 pivot a_rr
+                    General::swap_symmetric(&mut a, &mut l, &mut perm, k, r);
+                } else {
+                    // 2x2 pivot with a_kk and a_rr
+                    size = 2;
+                    General::swap_symmetric(&mut a, &mut l, &mut perm, k + 1, r);
+                }
+            }
+
+            if size == 1 {
+                let d_kk: T = a[[k, k]];
+                d[[k, k]] = d_kk;
+                for i in (k + 1)..n {
+                    l[[i, k]] = a[[i, k]] / d_kk;
+                }
+                for j in (k + 1)..n {
+                    for i in j..n {
+                        let a_ij: T = a[[i, j]] - l[[i, k]] * a[[j, k]];
+                        a[[i, j]] = a_ij;
+                        a[[j, i]] = a_ij;
+                    }
+                }
+            } else {
+                let d_00: T = a[[k, k]];
+                let d_10: T = a[[k + 1, k]];
+                let d_11: T = a[[k + 1, k + 1]];
+                let det: T = d_00 * d_11 - d_10 * d_10;
+                d[[k, k]] = d_00;
+                d[[k + 1, k]] = d_10;
+                d[[k, k + 1]] = d_10;
+                d[[k + 1, k + 1]] = d_11;
+
+                for i in (k + 2)..n {
+                    let a_i0: T = a[[i, k]];
+                    let a_i1: T = a[[i, k + 1]];
+                    l[[i, k]] = (d_11 * a_i0 - d_10 * a_i1) / det;
+                    l[[i, k + 1]] = (d_00 * a_i1 - d_10 * a_i0) / det;
+                }
+                for j in (k + 2)..n {
+                    for i in j..n {
+                        let a_ij: T =
+                            a[[i, j]] - l[[i, k]] * a[[j, k]] - l[[i, k + 1]] * a[[j, k + 1]];
+                        a[[i, j]] = a_ij;
+                        a[[j, i]] = a_ij;
+                    }
+                }
+            }
+
+            k += size;
+        }
+
+        let mut p: General<T> = General::zero(n, n);
+        for (i, perm_i) in perm.iter().enumerate() {
+            p[[i, *perm_i]] = T::one();
+        }
+
+        Ok(LDLDec::new(UnitLowerTriangular::new(l), d, p))
+    }
+}
+
+impl<T> General<T>
+where
+    T: Real,
+{
+    /// Returns the index and the absolute value of the entry with the largest
+    /// absolute value
+    fn abs_max(entries: impl Iterator<Item = (usize, T)>) -> (usize, T) {
+        entries.fold((0, T::zero()), |(i_max, max), (i, a_i)| {
+            if a_i.abs() > max {
+                (i, a_i.abs())
+            } else {
+                (i_max, max)
+            }
+        })
+    }
+
+    /// Interchanges the rows and columns i and j of the symmetric matrix a
+    /// and the rows i and j of the already computed columns of l
+    fn swap_symmetric(
+        a: &mut General<T>,
+        l: &mut General<T>,
+        perm: &mut [usize],
+        i: usize,
+        j: usize,
+    ) {
+        if i == j {
+            return;
+        }
+        let n: usize = a.nrows();
+        for c in 0..n {
+            let temp: T = a[[i, c]];
+            a[[i, c]] = a[[j, c]];
+            a[[j, c]] = temp;
+        }
+        for r in 0..n {
+            let temp: T = a[[r, i]];
+            a[[r, i]] = a[[r, j]];
+            a[[r, j]] = temp;
+        }
+        for c in 0..i.min(j) {
+            let temp: T = l[[i, c]];
+            l[[i, c]] = l[[j, c]];
+            l[[j, c]] = temp;
+        }
+        perm.swap(i, j);
+    }
+}
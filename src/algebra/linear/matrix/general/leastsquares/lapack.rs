@@ -0,0 +1,141 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{General, LeastSquares, LeastSquaresResult},
+        vector::Vector,
+    },
+};
+
+impl<T> LeastSquares<T> for General<T>
+where
+    T: Real,
+{
+    /// Solves the least squares problem with a QR decomposition with column
+    /// pivoting computed by xgeqp3
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{General, LeastSquares, LeastSquaresResult}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// // fit of a line y = c_0 + c_1 t through three points
+    /// let a: General<f64> = matrix![  1.0, 0.0;
+    ///                                 1.0, 1.0;
+    ///                                 1.0, 2.0];
+    /// let b: Vector<f64> = vector![1.0; 2.0; 4.0];
+    ///
+    /// let result: LeastSquaresResult<f64> = a.solve_least_squares_qr(&b).unwrap();
+    ///
+    /// assert_relative_eq!(*result.x(), vector![5.0 / 6.0; 1.5], epsilon = 1.0e-10);
+    /// assert_eq!(result.rank(), 2);
+    /// ```
+    fn solve_least_squares_qr(&self, b: &Vector<T>) -> Result<LeastSquaresResult<T>, LinalgError> {
+        self.least_squares_qrp(b)
+    }
+
+    /// Solves the least squares problem with xgelsd
+    ///
+    /// Singular values smaller than $\max(m, n) \epsilon \sigma_{max}$ are
+    /// treated as zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{General, LeastSquares, LeastSquaresResult}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// // rank deficient
+    /// let a: General<f64> = matrix![  1.0, 1.0;
+    ///                                 1.0, 1.0;
+    ///                                 1.0, 1.0];
+    /// let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+    ///
+    /// let result: LeastSquaresResult<f64> = a.solve_least_squares_svd(&b).unwrap();
+    ///
+    /// assert_relative_eq!(*result.x(), vector![1.0; 1.0], epsilon = 1.0e-10);
+    /// assert_eq!(result.rank(), 1);
+    /// ```
+    fn solve_least_squares_svd(&self, b: &Vector<T>) -> Result<LeastSquaresResult<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(
+            m,
+            b.dim().0,
+            "dimension of b does not match the number of rows"
+        );
+
+        let m_i32: i32 = m as i32;
+        let n_i32: i32 = n as i32;
+        let ldb: usize = m.max(n).max(1);
+        let rcond: T = T::from_u64(m.max(n) as u64) * T::default_epsilon();
+
+        let mut a_data: Vec<T> = self.clone().data;
+        let mut b_data: Vec<T> = General::least_squares_rhs(b, ldb);
+        let mut s: Vec<T> = vec![T::zero(); m.min(n)];
+        let mut rank: i32 = 0;
+        let mut info: i32 = 0;
+
+        let (lwork, liwork): (i32, i32) = T::xgelsd_work_size(
+            m_i32,
+            n_i32,
+            1,
+            &mut a_data[..],
+            m_i32,
+            &mut b_data[..],
+            ldb as i32,
+            &mut s[..],
+            rcond,
+            &mut rank,
+            &mut info,
+        );
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+        let mut iwork: Vec<i32> = vec![0; liwork.max(1) as usize];
+
+        T::xgelsd(
+            m_i32,
+            n_i32,
+            1,
+            &mut a_data[..],
+            m_i32,
+            &mut b_data[..],
+            ldb as i32,
+            &mut s[..],
+            rcond,
+            &mut rank,
+            &mut work[..],
+            lwork,
+            &mut iwork[..],
+            &mut info,
+        );
+
+        if info != 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+
+        let x: Vector<T> = Vector::new_column(b_data[0..n].to_vec());
+        let residual: T = (b - &(self * &x)).eucl_norm();
+
+        Ok(LeastSquaresResult::new(
+            x,
+            residual,
+            rank as usize,
+            Some(Vector::new_column(s)),
+        ))
+    }
+}
+
+impl<T> General<T>
+where
+    T: Real,
+{
+    /// Copies b into a buffer of length ldb, which holds the solution on exit
+    fn least_squares_rhs(b: &Vector<T>, ldb: usize) -> Vec<T> {
+        let mut b_data: Vec<T> = vec![T::zero(); ldb];
+        for (i, b_i) in b.iter().enumerate() {
+            b_data[i] = *b_i;
+        }
+        b_data
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(feature = "lapack")]
+mod lapack;
+#[cfg(feature = "native")]
+mod native;
+
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{
+            General, LeastSquaresResult, QRPDec, QRPDecomposition, Transpose, UpperTriangular,
+        },
+        vector::Vector,
+    },
+};
+
+impl<T> General<T>
+where
+    T: Real,
+{
+    /// Computes the basic solution of the least squares problem from the QR
+    /// decomposition with column pivoting $AP = QR$
+    ///
+    /// Diagonal entries of $R$ with $|r_{ii}| \leq \max(m, n) \epsilon
+    /// |r_{00}|$ determine the rank, the entries of the solution belonging to
+    /// the remaining columns are zero.
+    fn least_squares_qrp(&self, b: &Vector<T>) -> Result<LeastSquaresResult<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(
+            m,
+            b.dim().0,
+            "dimension of b does not match the number of rows"
+        );
+
+        let qrp: QRPDec<T> = self.dec_qrp()?;
+        let rank: usize = qrp.rank(T::from_u64(m.max(n) as u64) * T::default_epsilon());
+        let (q, r, p): (General<T>, UpperTriangular<T>, General<T>) = qrp.qrp();
+
+        let c: Vector<T> = q.transpose() * b.clone();
+
+        // Back substitution with the leading rank x rank block of R, the
+        // entries belonging to the remaining columns are zero
+        let mut y: Vector<T> = Vector::zero(n);
+        for i in (0..rank).rev() {
+            let mut s: T = c[i];
+            for k in (i + 1)..rank {
+                s -= r[[i, k]] * y[k];
+            }
+            y[i] = s / r[[i, i]];
+        }
+
+        let x: Vector<T> = &p * &y;
+
+        let residual: T = (b - &(self * &x)).eucl_norm();
+
+        Ok(LeastSquaresResult::new(x, residual, rank, None))
+    }
+}
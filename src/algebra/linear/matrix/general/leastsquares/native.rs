@@ -0,0 +1,91 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{General, LeastSquares, LeastSquaresResult, SingularValueDecomposition},
+        vector::Vector,
+    },
+};
+
+impl<T> LeastSquares<T> for General<T>
+where
+    T: Real,
+{
+    /// Solves the least squares problem with a Householder QR decomposition
+    /// with column pivoting
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{General, LeastSquares, LeastSquaresResult}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// // fit of a line y = c_0 + c_1 t through three points
+    /// let a: General<f64> = matrix![  1.0, 0.0;
+    ///                                 1.0, 1.0;
+    ///                                 1.0, 2.0];
+    /// let b: Vector<f64> = vector![1.0; 2.0; 4.0];
+    ///
+    /// let result: LeastSquaresResult<f64> = a.solve_least_squares_qr(&b).unwrap();
+    ///
+    /// assert_relative_eq!(*result.x(), vector![5.0 / 6.0; 1.5], epsilon = 1.0e-10);
+    /// assert_eq!(result.rank(), 2);
+    /// ```
+    fn solve_least_squares_qr(&self, b: &Vector<T>) -> Result<LeastSquaresResult<T>, LinalgError> {
+        self.least_squares_qrp(b)
+    }
+
+    /// Solves the least squares problem with the thin singular value
+    /// decomposition
+    ///
+    /// Singular values smaller than $\max(m, n) \epsilon \sigma_{max}$ are
+    /// treated as zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{General, LeastSquares, LeastSquaresResult}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// // rank deficient
+    /// let a: General<f64> = matrix![  1.0, 1.0;
+    ///                                 1.0, 1.0;
+    ///                                 1.0, 1.0];
+    /// let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+    ///
+    /// let result: LeastSquaresResult<f64> = a.solve_least_squares_svd(&b).unwrap();
+    ///
+    /// assert_relative_eq!(*result.x(), vector![1.0; 1.0], epsilon = 1.0e-10);
+    /// assert_eq!(result.rank(), 1);
+    /// ```
+    fn solve_least_squares_svd(&self, b: &Vector<T>) -> Result<LeastSquaresResult<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert_eq!(
+            m,
+            b.dim().0,
+            "dimension of b does not match the number of rows"
+        );
+
+        let (u, s, v): (General<T>, Vector<T>, General<T>) = self.dec_svd_thin()?.usv();
+        let k: usize = m.min(n);
+
+        let tolerance: T = if k == 0 {
+            T::zero()
+        } else {
+            self.singular_value_tolerance(&s)
+        };
+        let rank: usize = s.iter().filter(|s_i| **s_i > tolerance).count();
+
+        let mut x: Vector<T> = Vector::zero(n);
+        for j in 0..rank {
+            let c: T = (0..m).fold(T::zero(), |acc, i| acc + u[[i, j]] * b[i]) / s[j];
+            for i in 0..n {
+                x[i] += c * v[[i, j]];
+            }
+        }
+
+        let residual: T = (b - &(self * &x)).eucl_norm();
+
+        Ok(LeastSquaresResult::new(x, residual, rank, Some(s)))
+    }
+}
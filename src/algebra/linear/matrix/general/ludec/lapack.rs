@@ -1,5 +1,6 @@
 use crate::algebra::abstr::Zero;
 use crate::algebra::linear::matrix::{LUDecomposition, UnitLowerTriangular, UpperTriangular};
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{
     abstr::{Field, Scalar},
     linear::matrix::{General, LUDec},
@@ -24,7 +25,7 @@ where
     ///
     /// let (l, u, p): (UnitLowerTriangular<f64>, UpperTriangular<f64>, General<f64>) = a.dec_lu().unwrap().lup();
     /// ```
-    fn dec_lu(&self) -> Result<LUDec<T>, ()> {
+    fn dec_lu(&self) -> Result<LUDec<T>, LinalgError> {
         let (m, n): (usize, usize) = self.dim();
         debug_assert_eq!(m, n);
 
@@ -48,8 +49,11 @@ where
             &mut info,
         );
 
-        if info != 0 {
-            return Err(());
+        if info < 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+        if info > 0 {
+            return Err(LinalgError::Singular);
         }
 
         let mat: General<T> = General::new(m, n, self_data);
@@ -1,6 +1,7 @@
 use crate::algebra::linear::matrix::ludec::LUDecomposition;
 use crate::algebra::linear::matrix::UnitLowerTriangular;
 use crate::algebra::linear::matrix::UpperTriangular;
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{
     abstr::{Field, Scalar},
     linear::matrix::{General, LUDec},
@@ -27,7 +28,7 @@ where
     ///
     /// let (l, u, p): (UnitLowerTriangular<f64>, UpperTriangular<f64>, General<f64>) = a.dec_lu().unwrap().lup();
     /// ```
-    fn dec_lu(&self) -> Result<LUDec<T>, ()> {
+    fn dec_lu(&self) -> Result<LUDec<T>, LinalgError> {
         let (m, n): (usize, usize) = self.dim();
         debug_assert_eq!(m, n);
 
@@ -1,28 +1,43 @@
 mod abs_diff_eq;
 mod add;
 mod add_assign;
+mod checked;
 mod choleskydec;
+mod completeorthogonaldec;
 mod det;
 mod div;
 mod eigendec;
 mod from;
+mod function;
 mod general;
+mod generalizedeigendec;
+mod generalizedschurdec;
 mod hessenbergdec;
 mod index;
 mod inverse;
+mod ldldec;
+mod leastsquares;
 mod ludec;
 mod mul;
 mod mul_assign;
+mod norm;
 mod partial_eq;
+mod polardec;
 mod qrdec;
 mod relative_eq;
+mod schurdec;
 mod singular;
 mod solve;
 mod sub;
 mod sub_assign;
+mod symmetriceigendec;
 mod transpose;
+mod view;
 
 #[cfg(feature = "convert-mint")]
 mod mint;
 
+#[cfg(feature = "rayon")]
+mod parallel;
+
 pub use general::General;
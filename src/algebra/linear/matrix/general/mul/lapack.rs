@@ -15,7 +15,7 @@ where
         let (self_m, self_n): (usize, usize) = self.dim();
         let (v_m, v_n): (usize, usize) = v.dim();
 
-        if self_n != v_m {
+        if self_n != v_m || v_n != 1 {
             panic!("Matrix and Vector dimension do not match");
         }
 
@@ -103,7 +103,10 @@ where
         let (self_rows, self_cols) = self.dim();
         let (rhs_rows, rhs_cols) = rhs.dim();
 
-        debug_assert_eq!(self_cols, rhs_rows);
+        assert_eq!(
+            self_cols, rhs_rows,
+            "The number of columns of the left matrix does not match the number of rows of the right matrix"
+        );
 
         let m = self_rows as i32;
         let n = rhs_cols as i32;
@@ -152,12 +155,19 @@ where
         let (self_rows, self_cols) = self.dim();
         let (rhs_rows, rhs_cols) = rhs.dim();
 
-        debug_assert_eq!(self_cols, rhs_rows);
+        assert_eq!(
+            self_cols, rhs_rows,
+            "The number of columns of the left matrix does not match the number of rows of the right matrix"
+        );
 
         let m = self_rows as i32;
         let n = rhs_cols as i32;
         let k = self_cols as i32;
 
+        // The product may have more entries than self
+        let a: Vec<T> = self.data.clone();
+        self.data.resize(self_rows * rhs_cols, T::zero());
+
         T::xgemm(
             'N' as u8,
             'N' as u8,
@@ -165,7 +175,7 @@ where
             n,
             k,
             T::one(),
-            &self.data.clone()[..],
+            &a[..],
             m,
             &rhs.data[..],
             k,
@@ -174,7 +184,6 @@ where
             m,
         );
 
-        self.data.truncate(self_rows * rhs_cols);
         self.m = self_rows;
         self.n = rhs_cols;
         self
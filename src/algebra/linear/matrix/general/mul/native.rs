@@ -49,18 +49,25 @@ where
         let (l_rows, l_cols) = self.dim();
         let (r_rows, r_cols): (usize, usize) = rhs.dim();
 
-        debug_assert_eq!(l_cols, r_rows);
+        assert_eq!(
+            l_cols, r_rows,
+            "The number of columns of the left matrix does not match the number of rows of the right matrix"
+        );
 
         let m = l_rows;
         let n = r_cols;
         let k = l_cols;
 
+        // The product may have more entries than self
+        let a: Vec<T> = self.data.clone();
+        self.data.resize(m * n, T::zero());
+
         T::xgemm(
             m,
             k,
             n,
             T::one(),
-            self.clone().data[..].as_ptr(),
+            a[..].as_ptr(),
             1,
             m as isize,
             rhs.data[..].as_ptr(),
@@ -72,7 +79,6 @@ where
             m as isize,
         );
 
-        self.data.truncate(m * n);
         self.m = l_rows;
         self.n = r_cols;
         self
@@ -102,7 +108,7 @@ where
         let (self_m, self_n): (usize, usize) = self.dim();
         let (v_m, v_n): (usize, usize) = v.dim();
 
-        if self_n != v_m && v_n != 1 {
+        if self_n != v_m || v_n != 1 {
             panic!("Matrix and Vector dimension do not match");
         }
 
@@ -234,7 +240,10 @@ where
         let (self_rows, self_cols) = self.dim();
         let (rhs_rows, rhs_cols) = rhs.dim();
 
-        debug_assert_eq!(self_cols, rhs_rows);
+        assert_eq!(
+            self_cols, rhs_rows,
+            "The number of columns of the left matrix does not match the number of rows of the right matrix"
+        );
 
         let m = self_rows;
         let n = rhs_cols;
@@ -0,0 +1,89 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::matrix::{General, MatrixNorm, SingularValueDecomposition},
+};
+
+impl<T> MatrixNorm<T> for General<T>
+where
+    T: Real,
+{
+    /// Maximum absolute column sum
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, MatrixNorm};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, -2.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// assert_eq!(a.norm_1(), 6.0);
+    /// ```
+    fn norm_1(&self) -> T {
+        (0..self.n)
+            .map(|j| (0..self.m).fold(T::zero(), |s, i| s + self[[i, j]].abs()))
+            .fold(T::zero(), |acc, s| acc.max(s))
+    }
+
+    /// Maximum absolute row sum
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, MatrixNorm};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, -2.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// assert_eq!(a.norm_inf(), 7.0);
+    /// ```
+    fn norm_inf(&self) -> T {
+        (0..self.m)
+            .map(|i| (0..self.n).fold(T::zero(), |s, j| s + self[[i, j]].abs()))
+            .fold(T::zero(), |acc, s| acc.max(s))
+    }
+
+    /// Square root of the sum of the squared entries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, MatrixNorm};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, -2.0;
+    ///                                 2.0, 4.0];
+    ///
+    /// assert_eq!(a.norm_frobenius(), 5.0);
+    /// ```
+    fn norm_frobenius(&self) -> T {
+        self.data.iter().fold(T::zero(), |s, a| s + *a * *a).sqrt()
+    }
+
+    /// Largest singular value
+    ///
+    /// # Panics
+    ///
+    /// if the singular value decomposition does not converge
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, MatrixNorm};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  3.0, 0.0;
+    ///                                 4.0, 5.0];
+    ///
+    /// assert_relative_eq!(a.norm_2(), 45.0f64.sqrt(), epsilon = 1.0e-10);
+    /// ```
+    fn norm_2(&self) -> T {
+        if self.m == 0 || self.n == 0 {
+            return T::zero();
+        }
+        self.singular_values()
+            .expect("singular value decomposition did not converge")[0]
+    }
+}
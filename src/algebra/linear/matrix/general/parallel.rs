@@ -0,0 +1,57 @@
+use crate::algebra::linear::matrix::{General, MatrixView, MatrixViewMut};
+use rayon::prelude::*;
+
+impl<T> General<T>
+where
+    T: Send + Sync,
+{
+    /// Returns a parallel iterator over the columns as m x 1 views
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    /// use rayon::prelude::*;
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// let sums: Vec<f64> = a.par_column_iter().map(|c| c[[0, 0]] + c[[1, 0]]).collect();
+    ///
+    /// assert_eq!(sums, vec![4.0, 6.0]);
+    /// ```
+    pub fn par_column_iter(&self) -> impl IndexedParallelIterator<Item = MatrixView<'_, T>> {
+        let view: MatrixView<'_, T> = self.view();
+        (0..self.n).into_par_iter().map(move |j| view.column(j))
+    }
+
+    /// Returns a parallel iterator over the columns as mutable m x 1 views
+    ///
+    /// A matrix without rows yields no columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut a: General<f64> = matrix![  1.0, 2.0;
+    ///                                     3.0, 4.0];
+    ///
+    /// a.par_column_iter_mut()
+    ///     .enumerate()
+    ///     .for_each(|(j, mut c)| c.scale(j as f64));
+    ///
+    /// assert_eq!(a, matrix![0.0, 2.0; 0.0, 4.0]);
+    /// ```
+    pub fn par_column_iter_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = MatrixViewMut<'_, T>> {
+        let m: usize = self.m;
+        self.data
+            .par_chunks_mut(m.max(1))
+            .map(move |column| MatrixViewMut::new(column, m, 1, 1, m))
+    }
+}
@@ -0,0 +1,66 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{General, PolarDec, PolarDecomposition, SingularValueDecomposition, Transpose},
+        vector::Vector,
+    },
+};
+
+impl<T> PolarDecomposition<T> for General<T>
+where
+    T: Real,
+{
+    /// Computes the polar decomposition from the thin singular value
+    /// decomposition $A = W \Sigma V^T$
+    ///
+    /// ```math
+    /// U = WV^T, \quad P = V \Sigma V^T
+    /// ```
+    /// $U$ is the orthogonal matrix nearest to $A$ in the Frobenius norm. If
+    /// $A$ is square and nonsingular, $P$ is positive definite and the
+    /// decomposition is unique. $U$ is a rotation if $\det(A) > 0$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, PolarDecomposition, Transpose};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// // rotation by 90 degrees combined with a stretch
+    /// let a: General<f64> = matrix![  0.0, -3.0;
+    ///                                 2.0, 0.0];
+    ///
+    /// let (u, p): (General<f64>, General<f64>) = a.dec_polar().unwrap().up();
+    ///
+    /// assert_relative_eq!(u, matrix![0.0, -1.0; 1.0, 0.0], epsilon = 1.0e-10);
+    /// assert_relative_eq!(p, matrix![2.0, 0.0; 0.0, 3.0], epsilon = 1.0e-10);
+    /// assert_relative_eq!(&u * &p, a, epsilon = 1.0e-10);
+    /// ```
+    fn dec_polar(&self) -> Result<PolarDec<T>, LinalgError> {
+        let (w, s, v): (General<T>, Vector<T>, General<T>) = self.dec_svd_thin()?.usv();
+        let v_t: General<T> = v.clone().transpose();
+
+        let u: General<T> = &w * &v_t;
+
+        let mut v_s: General<T> = v;
+        for (j, s_j) in s.iter().enumerate() {
+            for i in 0..v_s.m {
+                v_s[[i, j]] *= *s_j;
+            }
+        }
+        let mut p: General<T> = &v_s * &v_t;
+
+        // P is symmetric up to rounding errors
+        let n: usize = p.m;
+        for j in 0..n {
+            for i in 0..j {
+                let p_ij: T = (p[[i, j]] + p[[j, i]]) / T::from_f64(2.0);
+                p[[i, j]] = p_ij;
+                p[[j, i]] = p_ij;
+            }
+        }
+
+        Ok(PolarDec::new(u, p))
+    }
+}
@@ -1,7 +1,10 @@
+use crate::algebra::linear::LinalgError;
 use crate::{
     algebra::{
-        abstr::{Field, Scalar, Zero},
-        linear::matrix::{General, QRDec, QRDecomposition, UpperTriangular},
+        abstr::{Field, Real, Scalar, Zero},
+        linear::matrix::{
+            General, QRDec, QRDecomposition, QRPDec, QRPDecomposition, UpperTriangular,
+        },
     },
     elementary::Power,
 };
@@ -29,7 +32,7 @@ where
     ///
     /// let (q, r): (General<f64>, UpperTriangular<f64>) = a.dec_qr().unwrap().qr();
     /// ```
-    fn dec_qr(&self) -> Result<QRDec<T>, ()> {
+    fn dec_qr(&self) -> Result<QRDec<T>, LinalgError> {
         let (m, n) = self.dim();
         debug_assert!(m >= n);
 
@@ -56,7 +59,7 @@ where
         );
 
         if info != 0 {
-            return Err(());
+            return Err(LinalgError::BackendError { info });
         }
 
         let mut work: Vec<T> = vec![T::zero(); lwork as usize];
@@ -73,7 +76,7 @@ where
         );
 
         if info != 0 {
-            return Err(());
+            return Err(LinalgError::BackendError { info });
         }
 
         let a: General<T> = General::new(m, n, self_data.clone());
@@ -89,7 +92,7 @@ where
             &mut info,
         );
         if info != 0 {
-            return Err(());
+            return Err(LinalgError::BackendError { info });
         }
 
         let mut work = vec![T::zero(); lwork as usize];
@@ -107,7 +110,7 @@ where
         );
 
         if info != 0 {
-            return Err(());
+            return Err(LinalgError::BackendError { info });
         }
 
         let q: General<T> = General::new(m, n, self_data);
@@ -116,6 +119,119 @@ where
     }
 }
 
+impl<T> QRPDecomposition<T> for General<T>
+where
+    T: Real,
+{
+    /// QR decomposition with column pivoting
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, QRPDecomposition, UpperTriangular};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0, 0.0;
+    ///                                 0.0, 4.0, 1.0;
+    ///                                 1.0, 2.0, 0.0];
+    ///
+    /// let (q, r, p): (General<f64>, UpperTriangular<f64>, General<f64>) = a.dec_qrp().unwrap().qrp();
+    ///
+    /// assert_relative_eq!(&a * &p, &q * &General::from(r), epsilon = 1.0e-10);
+    /// ```
+    fn dec_qrp(&self) -> Result<QRPDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+
+        let mut self_data: Vec<T> = self.clone().data;
+
+        let m_i32: i32 = m as i32;
+        let n_i32: i32 = n as i32;
+        let m_n_min: usize = m.min(n);
+
+        let mut jpvt: Vec<i32> = vec![0; n];
+        let mut tau: Vec<T> = vec![T::zero(); m_n_min];
+        let mut info: i32 = 0;
+
+        let lwork: i32 = T::xgeqp3_work_size(
+            m_i32,
+            n_i32,
+            &mut self_data[..],
+            m_i32,
+            &mut jpvt[..],
+            &mut tau[..],
+            &mut info,
+        );
+
+        if info != 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+
+        T::xgeqp3(
+            m_i32,
+            n_i32,
+            &mut self_data[..],
+            m_i32,
+            &mut jpvt[..],
+            &mut tau[..],
+            &mut work[..],
+            lwork,
+            &mut info,
+        );
+
+        if info != 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+
+        let r: UpperTriangular<T> = General::new(m, n, self_data.clone()).r();
+
+        // Build the full m x m matrix Q from the reflectors in the first
+        // min(m, n) columns
+        let mut q_data: Vec<T> = vec![T::zero(); m * m];
+        q_data[..m * m_n_min].copy_from_slice(&self_data[..m * m_n_min]);
+
+        let lwork: i32 = T::xorgqr_work_size(
+            m_i32,
+            m_i32,
+            m_n_min as i32,
+            &mut q_data[..],
+            m_i32,
+            &mut tau[..],
+            &mut info,
+        );
+
+        if info != 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+
+        T::xorgqr(
+            m_i32,
+            m_i32,
+            m_n_min as i32,
+            &mut q_data[..],
+            m_i32,
+            &mut tau[..],
+            &mut work[..],
+            lwork,
+            &mut info,
+        );
+
+        if info != 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+
+        let mut p: General<T> = General::zero(n, n);
+        for (j, jpvt_j) in jpvt.iter().enumerate() {
+            p[[(*jpvt_j - 1) as usize, j]] = T::one();
+        }
+
+        Ok(QRPDec::new(General::new(m, m, q_data), r, p))
+    }
+}
+
 impl<T> General<T>
 where
     T: Field + Scalar + Power,
@@ -1,8 +1,12 @@
 use crate::algebra::abstr::AbsDiffEq;
+use crate::algebra::linear::LinalgError;
 use crate::{
     algebra::{
-        abstr::{Field, Scalar},
-        linear::matrix::{General, QRDec, QRDecomposition, Transpose, UpperTriangular},
+        abstr::{Field, Real, Scalar},
+        linear::matrix::{
+            ConjugateTranspose, General, QRDec, QRDecomposition, QRPDec, QRPDecomposition,
+            UpperTriangular,
+        },
     },
     elementary::Power,
 };
@@ -14,7 +18,7 @@ where
     /// QR Decomposition with Givens rotations
     ///
     /// A = QR \
-    /// Q is an orthogonal matrix \
+    /// Q is an orthogonal, respectively unitary matrix \
     /// R is an upper triangular matrix \
     ///
     /// # Panics
@@ -30,7 +34,7 @@ where
     ///
     /// let (q, r): (General<f64>, UpperTriangular<f64>) = a.dec_qr().unwrap().qr();
     /// ```
-    fn dec_qr(&self) -> Result<QRDec<T>, ()> {
+    fn dec_qr(&self) -> Result<QRDec<T>, LinalgError> {
         let (m, n) = self.dim();
         debug_assert!(m >= n);
 
@@ -42,22 +46,138 @@ where
                 let a_jj: T = r[[j, j]];
                 let a_ij: T = r[[i, j]];
 
-                let p: T = (a_jj * a_jj + a_ij * a_ij).sqrt();
+                if a_ij.abs_diff_ne(&T::zero(), T::default_epsilon()) {
+                    // Unitary rotation G = [conj(a_jj), conj(a_ij); -a_ij, a_jj] / p
+                    // with G * [a_jj; a_ij] = [p; 0]
+                    let abs_jj: T = a_jj.abs();
+                    let abs_ij: T = a_ij.abs();
+                    let p: T = (abs_jj * abs_jj + abs_ij * abs_ij).sqrt();
+                    let (c_jj, c_ij): (T, T) = (a_jj.conj() / p, a_ij.conj() / p);
+                    let (s_ij, s_jj): (T, T) = (-a_ij / p, a_jj / p);
 
-                if p.abs_diff_ne(&T::zero(), T::default_epsilon())
-                    && a_jj.abs_diff_ne(&T::zero(), T::default_epsilon())
-                    && a_ij.abs_diff_ne(&T::zero(), T::default_epsilon())
-                {
-                    let c: T = a_jj / p;
-                    let s: T = -a_ij / p;
-                    let g_ij: General<T> = General::givens(r.m, i, j, c, s);
-
-                    r = &g_ij * &r;
-                    q = &g_ij * &q;
+                    General::rotate_rows(&mut r, j, i, c_jj, c_ij, s_ij, s_jj);
+                    General::rotate_rows(&mut q, j, i, c_jj, c_ij, s_ij, s_jj);
                 }
             }
         }
-        q = q.transpose();
+        q = q.conj_transpose();
         Ok(QRDec::new(q, UpperTriangular::new(r)))
     }
 }
+
+impl<T> General<T>
+where
+    T: Field + Scalar,
+{
+    /// Replaces the rows j and i by (c_jj row_j + c_ij row_i) and
+    /// (s_ij row_j + s_jj row_i)
+    fn rotate_rows(a: &mut General<T>, j: usize, i: usize, c_jj: T, c_ij: T, s_ij: T, s_jj: T) {
+        for k in 0..a.n {
+            let a_jk: T = a[[j, k]];
+            let a_ik: T = a[[i, k]];
+            a[[j, k]] = c_jj * a_jk + c_ij * a_ik;
+            a[[i, k]] = s_ij * a_jk + s_jj * a_ik;
+        }
+    }
+}
+
+impl<T> QRPDecomposition<T> for General<T>
+where
+    T: Real,
+{
+    /// QR decomposition with column pivoting and Householder reflections
+    ///
+    /// In step j the column with the largest remaining norm is moved to
+    /// position j.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, QRPDecomposition, UpperTriangular};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0, 0.0;
+    ///                                 0.0, 4.0, 1.0;
+    ///                                 1.0, 2.0, 0.0];
+    ///
+    /// let (q, r, p): (General<f64>, UpperTriangular<f64>, General<f64>) = a.dec_qrp().unwrap().qrp();
+    ///
+    /// assert_relative_eq!(&a * &p, &q * &General::from(r), epsilon = 1.0e-10);
+    /// ```
+    fn dec_qrp(&self) -> Result<QRPDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+
+        let mut q: General<T> = General::one(m);
+        let mut r: General<T> = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for j in 0..m.min(n) {
+            let norm_col = |r: &General<T>, c: usize| -> T {
+                (j..m).fold(T::zero(), |s, i| s + r[[i, c]] * r[[i, c]])
+            };
+            let mut p: usize = j;
+            let mut norm_p: T = norm_col(&r, j);
+            for c in (j + 1)..n {
+                let norm_c: T = norm_col(&r, c);
+                if norm_c > norm_p {
+                    p = c;
+                    norm_p = norm_c;
+                }
+            }
+
+            // the remaining columns are zero
+            if norm_p == T::zero() {
+                break;
+            }
+
+            if p != j {
+                for i in 0..m {
+                    let tmp: T = r[[i, j]];
+                    r[[i, j]] = r[[i, p]];
+                    r[[i, p]] = tmp;
+                }
+                perm.swap(j, p);
+            }
+
+            // Householder reflection H = I - 2 v v^T / (v^T v) with H r[j..m, j] = alpha e_1
+            let norm_p: T = norm_p.sqrt();
+            let alpha: T = if r[[j, j]] > T::zero() { -norm_p } else { norm_p };
+            let mut v: Vec<T> = (j..m).map(|i| r[[i, j]]).collect();
+            v[0] -= alpha;
+            let v_v: T = v.iter().fold(T::zero(), |s, v_i| s + *v_i * *v_i);
+            if v_v == T::zero() {
+                continue;
+            }
+
+            for c in (j + 1)..n {
+                let f: T = T::from_f64(2.0)
+                    * (j..m).fold(T::zero(), |s, i| s + v[i - j] * r[[i, c]])
+                    / v_v;
+                for i in j..m {
+                    r[[i, c]] -= f * v[i - j];
+                }
+            }
+            r[[j, j]] = alpha;
+            for i in (j + 1)..m {
+                r[[i, j]] = T::zero();
+            }
+
+            // Q = H_0 H_1 ... H_j
+            for i in 0..m {
+                let f: T = T::from_f64(2.0)
+                    * (j..m).fold(T::zero(), |s, k| s + q[[i, k]] * v[k - j])
+                    / v_v;
+                for k in j..m {
+                    q[[i, k]] -= f * v[k - j];
+                }
+            }
+        }
+
+        let mut p: General<T> = General::zero(n, n);
+        for (j, perm_j) in perm.iter().enumerate() {
+            p[[*perm_j, j]] = T::one();
+        }
+
+        Ok(QRPDec::new(q, UpperTriangular::new(r), p))
+    }
+}
@@ -0,0 +1,284 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Complex, One, Real, Scalar, Zero},
+    linear::matrix::{General, SchurDec, SchurDecomposition, UpperTriangular},
+};
+use crate::elementary::Power;
+
+impl<T> SchurDecomposition<Complex<T>> for General<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the complex Schur decomposition
+    /// ```math
+    /// A = QUQ^*
+    /// ```
+    /// with a unitary matrix $Q$ and an upper triangular matrix $U$. The
+    /// eigenvalues of $A$ are the diagonal entries of $U$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{ConjugateTranspose, General, SchurDecomposition, UpperTriangular};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<Complex<f64>> = matrix![Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0);
+    ///                                        Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)];
+    ///
+    /// let (q, u): (General<Complex<f64>>, UpperTriangular<Complex<f64>>) = a.dec_schur().unwrap().qu();
+    ///
+    /// assert_relative_eq!(&(&q * &General::from(u)) * &q.clone().conj_transpose(), a, epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// ```
+    fn dec_schur(&self) -> Result<SchurDec<Complex<T>>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        debug_assert_eq!(
+            m, n,
+            "Unable to compute the Schur decomposition of a non-square matrix"
+        );
+
+        self.schur_complex()
+    }
+}
+
+impl<T> General<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    pub(crate) fn modulus(z: Complex<T>) -> T {
+        (z.re * z.re + z.im * z.im).sqrt()
+    }
+
+    /// Computes the complex Schur form $A = QUQ^*$ with the shifted QR
+    /// algorithm
+    fn schur_complex(&self) -> Result<SchurDec<Complex<T>>, LinalgError> {
+        let n: usize = self.m;
+        let mut q: General<Complex<T>> = General::one(n);
+        let mut h: General<Complex<T>> = self.clone();
+
+        General::reduce_hessenberg_complex(&mut h, &mut q);
+
+        let eps: T = T::default_epsilon();
+        let max_iter: usize = 30 * n;
+        let mut iter: usize = 0;
+        let mut hi: usize = n - 1;
+
+        while hi > 0 {
+            // Find the lowest negligible subdiagonal entry
+            let mut lo: usize = hi;
+            while lo > 0 {
+                let s: T = General::modulus(h[[lo - 1, lo - 1]]) + General::modulus(h[[lo, lo]]);
+                if General::modulus(h[[lo, lo - 1]]) <= eps * s {
+                    h[[lo, lo - 1]] = Complex::zero();
+                    break;
+                }
+                lo -= 1;
+            }
+
+            if lo == hi {
+                hi -= 1;
+                iter = 0;
+                continue;
+            }
+
+            iter += 1;
+            if iter > max_iter {
+                return Err(LinalgError::NoConvergence { iters: max_iter });
+            }
+
+            let shift: Complex<T> = if iter.is_multiple_of(10) {
+                // exceptional shift
+                h[[hi, hi]] + Complex::new(General::modulus(h[[hi, hi - 1]]), T::zero())
+            } else {
+                General::wilkinson_shift(
+                    h[[hi - 1, hi - 1]],
+                    h[[hi - 1, hi]],
+                    h[[hi, hi - 1]],
+                    h[[hi, hi]],
+                )
+            };
+
+            // Implicit single shift QR step on the active block lo..=hi
+            let mut x: Complex<T> = h[[lo, lo]] - shift;
+            let mut y: Complex<T> = h[[lo + 1, lo]];
+            for k in lo..hi {
+                let (c, s) = General::rotation(x, y);
+                let col_start: usize = if k > lo { k - 1 } else { k };
+                General::rotate_left(&mut h, k, c, s, col_start);
+                General::rotate_right(&mut h, k, c, s, (k + 2).min(hi));
+                General::rotate_right(&mut q, k, c, s, n - 1);
+                if k > lo {
+                    h[[k + 1, k - 1]] = Complex::zero();
+                }
+                if k + 1 < hi {
+                    x = h[[k + 1, k]];
+                    y = h[[k + 2, k]];
+                }
+            }
+        }
+
+        // Clear the entries below the diagonal
+        for j in 0..n {
+            for i in (j + 1)..n {
+                h[[i, j]] = Complex::zero();
+            }
+        }
+
+        Ok(SchurDec::new(q, UpperTriangular::new(h)))
+    }
+
+    /// Reduces h to upper Hessenberg form with Householder reflections and
+    /// accumulates the reflections in q
+    fn reduce_hessenberg_complex(h: &mut General<Complex<T>>, q: &mut General<Complex<T>>) {
+        let n: usize = h.m;
+
+        for k in 0..n.saturating_sub(2) {
+            let norm: T = ((k + 1)..n)
+                .map(|i| {
+                    let a: T = General::modulus(h[[i, k]]);
+                    a * a
+                })
+                .fold(T::zero(), |acc, a| acc + a)
+                .sqrt();
+
+            if norm == T::zero() {
+                continue;
+            }
+
+            // v = x - alpha e_1 with alpha = -exp(i arg(x_0)) |x|
+            let x_0: Complex<T> = h[[k + 1, k]];
+            let abs_x_0: T = General::modulus(x_0);
+            let phase: Complex<T> = if abs_x_0 == T::zero() {
+                Complex::one()
+            } else {
+                x_0 / Complex::new(abs_x_0, T::zero())
+            };
+            let alpha: Complex<T> = -phase * Complex::new(norm, T::zero());
+
+            let mut v: Vec<Complex<T>> = ((k + 1)..n).map(|i| h[[i, k]]).collect();
+            v[0] -= alpha;
+            let v_norm: T = v
+                .iter()
+                .map(|v_i| {
+                    let a: T = General::modulus(*v_i);
+                    a * a
+                })
+                .fold(T::zero(), |acc, a| acc + a)
+                .sqrt();
+            if v_norm == T::zero() {
+                continue;
+            }
+            let v_norm: Complex<T> = Complex::new(v_norm, T::zero());
+            v.iter_mut().for_each(|v_i| *v_i /= v_norm);
+
+            let two: Complex<T> = Complex::new(T::from_f64(2.0), T::zero());
+
+            // H = (I - 2vv^*) H
+            for j in 0..n {
+                let mut dot: Complex<T> = Complex::zero();
+                for (p, v_p) in v.iter().enumerate() {
+                    dot += v_p.conj() * h[[k + 1 + p, j]];
+                }
+                dot *= two;
+                for (p, v_p) in v.iter().enumerate() {
+                    h[[k + 1 + p, j]] -= *v_p * dot;
+                }
+            }
+
+            // H = H (I - 2vv^*), Q = Q (I - 2vv^*)
+            for a in [&mut *h, &mut *q] {
+                for i in 0..n {
+                    let mut dot: Complex<T> = Complex::zero();
+                    for (p, v_p) in v.iter().enumerate() {
+                        dot += a[[i, k + 1 + p]] * *v_p;
+                    }
+                    dot *= two;
+                    for (p, v_p) in v.iter().enumerate() {
+                        a[[i, k + 1 + p]] -= dot * v_p.conj();
+                    }
+                }
+            }
+
+            for i in (k + 2)..n {
+                h[[i, k]] = Complex::zero();
+            }
+        }
+    }
+
+    /// Eigenvalue of the 2x2 matrix [a, b; c, d] which is closer to d
+    pub(crate) fn wilkinson_shift(
+        a: Complex<T>,
+        b: Complex<T>,
+        c: Complex<T>,
+        d: Complex<T>,
+    ) -> Complex<T> {
+        let two: Complex<T> = Complex::new(T::from_f64(2.0), T::zero());
+        let half_tr: Complex<T> = (a + d) / two;
+        let half_diff: Complex<T> = (a - d) / two;
+        let root: Complex<T> = (half_diff * half_diff + b * c).sqrt();
+
+        let l_1: Complex<T> = half_tr + root;
+        let l_2: Complex<T> = half_tr - root;
+        if General::modulus(l_1 - d) <= General::modulus(l_2 - d) {
+            l_1
+        } else {
+            l_2
+        }
+    }
+
+    /// Computes the rotation G = [c, s; -conj(s), c] with real c such that
+    /// G [x; y] = [r; 0]
+    pub(crate) fn rotation(x: Complex<T>, y: Complex<T>) -> (T, Complex<T>) {
+        let abs_x: T = General::modulus(x);
+        let abs_y: T = General::modulus(y);
+
+        if abs_y == T::zero() {
+            return (T::one(), Complex::zero());
+        }
+        if abs_x == T::zero() {
+            return (T::zero(), y.conj() / Complex::new(abs_y, T::zero()));
+        }
+
+        let r: T = (abs_x * abs_x + abs_y * abs_y).sqrt();
+        let c: T = abs_x / r;
+        let s: Complex<T> =
+            x / Complex::new(abs_x, T::zero()) * y.conj() / Complex::new(r, T::zero());
+        (c, s)
+    }
+
+    /// Applies G from the left on the rows k and k + 1, columns col_start..n
+    pub(crate) fn rotate_left(
+        a: &mut General<Complex<T>>,
+        k: usize,
+        c: T,
+        s: Complex<T>,
+        col_start: usize,
+    ) {
+        let c: Complex<T> = Complex::new(c, T::zero());
+        for j in col_start..a.n {
+            let x: Complex<T> = a[[k, j]];
+            let y: Complex<T> = a[[k + 1, j]];
+            a[[k, j]] = c * x + s * y;
+            a[[k + 1, j]] = c * y - s.conj() * x;
+        }
+    }
+
+    /// Applies G^* from the right on the columns k and k + 1, rows 0..=row_end
+    pub(crate) fn rotate_right(
+        a: &mut General<Complex<T>>,
+        k: usize,
+        c: T,
+        s: Complex<T>,
+        row_end: usize,
+    ) {
+        let c: Complex<T> = Complex::new(c, T::zero());
+        for i in 0..=row_end {
+            let x: Complex<T> = a[[i, k]];
+            let y: Complex<T> = a[[i, k + 1]];
+            a[[i, k]] = c * x + s.conj() * y;
+            a[[i, k + 1]] = c * y - s * x;
+        }
+    }
+}
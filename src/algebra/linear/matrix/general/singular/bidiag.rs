@@ -0,0 +1,91 @@
+use crate::algebra::abstr::{Field, Scalar};
+use crate::algebra::linear::{matrix::General, vector::Vector};
+use crate::elementary::Power;
+
+impl<T> General<T>
+where
+    T: Field + Scalar + Power,
+{
+    pub fn rot(f: T, g: T) -> (T, T, T) {
+        if f == T::zero() {
+            (T::zero(), T::one(), g)
+        } else {
+            let expo: T = T::from_f64(2.0);
+            let sqrt: T = T::from_f64(0.5);
+            if f.abs() > g.abs() {
+                let t: T = g / f;
+                let t1: T = (T::one() + t.pow(expo)).pow(sqrt);
+
+                (T::one() / t1, t / t1, f * t1)
+            } else {
+                let t: T = f / g;
+                let t1: T = (T::one() + t.pow(expo)).pow(sqrt);
+
+                (t / t1, T::one() / t1, g * t1)
+            }
+        }
+    }
+
+    ///
+    /// self is an m times n matrix with m >= n
+    /// A = UBV^{T}
+    /// U \in T^{m \times n}
+    /// B \in T^{n \times n}
+    /// V \in T^{n \times n}
+    pub fn householder_bidiag(&self) -> (Self, Self, Self) {
+        let (m, n): (usize, usize) = self.dim();
+        if m < n {
+            panic!("Read the API");
+        }
+
+        let mut u: General<T> = General::one(m);
+        let mut v: General<T> = General::one(n);
+        let mut a_i: General<T> = self.clone();
+
+        for i in 0..n - 1 {
+            // eliminate non-zeros below the diagonal
+            // Keep the product U*B unchanged
+            let u_x: Vector<T> = a_i.clone().get_column(i);
+            let u_slice: Vector<T> = u_x.get_slice(i, m - 1);
+
+            let u_i: General<T> = General::householder(&u_slice, 0);
+
+            let a_i_slice = &u_i * &a_i.clone().get_slice(i, m - 1, i, n - 1);
+            a_i = a_i.set_slice(&a_i_slice, i, i);
+            let mut u_mi: General<T> = General::one(m);
+            u_mi = u_mi.set_slice(&u_i, i, i);
+
+            u = &u * &u_mi;
+
+            //eliminate non-zeros to the right of the
+            //superdiagonal by working with the transpose
+            // Keep the product B*V' unchanged
+            //B_T = B';
+            if i < (n - 1) {
+                let v_x: Vector<T> = a_i.get_row(i);
+                let v_x_trans: Vector<T> = v_x.transpose();
+                let v_x_trans_slice: Vector<T> = v_x_trans.get_slice(i + 1, n - 1);
+
+                let v_i: General<T> = General::householder(&v_x_trans_slice, 0);
+
+                let mut v_ni: General<T> = General::one(n);
+                v_ni = v_ni.set_slice(&v_i, i + 1, i + 1);
+                //let a_i_slice = &a_i.clone().get_slice(i+1, m - 1, i+1, n - 1) * &v_i;
+                //a_i = a_i.set_slice(&a_i_slice, i+1, i+1);
+                a_i = &a_i * &v_ni;
+
+                v = &v * &v_ni;
+            }
+        }
+
+        //Null all elements beneath the diagonal, and superdiagonal
+        for i in 0..m {
+            for k in 0..n {
+                if k != i && k != (i + 1) {
+                    a_i[[i, k]] = T::zero();
+                }
+            }
+        }
+        (u, a_i, v)
+    }
+}
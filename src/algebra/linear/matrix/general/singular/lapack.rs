@@ -0,0 +1,125 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{General, SingularValueDec, SingularValueDecomposition, Transpose},
+        vector::Vector,
+    },
+};
+
+impl<T> SingularValueDecomposition<T> for General<T>
+where
+    T: Real,
+{
+    /// Computes the full singular value decomposition
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{General, SingularValueDec, SingularValueDecomposition, Transpose}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  3.0, 0.0;
+    ///                                 0.0, -4.0;
+    ///                                 0.0, 0.0];
+    ///
+    /// let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd().unwrap().usv();
+    ///
+    /// let mut sigma: General<f64> = General::zero(3, 2);
+    /// sigma[[0, 0]] = s[0];
+    /// sigma[[1, 1]] = s[1];
+    ///
+    /// assert_relative_eq!(s, vector![4.0; 3.0], epsilon = 1.0e-10);
+    /// assert_relative_eq!(&u * &(&sigma * &v.transpose()), a, epsilon = 1.0e-10);
+    /// ```
+    fn dec_svd(&self) -> Result<SingularValueDec<T>, LinalgError> {
+        self.xgesdd('A')
+    }
+
+    /// Computes the thin singular value decomposition
+    fn dec_svd_thin(&self) -> Result<SingularValueDec<T>, LinalgError> {
+        self.xgesdd('S')
+    }
+
+    /// Computes the singular values
+    fn singular_values(&self) -> Result<Vector<T>, LinalgError> {
+        Ok(self.xgesdd('N')?.s())
+    }
+}
+
+impl<T> General<T>
+where
+    T: Real,
+{
+    fn xgesdd(&self, jobz: char) -> Result<SingularValueDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        let k: usize = m.min(n);
+        let (u_n, vt_m): (usize, usize) = match jobz {
+            'A' => (m, n),
+            'S' => (k, k),
+            _ => (1, 1),
+        };
+        let ldu: usize = if jobz == 'N' { 1 } else { m };
+
+        let m_i32: i32 = m as i32;
+        let n_i32: i32 = n as i32;
+        let mut a_data: Vec<T> = self.clone().data;
+        let mut s: Vec<T> = vec![T::zero(); k];
+        let mut u: Vec<T> = vec![T::zero(); ldu * u_n];
+        let mut vt: Vec<T> = vec![T::zero(); vt_m * n];
+        let mut iwork: Vec<i32> = vec![0; 8 * k];
+        let mut info: i32 = 0;
+
+        let lwork: i32 = T::xgesdd_work_size(
+            jobz as u8,
+            m_i32,
+            n_i32,
+            &mut a_data[..],
+            m_i32,
+            &mut s[..],
+            &mut u[..],
+            ldu as i32,
+            &mut vt[..],
+            vt_m as i32,
+            &mut iwork[..],
+            &mut info,
+        );
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+
+        T::xgesdd(
+            jobz as u8,
+            m_i32,
+            n_i32,
+            &mut a_data[..],
+            m_i32,
+            &mut s[..],
+            &mut u[..],
+            ldu as i32,
+            &mut vt[..],
+            vt_m as i32,
+            &mut work[..],
+            lwork,
+            &mut iwork[..],
+            &mut info,
+        );
+
+        if info != 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+
+        let s: Vector<T> = Vector::new_column(s);
+        if jobz == 'N' {
+            return Ok(SingularValueDec::new(
+                General::zero(m, 0),
+                s,
+                General::zero(n, 0),
+            ));
+        }
+
+        let u: General<T> = General::new(m, u_n, u);
+        let v: General<T> = General::new(vt_m, n, vt).transpose();
+
+        Ok(SingularValueDec::new(u, s, v))
+    }
+}
@@ -1 +1,6 @@
-pub mod native;
+mod bidiag;
+#[cfg(feature = "lapack")]
+mod lapack;
+#[cfg(feature = "native")]
+mod native;
+mod randomized;
@@ -1,195 +1,490 @@
-use crate::algebra::abstr::{Field, Scalar};
-use crate::algebra::linear::matrix::Transpose;
-use crate::algebra::linear::{matrix::General, vector::Vector};
-use crate::elementary::Power;
-
-impl<T> General<T>
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{General, SingularValueDec, SingularValueDecomposition, Transpose},
+        vector::Vector,
+        LinalgError,
+    },
+};
+
+impl<T> SingularValueDecomposition<T> for General<T>
 where
-    T: Field + Scalar + Power,
+    T: Real,
 {
-    /// Computes the singular value decomposition
+    /// Computes the full singular value decomposition
+    ///
+    /// The matrix is reduced to bidiagonal form with Householder reflections,
+    /// the bidiagonal matrix is diagonalized with the implicit shifted QR
+    /// algorithm. The iteration stops as soon as all superdiagonal entries
+    /// are negligible relative to the machine precision.
     ///
-    /// M = U * S * V*
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{General, SingularValueDec, SingularValueDecomposition, Transpose}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
     ///
-    /// # Return
+    /// let a: General<f64> = matrix![  3.0, 0.0;
+    ///                                 0.0, -4.0;
+    ///                                 0.0, 0.0];
     ///
-    /// (u, s, v)
+    /// let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd().unwrap().usv();
+    ///
+    /// let mut sigma: General<f64> = General::zero(3, 2);
+    /// sigma[[0, 0]] = s[0];
+    /// sigma[[1, 1]] = s[1];
+    ///
+    /// assert_relative_eq!(s, vector![4.0; 3.0], epsilon = 1.0e-10);
+    /// assert_relative_eq!(&u * &(&sigma * &v.transpose()), a, epsilon = 1.0e-10);
+    /// ```
+    fn dec_svd(&self) -> Result<SingularValueDec<T>, LinalgError> {
+        self.dec_svd_golub_kahan(true, true)
+    }
+
+    /// Computes the thin singular value decomposition
     ///
     /// # Example
     ///
     /// ```
-    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::algebra::linear::{matrix::{Diagonal, General, SingularValueDec, SingularValueDecomposition, Transpose}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix};
     ///
-    /// let a: General<f64> = General::new(4,
-    ///                                  4,
-    ///                                  vec![4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, -2.0, 0.0, 3.0,
-    ///                                       -2.0, 2.0, 2.0, 1.0, -2.0, -1.0]);
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 3.0, 4.0;
+    ///                                 5.0, 6.0];
     ///
-    /// let (u, s, v): (General<f64>, General<f64>, General<f64>) = a.dec_sv();
+    /// let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_thin().unwrap().usv();
+    /// let s: General<f64> = Diagonal::new(&s.convert_to_vec()).into();
+    ///
+    /// assert_relative_eq!(&u * &(&s * &v.transpose()), a, epsilon = 1.0e-10);
     /// ```
-    pub fn dec_sv(&self) -> (Self, Self, Self) {
-        let (mut u, mut b, mut v): (General<T>, General<T>, General<T>) = self.householder_bidiag();
+    fn dec_svd_thin(&self) -> Result<SingularValueDec<T>, LinalgError> {
+        self.dec_svd_golub_kahan(false, true)
+    }
 
-        let (_m, n): (usize, usize) = b.dim();
-        let max_iterations: usize = 500 * n * n;
+    /// Computes the singular values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{General, SingularValueDecomposition}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  0.0, 2.0;
+    ///                                 -1.0, 0.0];
+    ///
+    /// assert_relative_eq!(a.singular_values().unwrap(), vector![2.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    fn singular_values(&self) -> Result<Vector<T>, LinalgError> {
+        Ok(self.dec_svd_golub_kahan(false, false)?.s())
+    }
+}
 
-        let mut u_k: General<T> = General::one(n);
+impl<T> General<T>
+where
+    T: Real,
+{
+    fn dec_svd_golub_kahan(&self, full: bool, vectors: bool) -> Result<SingularValueDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
 
-        for _k in 0..max_iterations {
-            let (u_ks, b_k, v_k): (General<T>, General<T>, General<T>) = General::msweep(u_k, b, v);
-            u_k = u_ks;
-            b = b_k;
-            v = v_k;
+        if m >= n {
+            General::svd_tall(self.clone(), full, vectors)
+        } else {
+            // A^T = U S V^T  =>  A = V S U^T
+            let (u, s, v) = General::svd_tall(self.clone().transpose(), full, vectors)?.usv();
+            Ok(SingularValueDec::new(v, s, u))
         }
+    }
 
-        u *= u_k.transpose();
-
-        let (b_m, _b_n): (usize, usize) = b.dim();
-
-        // check that all singular values are positive
-        for l in 0..b_m {
-            if b[[l, l]] < T::zero() {
-                b[[l, l]] = -b[[l, l]];
-                let mut column_l: Vector<T> = u.get_column(l);
-                column_l = &column_l * &-T::one();
-                u.set_column(&column_l, l);
+    /// Golub-Kahan-Reinsch SVD of a matrix with m >= n
+    ///
+    /// Port of the LINPACK routine dsvdc. U has m columns if full is set,
+    /// n columns otherwise.
+    fn svd_tall(
+        mut a: General<T>,
+        full: bool,
+        vectors: bool,
+    ) -> Result<SingularValueDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = a.dim();
+        let nu: usize = n;
+        let ncu: usize = if !vectors {
+            0
+        } else if full {
+            m
+        } else {
+            nu
+        };
+        let ncv: usize = if vectors { n } else { 0 };
+
+        let mut s: Vec<T> = vec![T::zero(); n];
+        let mut e: Vec<T> = vec![T::zero(); n];
+        let mut work: Vec<T> = vec![T::zero(); m];
+        let mut u: General<T> = General::zero(m, ncu);
+        let mut v: General<T> = General::zero(n, ncv);
+
+        // Reduce A to bidiagonal form, storing the diagonal elements in s and
+        // the super-diagonal elements in e.
+        let nct: usize = (m - 1).min(n);
+        let nrt: usize = n.saturating_sub(2).min(m);
+        for k in 0..nct.max(nrt) {
+            if k < nct {
+                // Compute the transformation for the k-th column
+                s[k] = T::zero();
+                for i in k..m {
+                    s[k] = General::hypot(s[k], a[[i, k]]);
+                }
+                if s[k] != T::zero() {
+                    if a[[k, k]] < T::zero() {
+                        s[k] = -s[k];
+                    }
+                    for i in k..m {
+                        a[[i, k]] /= s[k];
+                    }
+                    a[[k, k]] += T::one();
+                }
+                s[k] = -s[k];
             }
-        }
-
-        // null all values beneath the diagonal
-        for l in 0..b_m {
-            for k in 0..b_m {
-                if k != l {
-                    b[[k, l]] = T::zero();
+            for j in (k + 1)..n {
+                if k < nct && s[k] != T::zero() {
+                    // Apply the transformation
+                    let mut t: T = T::zero();
+                    for i in k..m {
+                        t += a[[i, k]] * a[[i, j]];
+                    }
+                    t = -t / a[[k, k]];
+                    for i in k..m {
+                        let a_ik: T = a[[i, k]];
+                        a[[i, j]] += t * a_ik;
+                    }
+                }
+                e[j] = a[[k, j]];
+            }
+            if ncu > 0 && k < nct {
+                for i in k..m {
+                    u[[i, k]] = a[[i, k]];
+                }
+            }
+            if k < nrt {
+                // Compute the transformation for the k-th row
+                e[k] = T::zero();
+                for i in (k + 1)..n {
+                    e[k] = General::hypot(e[k], e[i]);
+                }
+                if e[k] != T::zero() {
+                    if e[k + 1] < T::zero() {
+                        e[k] = -e[k];
+                    }
+                    for i in (k + 1)..n {
+                        let e_k: T = e[k];
+                        e[i] /= e_k;
+                    }
+                    e[k + 1] += T::one();
+                }
+                e[k] = -e[k];
+                if k + 1 < m && e[k] != T::zero() {
+                    // Apply the transformation
+                    for w_i in work.iter_mut().skip(k + 1) {
+                        *w_i = T::zero();
+                    }
+                    for j in (k + 1)..n {
+                        for i in (k + 1)..m {
+                            work[i] += e[j] * a[[i, j]];
+                        }
+                    }
+                    for j in (k + 1)..n {
+                        let t: T = -e[j] / e[k + 1];
+                        for i in (k + 1)..m {
+                            a[[i, j]] += t * work[i];
+                        }
+                    }
+                }
+                if ncv > 0 {
+                    for i in (k + 1)..n {
+                        v[[i, k]] = e[i];
+                    }
                 }
             }
         }
 
-        // sort singular values in descending order
-        (u, b, v)
-    }
+        // Set up the final bidiagonal matrix of order p
+        let mut p: usize = n.min(m + 1);
+        if nct < n {
+            s[nct] = a[[nct, nct]];
+        }
+        if m < p {
+            s[p - 1] = T::zero();
+        }
+        if nrt + 1 < p {
+            e[nrt] = a[[nrt, p - 1]];
+        }
+        e[p - 1] = T::zero();
 
-    fn msweep(
-        mut u: General<T>,
-        mut b: General<T>,
-        mut v: General<T>,
-    ) -> (General<T>, General<T>, General<T>) {
-        let (_m, n): (usize, usize) = b.dim();
-
-        for k in 0..n - 1 {
-            let mut q: General<T> = General::one(n);
-
-            // Construct matrix Q and multiply on the right by Q'.
-            // Q annihilates both B(k-1,k+1) and B(k,k+1)
-            // but makes B(k+1,k) non-zero.
-            let (c_r, s_r, _r_r): (T, T, T) = General::rot(b[[k, k]], b[[k, k + 1]]);
-
-            q[[k, k]] = c_r;
-            q[[k, k + 1]] = s_r;
-            q[[k + 1, k]] = -s_r;
-            q[[k + 1, k + 1]] = c_r;
-
-            let q_t: General<T> = q.clone().transpose();
-            b = &b * &q_t;
-            v = &v * &q_t;
-
-            // Construct matrix Q and multiply on the left by Q.
-            // Q annihilates B(k+1,k) but makes B(k,k+1) and
-            // B(k,k+2) non-zero.
-            let (c_l, s_l, _r_l): (T, T, T) = General::rot(b[[k, k]], b[[k + 1, k]]);
-
-            q[[k, k]] = c_l;
-            q[[k, k + 1]] = s_l;
-            q[[k + 1, k]] = -s_l;
-            q[[k + 1, k + 1]] = c_l;
-
-            b = &q * &b;
-            u = &q * &u;
+        // Generate U
+        if ncu > 0 {
+            for j in nct..ncu {
+                for i in 0..m {
+                    u[[i, j]] = T::zero();
+                }
+                u[[j, j]] = T::one();
+            }
+            for k in (0..nct).rev() {
+                if s[k] != T::zero() {
+                    for j in (k + 1)..ncu {
+                        let mut t: T = T::zero();
+                        for i in k..m {
+                            t += u[[i, k]] * u[[i, j]];
+                        }
+                        t = -t / u[[k, k]];
+                        for i in k..m {
+                            let u_ik: T = u[[i, k]];
+                            u[[i, j]] += t * u_ik;
+                        }
+                    }
+                    for i in k..m {
+                        u[[i, k]] = -u[[i, k]];
+                    }
+                    u[[k, k]] += T::one();
+                    for i in 0..k {
+                        u[[i, k]] = T::zero();
+                    }
+                } else {
+                    for i in 0..m {
+                        u[[i, k]] = T::zero();
+                    }
+                    u[[k, k]] = T::one();
+                }
+            }
         }
 
-        (u, b, v)
-    }
+        // Generate V
+        if ncv > 0 {
+            for k in (0..n).rev() {
+                if k < nrt && e[k] != T::zero() {
+                    for j in (k + 1)..n {
+                        let mut t: T = T::zero();
+                        for i in (k + 1)..n {
+                            t += v[[i, k]] * v[[i, j]];
+                        }
+                        t = -t / v[[k + 1, k]];
+                        for i in (k + 1)..n {
+                            let v_ik: T = v[[i, k]];
+                            v[[i, j]] += t * v_ik;
+                        }
+                    }
+                }
+                for i in 0..n {
+                    v[[i, k]] = T::zero();
+                }
+                v[[k, k]] = T::one();
+            }
+        }
 
-    pub fn rot(f: T, g: T) -> (T, T, T) {
-        if f == T::zero() {
-            (T::zero(), T::one(), g)
-        } else {
-            let expo: T = T::from_f64(2.0);
-            let sqrt: T = T::from_f64(0.5);
-            if f.abs() > g.abs() {
-                let t: T = g / f;
-                let t1: T = (T::one() + t.pow(expo)).pow(sqrt);
+        // Main iteration loop for the singular values
+        let pp: usize = p - 1;
+        let mut iter: usize = 0;
+        let eps: T = T::default_epsilon();
+        let tiny: T = eps * eps * eps;
+        let max_iter: usize = 75;
+
+        while p > 0 {
+            // Inspect the bidiagonal matrix
+            // kase = 1     if s(p) and e[k-1] are negligible and k<p
+            // kase = 2     if s(k) is negligible and k<p
+            // kase = 3     if e[k-1] is negligible, k<p, and
+            //              s(k), ..., s(p) are not negligible (qr step).
+            // kase = 4     if e(p-1) is negligible (convergence).
+            // k is the index of the first entry of the unreduced block, k = 0
+            // means that no superdiagonal entry is negligible.
+            let mut k: usize = p - 1;
+            while k > 0 {
+                if e[k - 1].abs() <= tiny + eps * (s[k - 1].abs() + s[k].abs()) {
+                    e[k - 1] = T::zero();
+                    break;
+                }
+                k -= 1;
+            }
 
-                (T::one() / t1, t / t1, f * t1)
+            let kase: u8;
+            if k == p - 1 {
+                kase = 4;
             } else {
-                let t: T = f / g;
-                let t1: T = (T::one() + t.pow(expo)).pow(sqrt);
+                let mut ks: usize = p;
+                while ks > k {
+                    let t: T = (if ks != p { e[ks - 1].abs() } else { T::zero() })
+                        + (if ks != k + 1 { e[ks - 2].abs() } else { T::zero() });
+                    if s[ks - 1].abs() <= tiny + eps * t {
+                        s[ks - 1] = T::zero();
+                        break;
+                    }
+                    ks -= 1;
+                }
+                if ks == k {
+                    kase = 3;
+                } else if ks == p {
+                    kase = 1;
+                } else {
+                    kase = 2;
+                    k = ks;
+                }
+            }
 
-                (t / t1, T::one() / t1, g * t1)
+            match kase {
+                // Deflate negligible s(p)
+                1 => {
+                    let mut f: T = e[p - 2];
+                    e[p - 2] = T::zero();
+                    for j in (k..=(p - 2)).rev() {
+                        let t: T = General::hypot(s[j], f);
+                        let cs: T = s[j] / t;
+                        let sn: T = f / t;
+                        s[j] = t;
+                        if j != k {
+                            f = -sn * e[j - 1];
+                            e[j - 1] = cs * e[j - 1];
+                        }
+                        if ncv > 0 {
+                            General::rotate_columns(&mut v, j, p - 1, cs, sn);
+                        }
+                    }
+                }
+                // Split at negligible s(k)
+                2 => {
+                    let mut f: T = e[k - 1];
+                    e[k - 1] = T::zero();
+                    for j in k..p {
+                        let t: T = General::hypot(s[j], f);
+                        let cs: T = s[j] / t;
+                        let sn: T = f / t;
+                        s[j] = t;
+                        f = -sn * e[j];
+                        e[j] = cs * e[j];
+                        if ncu > 0 {
+                            General::rotate_columns(&mut u, j, k - 1, cs, sn);
+                        }
+                    }
+                }
+                // Perform one qr step
+                3 => {
+                    iter += 1;
+                    if iter > max_iter {
+                        return Err(LinalgError::NoConvergence { iters: max_iter });
+                    }
+
+                    // Calculate the shift
+                    let scale: T = s[p - 1]
+                        .abs()
+                        .max(s[p - 2].abs())
+                        .max(e[p - 2].abs())
+                        .max(s[k].abs())
+                        .max(e[k].abs());
+                    let sp: T = s[p - 1] / scale;
+                    let spm1: T = s[p - 2] / scale;
+                    let epm1: T = e[p - 2] / scale;
+                    let sk: T = s[k] / scale;
+                    let ek: T = e[k] / scale;
+                    let b: T = ((spm1 + sp) * (spm1 - sp) + epm1 * epm1) / T::from_f64(2.0);
+                    let c: T = (sp * epm1) * (sp * epm1);
+                    let mut shift: T = T::zero();
+                    if b != T::zero() || c != T::zero() {
+                        shift = (b * b + c).sqrt();
+                        if b < T::zero() {
+                            shift = -shift;
+                        }
+                        shift = c / (b + shift);
+                    }
+                    let mut f: T = (sk + sp) * (sk - sp) + shift;
+                    let mut g: T = sk * ek;
+
+                    // Chase zeros
+                    for j in k..(p - 1) {
+                        let mut t: T = General::hypot(f, g);
+                        let mut cs: T = f / t;
+                        let mut sn: T = g / t;
+                        if j != k {
+                            e[j - 1] = t;
+                        }
+                        f = cs * s[j] + sn * e[j];
+                        e[j] = cs * e[j] - sn * s[j];
+                        g = sn * s[j + 1];
+                        s[j + 1] = cs * s[j + 1];
+                        if ncv > 0 {
+                            General::rotate_columns(&mut v, j, j + 1, cs, sn);
+                        }
+                        t = General::hypot(f, g);
+                        cs = f / t;
+                        sn = g / t;
+                        s[j] = t;
+                        f = cs * e[j] + sn * s[j + 1];
+                        s[j + 1] = -sn * e[j] + cs * s[j + 1];
+                        g = sn * e[j + 1];
+                        e[j + 1] = cs * e[j + 1];
+                        if ncu > 0 && j < m - 1 {
+                            General::rotate_columns(&mut u, j, j + 1, cs, sn);
+                        }
+                    }
+                    e[p - 2] = f;
+                }
+                // Convergence
+                _ => {
+                    // Make the singular values positive
+                    if s[k] <= T::zero() {
+                        s[k] = if s[k] < T::zero() { -s[k] } else { T::zero() };
+                        if ncv > 0 {
+                            for i in 0..=pp {
+                                v[[i, k]] = -v[[i, k]];
+                            }
+                        }
+                    }
+
+                    // Order the singular values
+                    while k < pp {
+                        if s[k] >= s[k + 1] {
+                            break;
+                        }
+                        s.swap(k, k + 1);
+                        if ncv > 0 && k < n - 1 {
+                            General::swap_columns(&mut v, k, k + 1);
+                        }
+                        if ncu > 0 && k < m - 1 {
+                            General::swap_columns(&mut u, k, k + 1);
+                        }
+                        k += 1;
+                    }
+                    iter = 0;
+                    p -= 1;
+                }
             }
         }
+
+        Ok(SingularValueDec::new(u, Vector::new_column(s), v))
     }
 
-    ///
-    /// self is an m times n matrix with m >= n
-    /// A = UBV^{T}
-    /// U \in T^{m \times n}
-    /// B \in T^{n \times n}
-    /// V \in T^{n \times n}
-    pub fn householder_bidiag(&self) -> (Self, Self, Self) {
-        let (m, n): (usize, usize) = self.dim();
-        if m < n {
-            panic!("Read the API");
+    fn hypot(a: T, b: T) -> T {
+        let a_abs: T = a.abs();
+        let b_abs: T = b.abs();
+        let max: T = a_abs.max(b_abs);
+        if max == T::zero() {
+            return T::zero();
         }
+        let min: T = a_abs.min(b_abs) / max;
+        max * (T::one() + min * min).sqrt()
+    }
 
-        let mut u: General<T> = General::one(m);
-        let mut v: General<T> = General::one(n);
-        let mut a_i: General<T> = self.clone();
-
-        for i in 0..n - 1 {
-            // eliminate non-zeros below the diagonal
-            // Keep the product U*B unchanged
-            let u_x: Vector<T> = a_i.clone().get_column(i);
-            let u_slice: Vector<T> = u_x.get_slice(i, m - 1);
-
-            let u_i: General<T> = General::householder(&u_slice, 0);
-
-            let a_i_slice = &u_i * &a_i.clone().get_slice(i, m - 1, i, n - 1);
-            a_i = a_i.set_slice(&a_i_slice, i, i);
-            let mut u_mi: General<T> = General::one(m);
-            u_mi = u_mi.set_slice(&u_i, i, i);
-
-            u = &u * &u_mi;
-
-            //eliminate non-zeros to the right of the
-            //superdiagonal by working with the transpose
-            // Keep the product B*V' unchanged
-            //B_T = B';
-            if i < (n - 1) {
-                let v_x: Vector<T> = a_i.get_row(i);
-                let v_x_trans: Vector<T> = v_x.transpose();
-                let v_x_trans_slice: Vector<T> = v_x_trans.get_slice(i + 1, n - 1);
-
-                let v_i: General<T> = General::householder(&v_x_trans_slice, 0);
-
-                let mut v_ni: General<T> = General::one(n);
-                v_ni = v_ni.set_slice(&v_i, i + 1, i + 1);
-                //let a_i_slice = &a_i.clone().get_slice(i+1, m - 1, i+1, n - 1) * &v_i;
-                //a_i = a_i.set_slice(&a_i_slice, i+1, i+1);
-                a_i = &a_i * &v_ni;
-
-                v = &v * &v_ni;
-            }
+    /// Applies the rotation $[c, s; -s, c]$ to the columns j and k
+    fn rotate_columns(a: &mut General<T>, j: usize, k: usize, c: T, s: T) {
+        for i in 0..a.m {
+            let a_ij: T = a[[i, j]];
+            let a_ik: T = a[[i, k]];
+            a[[i, j]] = c * a_ij + s * a_ik;
+            a[[i, k]] = -s * a_ij + c * a_ik;
         }
+    }
 
-        //Null all elements beneath the diagonal, and superdiagonal
-        for i in 0..m {
-            for k in 0..n {
-                if k != i && k != (i + 1) {
-                    a_i[[i, k]] = T::zero();
-                }
-            }
+    fn swap_columns(a: &mut General<T>, j: usize, k: usize) {
+        for i in 0..a.m {
+            let a_ij: T = a[[i, j]];
+            a[[i, j]] = a[[i, k]];
+            a[[i, k]] = a_ij;
         }
-        (u, a_i, v)
     }
 }
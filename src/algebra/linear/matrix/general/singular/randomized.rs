@@ -0,0 +1,218 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{General, SingularValueDec, SingularValueDecomposition, Transpose},
+        vector::Vector,
+        LinalgError,
+    },
+};
+
+impl<T> General<T>
+where
+    T: Real,
+{
+    /// Computes a truncated singular value decomposition with a randomized
+    /// range finder
+    ///
+    /// ```math
+    /// A \approx U_k \Sigma_k V_k^T
+    /// ```
+    /// The range of $A \in T^{m \times n}$ is sampled with $l = k + p$ random
+    /// vectors, $p$ is the oversampling. Every power iteration multiplies the
+    /// sample with $AA^T$, which improves the accuracy for slowly decaying
+    /// singular values. The orthonormal basis $Q$ of the sample is the thin
+    /// $Q$ of its Householder QR decomposition, the singular value
+    /// decomposition of the small matrix $Q^TA \in T^{l \times n}$ yields
+    /// the approximation (Halko, Martinsson, Tropp).
+    ///
+    /// Only products with $A$ and $l \times n$ or $m \times l$ matrices are
+    /// computed, i.e. $A$ is neither transposed nor decomposed, and the
+    /// additional memory is $O((m + n)l)$.
+    ///
+    /// Like [`SingularValueDecomposition::dec_svd_thin`], the decomposition
+    /// holds $V$ and not $V^T$. $U \in T^{m \times k}$ and $V \in T^{n
+    /// \times k}$ have orthonormal columns, the singular values are sorted in
+    /// descending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `k`: Rank of the approximation
+    /// * `oversampling`: Number of additional random vectors $p$, 5 or 10 are
+    ///   typical values
+    /// * `power_iterations`: Number of power iterations, 1 or 2 are typical
+    ///   values
+    ///
+    /// # Panics
+    ///
+    /// if k is zero or larger than $\min(m, n)$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{General, Transpose}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// // rank two
+    /// let a: General<f64> = matrix![  1.0, 2.0, 3.0, 4.0;
+    ///                                 2.0, 4.0, 6.0, 8.0;
+    ///                                 1.0, 0.0, 1.0, 0.0;
+    ///                                 0.0, 2.0, 2.0, 4.0];
+    ///
+    /// let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_randomized(2, 2, 1).unwrap().usv();
+    ///
+    /// let mut a_k: General<f64> = u.clone();
+    /// for i in 0..4 {
+    ///     a_k[[i, 0]] *= s[0];
+    ///     a_k[[i, 1]] *= s[1];
+    /// }
+    /// assert_relative_eq!(&a_k * &v.transpose(), a, epsilon = 1.0e-10);
+    /// ```
+    pub fn dec_svd_randomized(
+        &self,
+        k: usize,
+        oversampling: usize,
+        power_iterations: usize,
+    ) -> Result<SingularValueDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        assert!(
+            0 < k && k <= m.min(n),
+            "The rank of the approximation must be in 1..=min(m, n)"
+        );
+        let l: usize = (k + oversampling).min(m.min(n));
+
+        let omega: General<T> = General::new_random(n, l).apply(&|x| *x - T::from_f64(0.5));
+        let mut q: General<T> = orthonormal_columns(self * &omega);
+        for _ in 0..power_iterations {
+            let w: General<T> = orthonormal_columns((&q.clone().transpose() * self).transpose());
+            q = orthonormal_columns(self * &w);
+        }
+
+        let b: General<T> = &q.clone().transpose() * self;
+        let (u_b, s, v): (General<T>, Vector<T>, General<T>) = b.dec_svd_thin()?.usv();
+
+        let u: General<T> = &q * &u_b.get_slice(0, l - 1, 0, k - 1);
+        Ok(SingularValueDec::new(
+            u,
+            s.get_slice(0, k - 1),
+            v.get_slice(0, n - 1, 0, k - 1),
+        ))
+    }
+
+    /// Computes the rank $k$ approximation $U_k \Sigma_k V_k^T$ with a
+    /// randomized singular value decomposition
+    ///
+    /// See [`General::dec_svd_randomized`] for the arguments.
+    ///
+    /// # Panics
+    ///
+    /// if k is zero or larger than $\min(m, n)$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  3.0, 0.0, 0.0;
+    ///                                 0.0, 2.0, 0.0;
+    ///                                 0.0, 0.0, 0.1];
+    ///
+    /// let a_2: General<f64> = a.low_rank_approximation(2, 1, 2).unwrap();
+    ///
+    /// assert_relative_eq!(a_2, matrix![3.0, 0.0, 0.0; 0.0, 2.0, 0.0; 0.0, 0.0, 0.0], epsilon = 1.0e-10);
+    /// ```
+    pub fn low_rank_approximation(
+        &self,
+        k: usize,
+        oversampling: usize,
+        power_iterations: usize,
+    ) -> Result<General<T>, LinalgError> {
+        let (u, s, v): (General<T>, Vector<T>, General<T>) = self
+            .dec_svd_randomized(k, oversampling, power_iterations)?
+            .usv();
+
+        let mut u_s: General<T> = u;
+        for j in 0..k {
+            let s_j: T = s[j];
+            for u_ij in u_s.data[j * u_s.m..(j + 1) * u_s.m].iter_mut() {
+                *u_ij *= s_j;
+            }
+        }
+        Ok(&u_s * &v.transpose())
+    }
+}
+
+/// Returns the thin factor $Q \in T^{m \times l}$ of the QR decomposition
+/// $Y = QR$ with $Y \in T^{m \times l}$
+///
+/// The Householder vectors overwrite $Y$ and $Q$ is accumulated from them,
+/// hence only $O(ml)$ memory is needed.
+fn orthonormal_columns<T>(mut y: General<T>) -> General<T>
+where
+    T: Real,
+{
+    let (m, l): (usize, usize) = y.dim();
+    let two: T = T::from_f64(2.0);
+
+    // beta[j] = 2 / (v_j^T v_j), zero if the reflector is the identity
+    let mut beta: Vec<T> = vec![T::zero(); l];
+    for j in 0..l {
+        let col: usize = j * m;
+        let norm: T = y.data[col + j..col + m]
+            .iter()
+            .fold(T::zero(), |acc, y_ij| acc + *y_ij * *y_ij)
+            .sqrt();
+        if norm == T::zero() {
+            continue;
+        }
+        let alpha: T = if y.data[col + j] >= T::zero() {
+            -norm
+        } else {
+            norm
+        };
+        y.data[col + j] -= alpha;
+        let v_norm: T = y.data[col + j..col + m]
+            .iter()
+            .fold(T::zero(), |acc, v_i| acc + *v_i * *v_i);
+        beta[j] = two / v_norm;
+
+        for c in j + 1..l {
+            let (v, x) = y.data.split_at_mut(c * m);
+            reflect(&v[col + j..col + m], &mut x[j..m], beta[j]);
+        }
+    }
+
+    // Q = H_0 H_1 ... H_{l-1} [I; 0]
+    let mut q: General<T> = General::zero(m, l);
+    for j in 0..l {
+        q[[j, j]] = T::one();
+    }
+    for j in (0..l).rev() {
+        if beta[j] == T::zero() {
+            continue;
+        }
+        for c in j..l {
+            reflect(
+                &y.data[j * m + j..(j + 1) * m],
+                &mut q.data[c * m + j..(c + 1) * m],
+                beta[j],
+            );
+        }
+    }
+    q
+}
+
+/// Applies the reflector $I - \beta vv^T$ to x
+fn reflect<T>(v: &[T], x: &mut [T], beta: T)
+where
+    T: Real,
+{
+    let w: T = v
+        .iter()
+        .zip(x.iter())
+        .fold(T::zero(), |acc, (v_i, x_i)| acc + *v_i * *x_i)
+        * beta;
+    for (x_i, v_i) in x.iter_mut().zip(v.iter()) {
+        *x_i -= w * *v_i;
+    }
+}
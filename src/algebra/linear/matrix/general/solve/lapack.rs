@@ -1,3 +1,4 @@
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{
     abstr::{Field, Scalar, Zero},
     linear::{matrix::General, vector::Vector},
@@ -11,7 +12,7 @@ where
 {
     /// Solves $Ax = y$
     /// where $A \in \mathbb{R}^{m * n}, x \in \mathbb{R}^n, y \in \mathbb{R}^m$
-    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, ()> {
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
         return self.solve_vector_r(rhs);
     }
 }
@@ -20,7 +21,7 @@ impl<T> Solve<General<T>> for General<T>
 where
     T: Field + Scalar,
 {
-    fn solve(&self, rhs: &General<T>) -> Result<General<T>, ()> {
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
         return self.solve_matrix_r(rhs);
     }
 }
@@ -29,7 +30,7 @@ impl<T> General<T>
 where
     T: Field + Scalar,
 {
-    fn solve_vector_r(&self, y: &Vector<T>) -> Result<Vector<T>, ()> {
+    fn solve_vector_r(&self, y: &Vector<T>) -> Result<Vector<T>, LinalgError> {
         let (m, n): (usize, usize) = self.dim();
         let m_i32: i32 = m as i32;
         let n_i32: i32 = n as i32;
@@ -54,8 +55,11 @@ where
             &mut info,
         );
 
-        if info != 0 {
-            return Err(());
+        if info < 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+        if info > 0 {
+            return Err(LinalgError::Singular);
         }
 
         T::xgetrs(
@@ -70,7 +74,7 @@ where
         );
 
         if info != 0 {
-            return Err(());
+            return Err(LinalgError::BackendError { info });
         }
 
         Ok(Vector::new_column(y_data))
@@ -81,7 +85,7 @@ impl<T> General<T>
 where
     T: Field + Scalar,
 {
-    pub fn solve_matrix_r(&self, y: &General<T>) -> Result<General<T>, ()> {
+    pub fn solve_matrix_r(&self, y: &General<T>) -> Result<General<T>, LinalgError> {
         let (m, n): (usize, usize) = self.dim();
         let m_i32: i32 = m as i32;
         let n_i32: i32 = n as i32;
@@ -106,8 +110,11 @@ where
             &mut info,
         );
 
-        if info != 0 {
-            return Err(());
+        if info < 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+        if info > 0 {
+            return Err(LinalgError::Singular);
         }
 
         T::xgetrs(
@@ -122,7 +129,7 @@ where
         );
 
         if info != 0 {
-            return Err(());
+            return Err(LinalgError::BackendError { info });
         }
 
         Ok(General::new(y_m, y_n, y_data))
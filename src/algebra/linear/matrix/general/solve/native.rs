@@ -1,3 +1,4 @@
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{
     abstr::{AbsDiffEq, Field, RelativeEq, Scalar},
     linear::{matrix::General, vector::vector::Vector},
@@ -11,7 +12,7 @@ where
 {
     /// Solves $Ax = y$
     /// where $A \in \mathbb{R}^{m * n}, x \in \mathbb{R}^n, y \in \mathbb{R}^m$
-    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, ()> {
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
         self.dec_lu()?.solve(rhs)
     }
 }
@@ -20,7 +21,7 @@ impl<T> Solve<General<T>> for General<T>
 where
     T: Field + Scalar + AbsDiffEq,
 {
-    fn solve(&self, rhs: &General<T>) -> Result<General<T>, ()> {
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
         self.dec_lu()?.solve(rhs)
     }
 }
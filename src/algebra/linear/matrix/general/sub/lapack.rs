@@ -34,7 +34,11 @@ where
     /// let c: General<f64> = a - b;
     /// ```
     fn sub(self, rhs: Self) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
 
         let (m, n): (usize, usize) = rhs.dim();
 
@@ -60,7 +64,11 @@ where
     type Output = General<T>;
 
     fn sub(self, rhs: &'b General<T>) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
 
         let (m, n): (usize, usize) = rhs.dim();
 
@@ -86,7 +94,11 @@ where
     type Output = &'a mut General<T>;
 
     fn sub(self, rhs: &'b General<T>) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
 
         let (m, n): (usize, usize) = rhs.dim();
 
@@ -23,7 +23,11 @@ where
     /// let c: General<f64> = a - b;
     /// ```
     fn sub(mut self, rhs: Self) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
         self.data
             .iter_mut()
             .zip(rhs.data.iter())
@@ -51,7 +55,11 @@ where
     /// let c: General<f64> = &b - &a;
     /// ```
     fn sub(self, rhs: &'b General<T>) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
         let (m, n) = self.dim();
         General {
             m,
@@ -85,7 +93,11 @@ where
     /// let _ = &mut a - &b;
     /// ```
     fn sub(self, rhs: &'b General<T>) -> Self::Output {
-        debug_assert_eq!(self.dim(), rhs.dim());
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
         self.data
             .iter_mut()
             .zip(rhs.data.iter())
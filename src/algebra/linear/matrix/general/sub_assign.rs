@@ -20,6 +20,11 @@ where
     /// a -= b;
     /// ```
     fn sub_assign(&mut self, rhs: General<T>) {
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "The dimensions of the matrices do not match"
+        );
         self.data
             .iter_mut()
             .zip(rhs.data.iter())
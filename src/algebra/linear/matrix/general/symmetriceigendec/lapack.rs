@@ -0,0 +1,143 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Complex, Field, Real, Scalar},
+    linear::matrix::{General, SymmetricEigenDec, SymmetricEigenDecomposition},
+};
+use std::ops::Range;
+
+impl<T> SymmetricEigenDecomposition<T> for General<T>
+where
+    T: Real,
+{
+    /// Computes the eigenvalues and eigenvectors of a real symmetric matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Diagonal, General, SymmetricEigenDec, SymmetricEigenDecomposition};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  2.0, 1.0;
+    ///                                 1.0, 2.0];
+    ///
+    /// let (values, vectors): (Vec<f64>, General<f64>) = a.dec_symmetric_eigen().unwrap().pair();
+    ///
+    /// assert_relative_eq!(values[0], 1.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(values[1], 3.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(&a * &vectors, &vectors * &General::from(Diagonal::new(&values)), epsilon = 1.0e-10);
+    /// ```
+    fn dec_symmetric_eigen(&self) -> Result<SymmetricEigenDec<T>, LinalgError> {
+        self.dec_symmetric_eigen_range(0..self.m)
+    }
+
+    /// Computes a subset of the eigenpairs of a real symmetric matrix
+    fn dec_symmetric_eigen_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<SymmetricEigenDec<T>, LinalgError> {
+        let (values, vectors): (Vec<T>, General<T>) = General::xsyevd(self, range)?;
+
+        Ok(SymmetricEigenDec::new(values, vectors))
+    }
+}
+
+impl<T> SymmetricEigenDecomposition<T, Complex<T>> for General<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the eigenvalues and eigenvectors of a Hermitian matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{General, SymmetricEigenDec, SymmetricEigenDecomposition};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<Complex<f64>> = matrix![Complex::new(2.0, 0.0), Complex::new(1.0, 1.0);
+    ///                                        Complex::new(1.0, -1.0), Complex::new(3.0, 0.0)];
+    ///
+    /// let values: Vec<f64> = a.dec_symmetric_eigen().unwrap().values();
+    ///
+    /// assert_relative_eq!(values[0], 1.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(values[1], 4.0, epsilon = 1.0e-10);
+    /// ```
+    fn dec_symmetric_eigen(&self) -> Result<SymmetricEigenDec<T, Complex<T>>, LinalgError> {
+        self.dec_symmetric_eigen_range(0..self.m)
+    }
+
+    /// Computes a subset of the eigenpairs of a Hermitian matrix
+    fn dec_symmetric_eigen_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<SymmetricEigenDec<T, Complex<T>>, LinalgError> {
+        let (values, vectors): (Vec<Complex<T>>, General<Complex<T>>) =
+            General::xsyevd(self, range)?;
+
+        Ok(SymmetricEigenDec::new(
+            values.iter().map(|v| v.re).collect(),
+            vectors,
+        ))
+    }
+}
+
+impl<T> General<T>
+where
+    T: Field + Scalar,
+{
+    /// Calls xsyevd and returns the eigenpairs with the indices in range
+    fn xsyevd(a: &General<T>, range: Range<usize>) -> Result<(Vec<T>, General<T>), LinalgError> {
+        let (m, n): (usize, usize) = a.dim();
+        debug_assert_eq!(
+            m, n,
+            "Unable to compute the eigen value of a non-square matrix"
+        );
+        assert!(
+            range.start < range.end && range.end <= n,
+            "Invalid range of eigenvalues"
+        );
+
+        let n_i32: i32 = n as i32;
+        let mut a_data: Vec<T> = a.clone().data;
+        let mut w: Vec<T> = vec![T::zero(); n];
+        let mut info: i32 = 0;
+
+        let (lwork, liwork): (i32, i32) = T::xsyevd_work_size(
+            'V' as u8,
+            'L' as u8,
+            n_i32,
+            &mut a_data[..],
+            n_i32,
+            &mut w[..],
+            &mut info,
+        );
+
+        let mut work: Vec<T> = vec![T::zero(); lwork as usize];
+        let mut iwork: Vec<i32> = vec![0; liwork as usize];
+
+        T::xsyevd(
+            'V' as u8,
+            'L' as u8,
+            n_i32,
+            &mut a_data[..],
+            n_i32,
+            &mut w[..],
+            &mut work[..],
+            lwork,
+            &mut iwork[..],
+            liwork,
+            &mut info,
+        );
+
+        if info != 0 {
+            return Err(LinalgError::BackendError { info });
+        }
+
+        let k: usize = range.end - range.start;
+        let values: Vec<T> = w[range.clone()].to_vec();
+        let vectors: Vec<T> = a_data[range.start * n..range.end * n].to_vec();
+
+        Ok((values, General::new(n, k, vectors)))
+    }
+}
@@ -0,0 +1,554 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Complex, One, Real, Scalar, Sign, Zero},
+    linear::matrix::{General, SymmetricEigenDec, SymmetricEigenDecomposition},
+};
+use std::ops::Range;
+
+impl<T> SymmetricEigenDecomposition<T> for General<T>
+where
+    T: Real,
+{
+    /// Computes the eigenvalues and eigenvectors of a real symmetric matrix
+    ///
+    /// The matrix is reduced to tridiagonal form with Householder reflections,
+    /// the tridiagonal matrix is diagonalized with the implicit QL algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Diagonal, General, SymmetricEigenDec, SymmetricEigenDecomposition};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  2.0, 1.0;
+    ///                                 1.0, 2.0];
+    ///
+    /// let (values, vectors): (Vec<f64>, General<f64>) = a.dec_symmetric_eigen().unwrap().pair();
+    ///
+    /// assert_relative_eq!(values[0], 1.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(values[1], 3.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(&a * &vectors, &vectors * &General::from(Diagonal::new(&values)), epsilon = 1.0e-10);
+    /// ```
+    fn dec_symmetric_eigen(&self) -> Result<SymmetricEigenDec<T>, LinalgError> {
+        self.dec_symmetric_eigen_range(0..self.m)
+    }
+
+    /// Computes a subset of the eigenpairs of a real symmetric matrix
+    ///
+    /// All eigenvalues of the tridiagonal matrix are computed with the
+    /// implicit QL algorithm, the eigenvectors of the selected eigenvalues are
+    /// computed with inverse iteration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, SymmetricEigenDec, SymmetricEigenDecomposition};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  2.0, -1.0, 0.0;
+    ///                                 -1.0, 2.0, -1.0;
+    ///                                 0.0, -1.0, 2.0];
+    ///
+    /// // the two largest eigenvalues
+    /// let values: Vec<f64> = a.dec_symmetric_eigen_range(1..3).unwrap().values();
+    ///
+    /// assert_relative_eq!(values[0], 2.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(values[1], 2.0 + 2.0f64.sqrt(), epsilon = 1.0e-10);
+    /// ```
+    fn dec_symmetric_eigen_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<SymmetricEigenDec<T>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        debug_assert_eq!(
+            m, n,
+            "Unable to compute the eigen value of a non-square matrix"
+        );
+        assert!(
+            range.start < range.end && range.end <= n,
+            "Invalid range of eigenvalues"
+        );
+
+        let (d, e, q): (Vec<T>, Vec<T>, General<T>) = self.tridiagonalize_symmetric();
+        let (values, z): (Vec<T>, General<T>) = General::tridiagonal_eigen(d, e, range)?;
+
+        Ok(SymmetricEigenDec::new(values, &q * &z))
+    }
+}
+
+impl<T> SymmetricEigenDecomposition<T, Complex<T>> for General<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Computes the eigenvalues and eigenvectors of a Hermitian matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{General, SymmetricEigenDec, SymmetricEigenDecomposition};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<Complex<f64>> = matrix![Complex::new(2.0, 0.0), Complex::new(1.0, 1.0);
+    ///                                        Complex::new(1.0, -1.0), Complex::new(3.0, 0.0)];
+    ///
+    /// let values: Vec<f64> = a.dec_symmetric_eigen().unwrap().values();
+    ///
+    /// assert_relative_eq!(values[0], 1.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(values[1], 4.0, epsilon = 1.0e-10);
+    /// ```
+    fn dec_symmetric_eigen(&self) -> Result<SymmetricEigenDec<T, Complex<T>>, LinalgError> {
+        self.dec_symmetric_eigen_range(0..self.m)
+    }
+
+    /// Computes a subset of the eigenpairs of a Hermitian matrix
+    fn dec_symmetric_eigen_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<SymmetricEigenDec<T, Complex<T>>, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        debug_assert_eq!(
+            m, n,
+            "Unable to compute the eigen value of a non-square matrix"
+        );
+        assert!(
+            range.start < range.end && range.end <= n,
+            "Invalid range of eigenvalues"
+        );
+
+        let (d, e, q): (Vec<T>, Vec<T>, General<Complex<T>>) = self.tridiagonalize_hermitian();
+        let (values, z): (Vec<T>, General<T>) = General::tridiagonal_eigen(d, e, range)?;
+
+        Ok(SymmetricEigenDec::new(values, &q * &General::from(&z)))
+    }
+}
+
+impl<T> General<T>
+where
+    T: Real,
+{
+    /// Reduces the symmetric matrix to tridiagonal form $A = QTQ^T$
+    ///
+    /// Returns the diagonal, the subdiagonal and Q. The subdiagonal has length
+    /// n, the last entry is zero.
+    fn tridiagonalize_symmetric(&self) -> (Vec<T>, Vec<T>, General<T>) {
+        let n: usize = self.m;
+        let mut a: General<T> = self.clone();
+        for j in 0..n {
+            for i in 0..j {
+                a[[i, j]] = a[[j, i]];
+            }
+        }
+        let mut q: General<T> = General::one(n);
+
+        for k in 0..n.saturating_sub(2) {
+            let x_norm: T = ((k + 1)..n)
+                .fold(T::zero(), |acc, i| acc + a[[i, k]] * a[[i, k]])
+                .sqrt();
+            if x_norm == T::zero() {
+                continue;
+            }
+
+            let alpha: T = if a[[k + 1, k]] > T::zero() {
+                -x_norm
+            } else {
+                x_norm
+            };
+            let mut v: Vec<T> = ((k + 1)..n).map(|i| a[[i, k]]).collect();
+            v[0] -= alpha;
+            let tau: T = T::from_f64(2.0) / v.iter().fold(T::zero(), |acc, v_i| acc + *v_i * *v_i);
+
+            // A_22 = H A_22 H with H = I - tau v v^T
+            let m: usize = n - k - 1;
+            let p: Vec<T> = (0..m)
+                .map(|i| {
+                    tau * (0..m).fold(T::zero(), |acc, j| acc + a[[k + 1 + i, k + 1 + j]] * v[j])
+                })
+                .collect();
+            let kappa: T =
+                tau / T::from_f64(2.0) * (0..m).fold(T::zero(), |acc, i| acc + v[i] * p[i]);
+            let w: Vec<T> = (0..m).map(|i| p[i] - kappa * v[i]).collect();
+            for j in 0..m {
+                for i in 0..m {
+                    a[[k + 1 + i, k + 1 + j]] -= v[i] * w[j] + w[i] * v[j];
+                }
+            }
+
+            a[[k + 1, k]] = alpha;
+            a[[k, k + 1]] = alpha;
+            for i in (k + 2)..n {
+                a[[i, k]] = T::zero();
+                a[[k, i]] = T::zero();
+            }
+
+            // Q = Q H
+            for r in 0..n {
+                let s: T = tau * (0..m).fold(T::zero(), |acc, j| acc + q[[r, k + 1 + j]] * v[j]);
+                for j in 0..m {
+                    q[[r, k + 1 + j]] -= s * v[j];
+                }
+            }
+        }
+
+        let d: Vec<T> = (0..n).map(|i| a[[i, i]]).collect();
+        let e: Vec<T> = (0..n)
+            .map(|i| if i + 1 < n { a[[i + 1, i]] } else { T::zero() })
+            .collect();
+
+        (d, e, q)
+    }
+
+    /// Computes the eigenvalues with the indices in range and the
+    /// corresponding eigenvectors of the symmetric tridiagonal matrix with
+    /// diagonal d and subdiagonal e
+    fn tridiagonal_eigen(
+        d: Vec<T>,
+        e: Vec<T>,
+        range: Range<usize>,
+    ) -> Result<(Vec<T>, General<T>), LinalgError> {
+        let n: usize = d.len();
+
+        if range.end - range.start == n {
+            let mut values: Vec<T> = d;
+            let mut sub: Vec<T> = e;
+            let mut z: General<T> = General::one(n);
+            General::tridiagonal_ql(&mut values, &mut sub, Some(&mut z))?;
+
+            let order: Vec<usize> = General::ascending_order(&values);
+            let mut vectors: General<T> = General::zero(n, n);
+            for (j, o) in order.iter().enumerate() {
+                for i in 0..n {
+                    vectors[[i, j]] = z[[i, *o]];
+                }
+            }
+            let values: Vec<T> = order.iter().map(|o| values[*o]).collect();
+
+            Ok((values, vectors))
+        } else {
+            let mut values: Vec<T> = d.clone();
+            let mut sub: Vec<T> = e.clone();
+            General::tridiagonal_ql(&mut values, &mut sub, None)?;
+
+            let order: Vec<usize> = General::ascending_order(&values);
+            let values: Vec<T> = order[range].iter().map(|o| values[*o]).collect();
+            let vectors: General<T> = General::tridiagonal_inverse_iteration(&d, &e, &values);
+
+            Ok((values, vectors))
+        }
+    }
+
+    fn ascending_order(values: &[T]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by(|a, b| values[*a].partial_cmp(&values[*b]).unwrap());
+        order
+    }
+
+    /// Implicit QL algorithm with Wilkinson shifts
+    ///
+    /// On return d contains the unordered eigenvalues. The rotations are
+    /// accumulated in z, if present.
+    fn tridiagonal_ql(
+        d: &mut [T],
+        e: &mut [T],
+        mut z: Option<&mut General<T>>,
+    ) -> Result<(), LinalgError> {
+        let n: usize = d.len();
+        let eps: T = T::default_epsilon();
+        let two: T = T::from_f64(2.0);
+
+        let mut f: T = T::zero();
+        let mut tst1: T = T::zero();
+
+        for l in 0..n {
+            tst1 = tst1.max(d[l].abs() + e[l].abs());
+            let mut m: usize = l;
+            while m < n - 1 && e[m].abs() > eps * tst1 {
+                m += 1;
+            }
+
+            if m > l {
+                let mut iter: usize = 0;
+                loop {
+                    iter += 1;
+                    if iter > 30 {
+                        return Err(LinalgError::NoConvergence { iters: 30 });
+                    }
+
+                    // Compute the implicit shift
+                    let g: T = d[l];
+                    let mut p: T = (d[l + 1] - g) / (two * e[l]);
+                    let mut r: T = (p * p + T::one()).sqrt();
+                    if p < T::zero() {
+                        r = -r;
+                    }
+                    d[l] = e[l] / (p + r);
+                    d[l + 1] = e[l] * (p + r);
+                    let dl1: T = d[l + 1];
+                    let mut h: T = g - d[l];
+                    for d_i in d.iter_mut().skip(l + 2) {
+                        *d_i -= h;
+                    }
+                    f += h;
+
+                    p = d[m];
+                    let mut c: T = T::one();
+                    let mut c2: T = c;
+                    let mut c3: T = c;
+                    let el1: T = e[l + 1];
+                    let mut s: T = T::zero();
+                    let mut s2: T = T::zero();
+                    for i in (l..m).rev() {
+                        c3 = c2;
+                        c2 = c;
+                        s2 = s;
+                        let g: T = c * e[i];
+                        h = c * p;
+                        r = (p * p + e[i] * e[i]).sqrt();
+                        e[i + 1] = s * r;
+                        s = e[i] / r;
+                        c = p / r;
+                        p = c * d[i] - s * g;
+                        d[i + 1] = h + s * (c * g + s * d[i]);
+
+                        if let Some(z) = z.as_mut() {
+                            for k in 0..n {
+                                let z_ki1: T = z[[k, i + 1]];
+                                let z_ki: T = z[[k, i]];
+                                z[[k, i + 1]] = s * z_ki + c * z_ki1;
+                                z[[k, i]] = c * z_ki - s * z_ki1;
+                            }
+                        }
+                    }
+                    p = -s * s2 * c3 * el1 * e[l] / dl1;
+                    e[l] = s * p;
+                    d[l] = c * p;
+
+                    if e[l].abs() <= eps * tst1 {
+                        break;
+                    }
+                }
+            }
+            d[l] += f;
+            e[l] = T::zero();
+        }
+
+        Ok(())
+    }
+
+    /// Computes the eigenvectors of the symmetric tridiagonal matrix for the
+    /// ascending eigenvalues with inverse iteration
+    ///
+    /// Eigenvectors of close eigenvalues are orthogonalized against each
+    /// other.
+    fn tridiagonal_inverse_iteration(d: &[T], e: &[T], values: &[T]) -> General<T> {
+        let n: usize = d.len();
+        let mut norm: T = T::zero();
+        for i in 0..n {
+            let e_prev: T = if i > 0 { e[i - 1].abs() } else { T::zero() };
+            norm = norm.max(d[i].abs() + e[i].abs() + e_prev);
+        }
+        if norm == T::zero() {
+            norm = T::one();
+        }
+        let small: T = T::default_epsilon() * norm;
+        let cluster: T = T::from_f64(1.0e-3) * norm;
+
+        let mut z: General<T> = General::zero(n, values.len());
+
+        for (j, lambda) in values.iter().enumerate() {
+            // LU decomposition of T - lambda I with partial pivoting, U has two
+            // superdiagonals
+            let mut u_0: Vec<T> = d.iter().map(|d_i| *d_i - *lambda).collect();
+            let mut u_1: Vec<T> = e.to_vec();
+            let mut u_2: Vec<T> = vec![T::zero(); n];
+            let mut l: Vec<T> = vec![T::zero(); n];
+            let mut pivot: Vec<bool> = vec![false; n];
+
+            for i in 0..n.saturating_sub(1) {
+                if u_0[i].abs() >= e[i].abs() {
+                    if u_0[i].abs() < small {
+                        u_0[i] = small;
+                    }
+                    l[i] = e[i] / u_0[i];
+                    u_0[i + 1] -= l[i] * u_1[i];
+                } else {
+                    let u_0_i: T = u_0[i];
+                    let u_1_i: T = u_1[i];
+                    let u_0_i1: T = u_0[i + 1];
+                    let u_1_i1: T = if i + 2 < n { u_1[i + 1] } else { T::zero() };
+
+                    l[i] = u_0_i / e[i];
+                    u_0[i] = e[i];
+                    u_1[i] = u_0_i1;
+                    u_2[i] = u_1_i1;
+                    u_0[i + 1] = u_1_i - l[i] * u_0_i1;
+                    if i + 2 < n {
+                        u_1[i + 1] = -l[i] * u_1_i1;
+                    }
+                    pivot[i] = true;
+                }
+            }
+            if u_0[n - 1].abs() < small {
+                u_0[n - 1] = small;
+            }
+
+            let mut x: Vec<T> = (0..n)
+                .map(|i| T::from_f64(((i + 1) as f64 * (j + 1) as f64 * 0.618).sin() + 1.5))
+                .collect();
+
+            for _ in 0..3 {
+                // solve (T - lambda I) y = x
+                for i in 0..n.saturating_sub(1) {
+                    if pivot[i] {
+                        x.swap(i, i + 1);
+                    }
+                    let x_i: T = x[i];
+                    x[i + 1] -= l[i] * x_i;
+                }
+                for i in (0..n).rev() {
+                    let mut sum: T = x[i];
+                    if i + 1 < n {
+                        sum -= u_1[i] * x[i + 1];
+                    }
+                    if i + 2 < n {
+                        sum -= u_2[i] * x[i + 2];
+                    }
+                    x[i] = sum / u_0[i];
+                }
+
+                // orthogonalize against the vectors of the cluster
+                for k in (0..j).rev() {
+                    if values[j] - values[k] > cluster {
+                        break;
+                    }
+                    let dot: T = (0..n).fold(T::zero(), |acc, i| acc + z[[i, k]] * x[i]);
+                    for (i, x_i) in x.iter_mut().enumerate() {
+                        *x_i -= dot * z[[i, k]];
+                    }
+                }
+
+                let x_norm: T = x
+                    .iter()
+                    .fold(T::zero(), |acc, x_i| acc + *x_i * *x_i)
+                    .sqrt();
+                if x_norm <= small {
+                    x = vec![T::zero(); n];
+                    x[j % n] = T::one();
+                } else {
+                    x.iter_mut().for_each(|x_i| *x_i /= x_norm);
+                }
+            }
+
+            for (i, x_i) in x.iter().enumerate() {
+                z[[i, j]] = *x_i;
+            }
+        }
+
+        z
+    }
+}
+
+impl<T> General<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Reduces the Hermitian matrix to a real tridiagonal form $A = QTQ^*$
+    ///
+    /// Returns the diagonal, the subdiagonal and Q. The subdiagonal has length
+    /// n, the last entry is zero.
+    fn tridiagonalize_hermitian(&self) -> (Vec<T>, Vec<T>, General<Complex<T>>) {
+        let n: usize = self.m;
+        let mut a: General<Complex<T>> = self.clone();
+        for j in 0..n {
+            for i in 0..j {
+                a[[i, j]] = a[[j, i]].conj();
+            }
+            a[[j, j]] = Complex::new(a[[j, j]].re, T::zero());
+        }
+        let mut q: General<Complex<T>> = General::one(n);
+
+        for k in 0..n.saturating_sub(2) {
+            let x_norm: T = ((k + 1)..n)
+                .fold(T::zero(), |acc, i| {
+                    acc + a[[i, k]].re * a[[i, k]].re + a[[i, k]].im * a[[i, k]].im
+                })
+                .sqrt();
+            if x_norm == T::zero() {
+                continue;
+            }
+
+            let x_0: Complex<T> = a[[k + 1, k]];
+            let x_0_abs: T = x_0.abs().re;
+            let phase: Complex<T> = if x_0_abs == T::zero() {
+                Complex::one()
+            } else {
+                x_0 / Complex::new(x_0_abs, T::zero())
+            };
+            let alpha: Complex<T> = -phase * Complex::new(x_norm, T::zero());
+
+            let mut v: Vec<Complex<T>> = ((k + 1)..n).map(|i| a[[i, k]]).collect();
+            v[0] -= alpha;
+            let v_norm: T = v.iter().fold(T::zero(), |acc, v_i| {
+                acc + v_i.re * v_i.re + v_i.im * v_i.im
+            });
+            let tau: Complex<T> = Complex::new(T::from_f64(2.0) / v_norm, T::zero());
+
+            // A_22 = H A_22 H with H = I - tau v v^*
+            let m: usize = n - k - 1;
+            let p: Vec<Complex<T>> = (0..m)
+                .map(|i| {
+                    tau * (0..m).fold(Complex::zero(), |acc, j| {
+                        acc + a[[k + 1 + i, k + 1 + j]] * v[j]
+                    })
+                })
+                .collect();
+            let kappa: Complex<T> = tau / Complex::new(T::from_f64(2.0), T::zero())
+                * (0..m).fold(Complex::zero(), |acc, i| acc + v[i].conj() * p[i]);
+            let w: Vec<Complex<T>> = (0..m).map(|i| p[i] - kappa * v[i]).collect();
+            for j in 0..m {
+                for i in 0..m {
+                    a[[k + 1 + i, k + 1 + j]] -= v[i] * w[j].conj() + w[i] * v[j].conj();
+                }
+            }
+
+            a[[k + 1, k]] = alpha;
+            a[[k, k + 1]] = alpha.conj();
+            for i in (k + 2)..n {
+                a[[i, k]] = Complex::zero();
+                a[[k, i]] = Complex::zero();
+            }
+
+            // Q = Q H
+            for r in 0..n {
+                let s: Complex<T> =
+                    tau * (0..m).fold(Complex::zero(), |acc, j| acc + q[[r, k + 1 + j]] * v[j]);
+                for j in 0..m {
+                    q[[r, k + 1 + j]] -= s * v[j].conj();
+                }
+            }
+        }
+
+        // Scale the columns of Q such that the subdiagonal becomes real
+        let mut phi: Complex<T> = Complex::one();
+        let mut e: Vec<T> = vec![T::zero(); n];
+        for k in 0..n {
+            for r in 0..n {
+                q[[r, k]] *= phi;
+            }
+            if k + 1 < n {
+                let sub: Complex<T> = a[[k + 1, k]];
+                e[k] = sub.abs().re;
+                phi = if e[k] == T::zero() {
+                    Complex::one()
+                } else {
+                    phi * sub / Complex::new(e[k], T::zero())
+                };
+            }
+        }
+        let d: Vec<T> = (0..n).map(|i| a[[i, i]].re).collect();
+
+        (d, e, q)
+    }
+}
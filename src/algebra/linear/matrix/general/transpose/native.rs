@@ -1,4 +1,4 @@
-use crate::algebra::linear::matrix::Transpose;
+use crate::algebra::linear::matrix::{ConjugateTranspose, Transpose};
 use crate::algebra::{
     abstr::{Field, Scalar},
     linear::matrix::General,
@@ -98,3 +98,32 @@ where
         self
     }
 }
+
+impl<T> ConjugateTranspose for General<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    /// Conjugate transpose of a matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{ConjugateTranspose, General};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<Complex<f64>> = matrix![Complex::new(1.0, 2.0), Complex::new(3.0, -1.0)];
+    ///
+    /// let a_h: General<Complex<f64>> = matrix![Complex::new(1.0, -2.0);
+    ///                                          Complex::new(3.0, 1.0)];
+    ///
+    /// assert_eq!(a_h, a.conj_transpose());
+    /// ```
+    fn conj_transpose(self) -> General<T> {
+        let mut a: General<T> = self.transpose();
+        a.data.iter_mut().for_each(|a_ij| *a_ij = a_ij.conj());
+        a
+    }
+}
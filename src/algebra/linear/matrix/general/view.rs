@@ -0,0 +1,147 @@
+use crate::algebra::linear::matrix::{General, MatrixView, MatrixViewMut};
+
+impl<T> General<T> {
+    /// Returns a view of the whole matrix
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView::new(&self.data, self.m, self.n, 1, self.m)
+    }
+
+    /// Returns a mutable view of the whole matrix
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (m, n): (usize, usize) = (self.m, self.n);
+        MatrixViewMut::new(&mut self.data, m, n, 1, m)
+    }
+
+    /// Returns a view of a sub-block without copying
+    ///
+    /// In contrast to [`get_slice`](General::get_slice) the elements are
+    /// borrowed from the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// 0 <= row_s <= row_e < m \
+    /// 0 <= column_s <= column_e < n \
+    ///
+    /// row_s: start row \
+    /// row_e: end row \
+    /// column_s: start column \
+    /// column_e: end column \
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, MatrixView};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, -2.0, 4.0;
+    ///                                 3.0, -7.0, 5.0];
+    ///
+    /// let b: MatrixView<f64> = a.view_slice(0, 1, 1, 2);
+    ///
+    /// assert_eq!(b.dim(), (2, 2));
+    /// assert_eq!(b[[1, 0]], -7.0);
+    /// ```
+    pub fn view_slice(
+        &self,
+        row_s: usize,
+        row_e: usize,
+        column_s: usize,
+        column_e: usize,
+    ) -> MatrixView<'_, T> {
+        self.view().slice(row_s, row_e, column_s, column_e)
+    }
+
+    /// Returns a mutable view of a sub-block without copying
+    ///
+    /// In contrast to [`set_slice`](General::set_slice) the matrix is
+    /// changed in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let mut a: General<f64> = General::zero(2, 3);
+    /// let b: General<f64> = matrix![1.0, 2.0];
+    ///
+    /// a.view_slice_mut(1, 1, 1, 2).copy_from(&b.view());
+    ///
+    /// assert_eq!(a, matrix![  0.0, 0.0, 0.0;
+    ///                         0.0, 1.0, 2.0]);
+    /// ```
+    pub fn view_slice_mut(
+        &mut self,
+        row_s: usize,
+        row_e: usize,
+        column_s: usize,
+        column_e: usize,
+    ) -> MatrixViewMut<'_, T> {
+        assert!(row_s <= row_e && row_e < self.m);
+        assert!(column_s <= column_e && column_e < self.n);
+
+        let m: usize = self.m;
+        let offset: usize = column_s * m + row_s;
+        MatrixViewMut::new(
+            &mut self.data[offset..],
+            row_e - row_s + 1,
+            column_e - column_s + 1,
+            1,
+            m,
+        )
+    }
+
+    /// Returns a 1 x n view of the row i
+    pub fn view_row(&self, i: usize) -> MatrixView<'_, T> {
+        self.view().row(i)
+    }
+
+    /// Returns a mutable 1 x n view of the row i
+    pub fn view_row_mut(&mut self, i: usize) -> MatrixViewMut<'_, T> {
+        assert!(i < self.m);
+        let (m, n): (usize, usize) = (self.m, self.n);
+        // SAFETY: the row i lies within the matrix, which is borrowed
+        // mutably for the lifetime of the view
+        unsafe { MatrixViewMut::from_raw_parts(self.data.as_mut_ptr().add(i), 1, n, 1, m) }
+    }
+
+    /// Returns a m x 1 view of the column j
+    pub fn view_column(&self, j: usize) -> MatrixView<'_, T> {
+        self.view().column(j)
+    }
+
+    /// Returns a mutable m x 1 view of the column j
+    pub fn view_column_mut(&mut self, j: usize) -> MatrixViewMut<'_, T> {
+        assert!(j < self.n);
+        let m: usize = self.m;
+        MatrixViewMut::new(&mut self.data[j * m..(j + 1) * m], m, 1, 1, m)
+    }
+
+    /// Returns a min(m, n) x 1 view of the main diagonal
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let mut a: General<f64> = matrix![  1.0, 2.0;
+    ///                                     3.0, 4.0];
+    ///
+    /// assert_eq!(General::from(a.view_diagonal()), matrix![1.0; 4.0]);
+    ///
+    /// a.view_diagonal_mut().fill(0.0);
+    ///
+    /// assert_eq!(a, matrix![0.0, 2.0; 3.0, 0.0]);
+    /// ```
+    pub fn view_diagonal(&self) -> MatrixView<'_, T> {
+        self.view().diagonal()
+    }
+
+    /// Returns a mutable min(m, n) x 1 view of the main diagonal
+    pub fn view_diagonal_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let k: usize = self.m.min(self.n);
+        let stride: usize = self.m + 1;
+        MatrixViewMut::new(&mut self.data, k, 1, stride, 0)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Complex, Real, Zero},
+    linear::matrix::{General, SymmetricEigenDec},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Generalized eigen decomposition
+///
+/// ```math
+/// Ax = \lambda Bx
+/// ```
+pub trait GeneralizedEigenDecomposition<T> {
+    fn dec_eigen_generalized(&self, b: &General<T>) -> Result<GeneralizedEigenDec<T>, LinalgError>;
+}
+
+/// Generalized eigen decomposition of a symmetric matrix $A$ and a symmetric
+/// positive definite matrix $B$
+///
+/// The eigenvalues are real and sorted in ascending order. The eigenvectors
+/// are $B$-orthonormal, that means $X^TBX = I$.
+pub trait SymmetricGeneralizedEigenDecomposition<T> {
+    fn dec_symmetric_eigen_generalized(
+        &self,
+        b: &General<T>,
+    ) -> Result<SymmetricEigenDec<T>, LinalgError>;
+}
+
+/// Result of a generalized eigen decomposition
+///
+/// The eigenvalues are represented as pairs $(\alpha_j, \beta_j)$ with
+/// $\lambda_j = \alpha_j / \beta_j$ and $\beta_j \geq 0$. A zero $\beta_j$
+/// denotes an infinite eigenvalue, which occurs if $B$ is singular.
+/// The right eigenvectors $x_j$ are the columns of the matrix of eigenvectors
+/// and have unit euclidean norm.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct GeneralizedEigenDec<T> {
+    alpha: Vec<Complex<T>>,
+    beta: Vec<T>,
+    vectors: General<Complex<T>>,
+}
+
+impl<T> GeneralizedEigenDec<T> {
+    /// Return the numerators of the eigenvalues
+    pub fn alpha(self) -> Vec<Complex<T>> {
+        self.alpha
+    }
+
+    /// Return the denominators of the eigenvalues
+    pub fn beta(self) -> Vec<T> {
+        self.beta
+    }
+
+    /// Return the right eigenvectors
+    pub fn vectors(self) -> General<Complex<T>> {
+        self.vectors
+    }
+}
+
+impl<T> GeneralizedEigenDec<T>
+where
+    T: Real,
+{
+    /// Normalizes the eigenvectors to unit euclidean norm with a real
+    /// largest entry
+    pub(super) fn new(
+        alpha: Vec<Complex<T>>,
+        beta: Vec<T>,
+        mut vectors: General<Complex<T>>,
+    ) -> GeneralizedEigenDec<T> {
+        let (m, n): (usize, usize) = vectors.dim();
+        for j in 0..n {
+            let column: &mut [Complex<T>] = &mut vectors.data[j * m..(j + 1) * m];
+            let modulus = |z: &Complex<T>| (z.re * z.re + z.im * z.im).sqrt();
+
+            let norm: T = column
+                .iter()
+                .fold(T::zero(), |s, x| s + x.re * x.re + x.im * x.im)
+                .sqrt();
+            let x_max: Complex<T> = column.iter().fold(Complex::zero(), |x_max, x| {
+                if modulus(x) > modulus(&x_max) {
+                    *x
+                } else {
+                    x_max
+                }
+            });
+            if norm == T::zero() {
+                continue;
+            }
+
+            let f: Complex<T> = x_max.conj() / Complex::new(modulus(&x_max) * norm, T::zero());
+            column.iter_mut().for_each(|x| *x *= f);
+        }
+
+        GeneralizedEigenDec {
+            alpha,
+            beta,
+            vectors,
+        }
+    }
+
+    /// Return the eigenvalues
+    ///
+    /// Infinite eigenvalues have an infinite real part.
+    pub fn values(self) -> Vec<Complex<T>> {
+        self.alpha
+            .iter()
+            .zip(self.beta.iter())
+            .map(|(alpha, beta)| {
+                if *beta == T::zero() {
+                    Complex::new(T::infinity(), T::zero())
+                } else {
+                    Complex::new(alpha.re / *beta, alpha.im / *beta)
+                }
+            })
+            .collect()
+    }
+
+    /// Return the eigenvalues and the right eigenvectors
+    pub fn pair(self) -> (Vec<Complex<T>>, General<Complex<T>>) {
+        let vectors: General<Complex<T>> = self.vectors.clone();
+        (self.values(), vectors)
+    }
+}
@@ -0,0 +1,194 @@
+use crate::algebra::abstr::{Complex, Real, Scalar};
+use crate::algebra::linear::matrix::{General, UpperTriangular};
+use crate::algebra::linear::LinalgError;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Generalized Schur decomposition of a matrix pencil $(A, B)$
+pub trait GeneralizedSchurDecomposition<T> {
+    fn dec_generalized_schur(&self, b: &General<T>) -> Result<GeneralizedSchurDec<T>, LinalgError>;
+}
+
+/// Result of a generalized Schur decomposition
+///
+/// ```math
+/// A = QSZ^* \\\\
+/// B = QTZ^*
+/// ```
+/// with unitary matrices $Q$, $Z$ and upper triangular matrices $S$, $T$. The
+/// generalized eigenvalues are the ratios $s_{ii} / t_{ii}$.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct GeneralizedSchurDec<T> {
+    q: General<T>,
+    z: General<T>,
+    s: UpperTriangular<T>,
+    t: UpperTriangular<T>,
+}
+
+impl<T> GeneralizedSchurDec<T> {
+    pub(super) fn new(
+        q: General<T>,
+        z: General<T>,
+        s: UpperTriangular<T>,
+        t: UpperTriangular<T>,
+    ) -> GeneralizedSchurDec<T> {
+        GeneralizedSchurDec { q, z, s, t }
+    }
+
+    /// Return the left Schur vectors
+    pub fn q(self) -> General<T> {
+        self.q
+    }
+
+    /// Return the right Schur vectors
+    pub fn z(self) -> General<T> {
+        self.z
+    }
+
+    /// Return the upper triangular factor of A
+    pub fn s(self) -> UpperTriangular<T> {
+        self.s
+    }
+
+    /// Return the upper triangular factor of B
+    pub fn t(self) -> UpperTriangular<T> {
+        self.t
+    }
+
+    /// Return the matrices Q, Z, S and T
+    pub fn qzst(
+        self,
+    ) -> (
+        General<T>,
+        General<T>,
+        UpperTriangular<T>,
+        UpperTriangular<T>,
+    ) {
+        (self.q, self.z, self.s, self.t)
+    }
+}
+
+impl<T> GeneralizedSchurDec<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Reorders the generalized Schur decomposition $(A, B) = (QSZ^*,
+    /// QTZ^*)$, such that the selected generalized eigenvalues are the leading
+    /// diagonal entries of $(S, T)$
+    ///
+    /// Adjacent diagonal entries are swapped with Givens rotations from the
+    /// left and the right, which are accumulated in $Q$ and $Z$ (like
+    /// LAPACK's xtgsen). The relative order of the selected and the remaining
+    /// eigenvalues is preserved. The first $k$ columns of the reordered $Z$
+    /// are an orthonormal basis of the right deflating subspace belonging to
+    /// the $k$ selected eigenvalues.
+    ///
+    /// # Arguments
+    ///
+    /// * `select`: select\[i\] is true if the eigenvalue $s_{ii} / t_{ii}$
+    ///   is moved to the leading block
+    ///
+    /// # Panics
+    ///
+    /// if the length of select does not match the dimension of $S$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{ConjugateTranspose, General, GeneralizedSchurDecomposition};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<Complex<f64>> = matrix![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0);
+    ///                                        Complex::new(0.0, 0.0), Complex::new(4.0, 0.0)];
+    /// let b: General<Complex<f64>> = matrix![Complex::new(2.0, 0.0), Complex::new(1.0, 0.0);
+    ///                                        Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+    ///
+    /// // Eigenvalues 1/2 and 4, move 4 to the front
+    /// let (q, z, s, t) = a.dec_generalized_schur(&b).unwrap().reorder(&[false, true]).qzst();
+    /// let s: General<Complex<f64>> = s.into();
+    /// let t: General<Complex<f64>> = t.into();
+    /// let z_h: General<Complex<f64>> = z.conj_transpose();
+    ///
+    /// assert_relative_eq!(s[[0, 0]] / t[[0, 0]], Complex::new(4.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// assert_relative_eq!(&(&q * &s) * &z_h, a, epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// assert_relative_eq!(&(&q * &t) * &z_h, b, epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// ```
+    pub fn reorder(self, select: &[bool]) -> GeneralizedSchurDec<Complex<T>> {
+        let mut s: General<Complex<T>> = self.s.into();
+        let mut t: General<Complex<T>> = self.t.into();
+        let mut q: General<Complex<T>> = self.q;
+        let mut z: General<Complex<T>> = self.z;
+        let n: usize = s.nrows();
+        assert_eq!(
+            select.len(),
+            n,
+            "The length of select must match the dimension of the generalized Schur form"
+        );
+
+        let mut ks: usize = 0;
+        for (k, select_k) in select.iter().enumerate() {
+            if !select_k {
+                continue;
+            }
+            for j in (ks..k).rev() {
+                GeneralizedSchurDec::swap(&mut s, &mut t, &mut q, &mut z, j);
+            }
+            ks += 1;
+        }
+
+        GeneralizedSchurDec::new(q, z, UpperTriangular::new(s), UpperTriangular::new(t))
+    }
+
+    /// Swaps the generalized eigenvalues $s_{kk} / t_{kk}$ and $s_{k+1,k+1} /
+    /// t_{k+1,k+1}$ with unitary equivalence transformations
+    fn swap(
+        s: &mut General<Complex<T>>,
+        t: &mut General<Complex<T>>,
+        q: &mut General<Complex<T>>,
+        z: &mut General<Complex<T>>,
+        k: usize,
+    ) {
+        let n: usize = s.nrows();
+        let s_11: Complex<T> = s[[k, k]];
+        let s_12: Complex<T> = s[[k, k + 1]];
+        let s_22: Complex<T> = s[[k + 1, k + 1]];
+        let t_11: Complex<T> = t[[k, k]];
+        let t_12: Complex<T> = t[[k, k + 1]];
+        let t_22: Complex<T> = t[[k + 1, k + 1]];
+
+        // Right eigenvector of the 2 x 2 pencil belonging to s_22 / t_22
+        let x_1: Complex<T> = -(t_22 * s_12 - s_22 * t_12);
+        let x_2: Complex<T> = t_22 * s_11 - s_22 * t_11;
+        if General::modulus(x_1) + General::modulus(x_2) == T::zero() {
+            return;
+        }
+
+        // The rotation from the right maps the first unit vector onto the
+        // eigenvector
+        let (c, sn) = General::rotation(x_1, x_2);
+        General::rotate_right(s, k, c, sn, k + 1);
+        General::rotate_right(t, k, c, sn, k + 1);
+        General::rotate_right(z, k, c, sn, n - 1);
+
+        // The first columns of S and T are parallel, the rotation from the
+        // left annihilates their subdiagonal entries
+        let (y_1, y_2) = if General::modulus(s[[k, k]]) + General::modulus(s[[k + 1, k]])
+            >= General::modulus(t[[k, k]]) + General::modulus(t[[k + 1, k]])
+        {
+            (s[[k, k]], s[[k + 1, k]])
+        } else {
+            (t[[k, k]], t[[k + 1, k]])
+        };
+        let (c, sn) = General::rotation(y_1, y_2);
+        General::rotate_left(s, k, c, sn, k);
+        General::rotate_left(t, k, c, sn, k);
+        General::rotate_right(q, k, c, sn, n - 1);
+
+        s[[k + 1, k]] = Complex::new(T::zero(), T::zero());
+        t[[k + 1, k]] = Complex::new(T::zero(), T::zero());
+    }
+}
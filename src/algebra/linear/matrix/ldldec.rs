@@ -0,0 +1,262 @@
+//! LDL decomposition with Bunch-Kaufman pivoting
+//!
+//! The LDL decomposition of a symmetric, possibly indefinite matrix $A$ is
+//! ```math
+//! PAP^T = LDL^T
+//! ```
+//! with a permutation matrix $P$, a unit lower triangular matrix $L$ and a
+//! block diagonal matrix $D$ with $1 \times 1$ and $2 \times 2$ blocks.
+//! Contrary to the Cholesky decomposition, $A$ does not have to be positive
+//! definite, so saddle-point and KKT systems can be solved while the symmetry
+//! is preserved.
+//!
+//! ```
+//! use mathru::algebra::linear::matrix::{General, LDLDecomposition, Solve};
+//! use mathru::algebra::linear::vector::Vector;
+//! use mathru::{assert_relative_eq, matrix, vector};
+//!
+//! let a: General<f64> = matrix![  0.0, 1.0, 2.0;
+//!                                 1.0, 0.0, 3.0;
+//!                                 2.0, 3.0, 0.0];
+//! let b: Vector<f64> = vector![3.0; 4.0; 5.0];
+//!
+//! let x: Vector<f64> = a.dec_ldl().unwrap().solve(&b).unwrap();
+//!
+//! assert_relative_eq!(x, vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+//! ```
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{
+            Determinant, General, Solve, SubstituteBackward, SubstituteForward, Transpose,
+            UnitLowerTriangular, UnitUpperTriangular,
+        },
+        vector::Vector,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+pub trait LDLDecomposition<T> {
+    fn dec_ldl(&self) -> Result<LDLDec<T>, LinalgError>;
+}
+
+/// Result of a LDL decomposition
+///
+/// ```math
+/// PAP^T = LDL^T
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LDLDec<T> {
+    l: UnitLowerTriangular<T>,
+    d: General<T>,
+    p: General<T>,
+}
+
+impl<T> LDLDec<T> {
+    pub(super) fn new(l: UnitLowerTriangular<T>, d: General<T>, p: General<T>) -> LDLDec<T> {
+        LDLDec { l, d, p }
+    }
+
+    /// Return the unit lower triangular matrix $L$
+    pub fn l(self) -> UnitLowerTriangular<T> {
+        self.l
+    }
+
+    /// Return the block diagonal matrix $D$
+    pub fn d(self) -> General<T> {
+        self.d
+    }
+
+    /// Return the permutation matrix $P$
+    pub fn p(self) -> General<T> {
+        self.p
+    }
+
+    /// Return the matrices $L$, $D$ and $P$
+    pub fn ldp(self) -> (UnitLowerTriangular<T>, General<T>, General<T>) {
+        (self.l, self.d, self.p)
+    }
+}
+
+impl<T> LDLDec<T>
+where
+    T: Real,
+{
+    /// Returns the start index and the size of each diagonal block of $D$
+    fn blocks(&self) -> Vec<(usize, usize)> {
+        let n: usize = self.d.nrows();
+        let mut blocks: Vec<(usize, usize)> = Vec::new();
+        let mut k: usize = 0;
+        while k < n {
+            if k + 1 < n && self.d[[k + 1, k]] != T::zero() {
+                blocks.push((k, 2));
+                k += 2;
+            } else {
+                blocks.push((k, 1));
+                k += 1;
+            }
+        }
+        blocks
+    }
+
+    /// Computes the inertia of the decomposed matrix
+    ///
+    /// By Sylvester's law of inertia, $A$ and $D$ have the same number of
+    /// positive, negative and zero eigenvalues. Eigenvalues of $D$ with an
+    /// absolute value below $n \epsilon \max_{ij} |d_{ij}|$ are counted as
+    /// zero.
+    ///
+    /// # Return
+    ///
+    /// (positive, negative, zero)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, LDLDecomposition};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  2.0, 0.0, 1.0;
+    ///                                 0.0, 0.0, 1.0;
+    ///                                 1.0, 1.0, 0.0];
+    ///
+    /// assert_eq!(a.dec_ldl().unwrap().inertia(), (2, 1, 0));
+    /// ```
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        let n: usize = self.d.nrows();
+        let d_max: T = self.d.iter().fold(
+            T::zero(),
+            |m, d_ij| if d_ij.abs() > m { d_ij.abs() } else { m },
+        );
+        let tolerance: T = T::from_f64(n as f64) * T::default_epsilon() * d_max;
+
+        let mut eigenvalues: Vec<T> = Vec::with_capacity(n);
+        for (k, size) in self.blocks() {
+            if size == 1 {
+                eigenvalues.push(self.d[[k, k]]);
+            } else {
+                let a: T = self.d[[k, k]];
+                let b: T = self.d[[k + 1, k]];
+                let c: T = self.d[[k + 1, k + 1]];
+                let mean: T = (a + c) / T::from_f64(2.0);
+                let half_diff: T = (a - c) / T::from_f64(2.0);
+                let radius: T = (half_diff * half_diff + b * b).sqrt();
+                eigenvalues.push(mean + radius);
+                eigenvalues.push(mean - radius);
+            }
+        }
+
+        eigenvalues
+            .iter()
+            .fold((0, 0, 0), |(pos, neg, zero), lambda| {
+                if lambda.abs() <= tolerance {
+                    (pos, neg, zero + 1)
+                } else if *lambda > T::zero() {
+                    (pos + 1, neg, zero)
+                } else {
+                    (pos, neg + 1, zero)
+                }
+            })
+    }
+
+    /// Solves $DX = B$ block by block
+    fn solve_d(&self, b: &mut General<T>) -> Result<(), LinalgError> {
+        let (_, n_rhs): (usize, usize) = b.dim();
+        for (k, size) in self.blocks() {
+            if size == 1 {
+                let d_kk: T = self.d[[k, k]];
+                if d_kk == T::zero() {
+                    return Err(LinalgError::Singular);
+                }
+                for j in 0..n_rhs {
+                    b[[k, j]] /= d_kk;
+                }
+            } else {
+                let a: T = self.d[[k, k]];
+                let c: T = self.d[[k + 1, k]];
+                let e: T = self.d[[k + 1, k + 1]];
+                let det: T = a * e - c * c;
+                if det == T::zero() {
+                    return Err(LinalgError::Singular);
+                }
+                for j in 0..n_rhs {
+                    let b_0: T = b[[k, j]];
+                    let b_1: T = b[[k + 1, j]];
+                    b[[k, j]] = (e * b_0 - c * b_1) / det;
+                    b[[k + 1, j]] = (a * b_1 - c * b_0) / det;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Determinant<T> for LDLDec<T>
+where
+    T: Real,
+{
+    /// Computes the determinant of the decomposed matrix
+    ///
+    /// The permutations cancel out, so $\det(A) = \det(D)$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Determinant, General, LDLDecomposition};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  0.0, 1.0, 2.0;
+    ///                                 1.0, 0.0, 3.0;
+    ///                                 2.0, 3.0, 0.0];
+    ///
+    /// assert_relative_eq!(a.dec_ldl().unwrap().det(), 12.0, epsilon = 1.0e-10);
+    /// ```
+    fn det(&self) -> T {
+        self.blocks().iter().fold(T::one(), |det, (k, size)| {
+            let k: usize = *k;
+            if *size == 1 {
+                det * self.d[[k, k]]
+            } else {
+                det * (self.d[[k, k]] * self.d[[k + 1, k + 1]]
+                    - self.d[[k + 1, k]] * self.d[[k, k + 1]])
+            }
+        })
+    }
+}
+
+impl<T> Solve<General<T>> for LDLDec<T>
+where
+    T: Real,
+{
+    /// Solves $AX = B$
+    ///
+    /// ```math
+    /// A = P^TLDL^TP \\
+    /// X = P^TL^{-T}D^{-1}L^{-1}PB
+    /// ```
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        let b_hat: General<T> = &self.p * rhs;
+        let mut c: General<T> = self.l.substitute_forward(b_hat)?;
+        self.solve_d(&mut c)?;
+        let l_t: UnitUpperTriangular<T> = self.l.clone().transpose();
+        let y: General<T> = l_t.substitute_backward(c)?;
+
+        Ok(&self.p.clone().transpose() * &y)
+    }
+}
+
+impl<T> Solve<Vector<T>> for LDLDec<T>
+where
+    T: Real,
+{
+    /// Solves $Ax = b$
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let x: General<T> = self.solve(&rhs.data)?;
+
+        Ok(Vector { data: x })
+    }
+}
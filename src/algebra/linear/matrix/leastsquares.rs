@@ -0,0 +1,79 @@
+use crate::algebra::linear::vector::Vector;
+use crate::algebra::linear::LinalgError;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Linear least squares problem
+///
+/// ```math
+/// \min_x \lVert Ax - b \rVert_2
+/// ```
+/// with $A \in T^{m \times n}$ and $b \in T^m$.
+pub trait LeastSquares<T> {
+    /// Solves the least squares problem with a QR decomposition with column
+    /// pivoting
+    ///
+    /// If $A$ is rank deficient, the basic solution with at most rank
+    /// nonzero entries is returned. The result contains no singular values.
+    fn solve_least_squares_qr(&self, b: &Vector<T>) -> Result<LeastSquaresResult<T>, LinalgError>;
+
+    /// Solves the least squares problem with a singular value decomposition
+    ///
+    /// If $A$ is rank deficient, the solution with minimal norm is returned.
+    fn solve_least_squares_svd(&self, b: &Vector<T>) -> Result<LeastSquaresResult<T>, LinalgError>;
+}
+
+/// Result of a linear least squares solver
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LeastSquaresResult<T> {
+    x: Vector<T>,
+    residual: T,
+    rank: usize,
+    singular_values: Option<Vector<T>>,
+}
+
+impl<T> LeastSquaresResult<T> {
+    pub(super) fn new(
+        x: Vector<T>,
+        residual: T,
+        rank: usize,
+        singular_values: Option<Vector<T>>,
+    ) -> LeastSquaresResult<T> {
+        LeastSquaresResult {
+            x,
+            residual,
+            rank,
+            singular_values,
+        }
+    }
+
+    /// Returns the solution
+    pub fn x(&self) -> &Vector<T> {
+        &self.x
+    }
+
+    /// Returns the solution and consumes the result
+    pub fn into_x(self) -> Vector<T> {
+        self.x
+    }
+
+    /// Returns the residual norm $\lVert Ax - b \rVert_2$
+    pub fn residual(&self) -> T
+    where
+        T: Copy,
+    {
+        self.residual
+    }
+
+    /// Returns the effective rank of $A$
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Returns the singular values of $A$ in descending order, if they have
+    /// been computed
+    pub fn singular_values(&self) -> Option<&Vector<T>> {
+        self.singular_values.as_ref()
+    }
+}
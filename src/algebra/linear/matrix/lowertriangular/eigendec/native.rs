@@ -1,6 +1,7 @@
 use crate::algebra::abstr::{AbsDiffEq, Field, Scalar};
 use crate::algebra::linear::matrix::Diagonal;
 use crate::algebra::linear::matrix::{EigenDec, EigenDecomposition, General, LowerTriangular};
+use crate::algebra::linear::LinalgError;
 use crate::elementary::Power;
 
 impl<T> EigenDecomposition<T> for LowerTriangular<T>
@@ -23,7 +24,7 @@ where
     ///
     /// let eigen: EigenDec<f64> = a.dec_eigen().unwrap();
     /// ```
-    fn dec_eigen(&self) -> Result<EigenDec<T>, String> {
+    fn dec_eigen(&self) -> Result<EigenDec<T>, LinalgError> {
         let (m, _): (usize, usize) = self.dim();
 
         let mut eigen_values = Vec::with_capacity(m);
@@ -6,6 +6,7 @@ mod from;
 mod index;
 mod lowertriangular;
 mod mul;
+mod norm;
 mod partial_eq;
 mod relative_eq;
 mod substitute_forward;
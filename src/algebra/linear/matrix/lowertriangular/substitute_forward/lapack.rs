@@ -3,12 +3,13 @@ use crate::algebra::abstr::Scalar;
 use crate::algebra::linear::matrix::substitute::SubstituteForward;
 use crate::algebra::linear::matrix::{General, LowerTriangular};
 use crate::algebra::linear::vector::Vector;
+use crate::algebra::linear::LinalgError;
 
 impl<T> SubstituteForward<Vector<T>> for LowerTriangular<T>
 where
     T: Field + Scalar,
 {
-    fn substitute_forward(&self, b: Vector<T>) -> Result<Vector<T>, ()> {
+    fn substitute_forward(&self, b: Vector<T>) -> Result<Vector<T>, LinalgError> {
         let (b_m, b_n): (usize, usize) = b.dim();
         let mut b_data = b.convert_to_vec();
         let (m, _n) = self.matrix.dim();
@@ -35,7 +36,7 @@ impl<T> SubstituteForward<General<T>> for LowerTriangular<T>
 where
     T: Field + Scalar,
 {
-    fn substitute_forward(&self, b: General<T>) -> Result<General<T>, ()> {
+    fn substitute_forward(&self, b: General<T>) -> Result<General<T>, LinalgError> {
         let mut c: General<T> = b;
         let (m, _n) = self.matrix.dim();
 
@@ -4,12 +4,13 @@ use crate::algebra::abstr::Scalar;
 use crate::algebra::linear::matrix::substitute::SubstituteForward;
 use crate::algebra::linear::matrix::{General, LowerTriangular};
 use crate::algebra::linear::vector::Vector;
+use crate::algebra::linear::LinalgError;
 
 impl<T> SubstituteForward<Vector<T>> for LowerTriangular<T>
 where
     T: Field + Scalar + AbsDiffEq,
 {
-    fn substitute_forward(&self, a: Vector<T>) -> Result<Vector<T>, ()> {
+    fn substitute_forward(&self, a: Vector<T>) -> Result<Vector<T>, LinalgError> {
         let mut b: Vector<T> = a;
         let rows = self.matrix.nrows();
 
@@ -19,7 +20,7 @@ where
             }
             if self[[k, k]] == T::zero() {
                 if b[k] != T::zero() {
-                    return Err(());
+                    return Err(LinalgError::Singular);
                 } else {
                     b[k] = T::one();
                 }
@@ -36,7 +37,7 @@ impl<T> SubstituteForward<General<T>> for LowerTriangular<T>
 where
     T: Field + Scalar + AbsDiffEq,
 {
-    fn substitute_forward(&self, a: General<T>) -> Result<General<T>, ()> {
+    fn substitute_forward(&self, a: General<T>) -> Result<General<T>, LinalgError> {
         let mut b: General<T> = a;
         let rows = self.matrix.nrows();
 
@@ -45,7 +46,7 @@ where
                 b.set_row(&(b.get_row(k) - (b.get_row(l) * self[[k, l]])), k);
             }
             if self[[k, k]] == T::zero() {
-                return Err(());
+                return Err(LinalgError::Singular);
             }
             b.set_row(&(b.get_row(k) / self[[k, k]]), k);
         }
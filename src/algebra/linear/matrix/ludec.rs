@@ -1,11 +1,17 @@
 use crate::algebra::abstr::AbsDiffEq;
+use crate::algebra::abstr::Real;
 use crate::algebra::abstr::RelativeEq;
+use crate::algebra::linear::matrix::condest;
 use crate::algebra::linear::matrix::substitute::SubstituteBackward;
 use crate::algebra::linear::matrix::substitute::SubstituteForward;
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{
     abstr::{Field, Scalar},
     linear::{
-        matrix::{General, Inverse, Solve, UnitLowerTriangular, UpperTriangular},
+        matrix::{
+            General, Inverse, LowerTriangular, Solve, Transpose, UnitLowerTriangular,
+            UnitUpperTriangular, UpperTriangular,
+        },
         vector::Vector,
     },
 };
@@ -15,7 +21,7 @@ use serde::{Deserialize, Serialize};
 use std::clone::Clone;
 
 pub trait LUDecomposition<T> {
-    fn dec_lu(&self) -> Result<LUDec<T>, ()>;
+    fn dec_lu(&self) -> Result<LUDec<T>, LinalgError>;
 }
 
 /// Result of a LU decomposition
@@ -55,6 +61,57 @@ impl<T> LUDec<T> {
     }
 }
 
+impl<T> LUDec<T>
+where
+    T: Real,
+{
+    /// Estimates the condition number with respect to the 1-norm
+    ///
+    /// ```math
+    /// \kappa_1(A) = \lVert A \rVert_1 \lVert A^{-1} \rVert_1
+    /// ```
+    /// $\lVert A^{-1} \rVert_1$ is estimated with Hager's method from a few
+    /// solves with the factors, so the estimate is a lower bound that is
+    /// usually within a factor of three of the exact value. A singular $U$
+    /// yields an infinite condition number.
+    ///
+    /// # Arguments
+    ///
+    /// * `norm_1`: $\lVert A \rVert_1$ of the decomposed matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, LUDecomposition, MatrixNorm};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<f64> = matrix![  4.0, 1.0;
+    ///                                 2.0, 3.0];
+    ///
+    /// let cond: f64 = a.dec_lu().unwrap().cond_1_estimate(a.norm_1());
+    ///
+    /// assert_relative_eq!(cond, 3.0, epsilon = 1.0e-10);
+    /// ```
+    pub fn cond_1_estimate(&self, norm_1: T) -> T {
+        let (n, _): (usize, usize) = self.p.dim();
+        let l_t: UnitUpperTriangular<T> = self.l.clone().transpose();
+        let u_t: LowerTriangular<T> = self.u.clone().transpose();
+        let p_t: General<T> = self.p.clone().transpose();
+
+        let inv_norm_1: T = condest::inv_norm_1_estimate(
+            n,
+            |x| self.solve(&x),
+            |x| {
+                let c: Vector<T> = u_t.substitute_forward(x)?;
+                let d: Vector<T> = l_t.substitute_backward(c)?;
+                Ok(&p_t * &d)
+            },
+        );
+
+        norm_1 * inv_norm_1
+    }
+}
+
 impl<T> Inverse<T> for LUDec<T>
 where
     T: Field + Scalar + AbsDiffEq,
@@ -75,7 +132,7 @@ where
     /// let a: General<f64> = General::new(2, 2, vec![1.0, 0.0, 3.0, -7.0]);
     /// let b_inv: General<f64> = a.inv().unwrap();
     /// ```
-    fn inv(&self) -> Result<General<T>, ()> {
+    fn inv(&self) -> Result<General<T>, LinalgError> {
         let b = General::one(self.p.nrows());
         let x: General<T> = self.solve(&b)?;
         Ok(x)
@@ -100,7 +157,7 @@ where
     /// L * c = b_hat{x} \\
     /// U * x = c\\
     /// ```
-    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, ()> {
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
         let b_hat: Vector<T> = &self.p * rhs;
         let c: Vector<T> = self.l.substitute_forward(b_hat)?;
         self.u.substitute_backward(c)
@@ -111,10 +168,266 @@ impl<T> Solve<General<T>> for LUDec<T>
 where
     T: Field + Scalar + AbsDiffEq,
 {
-    fn solve(&self, rhs: &General<T>) -> Result<General<T>, ()> {
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
         let b_hat: General<T> = &self.p * rhs;
 
         let c: General<T> = self.l.substitute_forward(b_hat)?;
         self.u.substitute_backward(c)
     }
 }
+
+impl<T> LUDec<T>
+where
+    T: Field + Scalar,
+{
+    /// Updates the decomposition of $A$ to the decomposition of $A + uv^T$
+    ///
+    /// The factors are updated with Bennett's algorithm in $O(n^2)$
+    /// operations. The permutation is kept, i.e. no new pivoting is done,
+    /// so a refactorization is advisable if the update causes small pivots.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the lengths of $u$ and $v$
+    /// differ from the dimension of $A$ and [`LinalgError::Singular`] if a
+    /// pivot of the updated factors vanishes. The decomposition is left
+    /// unchanged in both cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, LUDecomposition, Solve};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  4.0, 1.0;
+    ///                                 2.0, 3.0];
+    ///
+    /// let mut dec = a.dec_lu().unwrap();
+    /// dec.rank_one_update(&vector![1.0; 0.0], &vector![0.0; 1.0]).unwrap();
+    ///
+    /// let x: Vector<f64> = dec.solve(&vector![6.0; 5.0]).unwrap();
+    /// assert_relative_eq!(x, vector![1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    pub fn rank_one_update(&mut self, u: &Vector<T>, v: &Vector<T>) -> Result<(), LinalgError> {
+        let n: usize = self.p.nrows();
+        if u.dim() != (n, 1) || v.dim() != (n, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        // PA + (Pu)v^T = LU + (Pu)v^T
+        let x: Vec<T> = (0..n)
+            .map(|i| (0..n).fold(T::zero(), |s, j| s + self.p[[i, j]] * u[j]))
+            .collect();
+        let y: Vec<T> = (0..n).map(|j| v[j]).collect();
+
+        let mut l: General<T> = self.l.matrix.clone();
+        let mut r: General<T> = self.u.matrix.clone();
+        rank_one_update(&mut l, &mut r, x, y, 0)?;
+
+        self.l.matrix = l;
+        self.u.matrix = r;
+        Ok(())
+    }
+
+    /// Updates the decomposition of $A$ to the decomposition of the matrix
+    /// with the row `row` and the column `column` inserted at the index `k`
+    ///
+    /// Both vectors have the dimension of the enlarged matrix and the new
+    /// diagonal entry is `column[k]`, `row[k]` is ignored. As for
+    /// [`rank_one_update`](LUDec::rank_one_update), no new pivoting is
+    /// done.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if `k` is greater than the
+    /// dimension of $A$ or the vectors have not the dimension of the
+    /// enlarged matrix and [`LinalgError::Singular`] if a pivot of the
+    /// updated factors vanishes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, LUDecomposition, Solve};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  4.0, 1.0;
+    ///                                 2.0, 3.0];
+    ///
+    /// let mut dec = a.dec_lu().unwrap();
+    /// dec.insert_row_column(2, &vector![1.0; 1.0; 5.0], &vector![0.0; 1.0; 5.0]).unwrap();
+    ///
+    /// // [4, 1, 0; 2, 3, 1; 1, 1, 5] x = [5; 6; 7]
+    /// let x: Vector<f64> = dec.solve(&vector![5.0; 6.0; 7.0]).unwrap();
+    /// assert_relative_eq!(x, vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    pub fn insert_row_column(
+        &mut self,
+        k: usize,
+        row: &Vector<T>,
+        column: &Vector<T>,
+    ) -> Result<(), LinalgError> {
+        let n: usize = self.p.nrows();
+        if k > n || row.dim() != (n + 1, 1) || column.dim() != (n + 1, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        // The factors of the matrix B with the unit row and column inserted
+        // at k are the factors of A with the unit row and column inserted at
+        // k. The enlarged matrix is B + (column - e_k)e_k^T + e_k row^T with
+        // row[k] = 0.
+        let index = |i: usize| if i < k { i } else { i + 1 };
+        let mut p: General<T> = General::zero(n + 1, n + 1);
+        let mut l: General<T> = General::zero(n + 1, n + 1);
+        let mut r: General<T> = General::zero(n + 1, n + 1);
+        for j in 0..n {
+            for i in 0..n {
+                p[[index(i), index(j)]] = self.p[[i, j]];
+                l[[index(i), index(j)]] = self.l.matrix[[i, j]];
+                r[[index(i), index(j)]] = self.u.matrix[[i, j]];
+            }
+        }
+        p[[k, k]] = T::one();
+        l[[k, k]] = T::one();
+        r[[k, k]] = T::one();
+
+        let mut c: Vec<T> = (0..=n).map(|i| column[i]).collect();
+        c[k] -= T::one();
+        let x: Vec<T> = (0..=n)
+            .map(|i| (0..=n).fold(T::zero(), |s, j| s + p[[i, j]] * c[j]))
+            .collect();
+        let mut y: Vec<T> = vec![T::zero(); n + 1];
+        y[k] = T::one();
+        rank_one_update(&mut l, &mut r, x, y, 0)?;
+
+        // P e_k = e_k
+        let mut x: Vec<T> = vec![T::zero(); n + 1];
+        x[k] = T::one();
+        let mut y: Vec<T> = (0..=n).map(|j| row[j]).collect();
+        y[k] = T::zero();
+        rank_one_update(&mut l, &mut r, x, y, 0)?;
+
+        self.p = p;
+        self.l.matrix = l;
+        self.u.matrix = r;
+        Ok(())
+    }
+
+    /// Updates the decomposition of $A$ to the decomposition of the matrix
+    /// with the row and column `k` removed
+    ///
+    /// As for [`rank_one_update`](LUDec::rank_one_update), no new pivoting
+    /// is done.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if `k` is not less than the
+    /// dimension of $A$ and [`LinalgError::Singular`] if a pivot of the
+    /// updated factors vanishes
+    pub fn remove_row_column(&mut self, k: usize) -> Result<(), LinalgError> {
+        let n: usize = self.p.nrows();
+        if k >= n {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        let mut l: General<T> = self.l.matrix.clone();
+
+        // Removing the column k of U leaves an upper Hessenberg matrix from the
+        // column k on, whose subdiagonal is eliminated by row operations
+        let mut u: General<T> = General::zero(n, n - 1);
+        for j in (0..n).filter(|j| *j != k) {
+            let j_new: usize = if j < k { j } else { j - 1 };
+            for i in 0..n {
+                u[[i, j_new]] = self.u.matrix[[i, j]];
+            }
+        }
+        for j in k..n - 1 {
+            if u[[j + 1, j]] == T::zero() {
+                continue;
+            }
+            if u[[j, j]] == T::zero() {
+                return Err(LinalgError::Singular);
+            }
+            let mu: T = u[[j + 1, j]] / u[[j, j]];
+            for c in j + 1..n - 1 {
+                let u_jc: T = u[[j, c]];
+                u[[j + 1, c]] -= mu * u_jc;
+            }
+            u[[j + 1, j]] = T::zero();
+            for i in 0..n {
+                let l_ij1: T = l[[i, j + 1]];
+                l[[i, j]] += mu * l_ij1;
+            }
+        }
+
+        // The row k of A is the row i of PA. Removing the row i of L and U
+        // leaves the rank-one term l_i u_i^T of the trailing block.
+        let i: usize = (0..n).find(|i| self.p[[*i, k]] != T::zero()).unwrap();
+        let mut l_new: General<T> = General::zero(n - 1, n - 1);
+        let mut u_new: General<T> = General::zero(n - 1, n - 1);
+        let mut p_new: General<T> = General::zero(n - 1, n - 1);
+        let mut x: Vec<T> = vec![T::zero(); n - 1];
+        let mut y: Vec<T> = vec![T::zero(); n - 1];
+        for r in (0..n).filter(|r| *r != i) {
+            let r_new: usize = if r < i { r } else { r - 1 };
+            for c in 0..n - 1 {
+                u_new[[r_new, c]] = u[[r, c]];
+            }
+            for c in (0..n).filter(|c| *c != i) {
+                let c_new: usize = if c < i { c } else { c - 1 };
+                l_new[[r_new, c_new]] = l[[r, c]];
+            }
+            for c in (0..n).filter(|c| *c != k) {
+                let c_new: usize = if c < k { c } else { c - 1 };
+                p_new[[r_new, c_new]] = self.p[[r, c]];
+            }
+        }
+        for r in i + 1..n {
+            x[r - 1] = l[[r, i]];
+        }
+        for c in i..n - 1 {
+            y[c] = u[[i, c]];
+        }
+        rank_one_update(&mut l_new, &mut u_new, x, y, i)?;
+
+        self.p = p_new;
+        self.l.matrix = l_new;
+        self.u.matrix = u_new;
+        Ok(())
+    }
+}
+
+/// Updates the trailing blocks $L_{k:n,k:n}$ and $U_{k:n,k:n}$ to the
+/// factors of $LU + xy^T$ with Bennett's algorithm, where $x$ and $y$ are
+/// zero above the index `k`
+fn rank_one_update<T>(
+    l: &mut General<T>,
+    u: &mut General<T>,
+    mut x: Vec<T>,
+    mut y: Vec<T>,
+    k: usize,
+) -> Result<(), LinalgError>
+where
+    T: Field + Scalar,
+{
+    let n: usize = u.nrows();
+    for j in k..n {
+        u[[j, j]] += x[j] * y[j];
+        if u[[j, j]] == T::zero() {
+            return Err(LinalgError::Singular);
+        }
+        y[j] /= u[[j, j]];
+        let (x_j, y_j): (T, T) = (x[j], y[j]);
+
+        for i in j + 1..n {
+            x[i] -= x_j * l[[i, j]];
+            l[[i, j]] += y_j * x[i];
+        }
+        for c in j + 1..n {
+            u[[j, c]] += x_j * y[c];
+            y[c] -= y_j * u[[j, c]];
+        }
+    }
+    Ok(())
+}
@@ -1,29 +1,61 @@
-use crate::algebra::abstr::{Field, Scalar};
-use crate::algebra::linear::{vector::Vector};
-use crate::vector;
-use std::slice::Iter;
-
-pub struct MatrixColumnIterator<'a, T>
-{
-    iter: Iter<'a, T>,
-    column: usize
+use super::MatrixView;
+use std::iter::FusedIterator;
+
+/// Iterator over the columns of a matrix
+///
+/// The columns are yielded as m x 1 views into the matrix.
+#[derive(Debug, Clone)]
+pub struct MatrixColumnIterator<'a, T> {
+    view: MatrixView<'a, T>,
+    front: usize,
+    back: usize,
 }
 
-impl<'a, T> MatrixColumnIterator<'a, T>
-{
-    pub fn new(iter: Iter<'a, T>) -> MatrixColumnIterator<'a, T>
-    {
-        MatrixColumnIterator{iter}
+impl<'a, T> MatrixColumnIterator<'a, T> {
+    pub fn new(view: MatrixView<'a, T>) -> MatrixColumnIterator<'a, T> {
+        MatrixColumnIterator {
+            view,
+            front: 0,
+            back: view.ncols(),
+        }
     }
 }
 
-impl<'a, T> Iterator for MatrixColumnIterator<'a, T> where T: Field + Scalar
-{
-    type Item = Vector<T>;
+impl<'a, T> Iterator for MatrixColumnIterator<'a, T> {
+    type Item = MatrixView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let column: MatrixView<'a, T> = self.view.column(self.front);
+            self.front += 1;
+            Some(column)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
 
-    // just return the str reference
-    fn next(&mut self) -> Option<Self::Item>
-    {
-        None
+impl<'a, T> DoubleEndedIterator for MatrixColumnIterator<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.view.column(self.back))
+        } else {
+            None
+        }
     }
 }
+
+impl<'a, T> ExactSizeIterator for MatrixColumnIterator<'a, T> {}
+
+impl<'a, T> FusedIterator for MatrixColumnIterator<'a, T> {}
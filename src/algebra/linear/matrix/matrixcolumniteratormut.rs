@@ -1,27 +1,82 @@
-use crate::algebra::abstr::{Field, Scalar};
-use crate::algebra::linear::vector::Vector;
-use std::slice::IterMut;
+use super::MatrixViewMut;
+use std::iter::FusedIterator;
 
-pub struct MatrixColumnIteratorMut<'a, T>
-{
-    iter: IterMut<'a, T>,
+/// Iterator over the mutable columns of a matrix
+///
+/// The columns are yielded as mutable m x 1 views into the matrix.
+#[derive(Debug)]
+pub struct MatrixColumnIteratorMut<'a, T> {
+    view: MatrixViewMut<'a, T>,
+    front: usize,
+    back: usize,
 }
 
-impl<'a, T> MatrixColumnIteratorMut<'a, T>
-{
-    pub fn new(iter: IterMut<'a, T>) -> MatrixColumnIteratorMut<'a, T>
-    {
-        return MatrixColumnIteratorMut{iter}
+impl<'a, T> MatrixColumnIteratorMut<'a, T> {
+    /// # Panics
+    ///
+    /// if elements of the view overlap
+    pub fn new(view: MatrixViewMut<'a, T>) -> MatrixColumnIteratorMut<'a, T> {
+        assert!(view.is_disjoint(), "Elements of the view overlap");
+        let back: usize = view.ncols();
+        MatrixColumnIteratorMut {
+            view,
+            front: 0,
+            back,
+        }
+    }
+
+    fn column(&mut self, j: usize) -> MatrixViewMut<'a, T> {
+        let m: usize = self.view.nrows();
+        let (row_stride, col_stride): (usize, usize) = self.view.strides();
+        // SAFETY: the columns of a view without overlapping elements are
+        // disjoint and every column is handed out once
+        unsafe {
+            MatrixViewMut::from_raw_parts(
+                self.view.as_mut_ptr().add(j * col_stride),
+                m,
+                1,
+                row_stride,
+                col_stride,
+            )
+        }
     }
 }
 
-impl<'a, T> Iterator for MatrixColumnIteratorMut<'a, T>
-    where T: Field + Scalar
-{
-    type Item = &'a mut Vector<T>;
+impl<'a, T> Iterator for MatrixColumnIteratorMut<'a, T> {
+    type Item = MatrixViewMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let column: MatrixViewMut<'a, T> = self.column(self.front);
+            self.front += 1;
+            Some(column)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.back - self.front;
+        (len, Some(len))
+    }
 
-    fn next(&mut self) -> Option<Self::Item>
-    {
-        return Some(&mut vector![T::zero()]);
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
     }
 }
+
+impl<'a, T> DoubleEndedIterator for MatrixColumnIteratorMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.column(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for MatrixColumnIteratorMut<'a, T> {}
+
+impl<'a, T> FusedIterator for MatrixColumnIteratorMut<'a, T> {}
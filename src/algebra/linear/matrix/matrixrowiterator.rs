@@ -1,27 +1,61 @@
-use crate::algebra::abstr::{Field, Scalar, Zero};
-use crate::algebra::linear::{vector::Vector};
-use std::slice::Iter;
+use super::MatrixView;
+use std::iter::FusedIterator;
 
-pub struct MatrixRowIterator<'a, T>
-{
-    iter: Iter<'a, T>,
+/// Iterator over the rows of a matrix
+///
+/// The rows are yielded as 1 x n views into the matrix.
+#[derive(Debug, Clone)]
+pub struct MatrixRowIterator<'a, T> {
+    view: MatrixView<'a, T>,
+    front: usize,
+    back: usize,
 }
 
-impl<'a, T> MatrixRowIterator<'a, T>
-    where T: Zero
-{
-    pub fn new(iter: Iter<'a, T>) -> MatrixRowIterator<'a, T>
-    {
-        return MatrixRowIterator{iter};
+impl<'a, T> MatrixRowIterator<'a, T> {
+    pub fn new(view: MatrixView<'a, T>) -> MatrixRowIterator<'a, T> {
+        MatrixRowIterator {
+            view,
+            front: 0,
+            back: view.nrows(),
+        }
     }
 }
 
-impl<'a, T> Iterator for MatrixRowIterator<'a, T> where T: Field + Scalar
-{
-    type Item = &'a Vector<T>;
+impl<'a, T> Iterator for MatrixRowIterator<'a, T> {
+    type Item = MatrixView<'a, T>;
 
-    fn next(&'a mut self) -> Option<Self::Item>
-    {
-        Some(&self.vec)
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let row: MatrixView<'a, T> = self.view.row(self.front);
+            self.front += 1;
+            Some(row)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
     }
 }
+
+impl<'a, T> DoubleEndedIterator for MatrixRowIterator<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.view.row(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for MatrixRowIterator<'a, T> {}
+
+impl<'a, T> FusedIterator for MatrixRowIterator<'a, T> {}
@@ -1,28 +1,82 @@
-use crate::algebra::abstr::{Field, Scalar, Zero};
-use crate::algebra::linear::{vector::Vector};
-use crate::vector;
-use std::slice::IterMut;
-
-pub struct MatrixRowIteratorMut<'a, T>
-{
-    iter: IterMut<'a, T>,
+use super::MatrixViewMut;
+use std::iter::FusedIterator;
+
+/// Iterator over the mutable rows of a matrix
+///
+/// The rows are yielded as mutable 1 x n views into the matrix.
+#[derive(Debug)]
+pub struct MatrixRowIteratorMut<'a, T> {
+    view: MatrixViewMut<'a, T>,
+    front: usize,
+    back: usize,
 }
 
-impl<'a, T> MatrixRowIteratorMut<'a, T>
-    where T: Zero
-{
-    pub fn new(iter: IterMut<'a, T>) -> MatrixRowIteratorMut<'a, T>
-    {
-        return MatrixRowIteratorMut{iter};
+impl<'a, T> MatrixRowIteratorMut<'a, T> {
+    /// # Panics
+    ///
+    /// if elements of the view overlap
+    pub fn new(view: MatrixViewMut<'a, T>) -> MatrixRowIteratorMut<'a, T> {
+        assert!(view.is_disjoint(), "Elements of the view overlap");
+        let back: usize = view.nrows();
+        MatrixRowIteratorMut {
+            view,
+            front: 0,
+            back,
+        }
+    }
+
+    fn row(&mut self, i: usize) -> MatrixViewMut<'a, T> {
+        let n: usize = self.view.ncols();
+        let (row_stride, col_stride): (usize, usize) = self.view.strides();
+        // SAFETY: the rows of a view without overlapping elements are
+        // disjoint and every row is handed out once
+        unsafe {
+            MatrixViewMut::from_raw_parts(
+                self.view.as_mut_ptr().add(i * row_stride),
+                1,
+                n,
+                row_stride,
+                col_stride,
+            )
+        }
     }
 }
 
-impl<'a, T> Iterator for MatrixRowIteratorMut<'a, T>
-{
-    type Item = &'a mut Vector<T>;
+impl<'a, T> Iterator for MatrixRowIteratorMut<'a, T> {
+    type Item = MatrixViewMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let row: MatrixViewMut<'a, T> = self.row(self.front);
+            self.front += 1;
+            Some(row)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
 
-    fn next(&'a mut self) -> Option<Self::Item>
-    {
-        None
+impl<'a, T> DoubleEndedIterator for MatrixRowIteratorMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.row(self.back))
+        } else {
+            None
+        }
     }
 }
+
+impl<'a, T> ExactSizeIterator for MatrixRowIteratorMut<'a, T> {}
+
+impl<'a, T> FusedIterator for MatrixRowIteratorMut<'a, T> {}
@@ -0,0 +1,71 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{General, MatrixView, MatrixViewMut},
+};
+use std::ops::{Add, AddAssign};
+
+impl<'a, 'b, 'c, 'd, T> Add<&'d MatrixView<'c, T>> for &'b MatrixView<'a, T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    /// Adds two views
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// assert_eq!(&a.view() + &a.view().transpose(), matrix![2.0, 5.0; 5.0, 8.0]);
+    /// ```
+    fn add(self, rhs: &'d MatrixView<'c, T>) -> Self::Output {
+        assert_eq!(self.dim(), rhs.dim(), "Matrix dimensions do not match");
+
+        let mut c: General<T> = General::from(*self);
+        c.view_mut().add_assign(rhs);
+        c
+    }
+}
+
+impl<'a, 'b, 'c, T> Add<&'c General<T>> for &'b MatrixView<'a, T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn add(self, rhs: &'c General<T>) -> Self::Output {
+        self + &rhs.view()
+    }
+}
+
+impl<'a, 'c, T> Add<&'c MatrixView<'a, T>> for &General<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn add(self, rhs: &'c MatrixView<'a, T>) -> Self::Output {
+        &self.view() + rhs
+    }
+}
+
+impl<'a, 'b, 'c, T> AddAssign<&'c MatrixView<'b, T>> for MatrixViewMut<'a, T>
+where
+    T: Field + Scalar,
+{
+    /// Adds a view element wise to self in place
+    fn add_assign(&mut self, rhs: &'c MatrixView<'b, T>) {
+        assert_eq!(self.dim(), rhs.dim(), "Matrix dimensions do not match");
+
+        for j in 0..self.n {
+            for i in 0..self.m {
+                *self.element_mut(i, j) += rhs[[i, j]];
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{General, MatrixView, MatrixViewMut},
+};
+
+impl<'a, T> MatrixViewMut<'a, T>
+where
+    T: Field + Scalar,
+{
+    /// Computes self = alpha * a * b + beta * self in place
+    ///
+    /// Views with a unit row or column stride are passed to xgemm directly,
+    /// other views are copied into a contiguous buffer first.
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions do not match or elements of self overlap
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 3.0, 4.0];
+    /// let mut c: General<f64> = General::one(3);
+    ///
+    /// // c[0..2, 1..3] = a * a^T + c[0..2, 1..3]
+    /// c.view_slice_mut(0, 1, 1, 2).gemm(1.0, &a.view(), &a.view().transpose(), 1.0);
+    ///
+    /// assert_eq!(c, matrix![  1.0, 5.0, 11.0;
+    ///                         0.0, 12.0, 25.0;
+    ///                         0.0, 0.0, 1.0]);
+    /// ```
+    pub fn gemm(&mut self, alpha: T, a: &MatrixView<'_, T>, b: &MatrixView<'_, T>, beta: T) {
+        assert_eq!(a.n, b.m, "Matrix dimensions do not match");
+        assert_eq!(
+            (self.m, self.n),
+            (a.m, b.n),
+            "Matrix dimensions do not match"
+        );
+        assert!(self.is_disjoint(), "Elements of the view overlap");
+
+        if self.m == 0 || self.n == 0 {
+            return;
+        }
+        if a.n == 0 {
+            self.scale(beta);
+            return;
+        }
+
+        match blas_layout(self.m, self.n, self.row_stride, self.col_stride) {
+            Some((b'N', ldc)) => {
+                let a_copy: General<T>;
+                let (transa, a_data, lda): (u8, &[T], i32) =
+                    match blas_layout(a.m, a.n, a.row_stride, a.col_stride) {
+                        Some((trans, lda)) => (trans, a.span(), lda),
+                        None => {
+                            a_copy = General::from(*a);
+                            (b'N', &a_copy.data[..], a.m as i32)
+                        }
+                    };
+                let b_copy: General<T>;
+                let (transb, b_data, ldb): (u8, &[T], i32) =
+                    match blas_layout(b.m, b.n, b.row_stride, b.col_stride) {
+                        Some((trans, ldb)) => (trans, b.span(), ldb),
+                        None => {
+                            b_copy = General::from(*b);
+                            (b'N', &b_copy.data[..], b.m as i32)
+                        }
+                    };
+
+                T::xgemm(
+                    transa,
+                    transb,
+                    a.m as i32,
+                    b.n as i32,
+                    a.n as i32,
+                    alpha,
+                    a_data,
+                    lda,
+                    b_data,
+                    ldb,
+                    beta,
+                    self.span_mut(),
+                    ldc,
+                );
+            }
+            // row major storage: C^T = alpha * B^T * A^T + beta * C^T
+            Some(_) => self
+                .transpose_mut()
+                .gemm(alpha, &b.transpose(), &a.transpose(), beta),
+            None => {
+                let mut c: General<T> = General::from(self.as_view());
+                c.view_mut().gemm(alpha, a, b, beta);
+                self.copy_from(&c.view());
+            }
+        }
+    }
+}
+
+/// Returns the BLAS transposition flag and the leading dimension of a
+/// non-empty m x n view, or None if neither its rows nor its columns are
+/// contiguous
+fn blas_layout(m: usize, n: usize, row_stride: usize, col_stride: usize) -> Option<(u8, i32)> {
+    if row_stride == 1 || m == 1 {
+        let ld: usize = if n == 1 { m } else { col_stride };
+        if ld >= m {
+            return Some((b'N', ld as i32));
+        }
+    }
+    if col_stride == 1 || n == 1 {
+        let ld: usize = if m == 1 { n } else { row_stride };
+        if ld >= n {
+            return Some((b'T', ld as i32));
+        }
+    }
+    None
+}
@@ -0,0 +1,66 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{MatrixView, MatrixViewMut},
+};
+
+impl<'a, T> MatrixViewMut<'a, T>
+where
+    T: Field + Scalar,
+{
+    /// Computes self = alpha * a * b + beta * self in place
+    ///
+    /// The strides of the views are handed to the matrix multiplication
+    /// kernel, none of the operands is copied.
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions do not match or elements of self overlap
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 3.0, 4.0];
+    /// let mut c: General<f64> = General::one(3);
+    ///
+    /// // c[0..2, 1..3] = a * a^T + c[0..2, 1..3]
+    /// c.view_slice_mut(0, 1, 1, 2).gemm(1.0, &a.view(), &a.view().transpose(), 1.0);
+    ///
+    /// assert_eq!(c, matrix![  1.0, 5.0, 11.0;
+    ///                         0.0, 12.0, 25.0;
+    ///                         0.0, 0.0, 1.0]);
+    /// ```
+    pub fn gemm(&mut self, alpha: T, a: &MatrixView<'_, T>, b: &MatrixView<'_, T>, beta: T) {
+        assert_eq!(a.n, b.m, "Matrix dimensions do not match");
+        assert_eq!(
+            (self.m, self.n),
+            (a.m, b.n),
+            "Matrix dimensions do not match"
+        );
+        assert!(self.is_disjoint(), "Elements of the view overlap");
+
+        if self.m == 0 || self.n == 0 {
+            return;
+        }
+
+        T::xgemm(
+            a.m,
+            a.n,
+            b.n,
+            alpha,
+            a.as_ptr(),
+            a.row_stride as isize,
+            a.col_stride as isize,
+            b.as_ptr(),
+            b.row_stride as isize,
+            b.col_stride as isize,
+            beta,
+            self.as_mut_ptr(),
+            self.row_stride as isize,
+            self.col_stride as isize,
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use super::{MatrixView, MatrixViewMut};
+use std::ops::{Index, IndexMut};
+
+impl<'a, T> Index<[usize; 2]> for MatrixView<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        assert!(
+            index[0] < self.m && index[1] < self.n,
+            "index out of bounds: is a {}x{} view but the index is {}x{}",
+            self.m,
+            self.n,
+            index[0],
+            index[1]
+        );
+
+        self.get(index[0], index[1]).unwrap()
+    }
+}
+
+impl<'a, T> Index<[usize; 2]> for MatrixViewMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        assert!(
+            index[0] < self.m && index[1] < self.n,
+            "index out of bounds: is a {}x{} view but the index is {}x{}",
+            self.m,
+            self.n,
+            index[0],
+            index[1]
+        );
+
+        self.as_view().get(index[0], index[1]).unwrap()
+    }
+}
+
+impl<'a, T> IndexMut<[usize; 2]> for MatrixViewMut<'a, T> {
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        self.element_mut(index[0], index[1])
+    }
+}
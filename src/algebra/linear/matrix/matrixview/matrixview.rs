@@ -0,0 +1,260 @@
+use crate::algebra::linear::matrix::{MatrixColumnIterator, MatrixRowIterator};
+use std::marker::PhantomData;
+
+/// Borrowed, strided view into the elements of a matrix
+///
+/// The element $(i, j)$ of the view is stored at `i * row_stride + j *
+/// col_stride` of the borrowed slice. Sub-blocks, rows, columns, diagonals
+/// and the transpose of a matrix are views of its storage and are created
+/// without copying.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::matrix::{General, MatrixView};
+/// use mathru::matrix;
+///
+/// let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+///                                 4.0, 5.0, 6.0;
+///                                 7.0, 8.0, 9.0];
+///
+/// let block: MatrixView<f64> = a.view_slice(1, 2, 0, 1);
+/// assert_eq!(General::from(block), matrix![4.0, 5.0; 7.0, 8.0]);
+///
+/// let diagonal: MatrixView<f64> = a.view_diagonal();
+/// assert_eq!(General::from(diagonal), matrix![1.0; 5.0; 9.0]);
+/// ```
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    ptr: *const T,
+    pub(crate) m: usize,
+    pub(crate) n: usize,
+    pub(crate) row_stride: usize,
+    pub(crate) col_stride: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Clone for MatrixView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for MatrixView<'a, T> {}
+
+// A view behaves like a shared reference to its elements
+unsafe impl<'a, T: Sync> Send for MatrixView<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for MatrixView<'a, T> {}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Creates a m x n view into data
+    ///
+    /// # Panics
+    ///
+    /// if data is too short to hold the elements addressed by the strides
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, MatrixView};
+    /// use mathru::matrix;
+    ///
+    /// // every second element of the buffer, interpreted as a 2 x 2 matrix
+    /// let data: [f64; 8] = [1.0, 0.0, 2.0, 0.0, 3.0, 0.0, 4.0, 0.0];
+    /// let a: MatrixView<f64> = MatrixView::new(&data, 2, 2, 2, 4);
+    ///
+    /// assert_eq!(General::from(a), matrix![1.0, 3.0; 2.0, 4.0]);
+    /// ```
+    pub fn new(
+        data: &'a [T],
+        m: usize,
+        n: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> MatrixView<'a, T> {
+        let len: usize = view_len(m, n, row_stride, col_stride);
+        assert!(
+            len <= data.len(),
+            "a {}x{} view with strides ({}, {}) needs {} elements but the slice has {}",
+            m,
+            n,
+            row_stride,
+            col_stride,
+            len,
+            data.len()
+        );
+
+        // SAFETY: data holds all elements addressed by the strides
+        unsafe { MatrixView::from_raw_parts(data.as_ptr(), m, n, row_stride, col_stride) }
+    }
+
+    /// Creates a view from a pointer to the element (0, 0)
+    ///
+    /// # Safety
+    ///
+    /// All elements addressed by the dimensions and strides must be valid
+    /// for reads and must not be mutated during 'a.
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: *const T,
+        m: usize,
+        n: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> MatrixView<'a, T> {
+        MatrixView {
+            ptr,
+            m,
+            n,
+            row_stride,
+            col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn dim(&self) -> (usize, usize) {
+        (self.m, self.n)
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.m
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the strides between two consecutive rows and columns
+    pub fn strides(&self) -> (usize, usize) {
+        (self.row_stride, self.col_stride)
+    }
+
+    /// Returns a view of a sub-block
+    ///
+    /// # Arguments
+    ///
+    /// 0 <= row_s <= row_e < m \
+    /// 0 <= column_s <= column_e < n \
+    ///
+    /// row_s: start row \
+    /// row_e: end row \
+    /// column_s: start column \
+    /// column_e: end column \
+    pub fn slice(
+        &self,
+        row_s: usize,
+        row_e: usize,
+        column_s: usize,
+        column_e: usize,
+    ) -> MatrixView<'a, T> {
+        assert!(row_s <= row_e && row_e < self.m);
+        assert!(column_s <= column_e && column_e < self.n);
+
+        // SAFETY: the sub-block lies within the view
+        unsafe {
+            MatrixView::from_raw_parts(
+                self.ptr
+                    .add(row_s * self.row_stride + column_s * self.col_stride),
+                row_e - row_s + 1,
+                column_e - column_s + 1,
+                self.row_stride,
+                self.col_stride,
+            )
+        }
+    }
+
+    /// Returns a 1 x n view of the row i
+    pub fn row(&self, i: usize) -> MatrixView<'a, T> {
+        assert!(i < self.m);
+        // SAFETY: the row i lies within the view
+        unsafe {
+            MatrixView::from_raw_parts(
+                self.ptr.add(i * self.row_stride),
+                1,
+                self.n,
+                self.row_stride,
+                self.col_stride,
+            )
+        }
+    }
+
+    /// Returns a m x 1 view of the column j
+    pub fn column(&self, j: usize) -> MatrixView<'a, T> {
+        assert!(j < self.n);
+        // SAFETY: the column j lies within the view
+        unsafe {
+            MatrixView::from_raw_parts(
+                self.ptr.add(j * self.col_stride),
+                self.m,
+                1,
+                self.row_stride,
+                self.col_stride,
+            )
+        }
+    }
+
+    /// Returns a min(m, n) x 1 view of the main diagonal
+    pub fn diagonal(&self) -> MatrixView<'a, T> {
+        let k: usize = self.m.min(self.n);
+        // SAFETY: the elements (i, i) lie within the view
+        unsafe { MatrixView::from_raw_parts(self.ptr, k, 1, self.row_stride + self.col_stride, 0) }
+    }
+
+    /// Returns an iterator over the rows as 1 x n views
+    pub fn row_iter(&self) -> MatrixRowIterator<'a, T> {
+        MatrixRowIterator::new(*self)
+    }
+
+    /// Returns an iterator over the columns as m x 1 views
+    pub fn column_iter(&self) -> MatrixColumnIterator<'a, T> {
+        MatrixColumnIterator::new(*self)
+    }
+
+    /// Returns the transposed view
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            ptr: self.ptr,
+            m: self.n,
+            n: self.m,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the element (i, j) or None if it is out of
+    /// bounds
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        if i < self.m && j < self.n {
+            // SAFETY: (i, j) lies within the view
+            Some(unsafe { &*self.ptr.add(i * self.row_stride + j * self.col_stride) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a pointer to the element (0, 0)
+    pub(crate) fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// Returns the storage spanned by the view
+    ///
+    /// The span contains elements that do not belong to the view if the
+    /// strides leave gaps. It is meant to be handed to BLAS routines which
+    /// only access the elements of the view.
+    #[cfg(feature = "lapack")]
+    pub(crate) fn span(&self) -> &'a [T] {
+        let len: usize = view_len(self.m, self.n, self.row_stride, self.col_stride);
+        // SAFETY: the span starts at (0, 0) and ends at (m - 1, n - 1)
+        unsafe { std::slice::from_raw_parts(self.ptr, len) }
+    }
+}
+
+/// Number of elements spanned by a m x n view with the given strides
+pub(crate) fn view_len(m: usize, n: usize, row_stride: usize, col_stride: usize) -> usize {
+    if m == 0 || n == 0 {
+        0
+    } else {
+        (m - 1) * row_stride + (n - 1) * col_stride + 1
+    }
+}
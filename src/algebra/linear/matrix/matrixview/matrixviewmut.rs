@@ -0,0 +1,392 @@
+use super::{matrixview::view_len, MatrixView};
+use crate::algebra::abstr::{Field, Scalar};
+use crate::algebra::linear::matrix::{MatrixColumnIteratorMut, MatrixRowIteratorMut};
+use std::marker::PhantomData;
+
+/// Borrowed, strided and mutable view into the elements of a matrix
+///
+/// Writes through the view change the underlying matrix in place.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::matrix::{General, MatrixViewMut};
+/// use mathru::matrix;
+///
+/// let mut a: General<f64> = General::zero(3, 3);
+///
+/// let mut block: MatrixViewMut<f64> = a.view_slice_mut(0, 1, 1, 2);
+/// block.fill(1.0);
+/// block[[1, 1]] = 2.0;
+///
+/// assert_eq!(a, matrix![  0.0, 1.0, 1.0;
+///                         0.0, 1.0, 2.0;
+///                         0.0, 0.0, 0.0]);
+/// ```
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    ptr: *mut T,
+    pub(crate) m: usize,
+    pub(crate) n: usize,
+    pub(crate) row_stride: usize,
+    pub(crate) col_stride: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+// A mutable view behaves like a mutable reference to its elements
+unsafe impl<'a, T: Send> Send for MatrixViewMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for MatrixViewMut<'a, T> {}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    /// Creates a mutable m x n view into data
+    ///
+    /// # Panics
+    ///
+    /// if data is too short to hold the elements addressed by the strides
+    pub fn new(
+        data: &'a mut [T],
+        m: usize,
+        n: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> MatrixViewMut<'a, T> {
+        let len: usize = view_len(m, n, row_stride, col_stride);
+        assert!(
+            len <= data.len(),
+            "a {}x{} view with strides ({}, {}) needs {} elements but the slice has {}",
+            m,
+            n,
+            row_stride,
+            col_stride,
+            len,
+            data.len()
+        );
+
+        // SAFETY: data holds all elements addressed by the strides and is
+        // borrowed mutably for 'a
+        unsafe { MatrixViewMut::from_raw_parts(data.as_mut_ptr(), m, n, row_stride, col_stride) }
+    }
+
+    /// Creates a mutable view from a pointer to the element (0, 0)
+    ///
+    /// # Safety
+    ///
+    /// All elements addressed by the dimensions and strides must be valid
+    /// for reads and writes and must not be accessed through any other
+    /// pointer during 'a.
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: *mut T,
+        m: usize,
+        n: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            ptr,
+            m,
+            n,
+            row_stride,
+            col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn dim(&self) -> (usize, usize) {
+        (self.m, self.n)
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.m
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the strides between two consecutive rows and columns
+    pub fn strides(&self) -> (usize, usize) {
+        (self.row_stride, self.col_stride)
+    }
+
+    /// Reborrows the view immutably
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        // SAFETY: self is borrowed immutably for the lifetime of the view
+        unsafe {
+            MatrixView::from_raw_parts(self.ptr, self.m, self.n, self.row_stride, self.col_stride)
+        }
+    }
+
+    /// Converts the mutable view into an immutable one with the same lifetime
+    pub fn into_view(self) -> MatrixView<'a, T> {
+        // SAFETY: self is consumed, so the elements can not be mutated
+        // anymore during 'a
+        unsafe {
+            MatrixView::from_raw_parts(self.ptr, self.m, self.n, self.row_stride, self.col_stride)
+        }
+    }
+
+    /// Returns a mutable view of a sub-block
+    ///
+    /// # Arguments
+    ///
+    /// 0 <= row_s <= row_e < m \
+    /// 0 <= column_s <= column_e < n \
+    ///
+    /// row_s: start row \
+    /// row_e: end row \
+    /// column_s: start column \
+    /// column_e: end column \
+    pub fn slice_mut(
+        &mut self,
+        row_s: usize,
+        row_e: usize,
+        column_s: usize,
+        column_e: usize,
+    ) -> MatrixViewMut<'_, T> {
+        assert!(row_s <= row_e && row_e < self.m);
+        assert!(column_s <= column_e && column_e < self.n);
+
+        // SAFETY: the sub-block lies within the view, which is borrowed
+        // mutably for the lifetime of the sub-view
+        unsafe {
+            MatrixViewMut::from_raw_parts(
+                self.ptr
+                    .add(row_s * self.row_stride + column_s * self.col_stride),
+                row_e - row_s + 1,
+                column_e - column_s + 1,
+                self.row_stride,
+                self.col_stride,
+            )
+        }
+    }
+
+    /// Returns a mutable 1 x n view of the row i
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, T> {
+        assert!(i < self.m);
+        // SAFETY: the row i lies within the view
+        unsafe {
+            MatrixViewMut::from_raw_parts(
+                self.ptr.add(i * self.row_stride),
+                1,
+                self.n,
+                self.row_stride,
+                self.col_stride,
+            )
+        }
+    }
+
+    /// Returns a mutable m x 1 view of the column j
+    pub fn column_mut(&mut self, j: usize) -> MatrixViewMut<'_, T> {
+        assert!(j < self.n);
+        // SAFETY: the column j lies within the view
+        unsafe {
+            MatrixViewMut::from_raw_parts(
+                self.ptr.add(j * self.col_stride),
+                self.m,
+                1,
+                self.row_stride,
+                self.col_stride,
+            )
+        }
+    }
+
+    /// Returns a mutable min(m, n) x 1 view of the main diagonal
+    pub fn diagonal_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let k: usize = self.m.min(self.n);
+        let stride: usize = self.row_stride + self.col_stride;
+        // SAFETY: the elements (i, i) lie within the view
+        unsafe { MatrixViewMut::from_raw_parts(self.ptr, k, 1, stride, 0) }
+    }
+
+    /// Returns an iterator over the rows as mutable 1 x n views
+    ///
+    /// # Panics
+    ///
+    /// if elements of the view overlap
+    pub fn row_iter_mut(&mut self) -> MatrixRowIteratorMut<'_, T> {
+        MatrixRowIteratorMut::new(self.reborrow())
+    }
+
+    /// Returns an iterator over the columns as mutable m x 1 views
+    ///
+    /// # Panics
+    ///
+    /// if elements of the view overlap
+    pub fn column_iter_mut(&mut self) -> MatrixColumnIteratorMut<'_, T> {
+        MatrixColumnIteratorMut::new(self.reborrow())
+    }
+
+    /// Reborrows the whole view
+    fn reborrow(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            ptr: self.ptr,
+            m: self.m,
+            n: self.n,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the transposed view
+    pub fn transpose_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            ptr: self.ptr,
+            m: self.n,
+            n: self.m,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// Splits the view into the columns 0..j and j..n
+    ///
+    /// # Panics
+    ///
+    /// if j > n or elements of the view overlap
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, MatrixViewMut};
+    /// use mathru::matrix;
+    ///
+    /// let mut a: General<f64> = matrix![  1.0, 2.0, 3.0;
+    ///                                     4.0, 5.0, 6.0];
+    ///
+    /// let (mut left, right): (MatrixViewMut<f64>, MatrixViewMut<f64>) =
+    ///     a.view_mut().split_at_column_mut(1);
+    /// left.copy_from(&right.as_view().column(1));
+    ///
+    /// assert_eq!(a, matrix![  3.0, 2.0, 3.0;
+    ///                         6.0, 5.0, 6.0]);
+    /// ```
+    pub fn split_at_column_mut(self, j: usize) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>) {
+        assert!(j <= self.n);
+        assert!(self.is_disjoint(), "Elements of the view overlap");
+
+        // SAFETY: the columns 0..j and j..n do not share elements
+        unsafe {
+            (
+                MatrixViewMut::from_raw_parts(
+                    self.ptr,
+                    self.m,
+                    j,
+                    self.row_stride,
+                    self.col_stride,
+                ),
+                MatrixViewMut::from_raw_parts(
+                    self.ptr.add(j * self.col_stride),
+                    self.m,
+                    self.n - j,
+                    self.row_stride,
+                    self.col_stride,
+                ),
+            )
+        }
+    }
+
+    /// Returns a mutable reference to the element (i, j) or None if it is
+    /// out of bounds
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i < self.m && j < self.n {
+            // SAFETY: (i, j) lies within the view
+            Some(unsafe { &mut *self.ptr.add(i * self.row_stride + j * self.col_stride) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a pointer to the element (0, 0)
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    /// Returns the storage spanned by the view
+    ///
+    /// The span contains elements that do not belong to the view if the
+    /// strides leave gaps. It is meant to be handed to BLAS routines which
+    /// only access the elements of the view.
+    #[cfg(feature = "lapack")]
+    pub(crate) fn span_mut(&mut self) -> &mut [T] {
+        let len: usize = view_len(self.m, self.n, self.row_stride, self.col_stride);
+        // SAFETY: the span starts at (0, 0) and ends at (m - 1, n - 1)
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, len) }
+    }
+
+    /// Returns a mutable reference to the element (i, j) without the
+    /// reborrow of a sub-view
+    ///
+    /// # Panics
+    ///
+    /// if (i, j) is out of bounds
+    pub(crate) fn element_mut(&mut self, i: usize, j: usize) -> &mut T {
+        assert!(
+            i < self.m && j < self.n,
+            "index out of bounds: is a {}x{} view but the index is {}x{}",
+            self.m,
+            self.n,
+            i,
+            j
+        );
+        // SAFETY: (i, j) lies within the view
+        unsafe { &mut *self.ptr.add(i * self.row_stride + j * self.col_stride) }
+    }
+
+    /// True if no two elements of the view share the same storage
+    pub(crate) fn is_disjoint(&self) -> bool {
+        let (m, n): (usize, usize) = (self.m, self.n);
+        (m <= 1 && (n <= 1 || self.col_stride > 0))
+            || (n <= 1 && self.row_stride > 0)
+            || (self.row_stride > 0 && self.col_stride >= m * self.row_stride)
+            || (self.col_stride > 0 && self.row_stride >= n * self.col_stride)
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T>
+where
+    T: Copy,
+{
+    /// Sets all elements of the view to value
+    pub fn fill(&mut self, value: T) {
+        for j in 0..self.n {
+            for i in 0..self.m {
+                *self.element_mut(i, j) = value;
+            }
+        }
+    }
+
+    /// Copies the elements of other into the view
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions of the views differ
+    pub fn copy_from(&mut self, other: &MatrixView<'_, T>) {
+        assert_eq!(
+            self.dim(),
+            other.dim(),
+            "Dimensions of the views do not match"
+        );
+
+        for j in 0..self.n {
+            for i in 0..self.m {
+                *self.element_mut(i, j) = other[[i, j]];
+            }
+        }
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T>
+where
+    T: Field + Scalar,
+{
+    /// Multiplies all elements of the view by alpha
+    pub fn scale(&mut self, alpha: T) {
+        for j in 0..self.n {
+            for i in 0..self.m {
+                *self.element_mut(i, j) *= alpha;
+            }
+        }
+    }
+}
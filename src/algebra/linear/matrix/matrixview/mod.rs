@@ -0,0 +1,11 @@
+mod add;
+mod gemm;
+mod index;
+mod matrixview;
+mod matrixviewmut;
+mod mul;
+mod solve;
+mod sub;
+
+pub use matrixview::MatrixView;
+pub use matrixviewmut::MatrixViewMut;
@@ -0,0 +1,89 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{General, MatrixView},
+        vector::Vector,
+    },
+};
+use std::ops::Mul;
+
+impl<'a, 'b, 'c, 'd, T> Mul<&'d MatrixView<'c, T>> for &'b MatrixView<'a, T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    /// Multiplies two views
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+    ///                                 4.0, 5.0, 6.0];
+    ///
+    /// // first two columns times the transposed last two columns
+    /// let c: General<f64> = &a.view_slice(0, 1, 0, 1) * &a.view_slice(0, 1, 1, 2).transpose();
+    ///
+    /// assert_eq!(c, matrix![  8.0, 17.0;
+    ///                         23.0, 50.0]);
+    /// ```
+    fn mul(self, rhs: &'d MatrixView<'c, T>) -> Self::Output {
+        let mut c: General<T> = General::zero(self.m, rhs.n);
+        c.view_mut().gemm(T::one(), self, rhs, T::zero());
+        c
+    }
+}
+
+impl<'a, 'b, 'c, T> Mul<&'c General<T>> for &'b MatrixView<'a, T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn mul(self, rhs: &'c General<T>) -> Self::Output {
+        self * &rhs.view()
+    }
+}
+
+impl<'a, 'c, T> Mul<&'c MatrixView<'a, T>> for &General<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn mul(self, rhs: &'c MatrixView<'a, T>) -> Self::Output {
+        &self.view() * rhs
+    }
+}
+
+impl<'a, 'b, 'c, T> Mul<&'c Vector<T>> for &'b MatrixView<'a, T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    /// Multiplies a view by a column vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::General, vector::Vector};
+    /// use mathru::{matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+    ///                                 4.0, 5.0, 6.0];
+    /// let x: Vector<f64> = vector![1.0; -1.0];
+    ///
+    /// assert_eq!(&a.view_slice(0, 1, 1, 2) * &x, vector![-1.0; -1.0]);
+    /// ```
+    fn mul(self, rhs: &'c Vector<T>) -> Self::Output {
+        let mut y: Vector<T> = Vector::zero(self.m);
+        y.data
+            .view_mut()
+            .gemm(T::one(), self, &rhs.data.view(), T::zero());
+        y
+    }
+}
@@ -0,0 +1,45 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::linear::{
+    matrix::{General, MatrixView, Solve},
+    vector::Vector,
+};
+
+impl<'a, T> Solve<Vector<T>> for MatrixView<'a, T>
+where
+    T: Copy,
+    General<T>: Solve<Vector<T>>,
+{
+    /// Solves Ax = y, where A is the viewed matrix
+    ///
+    /// The factorization works on a copy of the view.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{General, Solve}, vector::Vector};
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  6.0, 2.0, -1.0;
+    ///                                 0.0, 4.0, 1.0;
+    ///                                 0.0, 1.0, 3.0];
+    /// let b: Vector<f64> = vector![5.0; 9.0];
+    ///
+    /// let x: Vector<f64> = a.view_slice(1, 2, 1, 2).solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![6.0 / 11.0; 31.0 / 11.0], epsilon = 1.0e-10);
+    /// ```
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        General::from(*self).solve(rhs)
+    }
+}
+
+impl<'a, T> Solve<General<T>> for MatrixView<'a, T>
+where
+    T: Copy,
+    General<T>: Solve<General<T>>,
+{
+    /// Solves AX = Y, where A is the viewed matrix
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        General::from(*self).solve(rhs)
+    }
+}
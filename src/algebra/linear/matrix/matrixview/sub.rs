@@ -0,0 +1,71 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{General, MatrixView, MatrixViewMut},
+};
+use std::ops::{Sub, SubAssign};
+
+impl<'a, 'b, 'c, 'd, T> Sub<&'d MatrixView<'c, T>> for &'b MatrixView<'a, T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    /// Subs two views
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::General;
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 3.0, 4.0];
+    ///
+    /// assert_eq!(&a.view() - &a.view().transpose(), matrix![0.0, -1.0; 1.0, 0.0]);
+    /// ```
+    fn sub(self, rhs: &'d MatrixView<'c, T>) -> Self::Output {
+        assert_eq!(self.dim(), rhs.dim(), "Matrix dimensions do not match");
+
+        let mut c: General<T> = General::from(*self);
+        c.view_mut().sub_assign(rhs);
+        c
+    }
+}
+
+impl<'a, 'b, 'c, T> Sub<&'c General<T>> for &'b MatrixView<'a, T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn sub(self, rhs: &'c General<T>) -> Self::Output {
+        self - &rhs.view()
+    }
+}
+
+impl<'a, 'c, T> Sub<&'c MatrixView<'a, T>> for &General<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    fn sub(self, rhs: &'c MatrixView<'a, T>) -> Self::Output {
+        &self.view() - rhs
+    }
+}
+
+impl<'a, 'b, 'c, T> SubAssign<&'c MatrixView<'b, T>> for MatrixViewMut<'a, T>
+where
+    T: Field + Scalar,
+{
+    /// Subs a view element wise to self in place
+    fn sub_assign(&mut self, rhs: &'c MatrixView<'b, T>) {
+        assert_eq!(self.dim(), rhs.dim(), "Matrix dimensions do not match");
+
+        for j in 0..self.n {
+            for i in 0..self.m {
+                *self.element_mut(i, j) -= rhs[[i, j]];
+            }
+        }
+    }
+}
@@ -1,67 +1,127 @@
 #[macro_use]
-//mod matrixcolumniterator;
-//mod matrixcolumniteratormut;
+mod matrixcolumniterator;
+mod matrixcolumniteratormut;
 mod matrixintoiterator;
 mod matrixiterator;
 mod matrixiteratormut;
-//mod matrixrowiterator;
-//mod matrixrowiteratormut;
+mod matrixrowiterator;
+mod matrixrowiteratormut;
+mod completeorthogonaldec;
 mod eigendec;
 mod matrixcolumnintoiterator;
 mod matrixrowintoiterator;
-pub use self::eigendec::{EigenDec, EigenDecomposition};
+pub use self::completeorthogonaldec::{CompleteOrthogonalDec, CompleteOrthogonalDecomposition};
+pub use self::eigendec::{
+    ComplexEigenDec, ComplexEigenDecomposition, EigenDec, EigenDecomposition,
+};
+
+mod generalizedeigendec;
+pub use generalizedeigendec::{
+    GeneralizedEigenDec, GeneralizedEigenDecomposition, SymmetricGeneralizedEigenDecomposition,
+};
+
+mod generalizedschurdec;
+pub use generalizedschurdec::{GeneralizedSchurDec, GeneralizedSchurDecomposition};
 
 mod hessenbergdec;
 pub use hessenbergdec::{HessenbergDec, HessenbergDecomposition};
 
+mod ldldec;
+pub use ldldec::{LDLDec, LDLDecomposition};
+
+mod leastsquares;
+pub use leastsquares::{LeastSquares, LeastSquaresResult};
+
 mod ludec;
 pub use ludec::{LUDec, LUDecomposition};
 
+mod polardec;
+pub use polardec::{PolarDec, PolarDecomposition};
+
 mod qrdec;
-pub use qrdec::{QRDec, QRDecomposition};
+pub use qrdec::{QRDec, QRDecomposition, QRPDec, QRPDecomposition};
 
 mod schurdec;
 pub use schurdec::{SchurDec, SchurDecomposition};
 
+mod singularvaluedec;
+pub use singularvaluedec::{SingularValueDec, SingularValueDecomposition};
+
+mod symmetriceigendec;
+pub use symmetriceigendec::{SymmetricEigenDec, SymmetricEigenDecomposition};
+
 mod inverse;
 
+mod checked;
+pub use checked::{CheckedAdd, CheckedMul, CheckedSolve, CheckedSub};
+
 mod choleskydec;
 pub use choleskydec::{CholeskyDec, CholeskyDecomposition};
 
 mod det;
 pub use det::Determinant;
 
+mod matrixview;
+pub use matrixview::{MatrixView, MatrixViewMut};
+
+mod condest;
+mod norm;
+pub use norm::MatrixNorm;
+
+mod smatrix;
+pub use smatrix::{SCholeskyDec, SLUDec, SMatrix, SQRDec, SVector};
+
 mod solve;
 mod substitute;
 mod transpose;
 
+mod banded;
+mod circulant;
+mod compressed;
+mod coomatrix;
+mod cscmatrix;
+mod csrmatrix;
 mod diagonal;
 mod general;
 mod lowertriangular;
+mod toeplitz;
+mod tridiagonal;
 mod unitlowertriangular;
 mod unituppertriangular;
 mod upperhessenberg;
 mod uppertriangular;
+mod vandermonde;
 
 pub use self::{
+    banded::{Banded, BandedCholeskyDec, BandedLUDec},
+    circulant::Circulant,
+    coomatrix::{CooIterator, CooMatrix},
+    cscmatrix::{
+        CscCholeskyDec, CscCholeskySymbolic, CscColumnIterator, CscIterator, CscLUDec,
+        CscLUSymbolic, CscMatrix, SparseOrdering,
+    },
+    csrmatrix::{CsrIterator, CsrMatrix, CsrRowIterator},
     diagonal::Diagonal,
     general::General,
     inverse::Inverse,
     lowertriangular::LowerTriangular,
-    //matrixcolumniterator::MatrixColumnIterator,
-    //matrixcolumniteratormut::MatrixColumnIteratorMut,
+    matrixcolumniterator::MatrixColumnIterator,
+    matrixcolumniteratormut::MatrixColumnIteratorMut,
     matrixcolumnintoiterator::MatrixColumnIntoIterator,
     matrixintoiterator::MatrixIntoIterator,
     matrixiterator::MatrixIterator,
     matrixiteratormut::MatrixIteratorMut,
-    //matrixrowiterator::MatrixRowIterator,
-    //matrixrowiteratormut::MatrixRowIteratorMut,
+    matrixrowiterator::MatrixRowIterator,
+    matrixrowiteratormut::MatrixRowIteratorMut,
     matrixrowintoiterator::MatrixRowIntoIterator,
     solve::Solve,
     substitute::{SubstituteBackward, SubstituteForward},
-    transpose::Transpose,
+    transpose::{ConjugateTranspose, Transpose},
+    toeplitz::Toeplitz,
+    tridiagonal::Tridiagonal,
     unitlowertriangular::UnitLowerTriangular,
     unituppertriangular::UnitUpperTriangular,
     upperhessenberg::UpperHessenberg,
     uppertriangular::UpperTriangular,
+    vandermonde::Vandermonde,
 };
@@ -0,0 +1,38 @@
+//! Matrix norms
+//!
+//! The induced 1-, ∞- and 2-norms and the entrywise Frobenius norm:
+//! ```math
+//! \lVert A \rVert_1 = \max_j \sum_i \lvert a_{ij} \rvert \\
+//! \lVert A \rVert_\infty = \max_i \sum_j \lvert a_{ij} \rvert \\
+//! \lVert A \rVert_F = \sqrt{\sum_{i,j} \lvert a_{ij} \rvert^2} \\
+//! \lVert A \rVert_2 = \sigma_{max}(A)
+//! ```
+//!
+//! ```
+//! use mathru::algebra::linear::matrix::{General, MatrixNorm};
+//! use mathru::{assert_relative_eq, matrix};
+//!
+//! let a: General<f64> = matrix![  1.0, -2.0;
+//!                                 3.0, 4.0];
+//!
+//! assert_relative_eq!(a.norm_1(), 6.0);
+//! assert_relative_eq!(a.norm_inf(), 7.0);
+//! assert_relative_eq!(a.norm_frobenius(), 30.0f64.sqrt());
+//! ```
+pub trait MatrixNorm<T> {
+    /// Maximum absolute column sum
+    fn norm_1(&self) -> T;
+
+    /// Maximum absolute row sum
+    fn norm_inf(&self) -> T;
+
+    /// Square root of the sum of the squared absolute entries
+    fn norm_frobenius(&self) -> T;
+
+    /// Largest singular value
+    ///
+    /// # Panics
+    ///
+    /// if the singular value decomposition does not converge
+    fn norm_2(&self) -> T;
+}
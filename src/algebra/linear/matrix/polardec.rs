@@ -0,0 +1,45 @@
+use crate::algebra::linear::matrix::General;
+use crate::algebra::linear::LinalgError;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Polar decomposition
+///
+/// ```math
+/// A = UP
+/// ```
+/// with $U \in T^{m \times n}$ having orthonormal columns ($m \geq n$) or
+/// orthonormal rows ($m < n$) and a symmetric positive semidefinite matrix
+/// $P \in T^{n \times n}$.
+pub trait PolarDecomposition<T> {
+    fn dec_polar(&self) -> Result<PolarDec<T>, LinalgError>;
+}
+
+/// Result of a polar decomposition
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PolarDec<T> {
+    u: General<T>,
+    p: General<T>,
+}
+
+impl<T> PolarDec<T> {
+    pub(super) fn new(u: General<T>, p: General<T>) -> PolarDec<T> {
+        PolarDec { u, p }
+    }
+
+    /// Return the orthogonal factor $U \in T^{m \times n}$
+    pub fn u(self) -> General<T> {
+        self.u
+    }
+
+    /// Return the symmetric positive semidefinite factor
+    /// $P \in T^{n \times n}$
+    pub fn p(self) -> General<T> {
+        self.p
+    }
+
+    pub fn up(self) -> (General<T>, General<T>) {
+        (self.u, self.p)
+    }
+}
@@ -1,10 +1,17 @@
-use crate::algebra::linear::matrix::{General, UpperTriangular};
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{General, UpperTriangular},
+        vector::Vector,
+    },
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::clone::Clone;
 
 pub trait QRDecomposition<T> {
-    fn dec_qr(&self) -> Result<QRDec<T>, ()>;
+    fn dec_qr(&self) -> Result<QRDec<T>, LinalgError>;
 }
 
 /// Result of QR decomposition
@@ -38,3 +45,433 @@ impl<T> QRDec<T> {
         (self.q, self.r)
     }
 }
+
+/// QR decomposition with column pivoting
+///
+/// ```math
+/// AP = QR
+/// ```
+/// The columns are permuted such that the absolute values of the diagonal
+/// entries of $R$ are non-increasing, which reveals the numerical rank of
+/// $A$.
+pub trait QRPDecomposition<T> {
+    fn dec_qrp(&self) -> Result<QRPDec<T>, LinalgError>;
+}
+
+/// Result of a QR decomposition with column pivoting
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct QRPDec<T> {
+    q: General<T>,
+    r: UpperTriangular<T>,
+    p: General<T>,
+}
+
+impl<T> QRPDec<T> {
+    pub(super) fn new(q: General<T>, r: UpperTriangular<T>, p: General<T>) -> QRPDec<T> {
+        QRPDec { q, r, p }
+    }
+
+    /// Return the orthogonal matrix $Q \in T^{m \times m}$
+    pub fn q(self) -> General<T> {
+        self.q
+    }
+
+    /// Return the upper trapezoidal matrix $R \in T^{m \times n}$
+    pub fn r(self) -> UpperTriangular<T> {
+        self.r
+    }
+
+    /// Return the permutation matrix $P \in T^{n \times n}$
+    pub fn p(self) -> General<T> {
+        self.p
+    }
+
+    pub fn qrp(self) -> (General<T>, UpperTriangular<T>, General<T>) {
+        (self.q, self.r, self.p)
+    }
+}
+
+impl<T> QRPDec<T>
+where
+    T: Real,
+{
+    /// Estimates the numerical rank
+    ///
+    /// The rank is the number of diagonal entries of $R$ with
+    /// $|r_{ii}| > \text{tolerance} \cdot |r_{00}|$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, QRPDecomposition};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+    ///                                 2.0, 4.0, 6.0;
+    ///                                 1.0, 0.0, 1.0];
+    ///
+    /// assert_eq!(a.dec_qrp().unwrap().rank(1.0e-12), 2);
+    /// ```
+    pub fn rank(&self, tolerance: T) -> usize {
+        let (m, n): (usize, usize) = self.r.dim();
+        let k: usize = m.min(n);
+        if k == 0 {
+            return 0;
+        }
+
+        let threshold: T = tolerance * self.r[[0, 0]].abs();
+        (0..k)
+            .take_while(|i| self.r[[*i, *i]].abs() > threshold)
+            .count()
+    }
+}
+
+impl<T> QRDec<T>
+where
+    T: Real,
+{
+    /// Updates the decomposition of $A$ to the decomposition of $A + uv^T$
+    ///
+    /// The update applies $O(m + n)$ Givens rotations and costs
+    /// $O(m^2 + mn)$ operations. A downdate $A - uv^T$ is an update with
+    /// $-u$.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if $u \notin T^m$ or
+    /// $v \notin T^n$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, QRDecomposition};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 3.0, 4.0;
+    ///                                 5.0, 6.0];
+    ///
+    /// let mut dec = a.dec_qr().unwrap();
+    /// dec.rank_one_update(&vector![1.0; 0.0; 1.0], &vector![1.0; -1.0]).unwrap();
+    ///
+    /// let (q, r) = dec.qr();
+    /// let a_ref: General<f64> = matrix![  2.0, 1.0;
+    ///                                     3.0, 4.0;
+    ///                                     6.0, 5.0];
+    /// assert_relative_eq!(&q * &General::from(r), a_ref, epsilon = 1.0e-10);
+    /// ```
+    pub fn rank_one_update(&mut self, u: &Vector<T>, v: &Vector<T>) -> Result<(), LinalgError> {
+        let (m, n): (usize, usize) = (self.q.nrows(), self.r.matrix.ncols());
+        if u.dim() != (m, 1) || v.dim() != (n, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        self.complete_q();
+        let q: &mut General<T> = &mut self.q;
+        let r: &mut General<T> = &mut self.r.matrix;
+
+        // w = Q^T u
+        let mut w: Vec<T> = (0..m)
+            .map(|j| (0..m).fold(T::zero(), |s, i| s + q[[i, j]] * u[i]))
+            .collect();
+
+        // Rotate w to a multiple of e_0, which turns R into an upper Hessenberg matrix
+        for k in (1..m).rev() {
+            let (c, s): (T, T) = givens(w[k - 1], w[k]);
+            w[k - 1] = c * w[k - 1] + s * w[k];
+            w[k] = T::zero();
+            rotate_rows(r, k - 1, k, c, s);
+            rotate_columns(q, k - 1, k, c, s);
+        }
+
+        if m > 0 {
+            for j in 0..n {
+                r[[0, j]] += w[0] * v[j];
+            }
+        }
+
+        restore_upper_triangular(q, r, 0);
+        Ok(())
+    }
+
+    /// Updates the decomposition of $A$ to the decomposition of the matrix
+    /// with the column $a \in T^m$ inserted at the index `k`
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if `k` is greater than the number
+    /// of columns of $A$ or $a \notin T^m$
+    pub fn insert_column(&mut self, k: usize, a: &Vector<T>) -> Result<(), LinalgError> {
+        let (m, n): (usize, usize) = (self.q.nrows(), self.r.matrix.ncols());
+        if k > n || a.dim() != (m, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        self.complete_q();
+        let q: &mut General<T> = &mut self.q;
+
+        let mut r: General<T> = General::zero(m, n + 1);
+        for j in 0..n {
+            let j_new: usize = if j < k { j } else { j + 1 };
+            for i in 0..m {
+                r[[i, j_new]] = self.r.matrix[[i, j]];
+            }
+        }
+        // Q^T a
+        for j in 0..m {
+            r[[j, k]] = (0..m).fold(T::zero(), |s, i| s + q[[i, j]] * a[i]);
+        }
+
+        for j in (k + 1..m).rev() {
+            let (c, s): (T, T) = givens(r[[j - 1, k]], r[[j, k]]);
+            rotate_rows(&mut r, j - 1, j, c, s);
+            rotate_columns(q, j - 1, j, c, s);
+            r[[j, k]] = T::zero();
+        }
+
+        self.r.matrix = r;
+        Ok(())
+    }
+
+    /// Updates the decomposition of $A$ to the decomposition of the matrix
+    /// with the column `k` removed
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if `k` is not less than the number
+    /// of columns of $A$
+    pub fn remove_column(&mut self, k: usize) -> Result<(), LinalgError> {
+        let (m, n): (usize, usize) = (self.q.nrows(), self.r.matrix.ncols());
+        if k >= n {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        self.complete_q();
+
+        let mut r: General<T> = General::zero(m, n - 1);
+        for j in (0..n).filter(|j| *j != k) {
+            let j_new: usize = if j < k { j } else { j - 1 };
+            for i in 0..m {
+                r[[i, j_new]] = self.r.matrix[[i, j]];
+            }
+        }
+
+        restore_upper_triangular(&mut self.q, &mut r, k);
+        self.r.matrix = r;
+        Ok(())
+    }
+
+    /// Updates the decomposition of $A$ to the decomposition of the matrix
+    /// with the row $a \in T^n$ inserted at the index `k`
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if `k` is greater than the number
+    /// of rows of $A$ or $a \notin T^n$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, QRDecomposition};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, matrix, vector};
+    ///
+    /// let a: General<f64> = matrix![  1.0, 2.0;
+    ///                                 5.0, 6.0];
+    ///
+    /// let mut dec = a.dec_qr().unwrap();
+    /// dec.insert_row(1, &vector![3.0; 4.0]).unwrap();
+    ///
+    /// let (q, r) = dec.qr();
+    /// let a_ref: General<f64> = matrix![  1.0, 2.0;
+    ///                                     3.0, 4.0;
+    ///                                     5.0, 6.0];
+    /// assert_relative_eq!(&q * &General::from(r), a_ref, epsilon = 1.0e-10);
+    /// ```
+    pub fn insert_row(&mut self, k: usize, a: &Vector<T>) -> Result<(), LinalgError> {
+        let (m, n): (usize, usize) = (self.q.nrows(), self.r.matrix.ncols());
+        if k > m || a.dim() != (n, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        self.complete_q();
+
+        // [a^T; A] = [1, 0; 0, Q] [a^T; R] with the row of a moved to k
+        let mut q: General<T> = General::zero(m + 1, m + 1);
+        q[[k, 0]] = T::one();
+        for i in 0..m {
+            let i_new: usize = if i < k { i } else { i + 1 };
+            for j in 0..m {
+                q[[i_new, j + 1]] = self.q[[i, j]];
+            }
+        }
+
+        let mut r: General<T> = General::zero(m + 1, n);
+        for j in 0..n {
+            r[[0, j]] = a[j];
+            for i in 0..m {
+                r[[i + 1, j]] = self.r.matrix[[i, j]];
+            }
+        }
+
+        restore_upper_triangular(&mut q, &mut r, 0);
+        self.q = q;
+        self.r.matrix = r;
+        Ok(())
+    }
+
+    /// Updates the decomposition of $A$ to the decomposition of the matrix
+    /// with the row `k` removed
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if `k` is not less than the number
+    /// of rows of $A$
+    pub fn remove_row(&mut self, k: usize) -> Result<(), LinalgError> {
+        let (m, n): (usize, usize) = (self.q.nrows(), self.r.matrix.ncols());
+        if k >= m {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        self.complete_q();
+        let q: &mut General<T> = &mut self.q;
+        let r: &mut General<T> = &mut self.r.matrix;
+
+        // Rotate the row k of Q to a multiple of e_0^T, then the first column
+        // of Q is a multiple of e_k and the rows 1.. of R are upper triangular
+        let mut w: Vec<T> = (0..m).map(|j| q[[k, j]]).collect();
+        for j in (1..m).rev() {
+            let (c, s): (T, T) = givens(w[j - 1], w[j]);
+            w[j - 1] = c * w[j - 1] + s * w[j];
+            w[j] = T::zero();
+            rotate_rows(r, j - 1, j, c, s);
+            rotate_columns(q, j - 1, j, c, s);
+        }
+
+        let mut q_new: General<T> = General::zero(m - 1, m - 1);
+        for i in (0..m).filter(|i| *i != k) {
+            let i_new: usize = if i < k { i } else { i - 1 };
+            for j in 1..m {
+                q_new[[i_new, j - 1]] = q[[i, j]];
+            }
+        }
+
+        let mut r_new: General<T> = General::zero(m - 1, n);
+        for j in 0..n {
+            for i in 1..m {
+                r_new[[i - 1, j]] = r[[i, j]];
+            }
+        }
+
+        self.q = q_new;
+        self.r.matrix = r_new;
+        Ok(())
+    }
+
+    /// Completes a thin $Q \in T^{m \times n}$ to an orthogonal matrix
+    /// $Q \in T^{m \times m}$ and pads $R$ with zero rows
+    fn complete_q(&mut self) {
+        let (m, p): (usize, usize) = self.q.dim();
+        let (r_m, n): (usize, usize) = self.r.matrix.dim();
+
+        if r_m < m {
+            let mut r: General<T> = General::zero(m, n);
+            for j in 0..n {
+                for i in 0..r_m {
+                    r[[i, j]] = self.r.matrix[[i, j]];
+                }
+            }
+            self.r.matrix = r;
+        }
+
+        if p >= m {
+            return;
+        }
+
+        let mut q: General<T> = General::zero(m, m);
+        for j in 0..p {
+            for i in 0..m {
+                q[[i, j]] = self.q[[i, j]];
+            }
+        }
+
+        // Orthogonalize the unit vectors against the columns of Q. As long as
+        // Q is incomplete, the sum of the squared norms of the projections is
+        // at least one, so one of the m unit vectors exceeds the threshold.
+        let threshold: T = T::from_f64(0.5) / T::from_u64(m as u64).sqrt();
+        let mut cols: usize = p;
+        for e in 0..m {
+            if cols == m {
+                break;
+            }
+            let mut v: Vec<T> = vec![T::zero(); m];
+            v[e] = T::one();
+            for _ in 0..2 {
+                for j in 0..cols {
+                    let d: T = (0..m).fold(T::zero(), |s, i| s + q[[i, j]] * v[i]);
+                    for (i, v_i) in v.iter_mut().enumerate() {
+                        *v_i -= d * q[[i, j]];
+                    }
+                }
+            }
+            let norm: T = v.iter().fold(T::zero(), |s, v_i| s + *v_i * *v_i).sqrt();
+            if norm > threshold {
+                for (i, v_i) in v.iter().enumerate() {
+                    q[[i, cols]] = *v_i / norm;
+                }
+                cols += 1;
+            }
+        }
+
+        self.q = q;
+    }
+}
+
+/// Computes the Givens rotation with $ca + sb = r$ and $-sa + cb = 0$
+fn givens<T>(a: T, b: T) -> (T, T)
+where
+    T: Real,
+{
+    if b == T::zero() {
+        return (T::one(), T::zero());
+    }
+    let r: T = (a * a + b * b).sqrt();
+    (a / r, b / r)
+}
+
+/// Applies the rotation to the rows i and j of R
+fn rotate_rows<T>(r: &mut General<T>, i: usize, j: usize, c: T, s: T)
+where
+    T: Real,
+{
+    for k in 0..r.ncols() {
+        let (r_ik, r_jk): (T, T) = (r[[i, k]], r[[j, k]]);
+        r[[i, k]] = c * r_ik + s * r_jk;
+        r[[j, k]] = -s * r_ik + c * r_jk;
+    }
+}
+
+/// Applies the transposed rotation to the columns i and j of Q such that
+/// the product QR stays unchanged
+fn rotate_columns<T>(q: &mut General<T>, i: usize, j: usize, c: T, s: T)
+where
+    T: Real,
+{
+    for k in 0..q.nrows() {
+        let (q_ki, q_kj): (T, T) = (q[[k, i]], q[[k, j]]);
+        q[[k, i]] = c * q_ki + s * q_kj;
+        q[[k, j]] = -s * q_ki + c * q_kj;
+    }
+}
+
+/// Eliminates the subdiagonal of R, which is upper Hessenberg from the
+/// column k on
+fn restore_upper_triangular<T>(q: &mut General<T>, r: &mut General<T>, k: usize)
+where
+    T: Real,
+{
+    let (m, n): (usize, usize) = r.dim();
+    for j in k..n.min(m.saturating_sub(1)) {
+        let (c, s): (T, T) = givens(r[[j, j]], r[[j + 1, j]]);
+        rotate_rows(r, j, j + 1, c, s);
+        rotate_columns(q, j, j + 1, c, s);
+        r[[j + 1, j]] = T::zero();
+    }
+}
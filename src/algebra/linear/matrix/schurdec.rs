@@ -1,10 +1,13 @@
-use crate::algebra::linear::matrix::{General, UpperTriangular};
+use crate::algebra::abstr::{Complex, Real, Scalar};
+use crate::algebra::linear::matrix::{General, Solve, Transpose, UpperTriangular};
+use crate::algebra::linear::LinalgError;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::clone::Clone;
+use std::cmp::Ordering;
 
 pub trait SchurDecomposition<T> {
-    fn dec_schur(&self) -> Result<SchurDec<T>, ()>;
+    fn dec_schur(&self) -> Result<SchurDec<T>, LinalgError>;
 }
 
 /// Result of Schur decomposition
@@ -42,3 +45,297 @@ impl<T> SchurDec<T> {
         (self.q, self.u)
     }
 }
+
+impl<T> SchurDec<Complex<T>>
+where
+    T: Real,
+    Complex<T>: Scalar,
+{
+    /// Reorders the Schur decomposition $A = QUQ^*$, such that the selected
+    /// eigenvalues are the leading diagonal entries of $U$
+    ///
+    /// Adjacent diagonal entries are swapped with Givens rotations, which are
+    /// accumulated in $Q$ (like LAPACK's xtrsen). The relative order of the
+    /// selected and the remaining eigenvalues is preserved. The first $k$
+    /// columns of the reordered $Q$ are an orthonormal basis of the invariant
+    /// subspace belonging to the $k$ selected eigenvalues.
+    ///
+    /// # Arguments
+    ///
+    /// * `select`: select\[i\] is true if the eigenvalue $u_{ii}$ is moved
+    ///   to the leading block
+    ///
+    /// # Panics
+    ///
+    /// if the length of select does not match the dimension of $U$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::matrix::{ConjugateTranspose, General, SchurDec, SchurDecomposition, UpperTriangular};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// let a: General<Complex<f64>> = matrix![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0), Complex::new(0.0, 0.0);
+    ///                                        Complex::new(0.0, 0.0), Complex::new(-3.0, 0.0), Complex::new(1.0, 0.0);
+    ///                                        Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(-2.0, 0.0)];
+    ///
+    /// let schur: SchurDec<Complex<f64>> = a.dec_schur().unwrap();
+    /// let u: General<Complex<f64>> = schur.clone().u().into();
+    ///
+    /// // Move the eigenvalues with negative real part to the front
+    /// let select: Vec<bool> = (0..3).map(|i| u[[i, i]].re < 0.0).collect();
+    /// let (q, u): (General<Complex<f64>>, UpperTriangular<Complex<f64>>) = schur.reorder(&select).qu();
+    ///
+    /// assert!(u[[0, 0]].re < 0.0 && u[[1, 1]].re < 0.0);
+    /// assert_relative_eq!(u[[2, 2]], Complex::new(1.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// assert_relative_eq!(&(&q * &General::from(u)) * &q.clone().conj_transpose(), a, epsilon = Complex::new(1.0e-10, 1.0e-10));
+    /// ```
+    pub fn reorder(self, select: &[bool]) -> SchurDec<Complex<T>> {
+        let mut u: General<Complex<T>> = self.u.into();
+        let mut q: General<Complex<T>> = self.q;
+        let n: usize = u.nrows();
+        assert_eq!(
+            select.len(),
+            n,
+            "The length of select must match the dimension of the Schur form"
+        );
+
+        let mut ks: usize = 0;
+        for (k, select_k) in select.iter().enumerate() {
+            if !select_k {
+                continue;
+            }
+            for j in (ks..k).rev() {
+                SchurDec::swap(&mut u, &mut q, j);
+            }
+            ks += 1;
+        }
+
+        SchurDec::new(q, UpperTriangular::new(u))
+    }
+
+    /// Swaps the diagonal entries $u_{kk}$ and $u_{k+1,k+1}$ with a unitary
+    /// similarity transformation
+    fn swap(u: &mut General<Complex<T>>, q: &mut General<Complex<T>>, k: usize) {
+        let n: usize = u.nrows();
+        let u_11: Complex<T> = u[[k, k]];
+        let u_22: Complex<T> = u[[k + 1, k + 1]];
+
+        // The rotation maps the eigenvector [u_12; u_22 - u_11] belonging to
+        // u_22 onto the first unit vector
+        let (c, s) = General::rotation(u[[k, k + 1]], u_22 - u_11);
+        General::rotate_left(u, k, c, s, k);
+        General::rotate_right(u, k, c, s, k + 1);
+        General::rotate_right(q, k, c, s, n - 1);
+
+        u[[k, k]] = u_22;
+        u[[k + 1, k + 1]] = u_11;
+        u[[k + 1, k]] = Complex::new(T::zero(), T::zero());
+    }
+}
+
+impl<T> SchurDec<T>
+where
+    T: Real,
+{
+    /// Reorders the real Schur decomposition $A = QUQ^T$, such that the
+    /// selected eigenvalues are the leading diagonal blocks of $U$
+    ///
+    /// $U$ is quasi upper triangular, a nonzero subdiagonal entry $u_{i+1,
+    /// i}$ marks a $2 \times 2$ block. Such a block is moved as a whole.
+    /// Adjacent blocks are swapped with an orthogonal similarity
+    /// transformation, which is accumulated in $Q$ (like LAPACK's xtrsen). Two
+    /// $1 \times 1$ blocks are swapped with a Givens rotation, otherwise the
+    /// transformation is computed from the solution $X$ of the Sylvester
+    /// equation $U_{11}X - XU_{22} = U_{12}$, the columns of $[-X; I]$ span
+    /// the invariant subspace belonging to $U_{22}$. The relative order of
+    /// the selected and the remaining blocks is preserved.
+    ///
+    /// Like xtrexc, a swap is rejected if the entries below the swapped
+    /// blocks, which are set to zero, exceed $10 \epsilon \|D\|_F$, where $D$
+    /// is the diagonal part of $U$ holding both blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `select`: select\[i\] is true if the block containing $u_{ii}$ is
+    ///   moved to the leading blocks. A $2 \times 2$ block is moved if one of
+    ///   its two entries is selected.
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::IllConditioned`] if two blocks are too close to be
+    /// swapped, i.e. if they have a common eigenvalue or the swap was
+    /// rejected
+    ///
+    /// # Panics
+    ///
+    /// if the length of select does not match the dimension of $U$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, SchurDec, SchurDecomposition, Transpose, UpperHessenberg, UpperTriangular};
+    /// use mathru::{assert_relative_eq, matrix};
+    ///
+    /// // Eigenvalues i, -i and 3
+    /// let h: UpperHessenberg<f64> = matrix![  0.0, -1.0, 1.0;
+    ///                                         1.0, 0.0, 2.0;
+    ///                                         0.0, 0.0, 3.0].into();
+    ///
+    /// let schur: SchurDec<f64> = h.dec_schur().unwrap();
+    /// let (q, u): (General<f64>, UpperTriangular<f64>) = schur.reorder(&[false, false, true]).unwrap().qu();
+    /// let u: General<f64> = u.into();
+    ///
+    /// assert_relative_eq!(u[[0, 0]], 3.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(u[[1, 0]], 0.0);
+    /// assert_relative_eq!(&(&q * &u) * &q.transpose(), General::from(h), epsilon = 1.0e-10);
+    /// ```
+    pub fn reorder(self, select: &[bool]) -> Result<SchurDec<T>, LinalgError> {
+        let mut u: General<T> = self.u.into();
+        let mut q: General<T> = self.q;
+        let n: usize = u.nrows();
+        assert_eq!(
+            select.len(),
+            n,
+            "The length of select must match the dimension of the Schur form"
+        );
+
+        let mut ks: usize = 0;
+        let mut k: usize = 0;
+        while k < n {
+            let size: usize = if k + 1 < n && u[[k + 1, k]] != T::zero() {
+                2
+            } else {
+                1
+            };
+
+            if select[k] || (size == 2 && select[k + 1]) {
+                let mut j: usize = k;
+                while j > ks {
+                    let size_prev: usize = if j >= ks + 2 && u[[j - 1, j - 2]] != T::zero() {
+                        2
+                    } else {
+                        1
+                    };
+                    SchurDec::swap_blocks(&mut u, &mut q, j - size_prev, size_prev, size)?;
+                    j -= size_prev;
+                }
+                ks += size;
+            }
+            k += size;
+        }
+
+        Ok(SchurDec::new(q, UpperTriangular::new(u)))
+    }
+
+    /// Swaps the adjacent diagonal blocks $U_{11} \in T^{p \times p}$ and
+    /// $U_{22} \in T^{r \times r}$ starting in row $j$ with an orthogonal
+    /// similarity transformation
+    fn swap_blocks(
+        u: &mut General<T>,
+        q: &mut General<T>,
+        j: usize,
+        p: usize,
+        r: usize,
+    ) -> Result<(), LinalgError> {
+        let n: usize = u.nrows();
+        let m: usize = p + r;
+        let d: General<T> = u.get_slice(j, j + m - 1, j, j + m - 1);
+
+        // W is orthogonal and its first r columns span the invariant subspace
+        // belonging to U_22
+        let w: General<T> = if m == 2 {
+            // The eigenvector [u_12; u_22 - u_11] is the first column of W
+            let v_1: T = d[[0, 1]];
+            let v_2: T = d[[1, 1]] - d[[0, 0]];
+            let norm: T = (v_1 * v_1 + v_2 * v_2).sqrt();
+            if norm == T::zero() {
+                return Ok(());
+            }
+            let c: T = v_1 / norm;
+            let s: T = v_2 / norm;
+            let mut w: General<T> = General::zero(2, 2);
+            w[[0, 0]] = c;
+            w[[1, 0]] = s;
+            w[[0, 1]] = -s;
+            w[[1, 1]] = c;
+            w
+        } else {
+            // Kronecker form of U_11 X - X U_22 = U_12
+            let mut k: General<T> = General::zero(p * r, p * r);
+            let mut rhs: General<T> = General::zero(p * r, 1);
+            for c in 0..r {
+                for i in 0..p {
+                    let row: usize = c * p + i;
+                    rhs[[row, 0]] = d[[i, p + c]];
+                    for l in 0..p {
+                        k[[row, c * p + l]] += d[[i, l]];
+                    }
+                    for l in 0..r {
+                        k[[row, l * p + i]] -= d[[p + l, p + c]];
+                    }
+                }
+            }
+            let x: General<T> = k.solve(&rhs).map_err(|_| LinalgError::IllConditioned)?;
+
+            let mut v: General<T> = General::zero(m, r);
+            for c in 0..r {
+                for i in 0..p {
+                    v[[i, c]] = -x[[c * p + i, 0]];
+                }
+                v[[p + c, c]] = T::one();
+            }
+            let mut w: General<T> = General::one(m);
+            for c in 0..r {
+                let h: General<T> = General::householder(&v.get_column(c), c);
+                v = &h * &v;
+                w = &w * &h;
+            }
+            w
+        };
+        let w_t: General<T> = w.clone().transpose();
+
+        // Reject the swap if the entries, which are set to zero, are not
+        // negligible
+        let d_swapped: General<T> = &(&w_t * &d) * &w;
+        let mut residual: T = T::zero();
+        for l in 0..r {
+            for i in r..m {
+                residual += d_swapped[[i, l]] * d_swapped[[i, l]];
+            }
+        }
+        let d_norm: T = d.iter().fold(T::zero(), |acc, d_ij| acc + *d_ij * *d_ij);
+        let threshold: T = T::from_f64(10.0) * T::default_epsilon() * d_norm.sqrt();
+        match residual.sqrt().partial_cmp(&threshold) {
+            Some(Ordering::Greater) | None => return Err(LinalgError::IllConditioned),
+            _ => {}
+        }
+
+        let rows: General<T> = &w_t * &u.get_slice(j, j + m - 1, j, n - 1);
+        for (l, col) in (j..n).enumerate() {
+            for i in 0..m {
+                u[[j + i, col]] = rows[[i, l]];
+            }
+        }
+        let cols: General<T> = &u.get_slice(0, j + m - 1, j, j + m - 1) * &w;
+        for l in 0..m {
+            for i in 0..j + m {
+                u[[i, j + l]] = cols[[i, l]];
+            }
+        }
+        let cols: General<T> = &q.get_slice(0, n - 1, j, j + m - 1) * &w;
+        for l in 0..m {
+            for i in 0..n {
+                q[[i, j + l]] = cols[[i, l]];
+            }
+        }
+
+        for l in 0..r {
+            for i in r..m {
+                u[[j + i, j + l]] = T::zero();
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::linear::{matrix::General, vector::Vector};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Singular value decomposition
+///
+/// ```math
+/// A = U \Sigma V^T
+/// ```
+/// The singular values are sorted in descending order.
+pub trait SingularValueDecomposition<T> {
+    /// Computes the full decomposition, $U \in T^{m \times m}$ and
+    /// $V \in T^{n \times n}$
+    fn dec_svd(&self) -> Result<SingularValueDec<T>, LinalgError>;
+
+    /// Computes the thin decomposition, $U \in T^{m \times k}$ and
+    /// $V \in T^{n \times k}$ with $k = \min(m, n)$
+    fn dec_svd_thin(&self) -> Result<SingularValueDec<T>, LinalgError>;
+
+    /// Computes the singular values only
+    fn singular_values(&self) -> Result<Vector<T>, LinalgError>;
+}
+
+/// Result of a singular value decomposition
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SingularValueDec<T> {
+    u: General<T>,
+    s: Vector<T>,
+    v: General<T>,
+}
+
+impl<T> SingularValueDec<T> {
+    pub(super) fn new(u: General<T>, s: Vector<T>, v: General<T>) -> SingularValueDec<T> {
+        SingularValueDec { u, s, v }
+    }
+
+    /// Return the left singular vectors
+    pub fn u(self) -> General<T> {
+        self.u
+    }
+
+    /// Return the singular values in descending order
+    pub fn s(self) -> Vector<T> {
+        self.s
+    }
+
+    /// Return the right singular vectors
+    pub fn v(self) -> General<T> {
+        self.v
+    }
+
+    pub fn usv(self) -> (General<T>, Vector<T>, General<T>) {
+        (self.u, self.s, self.v)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::algebra::abstr::{AbsDiffEq, Field, Scalar};
+use crate::algebra::linear::matrix::SMatrix;
+
+impl<T, const M: usize, const N: usize> AbsDiffEq for SMatrix<T, M, N>
+where
+    T: Field + Scalar + AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &SMatrix<T, M, N>, epsilon: T) -> bool {
+        self.data
+            .iter()
+            .flatten()
+            .zip(other.data.iter().flatten())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
@@ -0,0 +1,52 @@
+use crate::algebra::abstr::{Field, Scalar};
+use crate::algebra::linear::matrix::SMatrix;
+use std::ops::{Add, AddAssign};
+
+impl<T, const M: usize, const N: usize> Add<SMatrix<T, M, N>> for SMatrix<T, M, N>
+where
+    T: Field + Scalar,
+{
+    type Output = SMatrix<T, M, N>;
+
+    /// Adds two matrices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 0.0], [3.0, -7.0]]);
+    /// let b: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [-3.0, 4.0]]);
+    ///
+    /// assert_eq!(a + b, SMatrix::from_rows([[2.0, 2.0], [0.0, -3.0]]));
+    /// ```
+    fn add(mut self, rhs: SMatrix<T, M, N>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T, const M: usize, const N: usize> Add<T> for SMatrix<T, M, N>
+where
+    T: Field + Scalar,
+{
+    type Output = SMatrix<T, M, N>;
+
+    /// Adds a scalar to every element of the matrix
+    fn add(self, rhs: T) -> Self::Output {
+        self.apply(&|x: &T| *x + rhs)
+    }
+}
+
+impl<T, const M: usize, const N: usize> AddAssign<SMatrix<T, M, N>> for SMatrix<T, M, N>
+where
+    T: Field + Scalar,
+{
+    fn add_assign(&mut self, rhs: SMatrix<T, M, N>) {
+        self.data
+            .iter_mut()
+            .flatten()
+            .zip(rhs.data.iter().flatten())
+            .for_each(|(a, b)| *a += *b);
+    }
+}
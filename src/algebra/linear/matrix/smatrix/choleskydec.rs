@@ -0,0 +1,102 @@
+use crate::algebra::abstr::Real;
+use crate::algebra::linear::matrix::{SMatrix, SVector, Solve};
+use crate::algebra::linear::LinalgError;
+
+/// Result of a Cholesky decomposition of a fixed-size matrix
+///
+/// ```math
+/// A = LL^T
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SCholeskyDec<T, const N: usize> {
+    l: SMatrix<T, N, N>,
+}
+
+impl<T, const N: usize> SCholeskyDec<T, N> {
+    /// Return the lower triangular matrix L
+    pub fn l(self) -> SMatrix<T, N, N> {
+        self.l
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Real,
+{
+    /// Decomposes the symmetric, positive definite matrix into a lower
+    /// triangular matrix and its transpose
+    ///
+    /// ```math
+    /// A = LL^T
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// if the matrix is not positive definite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{SMatrix, Transpose};
+    /// use mathru::assert_relative_eq;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[4.0, 2.0], [2.0, 5.0]]);
+    ///
+    /// let l: SMatrix<f64, 2, 2> = a.dec_cholesky().unwrap().l();
+    ///
+    /// assert_relative_eq!(l * l.transpose(), a);
+    /// ```
+    pub fn dec_cholesky(&self) -> Result<SCholeskyDec<T, N>, LinalgError> {
+        let mut l: SMatrix<T, N, N> = SMatrix::zero();
+
+        for j in 0..N {
+            let mut d: T = self.data[j][j];
+            for k in 0..j {
+                d -= l.data[k][j] * l.data[k][j];
+            }
+            if d <= T::zero() {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            let l_jj: T = d.sqrt();
+            l.data[j][j] = l_jj;
+
+            for i in (j + 1)..N {
+                let mut s: T = self.data[j][i];
+                for k in 0..j {
+                    s -= l.data[k][i] * l.data[k][j];
+                }
+                l.data[j][i] = s / l_jj;
+            }
+        }
+
+        Ok(SCholeskyDec { l })
+    }
+}
+
+impl<T, const N: usize> Solve<SVector<T, N>> for SCholeskyDec<T, N>
+where
+    T: Real,
+{
+    /// Solves Ax = b with A = LL^T
+    fn solve(&self, rhs: &SVector<T, N>) -> Result<SVector<T, N>, LinalgError> {
+        let mut x: SVector<T, N> = *rhs;
+
+        for i in 0..N {
+            for k in 0..i {
+                let x_k: T = x.data[0][k];
+                x.data[0][i] -= self.l.data[k][i] * x_k;
+            }
+            x.data[0][i] /= self.l.data[i][i];
+        }
+
+        for i in (0..N).rev() {
+            for k in (i + 1)..N {
+                let x_k: T = x.data[0][k];
+                x.data[0][i] -= self.l.data[i][k] * x_k;
+            }
+            x.data[0][i] /= self.l.data[i][i];
+        }
+
+        Ok(x)
+    }
+}
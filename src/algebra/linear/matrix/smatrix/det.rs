@@ -0,0 +1,24 @@
+use crate::algebra::abstr::{Field, Scalar};
+use crate::algebra::linear::matrix::{Determinant, SMatrix};
+
+impl<T, const N: usize> Determinant<T> for SMatrix<T, N, N>
+where
+    T: Field + Scalar,
+{
+    /// Computes the determinant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Determinant, SMatrix};
+    /// use mathru::assert_relative_eq;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, -2.0], [3.0, -7.0]]);
+    ///
+    /// assert_relative_eq!(a.det(), -1.0, epsilon = 1.0e-10);
+    /// ```
+    fn det(&self) -> T {
+        let (a, _, sign) = self.lu_packed();
+        (0..N).fold(sign, |d, i| d * a.data[i][i])
+    }
+}
@@ -0,0 +1,90 @@
+use crate::algebra::linear::{
+    matrix::{General, SMatrix, SVector},
+    vector::Vector,
+    LinalgError,
+};
+
+impl<T, const M: usize, const N: usize> From<SMatrix<T, M, N>> for General<T>
+where
+    T: Copy,
+{
+    /// Copies the fixed-size matrix into a heap allocated matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, SMatrix};
+    /// use mathru::matrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(General::from(a), matrix![1.0, 2.0; 3.0, 4.0]);
+    /// ```
+    fn from(a: SMatrix<T, M, N>) -> Self {
+        General::new(M, N, a.data.iter().flatten().copied().collect())
+    }
+}
+
+impl<T, const M: usize, const N: usize> TryFrom<General<T>> for SMatrix<T, M, N>
+where
+    T: Copy,
+{
+    type Error = LinalgError;
+
+    /// Copies a heap allocated matrix into a fixed-size matrix
+    ///
+    /// # Errors
+    ///
+    /// if the dimension of the matrix is not M x N
+    fn try_from(a: General<T>) -> Result<Self, Self::Error> {
+        if a.dim() != (M, N) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        Ok(SMatrix {
+            data: std::array::from_fn(|j| std::array::from_fn(|i| a.data[j * M + i])),
+        })
+    }
+}
+
+impl<T, const N: usize> From<SVector<T, N>> for Vector<T>
+where
+    T: Copy,
+{
+    fn from(v: SVector<T, N>) -> Self {
+        Vector::new_column(v.data[0].to_vec())
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vector<T>> for SVector<T, N>
+where
+    T: Copy,
+{
+    type Error = LinalgError;
+
+    /// Copies a column vector into a fixed-size vector
+    ///
+    /// # Errors
+    ///
+    /// if the vector is not a column vector with N entries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::SVector, vector::Vector};
+    /// use mathru::vector;
+    ///
+    /// let v: SVector<f64, 3> = SVector::try_from(vector![1.0; 2.0; 3.0]).unwrap();
+    ///
+    /// assert_eq!(v, SVector::new_column([1.0, 2.0, 3.0]));
+    /// ```
+    fn try_from(v: Vector<T>) -> Result<Self, Self::Error> {
+        if v.dim() != (N, 1) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        Ok(SMatrix {
+            data: [std::array::from_fn(|i| v.data.data[i])],
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::algebra::linear::matrix::SMatrix;
+use std::ops::{Index, IndexMut};
+
+impl<T, const M: usize, const N: usize> Index<[usize; 2]> for SMatrix<T, M, N> {
+    type Output = T;
+
+    /// Returns the element at row i and column j
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        &self.data[index[1]][index[0]]
+    }
+}
+
+impl<T, const M: usize, const N: usize> IndexMut<[usize; 2]> for SMatrix<T, M, N> {
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        &mut self.data[index[1]][index[0]]
+    }
+}
+
+impl<T, const N: usize> Index<usize> for SMatrix<T, N, 1> {
+    type Output = T;
+
+    /// Returns the i-th entry of a column vector
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[0][index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for SMatrix<T, N, 1> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[0][index]
+    }
+}
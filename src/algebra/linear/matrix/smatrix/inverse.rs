@@ -0,0 +1,38 @@
+use crate::algebra::abstr::{Field, Scalar};
+use crate::algebra::linear::matrix::{Inverse, SMatrix, SVector, Solve};
+use crate::algebra::linear::LinalgError;
+
+impl<T, const N: usize> Inverse<T> for SMatrix<T, N, N>
+where
+    T: Field + Scalar,
+{
+    type Output = SMatrix<T, N, N>;
+
+    /// Inverse Matrix
+    ///
+    /// # Errors
+    ///
+    /// if the matrix is singular
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Inverse, SMatrix};
+    /// use mathru::assert_relative_eq;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 0.0], [3.0, -7.0]]);
+    /// let b_inv: SMatrix<f64, 2, 2> = a.inv().unwrap();
+    ///
+    /// assert_relative_eq!(a * b_inv, SMatrix::one(), epsilon = 1.0e-10);
+    /// ```
+    fn inv(&self) -> Result<SMatrix<T, N, N>, LinalgError> {
+        let lu = self.dec_lu()?;
+        let mut a_inv: SMatrix<T, N, N> = SMatrix::zero();
+        for j in 0..N {
+            let mut e_j: SVector<T, N> = SMatrix::zero();
+            e_j.data[0][j] = T::one();
+            a_inv.data[j] = lu.solve(&e_j)?.data[0];
+        }
+        Ok(a_inv)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::algebra::abstr::{Field, Scalar};
+use crate::algebra::linear::matrix::{SMatrix, SVector, Solve};
+use crate::algebra::linear::LinalgError;
+
+/// Result of a LU decomposition of a fixed-size matrix
+///
+/// ```math
+/// PA = LU
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SLUDec<T, const N: usize> {
+    l: SMatrix<T, N, N>,
+    u: SMatrix<T, N, N>,
+    p: SMatrix<T, N, N>,
+}
+
+impl<T, const N: usize> SLUDec<T, N> {
+    /// Return the unit lower triangular matrix L
+    pub fn l(self) -> SMatrix<T, N, N> {
+        self.l
+    }
+
+    /// Return the upper triangular matrix U
+    pub fn u(self) -> SMatrix<T, N, N> {
+        self.u
+    }
+
+    /// Return the permutation matrix P
+    pub fn p(self) -> SMatrix<T, N, N> {
+        self.p
+    }
+
+    /// Return the matrices L, U and P
+    pub fn lup(self) -> (SMatrix<T, N, N>, SMatrix<T, N, N>, SMatrix<T, N, N>) {
+        (self.l, self.u, self.p)
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Field + Scalar,
+{
+    /// Decomposes the matrix into a unit lower and an upper triangular matrix
+    ///
+    /// ```math
+    /// PA = LU
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, -2.0], [3.0, -7.0]]);
+    ///
+    /// let (l, u, p) = a.dec_lu().unwrap().lup();
+    ///
+    /// assert_eq!(p * a, l * u);
+    /// ```
+    pub fn dec_lu(&self) -> Result<SLUDec<T, N>, LinalgError> {
+        let (a, perm, _) = self.lu_packed();
+
+        let mut l: SMatrix<T, N, N> = SMatrix::one();
+        let mut u: SMatrix<T, N, N> = SMatrix::zero();
+        let mut p: SMatrix<T, N, N> = SMatrix::zero();
+
+        for j in 0..N {
+            for i in 0..N {
+                if i > j {
+                    l.data[j][i] = a.data[j][i];
+                } else {
+                    u.data[j][i] = a.data[j][i];
+                }
+            }
+        }
+
+        for (i, k) in perm.into_iter().enumerate() {
+            p.data[k][i] = T::one();
+        }
+
+        Ok(SLUDec { l, u, p })
+    }
+
+    /// Gaussian elimination with partial pivoting
+    ///
+    /// Returns L and U packed into one matrix, the row permutation and the
+    /// sign of the permutation.
+    pub(super) fn lu_packed(&self) -> (SMatrix<T, N, N>, [usize; N], T) {
+        let mut a: SMatrix<T, N, N> = *self;
+        let mut perm: [usize; N] = std::array::from_fn(|i| i);
+        let mut sign: T = T::one();
+
+        for i in 0..N {
+            let mut max: T = T::zero();
+            let mut i_max: usize = i;
+
+            for k in i..N {
+                let p_cand: T = a.data[i][k].abs();
+                if p_cand > max {
+                    max = p_cand;
+                    i_max = k;
+                }
+            }
+
+            if i != i_max {
+                for j in 0..N {
+                    a.data[j].swap(i, i_max);
+                }
+                perm.swap(i, i_max);
+                sign = -sign;
+            }
+
+            if a.data[i][i] == T::zero() {
+                continue;
+            }
+
+            for k in (i + 1)..N {
+                let f: T = a.data[i][k] / a.data[i][i];
+                for j in (i + 1)..N {
+                    let a_ij: T = a.data[j][i];
+                    a.data[j][k] -= f * a_ij;
+                }
+                a.data[i][k] = f;
+            }
+        }
+
+        (a, perm, sign)
+    }
+}
+
+impl<T, const N: usize> Solve<SVector<T, N>> for SLUDec<T, N>
+where
+    T: Field + Scalar,
+{
+    /// Solves Ax = b
+    ///
+    /// # Errors
+    ///
+    /// if U is singular
+    fn solve(&self, rhs: &SVector<T, N>) -> Result<SVector<T, N>, LinalgError> {
+        let mut x: SVector<T, N> = self.p * *rhs;
+
+        for i in 0..N {
+            for k in 0..i {
+                let x_k: T = x.data[0][k];
+                x.data[0][i] -= self.l.data[k][i] * x_k;
+            }
+        }
+
+        for i in (0..N).rev() {
+            let u_ii: T = self.u.data[i][i];
+            if u_ii == T::zero() {
+                return Err(LinalgError::Singular);
+            }
+            for k in (i + 1)..N {
+                let x_k: T = x.data[0][k];
+                x.data[0][i] -= self.u.data[k][i] * x_k;
+            }
+            x.data[0][i] /= u_ii;
+        }
+
+        Ok(x)
+    }
+}
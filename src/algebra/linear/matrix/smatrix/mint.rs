@@ -0,0 +1,80 @@
+use crate::algebra::linear::matrix::{SMatrix, Transpose};
+use mint::{
+    ColumnMatrix2, ColumnMatrix2x3, ColumnMatrix2x4, ColumnMatrix3, ColumnMatrix3x2,
+    ColumnMatrix3x4, ColumnMatrix4, ColumnMatrix4x2, ColumnMatrix4x3, RowMatrix2, RowMatrix2x3,
+    RowMatrix2x4, RowMatrix3, RowMatrix3x2, RowMatrix3x4, RowMatrix4, RowMatrix4x2, RowMatrix4x3,
+    Vector2, Vector3, Vector4,
+};
+
+macro_rules! impl_mint_matrix(
+    ($(($rows: literal, $columns: literal) => $CM: ident, $RM: ident);* $(;)*) => {$(
+
+        impl<T> From<$CM<T>> for SMatrix<T, $rows, $columns>
+        {
+            fn from(m: $CM<T>) -> Self {
+                SMatrix::new(m.into())
+            }
+        }
+
+        impl<T> From<SMatrix<T, $rows, $columns>> for $CM<T>
+        {
+            fn from(m: SMatrix<T, $rows, $columns>) -> Self {
+                m.data.into()
+            }
+        }
+
+        impl<T> From<$RM<T>> for SMatrix<T, $rows, $columns>
+            where T: Copy
+        {
+            fn from(m: $RM<T>) -> Self {
+                SMatrix::<T, $columns, $rows>::new(m.into()).transpose()
+            }
+        }
+
+        impl<T> From<SMatrix<T, $rows, $columns>> for $RM<T>
+            where T: Copy
+        {
+            fn from(m: SMatrix<T, $rows, $columns>) -> Self {
+                m.transpose().data.into()
+            }
+        }
+    )*}
+);
+
+impl_mint_matrix!(
+    (2, 2) => ColumnMatrix2, RowMatrix2;
+    (2, 3) => ColumnMatrix2x3, RowMatrix2x3;
+    (2, 4) => ColumnMatrix2x4, RowMatrix2x4;
+    (3, 2) => ColumnMatrix3x2, RowMatrix3x2;
+    (3, 3) => ColumnMatrix3, RowMatrix3;
+    (3, 4) => ColumnMatrix3x4, RowMatrix3x4;
+    (4, 2) => ColumnMatrix4x2, RowMatrix4x2;
+    (4, 3) => ColumnMatrix4x3, RowMatrix4x3;
+    (4, 4) => ColumnMatrix4, RowMatrix4;
+);
+
+macro_rules! impl_mint_vector(
+    ($($rows: literal => $MV: ident);* $(;)*) => {$(
+
+        impl<T> From<$MV<T>> for SMatrix<T, $rows, 1>
+        {
+            fn from(v: $MV<T>) -> Self {
+                SMatrix::new_column(v.into())
+            }
+        }
+
+        impl<T> From<SMatrix<T, $rows, 1>> for $MV<T>
+        {
+            fn from(v: SMatrix<T, $rows, 1>) -> Self {
+                let [column] = v.data;
+                column.into()
+            }
+        }
+    )*}
+);
+
+impl_mint_vector!(
+    2 => Vector2;
+    3 => Vector3;
+    4 => Vector4;
+);
@@ -0,0 +1,25 @@
+mod abs_diff_eq;
+mod add;
+mod choleskydec;
+mod det;
+mod from;
+mod index;
+mod inverse;
+mod ludec;
+#[cfg(feature = "convert-mint")]
+mod mint;
+mod mul;
+mod neg;
+mod qrdec;
+mod relative_eq;
+mod smatrix;
+mod solve;
+mod sub;
+mod transpose;
+
+pub use self::{
+    choleskydec::SCholeskyDec,
+    ludec::SLUDec,
+    qrdec::SQRDec,
+    smatrix::{SMatrix, SVector},
+};
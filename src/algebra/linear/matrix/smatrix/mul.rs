@@ -0,0 +1,61 @@
+use crate::algebra::abstr::{Field, Scalar};
+use crate::algebra::linear::matrix::SMatrix;
+use std::ops::{Mul, MulAssign};
+
+impl<T, const M: usize, const K: usize, const N: usize> Mul<SMatrix<T, K, N>> for SMatrix<T, M, K>
+where
+    T: Field + Scalar,
+{
+    type Output = SMatrix<T, M, N>;
+
+    /// Multiplies two matrices
+    ///
+    /// The inner dimensions are checked at compile time. A matrix-vector
+    /// product is the special case of N = 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{SMatrix, SVector};
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+    /// let x: SVector<f64, 2> = SVector::new_column([1.0, -1.0]);
+    ///
+    /// assert_eq!(a * a, SMatrix::from_rows([[7.0, 10.0], [15.0, 22.0]]));
+    /// assert_eq!(a * x, SVector::new_column([-1.0, -1.0]));
+    /// ```
+    fn mul(self, rhs: SMatrix<T, K, N>) -> Self::Output {
+        let mut c: SMatrix<T, M, N> = SMatrix::zero();
+        for j in 0..N {
+            for k in 0..K {
+                let b_kj: T = rhs.data[j][k];
+                for i in 0..M {
+                    c.data[j][i] += self.data[k][i] * b_kj;
+                }
+            }
+        }
+        c
+    }
+}
+
+impl<T, const M: usize, const N: usize> Mul<T> for SMatrix<T, M, N>
+where
+    T: Field + Scalar,
+{
+    type Output = SMatrix<T, M, N>;
+
+    /// Multiplies every element of the matrix with a scalar
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T, const M: usize, const N: usize> MulAssign<T> for SMatrix<T, M, N>
+where
+    T: Field + Scalar,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.data.iter_mut().flatten().for_each(|a| *a *= rhs);
+    }
+}
@@ -0,0 +1,14 @@
+use crate::algebra::abstr::{Field, Scalar};
+use crate::algebra::linear::matrix::SMatrix;
+use std::ops::Neg;
+
+impl<T, const M: usize, const N: usize> Neg for SMatrix<T, M, N>
+where
+    T: Field + Scalar,
+{
+    type Output = SMatrix<T, M, N>;
+
+    fn neg(self) -> Self::Output {
+        self.apply(&|x: &T| -*x)
+    }
+}
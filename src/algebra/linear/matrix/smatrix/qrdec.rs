@@ -0,0 +1,104 @@
+use crate::algebra::abstr::Real;
+use crate::algebra::linear::matrix::SMatrix;
+use crate::algebra::linear::LinalgError;
+
+/// Result of a QR decomposition of a fixed-size matrix
+///
+/// ```math
+/// A = QR
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SQRDec<T, const M: usize, const N: usize> {
+    q: SMatrix<T, M, M>,
+    r: SMatrix<T, M, N>,
+}
+
+impl<T, const M: usize, const N: usize> SQRDec<T, M, N> {
+    /// Return the orthogonal matrix Q
+    pub fn q(self) -> SMatrix<T, M, M> {
+        self.q
+    }
+
+    /// Return the upper triangular matrix R
+    pub fn r(self) -> SMatrix<T, M, N> {
+        self.r
+    }
+
+    /// Return the matrices Q and R
+    pub fn qr(self) -> (SMatrix<T, M, M>, SMatrix<T, M, N>) {
+        (self.q, self.r)
+    }
+}
+
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+where
+    T: Real,
+{
+    /// QR decomposition with Householder reflections
+    ///
+    /// ```math
+    /// A = QR
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::SMatrix;
+    /// use mathru::assert_relative_eq;
+    ///
+    /// let a: SMatrix<f64, 3, 2> = SMatrix::from_rows([[1.0, -2.0], [3.0, -7.0], [2.0, 1.0]]);
+    ///
+    /// let (q, r) = a.dec_qr().unwrap().qr();
+    ///
+    /// assert_relative_eq!(q * r, a, epsilon = 1.0e-10);
+    /// ```
+    pub fn dec_qr(&self) -> Result<SQRDec<T, M, N>, LinalgError> {
+        let mut q: SMatrix<T, M, M> = SMatrix::one();
+        let mut r: SMatrix<T, M, N> = *self;
+
+        for k in 0..N.min(M.saturating_sub(1)) {
+            let norm: T = (k..M)
+                .fold(T::zero(), |s, i| s + r.data[k][i] * r.data[k][i])
+                .sqrt();
+            if norm == T::zero() {
+                continue;
+            }
+
+            let alpha: T = if r.data[k][k] > T::zero() {
+                -norm
+            } else {
+                norm
+            };
+            let mut v: [T; M] = [T::zero(); M];
+            v[k..].copy_from_slice(&r.data[k][k..]);
+            v[k] -= alpha;
+
+            let v_v: T = (k..M).fold(T::zero(), |s, i| s + v[i] * v[i]);
+            if v_v == T::zero() {
+                continue;
+            }
+            let two: T = T::from_f64(2.0);
+
+            for j in k..N {
+                let f: T = two * (k..M).fold(T::zero(), |s, i| s + v[i] * r.data[j][i]) / v_v;
+                for (r_ij, v_i) in r.data[j][k..].iter_mut().zip(&v[k..]) {
+                    *r_ij -= f * *v_i;
+                }
+            }
+
+            for i in 0..M {
+                let f: T = two * (k..M).fold(T::zero(), |s, l| s + q.data[l][i] * v[l]) / v_v;
+                for (q_l, v_l) in q.data[k..].iter_mut().zip(&v[k..]) {
+                    q_l[i] -= f * *v_l;
+                }
+            }
+
+            r.data[k][k] = alpha;
+            for i in (k + 1)..M {
+                r.data[k][i] = T::zero();
+            }
+        }
+
+        Ok(SQRDec { q, r })
+    }
+}
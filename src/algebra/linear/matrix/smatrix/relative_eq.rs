@@ -0,0 +1,25 @@
+use crate::algebra::abstr::{AbsDiffEq, Field, RelativeEq, Scalar};
+use crate::algebra::linear::matrix::SMatrix;
+
+impl<T, const M: usize, const N: usize> RelativeEq for SMatrix<T, M, N>
+where
+    T: Field + Scalar + AbsDiffEq<Epsilon = T> + RelativeEq,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    /// A test for equality that uses a relative comparison if the values are far apart.
+    fn relative_eq(
+        &self,
+        other: &SMatrix<T, M, N>,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.data
+            .iter()
+            .flatten()
+            .zip(other.data.iter().flatten())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
@@ -0,0 +1,204 @@
+use crate::algebra::abstr::{Field, Real, Scalar};
+use std::fmt;
+use std::fmt::Display;
+
+/// Fixed-size matrix with M rows and N columns
+///
+/// The entries are stored column-major on the stack, so small matrices do not
+/// need a heap allocation.
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::linear::matrix::SMatrix;
+///
+/// let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+///
+/// assert_eq!(a.dim(), (2, 3));
+/// assert_eq!(a[[1, 2]], 6.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SMatrix<T, const M: usize, const N: usize> {
+    /// Matrix entries, stored as an array of columns
+    pub(crate) data: [[T; M]; N],
+}
+
+/// Fixed-size column vector with N entries
+pub type SVector<T, const N: usize> = SMatrix<T, N, 1>;
+
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N> {
+    /// Creates a matrix from an array of columns
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::new([[1.0, 3.0], [2.0, 4.0]]);
+    ///
+    /// assert_eq!(a, SMatrix::from_rows([[1.0, 2.0], [3.0, 4.0]]));
+    /// ```
+    pub fn new(data: [[T; M]; N]) -> SMatrix<T, M, N> {
+        SMatrix { data }
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(&self) -> (usize, usize) {
+        (M, N)
+    }
+
+    /// Returns the columns of the matrix
+    pub fn into_columns(self) -> [[T; M]; N] {
+        self.data
+    }
+}
+
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+where
+    T: Copy,
+{
+    /// Creates a matrix from an array of rows
+    pub fn from_rows(rows: [[T; N]; M]) -> SMatrix<T, M, N> {
+        SMatrix {
+            data: std::array::from_fn(|j| std::array::from_fn(|i| rows[i][j])),
+        }
+    }
+
+    /// Returns the j-th column
+    pub fn column(&self, j: usize) -> SVector<T, M> {
+        SMatrix {
+            data: [self.data[j]],
+        }
+    }
+
+    /// Returns the i-th row as a row matrix
+    pub fn row(&self, i: usize) -> SMatrix<T, 1, N> {
+        SMatrix {
+            data: std::array::from_fn(|j| [self.data[j][i]]),
+        }
+    }
+
+    /// Applies the function f on every element of the matrix
+    pub fn apply(&self, f: &dyn Fn(&T) -> T) -> SMatrix<T, M, N> {
+        SMatrix {
+            data: std::array::from_fn(|j| std::array::from_fn(|i| f(&self.data[j][i]))),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+where
+    T: Field + Scalar,
+{
+    /// Returns the zero matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 3> = SMatrix::zero();
+    ///
+    /// assert_eq!(a, SMatrix::from_rows([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]));
+    /// ```
+    pub fn zero() -> SMatrix<T, M, N> {
+        SMatrix {
+            data: [[T::zero(); M]; N],
+        }
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Field + Scalar,
+{
+    /// Returns the identity matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::one();
+    ///
+    /// assert_eq!(a, SMatrix::from_rows([[1.0, 0.0], [0.0, 1.0]]));
+    /// ```
+    pub fn one() -> SMatrix<T, N, N> {
+        let mut a: SMatrix<T, N, N> = SMatrix::zero();
+        for i in 0..N {
+            a.data[i][i] = T::one();
+        }
+        a
+    }
+
+    /// Returns the sum of the diagonal elements
+    pub fn trace(&self) -> T {
+        (0..N).fold(T::zero(), |s, i| s + self.data[i][i])
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, 1> {
+    /// Creates a column vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::SVector;
+    ///
+    /// let v: SVector<f64, 3> = SVector::new_column([1.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(v[2], 3.0);
+    /// ```
+    pub fn new_column(data: [T; N]) -> SVector<T, N> {
+        SMatrix { data: [data] }
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, 1>
+where
+    T: Field + Scalar,
+{
+    /// Computes the dot product of two vectors
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::SVector;
+    ///
+    /// let a: SVector<f64, 3> = SVector::new_column([1.0, 2.0, 3.0]);
+    /// let b: SVector<f64, 3> = SVector::new_column([4.0, 5.0, 6.0]);
+    ///
+    /// assert_eq!(a.dot(&b), 32.0);
+    /// ```
+    pub fn dot(&self, rhs: &SVector<T, N>) -> T {
+        self.data[0]
+            .iter()
+            .zip(rhs.data[0].iter())
+            .fold(T::zero(), |s, (a, b)| s + *a * *b)
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, 1>
+where
+    T: Real,
+{
+    /// Computes the euclidean norm of the vector
+    pub fn norm(&self) -> T {
+        self.dot(self).sqrt()
+    }
+}
+
+impl<T, const M: usize, const N: usize> Display for SMatrix<T, M, N>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for i in 0..M {
+            for j in 0..N {
+                write!(f, "{} ", self.data[j][i])?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::algebra::abstr::{Field, Scalar};
+use crate::algebra::linear::matrix::{SMatrix, SVector, Solve};
+use crate::algebra::linear::LinalgError;
+
+impl<T, const N: usize> Solve<SVector<T, N>> for SMatrix<T, N, N>
+where
+    T: Field + Scalar,
+{
+    /// Solves Ax = y
+    ///
+    /// # Errors
+    ///
+    /// if the matrix is singular
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{SMatrix, SVector, Solve};
+    /// use mathru::assert_relative_eq;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[2.0, 1.0], [1.0, 3.0]]);
+    /// let b: SVector<f64, 2> = SVector::new_column([3.0, 4.0]);
+    ///
+    /// assert_relative_eq!(a.solve(&b).unwrap(), SVector::new_column([1.0, 1.0]), epsilon = 1.0e-10);
+    /// ```
+    fn solve(&self, rhs: &SVector<T, N>) -> Result<SVector<T, N>, LinalgError> {
+        self.dec_lu()?.solve(rhs)
+    }
+}
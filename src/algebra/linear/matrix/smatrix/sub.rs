@@ -0,0 +1,52 @@
+use crate::algebra::abstr::{Field, Scalar};
+use crate::algebra::linear::matrix::SMatrix;
+use std::ops::{Sub, SubAssign};
+
+impl<T, const M: usize, const N: usize> Sub<SMatrix<T, M, N>> for SMatrix<T, M, N>
+where
+    T: Field + Scalar,
+{
+    type Output = SMatrix<T, M, N>;
+
+    /// Subtracts two matrices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::SMatrix;
+    ///
+    /// let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 0.0], [3.0, -7.0]]);
+    /// let b: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [-3.0, 4.0]]);
+    ///
+    /// assert_eq!(a - b, SMatrix::from_rows([[0.0, -2.0], [6.0, -11.0]]));
+    /// ```
+    fn sub(mut self, rhs: SMatrix<T, M, N>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T, const M: usize, const N: usize> Sub<T> for SMatrix<T, M, N>
+where
+    T: Field + Scalar,
+{
+    type Output = SMatrix<T, M, N>;
+
+    /// Subtracts a scalar from every element of the matrix
+    fn sub(self, rhs: T) -> Self::Output {
+        self.apply(&|x: &T| *x - rhs)
+    }
+}
+
+impl<T, const M: usize, const N: usize> SubAssign<SMatrix<T, M, N>> for SMatrix<T, M, N>
+where
+    T: Field + Scalar,
+{
+    fn sub_assign(&mut self, rhs: SMatrix<T, M, N>) {
+        self.data
+            .iter_mut()
+            .flatten()
+            .zip(rhs.data.iter().flatten())
+            .for_each(|(a, b)| *a -= *b);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::algebra::abstr::Scalar;
+use crate::algebra::linear::matrix::{ConjugateTranspose, SMatrix, Transpose};
+
+impl<T, const M: usize, const N: usize> Transpose for SMatrix<T, M, N>
+where
+    T: Copy,
+{
+    type Output = SMatrix<T, N, M>;
+
+    /// Transposes the matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{SMatrix, Transpose};
+    ///
+    /// let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///
+    /// assert_eq!(a.transpose(), SMatrix::from_rows([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]));
+    /// ```
+    fn transpose(self) -> Self::Output {
+        SMatrix {
+            data: std::array::from_fn(|j| std::array::from_fn(|i| self.data[i][j])),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> ConjugateTranspose for SMatrix<T, M, N>
+where
+    T: Scalar,
+{
+    type Output = SMatrix<T, N, M>;
+
+    /// Transposes the matrix and conjugates every element
+    fn conj_transpose(self) -> Self::Output {
+        SMatrix {
+            data: std::array::from_fn(|j| std::array::from_fn(|i| self.data[i][j].conj())),
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use crate::algebra::linear::LinalgError;
+
 pub trait Solve<T> {
     /// A * x = b
-    fn solve(&self, rhs: &T) -> Result<T, ()>;
+    fn solve(&self, rhs: &T) -> Result<T, LinalgError>;
 }
@@ -1,7 +1,9 @@
+use crate::algebra::linear::LinalgError;
+
 pub trait SubstituteForward<T> {
-    fn substitute_forward(&self, b: T) -> Result<T, ()>;
+    fn substitute_forward(&self, b: T) -> Result<T, LinalgError>;
 }
 
 pub trait SubstituteBackward<T> {
-    fn substitute_backward(&self, b: T) -> Result<T, ()>;
+    fn substitute_backward(&self, b: T) -> Result<T, LinalgError>;
 }
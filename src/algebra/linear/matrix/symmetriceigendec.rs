@@ -0,0 +1,58 @@
+use crate::algebra::linear::matrix::General;
+use crate::algebra::linear::LinalgError;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+use std::ops::Range;
+
+/// Eigen decomposition of a symmetric or Hermitian matrix
+///
+/// ```math
+/// A = V \Lambda V^*
+/// ```
+/// The eigenvalues are real and sorted in ascending order, the eigenvectors
+/// are orthonormal. Only the lower triangular part of the matrix is
+/// referenced.
+pub trait SymmetricEigenDecomposition<R, T = R> {
+    /// Computes all eigenvalues and eigenvectors
+    fn dec_symmetric_eigen(&self) -> Result<SymmetricEigenDec<R, T>, LinalgError>;
+
+    /// Computes the eigenpairs with the indices in `range`, where the index
+    /// refers to the ascending order of the eigenvalues
+    ///
+    /// # Panics
+    ///
+    /// if the range is empty or exceeds the dimension of the matrix
+    fn dec_symmetric_eigen_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<SymmetricEigenDec<R, T>, LinalgError>;
+}
+
+/// Result of a symmetric Eigen decomposition
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SymmetricEigenDec<R, T = R> {
+    values: Vec<R>,
+    vectors: General<T>,
+}
+
+impl<R, T> SymmetricEigenDec<R, T> {
+    pub(super) fn new(values: Vec<R>, vectors: General<T>) -> SymmetricEigenDec<R, T> {
+        SymmetricEigenDec { values, vectors }
+    }
+
+    /// Return the eigenvalues in ascending order
+    pub fn values(self) -> Vec<R> {
+        self.values
+    }
+
+    /// Return the eigenvectors, the j-th column belongs to the j-th eigenvalue
+    pub fn vectors(self) -> General<T> {
+        self.vectors
+    }
+
+    pub fn pair(self) -> (Vec<R>, General<T>) {
+        (self.values, self.vectors)
+    }
+}
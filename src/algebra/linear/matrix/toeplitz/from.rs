@@ -0,0 +1,21 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{General, Toeplitz},
+};
+
+impl<T> From<Toeplitz<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a Toeplitz matrix into a general matrix
+    fn from(t: Toeplitz<T>) -> General<T> {
+        let (n, _): (usize, usize) = t.dim();
+        let mut g: General<T> = General::zero(n, n);
+        for j in 0..n {
+            for i in 0..n {
+                g[[i, j]] = t.get(i, j);
+            }
+        }
+        g
+    }
+}
@@ -0,0 +1,6 @@
+mod from;
+mod mul;
+mod solve;
+mod toeplitz;
+
+pub use toeplitz::Toeplitz;
@@ -0,0 +1,41 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{matrix::Toeplitz, vector::Vector},
+};
+use std::ops::Mul;
+
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a Toeplitz<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    /// Multiplies a Toeplitz matrix with a vector without forming the matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::Toeplitz;
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::vector;
+    ///
+    /// let t: Toeplitz<f64> = Toeplitz::new(vec![1.0, 2.0, 3.0], vec![1.0, 4.0, 5.0]);
+    /// let x: Vector<f64> = vector![1.0; 1.0; 1.0];
+    ///
+    /// assert_eq!(&t * &x, vector![10.0; 7.0; 6.0]);
+    /// ```
+    fn mul(self, rhs: &'b Vector<T>) -> Self::Output {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, _): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the vector do not match"
+        );
+
+        let mut y: Vector<T> = Vector::zero(n);
+        for i in 0..n {
+            y[i] = (0..n).fold(T::zero(), |s, j| s + self.get(i, j) * rhs[j]);
+        }
+        y
+    }
+}
@@ -0,0 +1,163 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{General, Solve, Toeplitz},
+        vector::Vector,
+    },
+};
+
+impl<T> Toeplitz<T>
+where
+    T: Field + Scalar,
+{
+    /// Returns $t_k = a_{i+k,i}$, i.e. the column for $k \geq 0$ and the row
+    /// for $k < 0$
+    fn diagonal(&self, k: isize) -> T {
+        if k >= 0 {
+            self.column[k as usize]
+        } else {
+            self.row[(-k) as usize]
+        }
+    }
+
+    /// Levinson recursion for a non-symmetric Toeplitz matrix
+    ///
+    /// The buffers are indexed from one, the first entry is not used.
+    fn levinson(&self, y: &[T]) -> Result<Vec<T>, LinalgError> {
+        let n: usize = self.column.len();
+        let r = |k: isize| self.diagonal(k);
+
+        let mut x: Vec<T> = vec![T::zero(); n + 1];
+        let mut g: Vec<T> = vec![T::zero(); n + 1];
+        let mut h: Vec<T> = vec![T::zero(); n + 1];
+
+        if r(0) == T::zero() {
+            return Err(LinalgError::Singular);
+        }
+        x[1] = y[0] / r(0);
+        if n == 1 {
+            return Ok(x[1..].to_vec());
+        }
+        g[1] = r(-1) / r(0);
+        h[1] = r(1) / r(0);
+
+        for m in 1..n {
+            let m1: usize = m + 1;
+            let mut sxn: T = -y[m1 - 1];
+            let mut sd: T = -r(0);
+            for j in 1..=m {
+                sxn += r((m1 - j) as isize) * x[j];
+                sd += r((m1 - j) as isize) * g[m - j + 1];
+            }
+            if sd == T::zero() {
+                return Err(LinalgError::Singular);
+            }
+            let x_m1: T = sxn / sd;
+            x[m1] = x_m1;
+            for j in 1..=m {
+                x[j] -= x_m1 * g[m - j + 1];
+            }
+            if m1 == n {
+                break;
+            }
+
+            let mut sgn: T = -r(-(m1 as isize));
+            let mut shn: T = -r(m1 as isize);
+            let mut sgd: T = -r(0);
+            for j in 1..=m {
+                sgn += r(j as isize - m1 as isize) * g[j];
+                shn += r((m1 - j) as isize) * h[j];
+                sgd += r(j as isize - m1 as isize) * h[m - j + 1];
+            }
+            if sgd == T::zero() {
+                return Err(LinalgError::Singular);
+            }
+            g[m1] = sgn / sgd;
+            h[m1] = shn / sd;
+
+            let pp: T = g[m1];
+            let qq: T = h[m1];
+            let mut k: usize = m;
+            for j in 1..=m.div_ceil(2) {
+                let pt1: T = g[j];
+                let pt2: T = g[k];
+                let qt1: T = h[j];
+                let qt2: T = h[k];
+                g[j] = pt1 - pp * qt2;
+                g[k] = pt2 - pp * qt1;
+                h[j] = qt1 - qq * pt2;
+                h[k] = qt2 - qq * pt1;
+                k -= 1;
+            }
+        }
+
+        Ok(x[1..].to_vec())
+    }
+}
+
+impl<T> Solve<Vector<T>> for Toeplitz<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $Tx = b$ with the Levinson recursion in O(n²) operations
+    ///
+    /// # Errors
+    ///
+    /// If a leading principal submatrix of $T$ is singular. The recursion
+    /// does not pivot, so it may be inaccurate for matrices which are not
+    /// well-conditioned in all leading principal submatrices, e.g. for
+    /// indefinite matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Solve, Toeplitz};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, vector};
+    ///
+    /// let t: Toeplitz<f64> = Toeplitz::new(vec![4.0, 2.0, 1.0], vec![4.0, 1.0, 0.5]);
+    /// let b: Vector<f64> = vector![5.5; 7.0; 7.0];
+    ///
+    /// let x: Vector<f64> = t.solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, _): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the vector do not match"
+        );
+
+        let b: Vec<T> = rhs.iter().copied().collect();
+
+        Ok(Vector::new_column(self.levinson(&b)?))
+    }
+}
+
+impl<T> Solve<General<T>> for Toeplitz<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $TX = B$ column by column
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, k): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the right-hand side do not match"
+        );
+
+        let mut x: General<T> = General::zero(n, k);
+        for j in 0..k {
+            let b_j: Vec<T> = (0..n).map(|i| rhs[[i, j]]).collect();
+            let x_j: Vec<T> = self.levinson(&b_j)?;
+            for (i, x_ij) in x_j.iter().enumerate() {
+                x[[i, j]] = *x_ij;
+            }
+        }
+        Ok(x)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::algebra::abstr::{Field, Scalar};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Toeplitz matrix
+///
+/// A square matrix with constant diagonals, $a_{ij} = t_{i-j}$. It is
+/// determined by its first column and its first row.
+///
+/// ```math
+/// \begin{pmatrix}
+/// c_0 & r_1 & \cdots & r_{n-1} \\
+/// c_1 & c_0 & \ddots & \vdots \\
+/// \vdots & \ddots & \ddots & r_1 \\
+/// c_{n-1} & \cdots & c_1 & c_0
+/// \end{pmatrix}
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toeplitz<T> {
+    pub(crate) column: Vec<T>,
+    pub(crate) row: Vec<T>,
+}
+
+impl<T> Toeplitz<T>
+where
+    T: Field + Scalar,
+{
+    /// Creates a Toeplitz matrix from its first column and its first row
+    ///
+    /// # Panics
+    ///
+    /// If the column and the row do not have the same length or if they do
+    /// not start with the same element
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, Toeplitz};
+    /// use mathru::matrix;
+    ///
+    /// let t: Toeplitz<f64> = Toeplitz::new(vec![1.0, 2.0, 3.0], vec![1.0, 4.0, 5.0]);
+    ///
+    /// let t_ref: General<f64> = matrix![  1.0, 4.0, 5.0;
+    ///                                     2.0, 1.0, 4.0;
+    ///                                     3.0, 2.0, 1.0];
+    ///
+    /// assert_eq!(General::from(t), t_ref);
+    /// ```
+    pub fn new(column: Vec<T>, row: Vec<T>) -> Toeplitz<T> {
+        assert!(
+            !column.is_empty(),
+            "A Toeplitz matrix needs at least one element"
+        );
+        assert_eq!(
+            column.len(),
+            row.len(),
+            "The column and the row must have the same length"
+        );
+        assert!(
+            column[0] == row[0],
+            "The column and the row must start with the same element"
+        );
+
+        Toeplitz { column, row }
+    }
+
+    /// Creates a symmetric Toeplitz matrix from its first column
+    pub fn new_symmetric(column: Vec<T>) -> Toeplitz<T> {
+        let row: Vec<T> = column.clone();
+        Toeplitz::new(column, row)
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(&self) -> (usize, usize) {
+        (self.column.len(), self.column.len())
+    }
+
+    /// Returns the first column
+    pub fn column(&self) -> &[T] {
+        &self.column
+    }
+
+    /// Returns the first row
+    pub fn row(&self) -> &[T] {
+        &self.row
+    }
+
+    /// Returns the element $a_{ij}$
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i >= j {
+            self.column[i - j]
+        } else {
+            self.row[j - i]
+        }
+    }
+}
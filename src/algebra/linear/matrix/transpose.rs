@@ -2,3 +2,11 @@ pub trait Transpose {
     type Output;
     fn transpose(self) -> Self::Output;
 }
+
+/// Conjugate transpose, also known as Hermitian transpose
+///
+/// For real matrices the conjugate transpose is the transpose.
+pub trait ConjugateTranspose {
+    type Output;
+    fn conj_transpose(self) -> Self::Output;
+}
@@ -0,0 +1,36 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{Determinant, Tridiagonal},
+};
+
+impl<T> Determinant<T> for Tridiagonal<T>
+where
+    T: Field + Scalar,
+{
+    /// Calculates the determinant with the three-term recurrence
+    ///
+    /// ```math
+    /// f_k = d_k f_{k-1} - l_{k-1} u_{k-1} f_{k-2}
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Determinant, Tridiagonal};
+    ///
+    /// let a: Tridiagonal<f64> = Tridiagonal::new(vec![-1.0, -1.0], vec![2.0, 2.0, 2.0], vec![-1.0, -1.0]);
+    ///
+    /// assert_eq!(a.det(), 4.0);
+    /// ```
+    fn det(&self) -> T {
+        let (n, _): (usize, usize) = self.dim();
+        let mut f_2: T = T::one();
+        let mut f_1: T = self.diag[0];
+        for k in 1..n {
+            let f_k: T = self.diag[k] * f_1 - self.lower[k - 1] * self.upper[k - 1] * f_2;
+            f_2 = f_1;
+            f_1 = f_k;
+        }
+        f_1
+    }
+}
@@ -0,0 +1,76 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{Banded, General, Tridiagonal},
+};
+
+impl<T> From<Tridiagonal<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a tridiagonal matrix into a general matrix
+    fn from(a: Tridiagonal<T>) -> General<T> {
+        let (n, _): (usize, usize) = a.dim();
+        let mut g: General<T> = General::zero(n, n);
+        for i in 0..n {
+            g[[i, i]] = a.diag[i];
+        }
+        for i in 0..(n - 1) {
+            g[[i + 1, i]] = a.lower[i];
+            g[[i, i + 1]] = a.upper[i];
+        }
+        g
+    }
+}
+
+impl<T> From<&General<T>> for Tridiagonal<T>
+where
+    T: Field + Scalar,
+{
+    /// Extracts the three diagonals of a square matrix. Entries outside of
+    /// the band are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, Tridiagonal};
+    /// use mathru::matrix;
+    ///
+    /// let a: General<f64> = matrix![  2.0, -1.0, 0.0;
+    ///                                 -1.0, 2.0, -1.0;
+    ///                                 0.0, -1.0, 2.0];
+    ///
+    /// let t: Tridiagonal<f64> = Tridiagonal::from(&a);
+    ///
+    /// assert_eq!(t.diag(), &[2.0, 2.0, 2.0]);
+    /// ```
+    fn from(a: &General<T>) -> Tridiagonal<T> {
+        let (m, n): (usize, usize) = a.dim();
+        assert_eq!(m, n, "A tridiagonal matrix has to be square");
+
+        Tridiagonal::new(
+            (0..(n - 1)).map(|i| a[[i + 1, i]]).collect(),
+            (0..n).map(|i| a[[i, i]]).collect(),
+            (0..(n - 1)).map(|i| a[[i, i + 1]]).collect(),
+        )
+    }
+}
+
+impl<T> From<Tridiagonal<T>> for Banded<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a tridiagonal matrix into a banded matrix with one sub- and
+    /// one superdiagonal
+    fn from(a: Tridiagonal<T>) -> Banded<T> {
+        let (n, _): (usize, usize) = a.dim();
+        let mut b: Banded<T> = Banded::zero(n, 1, 1);
+        for i in 0..n {
+            b[[i, i]] = a.diag[i];
+        }
+        for i in 0..(n - 1) {
+            b[[i + 1, i]] = a.lower[i];
+            b[[i, i + 1]] = a.upper[i];
+        }
+        b
+    }
+}
@@ -0,0 +1,54 @@
+use crate::algebra::linear::matrix::Tridiagonal;
+use std::ops::{Index, IndexMut};
+
+impl<T> Index<[usize; 2]> for Tridiagonal<T> {
+    type Output = T;
+
+    /// Gets the element in the matrix
+    ///
+    /// # Panics
+    ///
+    /// If the element lies outside of the three diagonals
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::Tridiagonal;
+    ///
+    /// let a: Tridiagonal<f64> = Tridiagonal::new(vec![1.0, 2.0], vec![3.0, 4.0, 5.0], vec![6.0, 7.0]);
+    ///
+    /// assert_eq!(a[[2, 1]], 2.0);
+    /// ```
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        let [i, j] = index;
+        if i == j {
+            &self.diag[i]
+        } else if i == j + 1 {
+            &self.lower[j]
+        } else if j == i + 1 {
+            &self.upper[i]
+        } else {
+            panic!("The element [{}, {}] is not on the tridiagonal band", i, j)
+        }
+    }
+}
+
+impl<T> IndexMut<[usize; 2]> for Tridiagonal<T> {
+    /// Sets the element in the matrix
+    ///
+    /// # Panics
+    ///
+    /// If the element lies outside of the three diagonals
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        let [i, j] = index;
+        if i == j {
+            &mut self.diag[i]
+        } else if i == j + 1 {
+            &mut self.lower[j]
+        } else if j == i + 1 {
+            &mut self.upper[i]
+        } else {
+            panic!("The element [{}, {}] is not on the tridiagonal band", i, j)
+        }
+    }
+}
@@ -0,0 +1,8 @@
+mod det;
+mod from;
+mod index;
+mod mul;
+mod solve;
+mod tridiagonal;
+
+pub use tridiagonal::Tridiagonal;
@@ -0,0 +1,80 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{General, Tridiagonal},
+        vector::Vector,
+    },
+};
+use std::ops::Mul;
+
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a Tridiagonal<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    /// Multiplies a tridiagonal matrix with a vector in O(n)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::Tridiagonal;
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::vector;
+    ///
+    /// let a: Tridiagonal<f64> = Tridiagonal::new(vec![-1.0, -1.0], vec![2.0, 2.0, 2.0], vec![-1.0, -1.0]);
+    /// let x: Vector<f64> = vector![1.0; 2.0; 3.0];
+    ///
+    /// assert_eq!(&a * &x, vector![0.0; 0.0; 4.0]);
+    /// ```
+    fn mul(self, rhs: &'b Vector<T>) -> Self::Output {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, _): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the vector do not match"
+        );
+
+        let mut y: Vector<T> = Vector::zero(n);
+        for i in 0..n {
+            let mut y_i: T = self.diag[i] * rhs[i];
+            if i > 0 {
+                y_i += self.lower[i - 1] * rhs[i - 1];
+            }
+            if i + 1 < n {
+                y_i += self.upper[i] * rhs[i + 1];
+            }
+            y[i] = y_i;
+        }
+        y
+    }
+}
+
+impl<'a, 'b, T> Mul<&'b General<T>> for &'a Tridiagonal<T>
+where
+    T: Field + Scalar,
+{
+    type Output = General<T>;
+
+    /// Multiplies a tridiagonal matrix with a general matrix
+    fn mul(self, rhs: &'b General<T>) -> Self::Output {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, k): (usize, usize) = rhs.dim();
+        assert_eq!(n, m, "The dimensions of the matrices do not match");
+
+        let mut c: General<T> = General::zero(n, k);
+        for j in 0..k {
+            for i in 0..n {
+                let mut c_ij: T = self.diag[i] * rhs[[i, j]];
+                if i > 0 {
+                    c_ij += self.lower[i - 1] * rhs[[i - 1, j]];
+                }
+                if i + 1 < n {
+                    c_ij += self.upper[i] * rhs[[i + 1, j]];
+                }
+                c[[i, j]] = c_ij;
+            }
+        }
+        c
+    }
+}
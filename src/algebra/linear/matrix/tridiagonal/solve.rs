@@ -0,0 +1,116 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{General, Solve, Tridiagonal},
+        vector::Vector,
+    },
+};
+
+impl<T> Solve<General<T>> for Tridiagonal<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $AX = B$ with Gaussian elimination and partial pivoting in
+    /// O(n) operations per right-hand side
+    ///
+    /// Row interchanges create a second superdiagonal, which is stored
+    /// separately, so the matrix itself is not modified.
+    ///
+    /// # Errors
+    ///
+    /// If the matrix is singular
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, k): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the right-hand side do not match"
+        );
+
+        let mut dl: Vec<T> = self.lower.clone();
+        let mut d: Vec<T> = self.diag.clone();
+        let mut du: Vec<T> = self.upper.clone();
+        // second superdiagonal, which is filled by row interchanges
+        let mut du2: Vec<T> = vec![T::zero(); n.saturating_sub(2)];
+        let mut b: General<T> = rhs.clone();
+
+        for i in 0..(n - 1) {
+            if d[i].abs() >= dl[i].abs() {
+                // no row interchange
+                if d[i] == T::zero() {
+                    return Err(LinalgError::Singular);
+                }
+                let fact: T = dl[i] / d[i];
+                d[i + 1] -= fact * du[i];
+                for j in 0..k {
+                    let b_ij: T = b[[i, j]];
+                    b[[i + 1, j]] -= fact * b_ij;
+                }
+            } else {
+                // interchange the rows i and i + 1
+                let fact: T = d[i] / dl[i];
+                d[i] = dl[i];
+                let temp: T = d[i + 1];
+                d[i + 1] = du[i] - fact * temp;
+                if i + 2 < n {
+                    du2[i] = du[i + 1];
+                    du[i + 1] = -fact * du2[i];
+                }
+                du[i] = temp;
+                for j in 0..k {
+                    let b_ij: T = b[[i, j]];
+                    let b_i1j: T = b[[i + 1, j]];
+                    b[[i, j]] = b_i1j;
+                    b[[i + 1, j]] = b_ij - fact * b_i1j;
+                }
+            }
+            dl[i] = T::zero();
+        }
+        if d[n - 1] == T::zero() {
+            return Err(LinalgError::Singular);
+        }
+
+        for j in 0..k {
+            for i in (0..n).rev() {
+                let mut b_ij: T = b[[i, j]];
+                if i + 1 < n {
+                    b_ij -= du[i] * b[[i + 1, j]];
+                }
+                if i + 2 < n {
+                    b_ij -= du2[i] * b[[i + 2, j]];
+                }
+                b[[i, j]] = b_ij / d[i];
+            }
+        }
+
+        Ok(b)
+    }
+}
+
+impl<T> Solve<Vector<T>> for Tridiagonal<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $Ax = b$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Solve, Tridiagonal};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, vector};
+    ///
+    /// let a: Tridiagonal<f64> = Tridiagonal::new(vec![1.0; 4], vec![-2.0; 5], vec![1.0; 4]);
+    /// let b: Vector<f64> = vector![-1.0; -1.0; -1.0; -1.0; -1.0];
+    ///
+    /// let x: Vector<f64> = a.solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(x, vector![2.5; 4.0; 4.5; 4.0; 2.5], epsilon = 1.0e-10);
+    /// ```
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let x: General<T> = self.solve(&rhs.data)?;
+
+        Ok(Vector { data: x })
+    }
+}
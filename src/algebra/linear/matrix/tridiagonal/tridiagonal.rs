@@ -0,0 +1,110 @@
+use crate::algebra::abstr::{Field, Scalar};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Tridiagonal matrix
+///
+/// Only the subdiagonal, the diagonal and the superdiagonal of the square
+/// matrix are stored.
+///
+/// ```math
+/// \begin{pmatrix}
+/// d_0 & u_0 & & \\
+/// l_0 & d_1 & \ddots & \\
+/// & \ddots & \ddots & u_{n-2} \\
+/// & & l_{n-2} & d_{n-1}
+/// \end{pmatrix}
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tridiagonal<T> {
+    pub(crate) lower: Vec<T>,
+    pub(crate) diag: Vec<T>,
+    pub(crate) upper: Vec<T>,
+}
+
+impl<T> Tridiagonal<T> {
+    /// Creates a tridiagonal matrix from its three diagonals
+    ///
+    /// # Arguments
+    ///
+    /// * `lower`: subdiagonal with n - 1 entries
+    /// * `diag`: diagonal with n entries
+    /// * `upper`: superdiagonal with n - 1 entries
+    ///
+    /// # Panics
+    ///
+    /// If the length of the diagonals does not fit together
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, Tridiagonal};
+    /// use mathru::matrix;
+    ///
+    /// let a: Tridiagonal<f64> = Tridiagonal::new(vec![1.0, 2.0], vec![3.0, 4.0, 5.0], vec![6.0, 7.0]);
+    ///
+    /// let a_ref: General<f64> = matrix![  3.0, 6.0, 0.0;
+    ///                                     1.0, 4.0, 7.0;
+    ///                                     0.0, 2.0, 5.0];
+    ///
+    /// assert_eq!(General::from(a), a_ref);
+    /// ```
+    pub fn new(lower: Vec<T>, diag: Vec<T>, upper: Vec<T>) -> Tridiagonal<T> {
+        let n: usize = diag.len();
+        assert!(
+            n > 0,
+            "A tridiagonal matrix needs at least one diagonal entry"
+        );
+        assert_eq!(
+            lower.len(),
+            n - 1,
+            "The subdiagonal must have n - 1 entries"
+        );
+        assert_eq!(
+            upper.len(),
+            n - 1,
+            "The superdiagonal must have n - 1 entries"
+        );
+
+        Tridiagonal { lower, diag, upper }
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(&self) -> (usize, usize) {
+        (self.diag.len(), self.diag.len())
+    }
+
+    /// Returns the subdiagonal
+    pub fn lower(&self) -> &[T] {
+        &self.lower
+    }
+
+    /// Returns the diagonal
+    pub fn diag(&self) -> &[T] {
+        &self.diag
+    }
+
+    /// Returns the superdiagonal
+    pub fn upper(&self) -> &[T] {
+        &self.upper
+    }
+}
+
+impl<T> Tridiagonal<T>
+where
+    T: Field + Scalar,
+{
+    /// Returns the element $a_{ij}$, which is zero outside of the band
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i == j {
+            self.diag[i]
+        } else if i == j + 1 {
+            self.lower[j]
+        } else if j == i + 1 {
+            self.upper[i]
+        } else {
+            T::zero()
+        }
+    }
+}
@@ -5,6 +5,7 @@ mod eigendec;
 mod from;
 mod index;
 mod mul;
+mod norm;
 mod partial_eq;
 mod relative_eq;
 mod substitute_forward;
@@ -4,12 +4,13 @@ use crate::algebra::abstr::Scalar;
 use crate::algebra::linear::matrix::substitute::SubstituteForward;
 use crate::algebra::linear::matrix::{General, UnitLowerTriangular};
 use crate::algebra::linear::vector::Vector;
+use crate::algebra::linear::LinalgError;
 
 impl<T> SubstituteForward<Vector<T>> for UnitLowerTriangular<T>
 where
     T: Field + Scalar + AbsDiffEq,
 {
-    fn substitute_forward(&self, a: Vector<T>) -> Result<Vector<T>, ()> {
+    fn substitute_forward(&self, a: Vector<T>) -> Result<Vector<T>, LinalgError> {
         let mut b: Vector<T> = a;
         let rows = self.matrix.nrows();
 
@@ -27,7 +28,7 @@ impl<T> SubstituteForward<General<T>> for UnitLowerTriangular<T>
 where
     T: Field + Scalar + AbsDiffEq,
 {
-    fn substitute_forward(&self, a: General<T>) -> Result<General<T>, ()> {
+    fn substitute_forward(&self, a: General<T>) -> Result<General<T>, LinalgError> {
         let mut b: General<T> = a;
         let rows = self.matrix.nrows();
 
@@ -1,6 +1,7 @@
 use crate::algebra::abstr::{AbsDiffEq, Field, Scalar};
 use crate::algebra::linear::matrix::{Diagonal, UnitUpperTriangular};
 use crate::algebra::linear::matrix::{EigenDec, EigenDecomposition, General};
+use crate::algebra::linear::LinalgError;
 use crate::elementary::Power;
 
 impl<T> EigenDecomposition<T> for UnitUpperTriangular<T>
@@ -29,7 +30,7 @@ where
     ///    epsilon = 1.0e-5
     /// );
     /// ```
-    fn dec_eigen(&self) -> Result<EigenDec<T>, String> {
+    fn dec_eigen(&self) -> Result<EigenDec<T>, LinalgError> {
         let (m, _): (usize, usize) = self.dim();
         let eigen_values = vec![T::one(); m];
         let values: Diagonal<T> = Diagonal::new(&eigen_values);
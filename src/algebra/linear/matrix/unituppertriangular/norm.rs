@@ -0,0 +1,46 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::matrix::{General, MatrixNorm, UnitUpperTriangular},
+};
+
+impl<T> MatrixNorm<T> for UnitUpperTriangular<T>
+where
+    T: Real,
+{
+    fn norm_1(&self) -> T {
+        self.norm_matrix().norm_1()
+    }
+
+    fn norm_inf(&self) -> T {
+        self.norm_matrix().norm_inf()
+    }
+
+    fn norm_frobenius(&self) -> T {
+        self.norm_matrix().norm_frobenius()
+    }
+
+    fn norm_2(&self) -> T {
+        self.norm_matrix().norm_2()
+    }
+}
+
+impl<T> UnitUpperTriangular<T>
+where
+    T: Real,
+{
+    /// Entries above the diagonal with a unit diagonal
+    fn norm_matrix(&self) -> General<T> {
+        let (m, n): (usize, usize) = self.matrix.dim();
+        let mut a: General<T> = General::zero(m, n);
+        for i in 0..m {
+            for j in i..n {
+                a[[i, j]] = if i == j {
+                    T::one()
+                } else {
+                    self.matrix[[i, j]]
+                };
+            }
+        }
+        a
+    }
+}
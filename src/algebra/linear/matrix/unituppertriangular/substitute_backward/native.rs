@@ -1,5 +1,6 @@
 use crate::algebra::abstr::{Field, Scalar};
 use crate::algebra::linear::matrix::substitute::SubstituteBackward;
+use crate::algebra::linear::LinalgError;
 use crate::algebra::linear::{
     matrix::{General, UnitUpperTriangular},
     vector::Vector,
@@ -9,7 +10,7 @@ impl<T> SubstituteBackward<Vector<T>> for UnitUpperTriangular<T>
 where
     T: Field + Scalar,
 {
-    fn substitute_backward(&self, c: Vector<T>) -> Result<Vector<T>, ()> {
+    fn substitute_backward(&self, c: Vector<T>) -> Result<Vector<T>, LinalgError> {
         let mut b: Vector<T> = c;
         let rows = self.matrix.nrows();
 
@@ -27,7 +28,7 @@ impl<T> SubstituteBackward<General<T>> for UnitUpperTriangular<T>
 where
     T: Field + Scalar,
 {
-    fn substitute_backward(&self, a: General<T>) -> Result<General<T>, ()> {
+    fn substitute_backward(&self, a: General<T>) -> Result<General<T>, LinalgError> {
         let mut b: General<T> = a;
         let rows = self.matrix.nrows();
 
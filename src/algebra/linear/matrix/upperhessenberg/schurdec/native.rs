@@ -1,6 +1,7 @@
 use crate::algebra::abstr::AbsDiffEq;
 use crate::algebra::linear::matrix::{SchurDecomposition, UpperHessenberg};
 use crate::algebra::linear::vector::Vector;
+use crate::algebra::linear::LinalgError;
 use crate::matrix;
 use crate::{
     algebra::{
@@ -25,7 +26,7 @@ where
     ///
     /// # Example
     ///
-    fn dec_schur(&self) -> Result<SchurDec<T>, ()> {
+    fn dec_schur(&self) -> Result<SchurDec<T>, LinalgError> {
         let (q, u): (General<T>, UpperTriangular<T>) = if self.matrix.m > 2 {
             let h = self.clone();
             h.francis()
@@ -37,13 +38,33 @@ where
                 let a_22 = self[[1, 1]];
                 let b = -(a_11 + a_22);
                 let c = a_11 * a_22 - a_21 * a_12;
-                let t_1 = (b * b - T::from_f32(4.0) * c).sqrt();
-                let x_1 = (-b - t_1) / T::from_f32(2.0);
-                let x_2 = (-b + t_1) / T::from_f32(2.0);
-                (
-                    General::zero(self.matrix.m, self.matrix.m),
-                    UpperTriangular::from(matrix![x_1, T::zero(); T::zero(), x_2]),
-                )
+                let d = b * b - T::from_f32(4.0) * c;
+                if d < T::zero() {
+                    // complex conjugate pair, the matrix is already in real Schur form
+                    (General::one(2), self.matrix.clone().into())
+                } else {
+                    let t_1 = d.sqrt();
+                    let x_1 = (-b - t_1) / T::from_f32(2.0);
+
+                    // The first column of Q is an eigenvector belonging to x_1
+                    let (v_1, v_2) =
+                        if a_12.abs() + (x_1 - a_11).abs() >= (x_1 - a_22).abs() + a_21.abs() {
+                            (a_12, x_1 - a_11)
+                        } else {
+                            (x_1 - a_22, a_21)
+                        };
+                    let r = (v_1 * v_1 + v_2 * v_2).sqrt();
+                    if r == T::zero() {
+                        (General::one(2), self.matrix.clone().into())
+                    } else {
+                        let c = v_1 / r;
+                        let s = v_2 / r;
+                        let q: General<T> = matrix![c, -s; s, c];
+                        let mut u: General<T> = &(&q.clone().transpose() * &self.matrix) * &q;
+                        u[[1, 0]] = T::zero();
+                        (q, u.into())
+                    }
+                }
             } else {
                 (matrix![T::one()], self.matrix.clone().into())
             }
@@ -137,7 +158,7 @@ where
                 let temp: General<T> = &self.get_slice(0, p - 1, p - 2, p - 1) * &g_trans;
                 self = self.set_slice(&temp, 0, p - 2);
 
-                let u_slice = &self.get_slice(0, p - 1, p - 2, p - 1) * &g_trans;
+                let u_slice = &u.get_slice(0, n - 1, p - 2, p - 1) * &g_trans;
                 u = u.set_slice(&u_slice, 0, p - 2);
             }
 
@@ -157,6 +178,14 @@ where
             }
         }
 
+        // Remove the rounding errors of the bulge chasing below the
+        // subdiagonal
+        for j in 0..n {
+            for i in (j + 2)..m {
+                self[[i, j]] = T::zero();
+            }
+        }
+
         (u, self.matrix.into())
     }
 }
@@ -0,0 +1,42 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::matrix::{General, MatrixNorm, UpperTriangular},
+};
+
+impl<T> MatrixNorm<T> for UpperTriangular<T>
+where
+    T: Real,
+{
+    fn norm_1(&self) -> T {
+        self.norm_matrix().norm_1()
+    }
+
+    fn norm_inf(&self) -> T {
+        self.norm_matrix().norm_inf()
+    }
+
+    fn norm_frobenius(&self) -> T {
+        self.norm_matrix().norm_frobenius()
+    }
+
+    fn norm_2(&self) -> T {
+        self.norm_matrix().norm_2()
+    }
+}
+
+impl<T> UpperTriangular<T>
+where
+    T: Real,
+{
+    /// Entries on and above the diagonal
+    fn norm_matrix(&self) -> General<T> {
+        let (m, n): (usize, usize) = self.matrix.dim();
+        let mut a: General<T> = General::zero(m, n);
+        for i in 0..m {
+            for j in i..n {
+                a[[i, j]] = self.matrix[[i, j]];
+            }
+        }
+        a
+    }
+}
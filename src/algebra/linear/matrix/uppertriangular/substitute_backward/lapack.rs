@@ -1,5 +1,6 @@
 use crate::algebra::abstr::{Field, Scalar};
 use crate::algebra::linear::matrix::substitute::SubstituteBackward;
+use crate::algebra::linear::LinalgError;
 use crate::algebra::linear::{
     matrix::{General, UpperTriangular},
     vector::Vector,
@@ -9,7 +10,7 @@ impl<T> SubstituteBackward<Vector<T>> for UpperTriangular<T>
 where
     T: Field + Scalar,
 {
-    fn substitute_backward(&self, b: Vector<T>) -> Result<Vector<T>, ()> {
+    fn substitute_backward(&self, b: Vector<T>) -> Result<Vector<T>, LinalgError> {
         let (b_m, b_n): (usize, usize) = b.dim();
 
         let mut b_data = b.convert_to_vec();
@@ -35,7 +36,7 @@ impl<T> SubstituteBackward<General<T>> for UpperTriangular<T>
 where
     T: Field + Scalar,
 {
-    fn substitute_backward(&self, b: General<T>) -> Result<General<T>, ()> {
+    fn substitute_backward(&self, b: General<T>) -> Result<General<T>, LinalgError> {
         let (m, n) = self.matrix.dim();
 
         let mut c: General<T> = b;
@@ -1,6 +1,7 @@
 use crate::algebra::abstr::{AbsDiffEq, RelativeEq};
 use crate::algebra::abstr::{Field, Scalar};
 use crate::algebra::linear::matrix::substitute::SubstituteBackward;
+use crate::algebra::linear::LinalgError;
 use crate::algebra::linear::{
     matrix::{General, UpperTriangular},
     vector::Vector,
@@ -11,7 +12,7 @@ impl<T> SubstituteBackward<Vector<T>> for UpperTriangular<T>
 where
     T: Field + Scalar + AbsDiffEq<Epsilon = T> + RelativeEq,
 {
-    fn substitute_backward(&self, c: Vector<T>) -> Result<Vector<T>, ()> {
+    fn substitute_backward(&self, c: Vector<T>) -> Result<Vector<T>, LinalgError> {
         let mut b: Vector<T> = c;
         let rows = self.matrix.nrows();
 
@@ -25,7 +26,7 @@ where
                 b[k] = div;
             } else {
                 if !relative_eq!(b[k], T::from_f64(0.000000000001) * T::default_epsilon()) {
-                    return Err(());
+                    return Err(LinalgError::Singular);
                 } else {
                     b[k] = T::one();
                 }
@@ -40,7 +41,7 @@ impl<T> SubstituteBackward<General<T>> for UpperTriangular<T>
 where
     T: Field + Scalar,
 {
-    fn substitute_backward(&self, a: General<T>) -> Result<General<T>, ()> {
+    fn substitute_backward(&self, a: General<T>) -> Result<General<T>, LinalgError> {
         let mut b: General<T> = a;
         let rows = self.matrix.nrows();
 
@@ -49,7 +50,7 @@ where
                 b.set_row(&(b.get_row(k) - (b.get_row(l) * self[[k, l]])), k);
             }
             if self[[k, k]] == T::zero() {
-                return Err(());
+                return Err(LinalgError::Singular);
             }
             b.set_row(&(b.get_row(k) / self[[k, k]]), k);
         }
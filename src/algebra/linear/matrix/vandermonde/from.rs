@@ -0,0 +1,23 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::matrix::{General, Vandermonde},
+};
+
+impl<T> From<Vandermonde<T>> for General<T>
+where
+    T: Field + Scalar,
+{
+    /// Converts a Vandermonde matrix into a general matrix
+    fn from(v: Vandermonde<T>) -> General<T> {
+        let (n, _): (usize, usize) = v.dim();
+        let mut g: General<T> = General::zero(n, n);
+        for i in 0..n {
+            let mut p: T = T::one();
+            for j in 0..n {
+                g[[i, j]] = p;
+                p *= v.nodes[i];
+            }
+        }
+        g
+    }
+}
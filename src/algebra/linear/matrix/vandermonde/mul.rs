@@ -0,0 +1,43 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{matrix::Vandermonde, vector::Vector},
+};
+use std::ops::Mul;
+
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a Vandermonde<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    /// Evaluates the polynomial with the coefficients rhs at all nodes with
+    /// Horner's scheme
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::Vandermonde;
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::vector;
+    ///
+    /// let v: Vandermonde<f64> = Vandermonde::new(vec![1.0, 2.0, 3.0]);
+    /// // p(x) = 1 - x + x^2
+    /// let a: Vector<f64> = vector![1.0; -1.0; 1.0];
+    ///
+    /// assert_eq!(&v * &a, vector![1.0; 3.0; 7.0]);
+    /// ```
+    fn mul(self, rhs: &'b Vector<T>) -> Self::Output {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, _): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the vector do not match"
+        );
+
+        let mut y: Vector<T> = Vector::zero(n);
+        for (i, x_i) in self.nodes.iter().enumerate() {
+            y[i] = (0..n).rev().fold(T::zero(), |p, j| p * *x_i + rhs[j]);
+        }
+        y
+    }
+}
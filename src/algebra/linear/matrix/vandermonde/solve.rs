@@ -0,0 +1,108 @@
+use crate::algebra::linear::LinalgError;
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{General, Solve, Vandermonde},
+        vector::Vector,
+    },
+};
+
+impl<T> Vandermonde<T>
+where
+    T: Field + Scalar,
+{
+    /// Björck-Pereyra algorithm for one right-hand side
+    fn bjoerck_pereyra(&self, f: &[T]) -> Result<Vec<T>, LinalgError> {
+        let x: &[T] = &self.nodes;
+        let n: usize = x.len();
+        let mut c: Vec<T> = f.to_vec();
+
+        // Newton divided differences
+        for k in 0..(n - 1) {
+            for i in ((k + 1)..n).rev() {
+                let dx: T = x[i] - x[i - k - 1];
+                if dx == T::zero() {
+                    return Err(LinalgError::Singular);
+                }
+                c[i] = (c[i] - c[i - 1]) / dx;
+            }
+        }
+
+        // Newton form to monomial coefficients
+        for k in (0..(n - 1)).rev() {
+            for i in k..(n - 1) {
+                let c_i1: T = c[i + 1];
+                c[i] -= x[k] * c_i1;
+            }
+        }
+
+        Ok(c)
+    }
+}
+
+impl<T> Solve<Vector<T>> for Vandermonde<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $Va = f$ with the Björck-Pereyra algorithm in O(n²)
+    /// operations
+    ///
+    /// The solution are the coefficients of the polynomial, which
+    /// interpolates the values $f_i$ at the nodes $x_i$.
+    ///
+    /// # Errors
+    ///
+    /// If two nodes coincide
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{Solve, Vandermonde};
+    /// use mathru::algebra::linear::vector::Vector;
+    /// use mathru::{assert_relative_eq, vector};
+    ///
+    /// let v: Vandermonde<f64> = Vandermonde::new(vec![1.0, 2.0, 3.0]);
+    /// let f: Vector<f64> = vector![1.0; 3.0; 7.0];
+    ///
+    /// let a: Vector<f64> = v.solve(&f).unwrap();
+    ///
+    /// assert_relative_eq!(a, vector![1.0; -1.0; 1.0], epsilon = 1.0e-10);
+    /// ```
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, _): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the vector do not match"
+        );
+
+        let f: Vec<T> = rhs.iter().copied().collect();
+
+        Ok(Vector::new_column(self.bjoerck_pereyra(&f)?))
+    }
+}
+
+impl<T> Solve<General<T>> for Vandermonde<T>
+where
+    T: Field + Scalar,
+{
+    /// Solves $VA = F$ column by column
+    fn solve(&self, rhs: &General<T>) -> Result<General<T>, LinalgError> {
+        let (n, _): (usize, usize) = self.dim();
+        let (m, k): (usize, usize) = rhs.dim();
+        assert_eq!(
+            n, m,
+            "The dimensions of the matrix and the right-hand side do not match"
+        );
+
+        let mut a: General<T> = General::zero(n, k);
+        for j in 0..k {
+            let f_j: Vec<T> = (0..n).map(|i| rhs[[i, j]]).collect();
+            let a_j: Vec<T> = self.bjoerck_pereyra(&f_j)?;
+            for (i, a_ij) in a_j.iter().enumerate() {
+                a[[i, j]] = *a_ij;
+            }
+        }
+        Ok(a)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::algebra::abstr::{Field, Scalar};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Vandermonde matrix
+///
+/// A square matrix with the powers of the nodes $x_i$ in its rows,
+/// $a_{ij} = x_i^j$.
+///
+/// ```math
+/// \begin{pmatrix}
+/// 1 & x_0 & x_0^2 & \cdots & x_0^{n-1} \\
+/// 1 & x_1 & x_1^2 & \cdots & x_1^{n-1} \\
+/// \vdots & \vdots & \vdots & & \vdots \\
+/// 1 & x_{n-1} & x_{n-1}^2 & \cdots & x_{n-1}^{n-1}
+/// \end{pmatrix}
+/// ```
+/// Solving $Va = f$ yields the coefficients $a_j$ of the interpolation
+/// polynomial $p(x) = \sum_j a_j x^j$ with $p(x_i) = f_i$.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vandermonde<T> {
+    pub(crate) nodes: Vec<T>,
+}
+
+impl<T> Vandermonde<T> {
+    /// Creates a Vandermonde matrix from its nodes
+    ///
+    /// # Panics
+    ///
+    /// If there are no nodes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::matrix::{General, Vandermonde};
+    /// use mathru::matrix;
+    ///
+    /// let v: Vandermonde<f64> = Vandermonde::new(vec![1.0, 2.0, 3.0]);
+    ///
+    /// let v_ref: General<f64> = matrix![  1.0, 1.0, 1.0;
+    ///                                     1.0, 2.0, 4.0;
+    ///                                     1.0, 3.0, 9.0];
+    ///
+    /// assert_eq!(General::from(v), v_ref);
+    /// ```
+    pub fn new(nodes: Vec<T>) -> Vandermonde<T> {
+        assert!(
+            !nodes.is_empty(),
+            "A Vandermonde matrix needs at least one node"
+        );
+        Vandermonde { nodes }
+    }
+
+    /// Returns the matrix dimension
+    pub fn dim(&self) -> (usize, usize) {
+        (self.nodes.len(), self.nodes.len())
+    }
+
+    /// Returns the nodes
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+}
+
+impl<T> Vandermonde<T>
+where
+    T: Field + Scalar,
+{
+    /// Returns the element $a_{ij} = x_i^j$
+    pub fn get(&self, i: usize, j: usize) -> T {
+        (0..j).fold(T::one(), |p, _| p * self.nodes[i])
+    }
+}
@@ -24,10 +24,15 @@
 //!     large-scale mathematical computations and image calculations, optimized
 //!     for high performance and low-energy consumption. (macOS only)
 
+mod error;
+pub use error::LinalgError;
+
 #[macro_use]
 pub mod vector;
 #[macro_use]
 pub mod matrix;
+pub mod equations;
+pub mod iterative;
 #[cfg(feature = "lapack")]
 pub mod blas;
 #[cfg(feature = "lapack")]
@@ -0,0 +1,82 @@
+use crate::algebra::{
+    abstr::{Field, Scalar},
+    linear::{
+        matrix::{CheckedAdd, CheckedMul, CheckedSub, General},
+        vector::Vector,
+        LinalgError,
+    },
+};
+
+impl<T> CheckedAdd<Vector<T>> for Vector<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    fn checked_add(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        if self.dim() != rhs.dim() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(self + rhs)
+    }
+}
+
+impl<T> CheckedSub<Vector<T>> for Vector<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    fn checked_sub(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        if self.dim() != rhs.dim() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(self - rhs)
+    }
+}
+
+impl<T> CheckedMul<General<T>> for Vector<T>
+where
+    T: Field + Scalar,
+{
+    type Output = Vector<T>;
+
+    fn checked_mul(&self, rhs: &General<T>) -> Result<Vector<T>, LinalgError> {
+        if self.dim().1 != rhs.nrows() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(self * rhs)
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Field + Scalar,
+{
+    /// Computes the dot product of two column vectors
+    ///
+    /// # Errors
+    ///
+    /// [`LinalgError::DimensionMismatch`] if the vectors are not column
+    /// vectors of the same length
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{vector::Vector, LinalgError};
+    /// use mathru::vector;
+    ///
+    /// let a: Vector<f64> = vector![1.0; 0.0; 3.0];
+    /// let b: Vector<f64> = vector![-1.0; 2.0; 3.0];
+    ///
+    /// assert_eq!(a.checked_dotp(&b), Ok(8.0));
+    /// assert_eq!(a.checked_dotp(&vector![1.0; 2.0]), Err(LinalgError::DimensionMismatch));
+    /// ```
+    pub fn checked_dotp(&self, rhs: &Self) -> Result<T, LinalgError> {
+        let (m, n): (usize, usize) = self.dim();
+        if m == 0 || n != 1 || rhs.dim() != (m, n) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(self.dotp(rhs))
+    }
+}
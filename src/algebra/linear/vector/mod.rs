@@ -6,6 +6,7 @@ pub mod vectoriteratormut;
 
 mod add;
 mod add_assign;
+mod checked;
 mod div;
 mod index;
 mod mul;
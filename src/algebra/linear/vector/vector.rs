@@ -5,7 +5,7 @@ use crate::{
     algebra::{
         abstr::{AbsDiffEq, RelativeEq},
         abstr::{Field, Scalar, Sign},
-        linear::matrix::{General, Transpose},
+        linear::matrix::{ConjugateTranspose, General, MatrixView, MatrixViewMut, Transpose},
     },
     elementary::{Exponential, Power},
 };
@@ -258,6 +258,25 @@ where
 
         self
     }
+
+    /// Returns the conjugate transposed vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Complex;
+    /// use mathru::algebra::linear::vector::Vector;
+    ///
+    /// let a: Vector<Complex<f64>> = Vector::new_column(vec![Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)]);
+    /// let b: Vector<Complex<f64>> = Vector::new_row(vec![Complex::new(1.0, -2.0), Complex::new(0.0, 1.0)]);
+    ///
+    /// assert_eq!(b, a.conj_transpose());
+    /// ```
+    pub fn conj_transpose(mut self) -> Self {
+        self.data = self.data.conj_transpose();
+
+        self
+    }
 }
 
 impl<T> Vector<T>
@@ -282,10 +301,13 @@ where
     pub fn dotp(&self, rhs: &Self) -> T {
         let (lhs_m, lhs_n) = self.dim();
         let (rhs_m, rhs_n) = rhs.dim();
-        debug_assert_ne!(lhs_m, 0);
-        debug_assert_eq!(lhs_n, 1);
-        debug_assert_eq!(lhs_m, rhs_m);
-        debug_assert_eq!(lhs_n, rhs_n);
+        assert_ne!(lhs_m, 0);
+        assert_eq!(lhs_n, 1);
+        assert_eq!(
+            (lhs_m, lhs_n),
+            (rhs_m, rhs_n),
+            "The dimensions of the vectors do not match"
+        );
 
         let temp: Vector<T> = self.clone().transpose();
         let res: General<T> = &temp.data * &rhs.data;
@@ -557,6 +579,40 @@ where
             self[r] = rhs[r - s];
         }
     }
+
+    /// Returns a view of the elements s..=e without copying
+    ///
+    /// The view is a column for column vectors and a row for row vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::linear::{matrix::{General, MatrixView}, vector::Vector};
+    /// use mathru::{matrix, vector};
+    ///
+    /// let a: Vector<f64> = vector![1.0; -2.0; 3.0; -7.0];
+    /// let b: MatrixView<f64> = a.view_slice(1, 2);
+    ///
+    /// assert_eq!(General::from(b), matrix![-2.0; 3.0]);
+    /// ```
+    pub fn view_slice(&self, s: usize, e: usize) -> MatrixView<'_, T> {
+        let (m, n): (usize, usize) = self.dim();
+        if m == 1 {
+            self.data.view_slice(0, 0, s, e)
+        } else {
+            self.data.view_slice(s, e, 0, n - 1)
+        }
+    }
+
+    /// Returns a mutable view of the elements s..=e
+    pub fn view_slice_mut(&mut self, s: usize, e: usize) -> MatrixViewMut<'_, T> {
+        let (m, n): (usize, usize) = self.dim();
+        if m == 1 {
+            self.data.view_slice_mut(0, 0, s, e)
+        } else {
+            self.data.view_slice_mut(s, e, 0, n - 1)
+        }
+    }
 }
 
 impl<T> PartialEq<Self> for Vector<T>
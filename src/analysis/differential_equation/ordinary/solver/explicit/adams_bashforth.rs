@@ -1,4 +1,5 @@
 //! Solves an ODE using Adams-Bashforth method.
+use crate::algebra::linear::LinalgError;
 use crate::{
     algebra::{abstr::Real, linear::vector::vector::Vector},
     analysis::differential_equation::ordinary::{ExplicitInitialValueProblem, ExplicitODE},
@@ -110,7 +111,7 @@ where
     pub fn solve<O>(
         &self,
         prob: &ExplicitInitialValueProblem<T, O>,
-    ) -> Result<(Vec<T>, Vec<Vector<T>>), String>
+    ) -> Result<(Vec<T>, Vec<Vector<T>>), LinalgError>
     where
         O: ExplicitODE<T>,
     {
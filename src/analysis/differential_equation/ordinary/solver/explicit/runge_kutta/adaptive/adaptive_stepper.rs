@@ -1,4 +1,5 @@
 //! Adaptive step size stepper
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{abstr::Real, linear::vector::vector::Vector};
 use crate::analysis::differential_equation::ordinary::solver::explicit::runge_kutta::adaptive::ExplicitRKEmbeddedMethod;
 use crate::analysis::differential_equation::ordinary::ExplicitInitialValueProblem;
@@ -131,7 +132,7 @@ where
         &self,
         prob: &ExplicitInitialValueProblem<T, O>,
         method: &M,
-    ) -> Result<(Vec<T>, Vec<Vector<T>>), &'static str>
+    ) -> Result<(Vec<T>, Vec<Vector<T>>), LinalgError>
     where
         M: ExplicitRKEmbeddedMethod<T>,
         O: ExplicitODE<T>,
@@ -196,7 +197,9 @@ where
         match t_stop {
             Some(t_e) => {
                 if t_n < t_e && callback_condition {
-                    Err("Maximum number of iterations reached")
+                    Err(LinalgError::NoConvergence {
+                        iters: self.n_max as usize,
+                    })
                 } else {
                     Ok((t_vec, res_vec))
                 }
@@ -1,4 +1,5 @@
 //! Fixed step size Stepper
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{abstr::Real, linear::vector::vector::Vector};
 use crate::analysis::differential_equation::ordinary::{
     solver::explicit::runge_kutta::fixed::ExplicitRKMethod, ExplicitInitialValueProblem,
@@ -40,7 +41,7 @@ where
         &self,
         prob: &ExplicitInitialValueProblem<T, O>,
         method: &M,
-    ) -> Result<(Vec<T>, Vec<Vector<T>>), String>
+    ) -> Result<(Vec<T>, Vec<Vector<T>>), LinalgError>
     where
         M: ExplicitRKMethod<T>,
         O: ExplicitODE<T>,
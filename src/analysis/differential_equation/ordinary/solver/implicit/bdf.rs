@@ -1,4 +1,5 @@
 //! Solves an ODE using backward differentiation formula
+use crate::algebra::linear::LinalgError;
 use crate::{
     algebra::{abstr::Real, linear::vector::vector::Vector},
     analysis::differential_equation::ordinary::{ImplicitInitialValueProblem, ImplicitODE},
@@ -137,7 +138,7 @@ where
     pub fn solve<O>(
         &self,
         prob: &ImplicitInitialValueProblem<T, O>,
-    ) -> Result<(Vec<T>, Vec<Vector<T>>), String>
+    ) -> Result<(Vec<T>, Vec<Vector<T>>), LinalgError>
     where
         O: ImplicitODE<T>,
     {
@@ -1,4 +1,5 @@
 /// Fixed step size Stepper
+use crate::algebra::linear::LinalgError;
 use crate::algebra::{abstr::Real, linear::vector::vector::Vector};
 use crate::analysis::differential_equation::ordinary::solver::implicit::runge_kutta::ImplicitFixedStepSizeMethod;
 use crate::analysis::differential_equation::ordinary::{ImplicitInitialValueProblem, ImplicitODE};
@@ -36,7 +37,7 @@ where
         &self,
         prob: &ImplicitInitialValueProblem<T, O>,
         method: &M,
-    ) -> Result<(Vec<T>, Vec<Vector<T>>), String>
+    ) -> Result<(Vec<T>, Vec<Vector<T>>), LinalgError>
     where
         O: ImplicitODE<T>,
         M: ImplicitFixedStepSizeMethod<T>,
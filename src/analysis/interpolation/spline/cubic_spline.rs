@@ -1,6 +1,7 @@
 use crate::algebra::abstr::Polynomial;
 use crate::algebra::abstr::Real;
-use crate::algebra::linear::matrix::{LUDecomposition, Solve};
+use crate::algebra::linear::matrix::{LUDecomposition, Solve, Tridiagonal};
+use crate::algebra::linear::LinalgError;
 use crate::algebra::linear::{matrix::General, vector::Vector};
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter, Result as FmtResult};
@@ -108,11 +109,13 @@ where
             let y_1 = y[1];
             let y_2 = y[2];
 
-            let mut a: General<T> = General::zero(n - 1, n - 1);
+            let mut lower: Vec<T> = vec![T::zero(); n - 2];
+            let mut diag: Vec<T> = vec![T::zero(); n - 1];
+            let mut upper: Vec<T> = vec![T::zero(); n - 2];
             let mut b: Vector<T> = Vector::zero(n - 1);
 
-            a[[0, 0]] = T::from_f64(2.0) * (h_0 + h_1);
-            a[[0, 1]] = h_1;
+            diag[0] = T::from_f64(2.0) * (h_0 + h_1);
+            upper[0] = h_1;
             b[0] = T::from_f64(6.0) * ((y_2 - y_1) / h_1 - (y_1 - y_0) / h_0);
 
             for i in 2..=(n - 2) {
@@ -122,18 +125,19 @@ where
                 let h_1i = x[i] - x[i - 1];
                 let h_i = x[i + 1] - x[i];
 
-                a[[i - 1, i - 2]] = h_1i;
-                a[[i - 1, i - 1]] = T::from_f64(2.0) * (h_1i + h_i);
-                a[[i - 1, i]] = h_i;
+                lower[i - 2] = h_1i;
+                diag[i - 1] = T::from_f64(2.0) * (h_1i + h_i);
+                upper[i - 1] = h_i;
 
                 b[i - 1] = T::from_f64(6.0) * ((y_i1 - y_i) / h_i - (y_i - y_1i) / h_1i);
             }
 
-            a[[n - 2, n - 2]] = T::from_f64(2.0) * (h_2n + h_1n);
-            a[[n - 2, n - 3]] = h_2n;
+            diag[n - 2] = T::from_f64(2.0) * (h_2n + h_1n);
+            lower[n - 3] = h_2n;
             b[n - 2] = T::from_f64(6.0) * ((y_n - y_1n) / h_1n - (y_1n - y_2n) / h_2n);
 
-            let d = a.dec_lu().unwrap().solve(&b).unwrap();
+            let a: Tridiagonal<T> = Tridiagonal::new(lower, diag, upper);
+            let d = a.solve(&b).unwrap();
             d.convert_to_vec()
         } else {
             if n == 1 {
@@ -289,30 +293,27 @@ where
         CubicSpline { polynomials }
     }
 
+    /// Solves a tridiagonal system of equations
+    ///
+    /// # Arguments
+    ///
+    /// * `a`: subdiagonal
+    /// * `b`: diagonal
+    /// * `c`: superdiagonal
+    /// * `d`: right-hand side
     pub fn solve_thomas(
         a: &Vector<T>,
         b: &Vector<T>,
-        mut c: Vector<T>,
-        mut d: Vector<T>,
-    ) -> Result<Vector<T>, String> {
-        let (n, _) = b.dim();
-
-        c[0] /= b[0];
-        for i in 1..=(n - 2) {
-            let c_m = c[i - 1];
-            c[i] /= b[i] - c_m * a[i];
-        }
-
-        d[0] /= b[0];
-        for i in 1..=(n - 1) {
-            d[i] = (d[i] - d[i - 1] * a[i - 1]) / (b[i] - c[i - 1] * a[i - 1])
-        }
-
-        for i in (0..=(n - 2)).rev() {
-            d[i] = d[i] - c[i] * d[i + 1]
-        }
-
-        Ok(d)
+        c: Vector<T>,
+        d: Vector<T>,
+    ) -> Result<Vector<T>, LinalgError> {
+        let t: Tridiagonal<T> = Tridiagonal::new(
+            a.iter().copied().collect(),
+            b.iter().copied().collect(),
+            c.convert_to_vec(),
+        );
+
+        t.solve(&d)
     }
 }
 
@@ -1,4 +1,5 @@
 //! Newton-Raphson's root finding algorithm
+use crate::algebra::linear::LinalgError;
 use crate::{
     algebra::{
         abstr::Real,
@@ -49,7 +50,7 @@ impl<T> NewtonRaphson<T>
 where
     T: Real,
 {
-    pub fn find_root<F>(&self, func: &F, x_0: &Vector<T>) -> Result<Vector<T>, &'static str>
+    pub fn find_root<F>(&self, func: &F, x_0: &Vector<T>) -> Result<Vector<T>, LinalgError>
     where
         F: Function<Vector<T>, Codomain = Vector<T>> + Jacobian<T>,
     {
@@ -60,7 +61,7 @@ where
 
             let jacobian_x: General<T> = func.jacobian(&x);
 
-            let b: Vector<T> = jacobian_x.solve(&func_x).unwrap();
+            let b: Vector<T> = jacobian_x.solve(&func_x)?;
 
             let x_current: Vector<T> = &x - &b;
 
@@ -70,6 +71,8 @@ where
             x = x_current;
         }
 
-        Err("Maximum number of iterations reached")
+        Err(LinalgError::NoConvergence {
+            iters: self.iters as usize,
+        })
     }
 }
@@ -1,3 +1,4 @@
+use crate::algebra::linear::LinalgError;
 use crate::{
     algebra::{
         abstr::Real,
@@ -41,7 +42,11 @@ where
     /// # Return
     ///
     /// local minimum
-    pub fn minimize<F>(&self, func: &F, x_0: &Vector<T>) -> Result<OptimResult<Vector<T>>, ()>
+    pub fn minimize<F>(
+        &self,
+        func: &F,
+        x_0: &Vector<T>,
+    ) -> Result<OptimResult<Vector<T>>, LinalgError>
     where
         F: Optim<T>,
     {
@@ -1,3 +1,4 @@
+use crate::algebra::linear::LinalgError;
 use crate::{
     algebra::{
         abstr::Real,
@@ -62,7 +63,11 @@ where
     /// # Return
     ///
     /// local minimum
-    pub fn minimize<F>(&self, func: &F, x_0: &Vector<T>) -> Result<OptimResult<Vector<T>>, ()>
+    pub fn minimize<F>(
+        &self,
+        func: &F,
+        x_0: &Vector<T>,
+    ) -> Result<OptimResult<Vector<T>>, LinalgError>
     where
         F: Optim<T>,
     {
@@ -1,3 +1,4 @@
+use crate::algebra::linear::LinalgError;
 use crate::{
     algebra::{
         abstr::Real,
@@ -84,7 +85,7 @@ where
         for _i in 0..self.iters {
             let hessian_x_n: General<T> = func.hessian(&x_n);
             let grad_x_n: Vector<T> = func.jacobian(&x_n).get_row(0).transpose();
-            let res_solve: Result<Vector<T>, ()> = hessian_x_n.solve(&-grad_x_n.clone());
+            let res_solve: Result<Vector<T>, LinalgError> = hessian_x_n.solve(&-grad_x_n.clone());
             let d_k: Vector<T>;
 
             match res_solve {
@@ -0,0 +1,67 @@
+use mathru::algebra::linear::{
+    equations,
+    matrix::{General, SymmetricEigenDecomposition, Transpose},
+    LinalgError,
+};
+
+fn assert_positive_definite(x: &General<f64>) {
+    assert_eq!(x.clone().transpose(), x.clone());
+    let values: Vec<f64> = x.dec_symmetric_eigen().unwrap().values();
+    assert!(values.iter().all(|l| *l > 0.0));
+}
+
+#[test]
+fn solve_continuous_lyapunov() {
+    // stable with the eigenvalues -1 ± 2i and -3
+    let a: General<f64> = matrix![  -1.0, 2.0, 0.5;
+                                    -2.0, -1.0, 1.0;
+                                    0.0, 0.0, -3.0];
+    let q: General<f64> = matrix![  2.0, 1.0, 0.0;
+                                    1.0, 3.0, 0.0;
+                                    0.0, 0.0, 1.0];
+
+    let x: General<f64> = equations::solve_continuous_lyapunov(&a, &q).unwrap();
+
+    assert_relative_eq!(&(&a * &x) + &(&x * &a.clone().transpose()), &q * &-1.0, epsilon = 1.0e-10);
+    assert_positive_definite(&x);
+}
+
+#[test]
+fn solve_continuous_lyapunov_singular() {
+    let a: General<f64> = matrix![  1.0, 0.0;
+                                    0.0, -1.0];
+
+    assert_eq!(equations::solve_continuous_lyapunov(&a, &General::one(2)), Err(LinalgError::Singular));
+}
+
+#[test]
+fn solve_discrete_lyapunov() {
+    let a: General<f64> = matrix![  0.5, -0.4, 0.1;
+                                    0.3, 0.6, 0.0;
+                                    0.0, 0.2, -0.7];
+    let q: General<f64> = matrix![  1.0, 0.5, 0.0;
+                                    0.5, 2.0, 0.0;
+                                    0.0, 0.0, 1.0];
+
+    let x: General<f64> = equations::solve_discrete_lyapunov(&a, &q).unwrap();
+
+    assert_relative_eq!(&(&(&a * &x) * &a.clone().transpose()) - &x, &q * &-1.0, epsilon = 1.0e-10);
+    assert_positive_definite(&x);
+
+    // X = sum_k A^k Q (A^T)^k
+    let mut series: General<f64> = General::zero(3, 3);
+    let mut term: General<f64> = q.clone();
+    for _ in 0..200 {
+        series = &series + &term;
+        term = &(&a * &term) * &a.clone().transpose();
+    }
+    assert_relative_eq!(x, series, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_discrete_lyapunov_singular() {
+    let a: General<f64> = matrix![  1.0, 1.0;
+                                    0.0, 0.5];
+
+    assert_eq!(equations::solve_discrete_lyapunov(&a, &General::one(2)), Err(LinalgError::Singular));
+}
@@ -0,0 +1,3 @@
+mod lyapunov;
+mod riccati;
+mod sylvester;
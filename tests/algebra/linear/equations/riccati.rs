@@ -0,0 +1,127 @@
+use mathru::algebra::abstr::Complex;
+use mathru::algebra::linear::{
+    equations,
+    matrix::{ComplexEigenDecomposition, General, Solve, Transpose},
+    LinalgError,
+};
+
+fn system() -> (General<f64>, General<f64>, General<f64>, General<f64>) {
+    // unstable with the eigenvalues 1, 2 and -3
+    let a: General<f64> = matrix![  1.0, 1.0, 0.0;
+                                    0.0, 2.0, 1.0;
+                                    0.0, 0.0, -3.0];
+    let b: General<f64> = matrix![  0.0, 1.0;
+                                    1.0, 0.0;
+                                    1.0, 1.0];
+    let q: General<f64> = matrix![  2.0, 0.0, 0.0;
+                                    0.0, 1.0, 0.5;
+                                    0.0, 0.5, 1.0];
+    let r: General<f64> = matrix![  1.0, 0.2;
+                                    0.2, 2.0];
+    (a, b, q, r)
+}
+
+#[test]
+fn solve_continuous_are_scalar() {
+    let one: General<f64> = matrix![1.0];
+
+    let x: General<f64> = equations::solve_continuous_are(&one, &one, &one, &one).unwrap();
+
+    assert_relative_eq!(x[[0, 0]], 1.0 + 2.0f64.sqrt(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_continuous_are() {
+    let (a, b, q, r) = system();
+
+    let x: General<f64> = equations::solve_continuous_are(&a, &b, &q, &r).unwrap();
+
+    let a_t: General<f64> = a.clone().transpose();
+    let k: General<f64> = r.solve(&(&b.clone().transpose() * &x)).unwrap();
+    let residual: General<f64> = &(&(&(&a_t * &x) + &(&x * &a)) - &(&(&x * &b) * &k)) + &q;
+    assert_relative_eq!(residual, General::zero(3, 3), epsilon = 1.0e-10);
+    assert_eq!(x.clone().transpose(), x);
+
+    // The closed loop is stable
+    let values: Vec<Complex<f64>> = (&a - &(&b * &k)).dec_eigen_complex().unwrap().values();
+    assert!(values.iter().all(|l| l.re < 0.0));
+}
+
+#[test]
+fn solve_continuous_are_not_stabilizable() {
+    let a: General<f64> = matrix![1.0];
+    let b: General<f64> = matrix![0.0];
+    let one: General<f64> = matrix![1.0];
+
+    assert_eq!(equations::solve_continuous_are(&a, &b, &one, &one), Err(LinalgError::NoStabilizingSolution));
+}
+
+#[test]
+fn solve_continuous_are_eigenvalues_on_imaginary_axis() {
+    let a: General<f64> = matrix![  0.0, 1.0;
+                                    -1.0, 0.0];
+    let b: General<f64> = matrix![  0.0;
+                                    0.0];
+    let q: General<f64> = General::zero(2, 2);
+    let r: General<f64> = matrix![1.0];
+
+    assert_eq!(equations::solve_continuous_are(&a, &b, &q, &r), Err(LinalgError::NoStabilizingSolution));
+}
+
+#[test]
+fn solve_discrete_are() {
+    let (a, b, q, r) = system();
+
+    let x: General<f64> = equations::solve_discrete_are(&a, &b, &q, &r).unwrap();
+
+    let a_t: General<f64> = a.clone().transpose();
+    let b_t: General<f64> = b.clone().transpose();
+    let k: General<f64> = (&r + &(&(&b_t * &x) * &b)).solve(&(&(&b_t * &x) * &a)).unwrap();
+    let residual: General<f64> = &(&(&(&(&a_t * &x) * &a) - &x) - &(&(&(&a_t * &x) * &b) * &k)) + &q;
+    assert_relative_eq!(residual, General::zero(3, 3), epsilon = 1.0e-8);
+    assert_eq!(x.clone().transpose(), x);
+
+    // The closed loop is stable
+    let values: Vec<Complex<f64>> = (&a - &(&b * &k)).dec_eigen_complex().unwrap().values();
+    assert!(values.iter().all(|l| (l.re * l.re + l.im * l.im).sqrt() < 1.0));
+}
+
+#[test]
+fn solve_discrete_are_not_stabilizable() {
+    let a: General<f64> = matrix![2.0];
+    let b: General<f64> = matrix![0.0];
+    let one: General<f64> = matrix![1.0];
+
+    assert_eq!(equations::solve_discrete_are(&a, &b, &one, &one), Err(LinalgError::NoStabilizingSolution));
+}
+
+#[test]
+fn solve_discrete_are_singular_a() {
+    let a: General<f64> = matrix![  0.0, 1.0;
+                                    0.0, 0.0];
+    let b: General<f64> = matrix![  0.0;
+                                    1.0];
+    let q: General<f64> = General::one(2);
+    let r: General<f64> = matrix![1.0];
+
+    let x: General<f64> = equations::solve_discrete_are(&a, &b, &q, &r).unwrap();
+
+    let a_t: General<f64> = a.clone().transpose();
+    let b_t: General<f64> = b.clone().transpose();
+    let k: General<f64> = (&r + &(&(&b_t * &x) * &b)).solve(&(&(&b_t * &x) * &a)).unwrap();
+    let residual: General<f64> = &(&(&(&(&a_t * &x) * &a) - &x) - &(&(&(&a_t * &x) * &b) * &k)) + &q;
+    assert_relative_eq!(residual, General::zero(2, 2), epsilon = 1.0e-10);
+    assert_eq!(x.clone().transpose(), x);
+
+    // The closed loop is stable
+    let values: Vec<Complex<f64>> = (&a - &(&b * &k)).dec_eigen_complex().unwrap().values();
+    assert!(values.iter().all(|l| (l.re * l.re + l.im * l.im).sqrt() < 1.0));
+}
+
+#[test]
+#[should_panic]
+fn solve_continuous_are_dimension_mismatch() {
+    let (a, b, q, _) = system();
+
+    let _ = equations::solve_continuous_are(&a, &b, &q, &General::one(3));
+}
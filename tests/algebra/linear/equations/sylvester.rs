@@ -0,0 +1,51 @@
+use mathru::algebra::linear::{equations, matrix::General, LinalgError};
+
+#[test]
+fn solve_sylvester() {
+    let a: General<f64> = matrix![  1.0, -2.0, 0.5;
+                                    2.0, 1.0, 0.0;
+                                    0.0, 1.0, 3.0];
+    let b: General<f64> = matrix![  2.0, 1.0;
+                                    -1.0, 4.0];
+    let c: General<f64> = matrix![  1.0, 0.0;
+                                    2.0, -1.0;
+                                    0.0, 3.0];
+
+    let x: General<f64> = equations::solve_sylvester(&a, &b, &c).unwrap();
+
+    assert_eq!(x.dim(), (3, 2));
+    assert_relative_eq!(&(&a * &x) + &(&x * &b), c, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_sylvester_diagonal() {
+    let a: General<f64> = matrix![  1.0, 0.0;
+                                    0.0, 2.0];
+    let b: General<f64> = matrix![  3.0, 0.0;
+                                    0.0, 4.0];
+    let c: General<f64> = matrix![  4.0, 5.0;
+                                    5.0, 6.0];
+
+    let x: General<f64> = equations::solve_sylvester(&a, &b, &c).unwrap();
+
+    assert_relative_eq!(x, General::ones(2, 2), epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_sylvester_singular() {
+    let a: General<f64> = matrix![  1.0, 0.0;
+                                    0.0, 2.0];
+    let b: General<f64> = matrix![  -1.0, 0.0;
+                                    0.0, 3.0];
+
+    assert_eq!(equations::solve_sylvester(&a, &b, &General::one(2)), Err(LinalgError::Singular));
+}
+
+#[test]
+#[should_panic]
+fn solve_sylvester_dimension_mismatch() {
+    let a: General<f64> = General::one(2);
+    let b: General<f64> = General::one(3);
+
+    let _ = equations::solve_sylvester(&a, &b, &General::one(2));
+}
@@ -0,0 +1,29 @@
+use mathru::algebra::linear::{
+    matrix::{General, Solve},
+    vector::Vector,
+    LinalgError,
+};
+use std::error::Error;
+
+#[test]
+fn display() {
+    assert_eq!(LinalgError::Singular.to_string(), "The matrix is singular");
+    assert_eq!(LinalgError::NoConvergence { iters: 30 }.to_string(), "No convergence after 30 iterations");
+    assert_eq!(LinalgError::BackendError { info: -4 }.to_string(), "LAPACK reported the error code -4");
+    assert_eq!(LinalgError::NoStabilizingSolution.to_string(), "The Riccati equation does not have a stabilizing solution");
+}
+
+#[test]
+fn question_mark_into_box_dyn_error() {
+    fn solve(a: &General<f64>, b: &Vector<f64>) -> Result<Vector<f64>, Box<dyn Error>> {
+        Ok(a.solve(b)?)
+    }
+
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    2.0, 4.0];
+    let b: Vector<f64> = vector![1.0; 3.0];
+
+    let err: Box<dyn Error> = solve(&a, &b).unwrap_err();
+
+    assert_eq!(err.downcast_ref::<LinalgError>(), Some(&LinalgError::Singular));
+}
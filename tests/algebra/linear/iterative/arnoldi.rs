@@ -0,0 +1,134 @@
+use super::{convection_diffusion, poisson};
+use mathru::algebra::abstr::Complex;
+use mathru::algebra::linear::{
+    iterative::{Arnoldi, IterativeEigenResult, Lanczos, Which},
+    matrix::{CsrMatrix, General},
+    vector::Vector,
+};
+
+fn start(n: usize) -> Vector<f64> {
+    Vector::new_column((0..n).map(|i| 1.0 + 0.5 * (i as f64).cos()).collect())
+}
+
+/// Normal matrix $HBH$ with a Householder reflection $H$ and a block
+/// diagonal matrix $B$ with the real eigenvalues 0.0, 0.1, ... and the 2x2
+/// blocks of the complex eigenvalues
+fn normal_matrix(n: usize, complex: &[Complex<f64>]) -> CsrMatrix<f64> {
+    let mut b: General<f64> = General::zero(n, n);
+    let n_real: usize = n - 2 * complex.len();
+    for i in 0..n_real {
+        b[[i, i]] = 0.1 * i as f64;
+    }
+    for (l, lambda) in complex.iter().enumerate() {
+        let i: usize = n_real + 2 * l;
+        b[[i, i]] = lambda.re;
+        b[[i, i + 1]] = lambda.im;
+        b[[i + 1, i]] = -lambda.im;
+        b[[i + 1, i + 1]] = lambda.re;
+    }
+
+    let v: Vector<f64> = Vector::new_column((0..n).map(|i| 1.0 + (i % 3) as f64).collect());
+    let mut h: General<f64> = General::one(n);
+    let vv: f64 = v.dotp(&v);
+    for i in 0..n {
+        for j in 0..n {
+            h[[i, j]] -= 2.0 * v[i] * v[j] / vv;
+        }
+    }
+    CsrMatrix::from(&(&(&h * &b) * &h))
+}
+
+fn assert_eigenpairs(a: &CsrMatrix<f64>, result: &IterativeEigenResult<f64, Complex<f64>>) {
+    let x: &General<Complex<f64>> = result.vectors();
+    let (n, _): (usize, usize) = x.dim();
+    for (j, lambda) in result.values().iter().enumerate() {
+        let x_re: Vector<f64> = Vector::new_column((0..n).map(|i| x[[i, j]].re).collect());
+        let x_im: Vector<f64> = Vector::new_column((0..n).map(|i| x[[i, j]].im).collect());
+        let r_re: Vector<f64> = &(&(a * &x_re) - &(&x_re * &lambda.re)) + &(&x_im * &lambda.im);
+        let r_im: Vector<f64> = &(&(a * &x_im) - &(&x_im * &lambda.re)) - &(&x_re * &lambda.im);
+        assert!((r_re.dotp(&r_re) + r_im.dotp(&r_im)).sqrt() < 1.0e-8);
+        assert_relative_eq!(x_re.dotp(&x_re) + x_im.dotp(&x_im), 1.0, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn largest_real() {
+    let n: usize = 100;
+    let a: CsrMatrix<f64> = normal_matrix(n, &[Complex::new(3.0, 2.0), Complex::new(1.0, -5.0)]);
+
+    let arnoldi: Arnoldi<f64> = Arnoldi::new(300, 1.0e-10);
+    let result: IterativeEigenResult<f64, Complex<f64>> = arnoldi.solve(&a, &start(n), 3, Which::LargestReal).unwrap();
+
+    assert!(result.converged());
+    assert!(result.iterations() > 0);
+    for (j, lambda) in result.values().iter().enumerate() {
+        assert_relative_eq!(*lambda, Complex::new(9.5 - 0.1 * j as f64, 0.0), epsilon = Complex::new(1.0e-8, 1.0e-8));
+    }
+    assert_eigenpairs(&a, &result);
+}
+
+#[test]
+fn complex_conjugate_pair() {
+    let n: usize = 80;
+    let a: CsrMatrix<f64> = normal_matrix(n, &[Complex::new(3.0, 2.0), Complex::new(1.0, 20.0)]);
+
+    let arnoldi: Arnoldi<f64> = Arnoldi::new(300, 1.0e-10);
+    let result: IterativeEigenResult<f64, Complex<f64>> = arnoldi.solve(&a, &start(n), 2, Which::LargestMagnitude).unwrap();
+
+    assert!(result.converged());
+    let mut values: Vec<Complex<f64>> = result.values().to_vec();
+    values.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+    assert_relative_eq!(values[0], Complex::new(1.0, -20.0), epsilon = Complex::new(1.0e-8, 1.0e-8));
+    assert_relative_eq!(values[1], Complex::new(1.0, 20.0), epsilon = Complex::new(1.0e-8, 1.0e-8));
+    assert_eigenpairs(&a, &result);
+}
+
+#[test]
+fn symmetric() {
+    let n: usize = 60;
+    let a: CsrMatrix<f64> = poisson(n);
+
+    let arnoldi: Arnoldi<f64> = Arnoldi::new(300, 1.0e-10);
+    let lanczos: Lanczos<f64> = Lanczos::new(300, 1.0e-10);
+    let result: IterativeEigenResult<f64, Complex<f64>> = arnoldi.solve(&a, &start(n), 2, Which::LargestReal).unwrap();
+    let reference: IterativeEigenResult<f64> = lanczos.solve(&a, &start(n), 2, Which::LargestReal).unwrap();
+
+    assert!(result.converged());
+    for (lambda, mu) in result.values().iter().zip(reference.values().iter()) {
+        assert_relative_eq!(*lambda, Complex::new(*mu, 0.0), epsilon = Complex::new(1.0e-8, 1.0e-8));
+    }
+}
+
+#[test]
+fn full_subspace() {
+    let a: General<f64> = matrix![  0.0, -1.0, 0.0;
+                                    1.0, 0.0, 0.0;
+                                    0.0, 0.0, 0.5];
+
+    let arnoldi: Arnoldi<f64> = Arnoldi::new(0, 1.0e-10);
+    let result: IterativeEigenResult<f64, Complex<f64>> = arnoldi.solve(&a, &vector![1.0; 1.0; 1.0], 1, Which::SmallestMagnitude).unwrap();
+
+    assert!(result.converged());
+    assert_relative_eq!(result.values()[0], Complex::new(0.5, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
+
+#[test]
+#[should_panic]
+fn zero_start() {
+    let a: CsrMatrix<f64> = poisson(10);
+
+    let _ = Arnoldi::new(10, 1.0e-10).solve(&a, &Vector::zero(10), 1, Which::LargestReal);
+}
+
+#[test]
+fn nonnormal() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = convection_diffusion(n, 0.2, 0.5);
+
+    let arnoldi: Arnoldi<f64> = Arnoldi::new(300, 1.0e-10);
+    let result: IterativeEigenResult<f64, Complex<f64>> = arnoldi.solve(&a, &start(n), 2, Which::SmallestReal).unwrap();
+
+    assert!(result.converged());
+    assert!(result.values()[0].re <= result.values()[1].re);
+    assert_eigenpairs(&a, &result);
+}
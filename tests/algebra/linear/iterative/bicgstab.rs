@@ -0,0 +1,67 @@
+use super::{convection_diffusion, residual, rhs};
+use mathru::algebra::linear::{
+    iterative::{BiCgStab, Ilu0, IterativeResult, Jacobi},
+    matrix::{CsrMatrix, General},
+    vector::Vector,
+};
+
+#[test]
+fn solve_general() {
+    let a: General<f64> = matrix![4.0, 1.0, 0.0;
+                                  -2.0, 3.0, 1.0;
+                                  0.0, 1.0, 5.0];
+    let x_ref: Vector<f64> = vector![1.0; -1.0; 2.0];
+    let b: Vector<f64> = &a * &x_ref;
+
+    let bicgstab: BiCgStab<f64> = BiCgStab::new(20, 1.0e-12);
+    let result: IterativeResult<f64> = bicgstab.solve(&a, &b, &Vector::zero(3));
+
+    assert!(result.converged());
+    assert_relative_eq!(*result.x(), x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_sparse() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = convection_diffusion(n, 0.4, 0.1);
+    let b: Vector<f64> = rhs(n);
+
+    let bicgstab: BiCgStab<f64> = BiCgStab::new(200, 1.0e-10);
+    let result: IterativeResult<f64> = bicgstab.solve(&a, &b, &Vector::zero(n));
+
+    assert!(result.converged());
+    assert!(residual(&a, &b, result.x()) < 1.0e-10);
+    assert_eq!(result.residuals().len(), result.iterations() + 1);
+}
+
+#[test]
+fn solve_jacobi() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = convection_diffusion(n, 0.4, 0.1);
+    let b: Vector<f64> = rhs(n);
+    let jacobi: Jacobi<f64> = Jacobi::new(&a).unwrap();
+
+    let bicgstab: BiCgStab<f64> = BiCgStab::new(200, 1.0e-10);
+    let result: IterativeResult<f64> =
+        bicgstab.solve_preconditioned(&a, &b, &Vector::zero(n), &jacobi);
+
+    assert!(result.converged());
+    assert!(residual(&a, &b, result.x()) < 1.0e-10);
+}
+
+#[test]
+fn solve_ilu0() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = convection_diffusion(n, 0.4, 0.1);
+    let b: Vector<f64> = rhs(n);
+    let ilu: Ilu0<f64> = Ilu0::new(&a).unwrap();
+
+    let bicgstab: BiCgStab<f64> = BiCgStab::new(200, 1.0e-10);
+    let result: IterativeResult<f64> =
+        bicgstab.solve_preconditioned(&a, &b, &Vector::zero(n), &ilu);
+
+    // ILU(0) of a tridiagonal matrix is the exact LU decomposition
+    assert!(result.converged());
+    assert_eq!(result.iterations(), 1);
+    assert!(residual(&a, &b, result.x()) < 1.0e-10);
+}
@@ -0,0 +1,106 @@
+use super::{poisson, residual, rhs};
+use mathru::algebra::linear::{
+    iterative::{ConjugateGradient, IterativeResult, Jacobi, Ssor},
+    matrix::{CsrMatrix, General},
+    vector::Vector,
+};
+
+#[test]
+fn solve_general() {
+    let a: General<f64> = matrix![4.0, 1.0, 0.0;
+                                  1.0, 3.0, -1.0;
+                                  0.0, -1.0, 2.0];
+    let x_ref: Vector<f64> = vector![1.0; -2.0; 3.0];
+    let b: Vector<f64> = &a * &x_ref;
+
+    let cg: ConjugateGradient<f64> = ConjugateGradient::new(10, 1.0e-12);
+    let result: IterativeResult<f64> = cg.solve(&a, &b, &Vector::zero(3));
+
+    assert!(result.converged());
+    assert!(result.iterations() <= 3);
+    assert_relative_eq!(*result.x(), x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_sparse() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = poisson(n);
+    let b: Vector<f64> = rhs(n);
+
+    let cg: ConjugateGradient<f64> = ConjugateGradient::new(100, 1.0e-10);
+    let result: IterativeResult<f64> = cg.solve(&a, &b, &Vector::zero(n));
+
+    assert!(result.converged());
+    assert!(residual(&a, &b, result.x()) < 1.0e-10);
+    assert_eq!(result.residuals().len(), result.iterations() + 1);
+}
+
+#[test]
+fn solve_jacobi() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = poisson(n);
+    let b: Vector<f64> = rhs(n);
+    let jacobi: Jacobi<f64> = Jacobi::new(&a).unwrap();
+
+    let cg: ConjugateGradient<f64> = ConjugateGradient::new(100, 1.0e-10);
+    let result: IterativeResult<f64> = cg.solve_preconditioned(&a, &b, &Vector::zero(n), &jacobi);
+
+    assert!(result.converged());
+    assert!(residual(&a, &b, result.x()) < 1.0e-10);
+}
+
+#[test]
+fn solve_ssor() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = poisson(n);
+    let b: Vector<f64> = rhs(n);
+    let ssor: Ssor<f64> = Ssor::new(&a, 1.5).unwrap();
+
+    let cg: ConjugateGradient<f64> = ConjugateGradient::new(100, 1.0e-10);
+    let plain: IterativeResult<f64> = cg.solve(&a, &b, &Vector::zero(n));
+    let result: IterativeResult<f64> = cg.solve_preconditioned(&a, &b, &Vector::zero(n), &ssor);
+
+    assert!(result.converged());
+    assert!(result.iterations() < plain.iterations());
+    assert!(residual(&a, &b, result.x()) < 1.0e-10);
+}
+
+#[test]
+fn solve_initial_solution() {
+    let a: General<f64> = matrix![2.0, 0.0; 0.0, 3.0];
+    let x: Vector<f64> = vector![1.0; 1.0];
+    let b: Vector<f64> = &a * &x;
+
+    let cg: ConjugateGradient<f64> = ConjugateGradient::new(10, 1.0e-12);
+    let result: IterativeResult<f64> = cg.solve(&a, &b, &x);
+
+    assert!(result.converged());
+    assert_eq!(result.iterations(), 0);
+    assert_eq!(result.residuals(), &[0.0]);
+}
+
+#[test]
+fn solve_max_iter() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = poisson(n);
+    let b: Vector<f64> = rhs(n);
+
+    let cg: ConjugateGradient<f64> = ConjugateGradient::new(5, 1.0e-10);
+    let result: IterativeResult<f64> = cg.solve(&a, &b, &Vector::zero(n));
+
+    assert!(!result.converged());
+    assert_eq!(result.iterations(), 5);
+    assert_eq!(result.residuals().len(), 6);
+}
+
+#[test]
+fn solve_f32() {
+    let a: General<f32> = matrix![4.0, 1.0; 1.0, 3.0];
+    let b: Vector<f32> = vector![1.0; 2.0];
+
+    let cg: ConjugateGradient<f32> = ConjugateGradient::new(10, 1.0e-6);
+    let result: IterativeResult<f32> = cg.solve(&a, &b, &Vector::zero(2));
+
+    assert!(result.converged());
+    assert_relative_eq!(*result.x(), vector![1.0 / 11.0; 7.0 / 11.0], epsilon = 1.0e-5);
+}
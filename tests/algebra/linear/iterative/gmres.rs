@@ -0,0 +1,67 @@
+use super::{convection_diffusion, residual, rhs};
+use mathru::algebra::linear::{
+    iterative::{Gmres, Ilu0, IterativeResult},
+    matrix::{CsrMatrix, General},
+    vector::Vector,
+};
+
+#[test]
+fn solve_general() {
+    let a: General<f64> = matrix![4.0, 1.0, 0.0;
+                                  -2.0, 3.0, 1.0;
+                                  0.0, 1.0, 5.0];
+    let x_ref: Vector<f64> = vector![1.0; -1.0; 2.0];
+    let b: Vector<f64> = &a * &x_ref;
+
+    let gmres: Gmres<f64> = Gmres::new(10, 1.0e-12, 10);
+    let result: IterativeResult<f64> = gmres.solve(&a, &b, &Vector::zero(3));
+
+    assert!(result.converged());
+    assert!(result.iterations() <= 3);
+    assert_relative_eq!(*result.x(), x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_restarted() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = convection_diffusion(n, 0.4, 0.5);
+    let b: Vector<f64> = rhs(n);
+
+    let gmres: Gmres<f64> = Gmres::new(500, 1.0e-10, 10);
+    let result: IterativeResult<f64> = gmres.solve(&a, &b, &Vector::zero(n));
+
+    assert!(result.converged());
+    assert!(result.iterations() > 10);
+    assert!(residual(&a, &b, result.x()) < 1.0e-9);
+    assert_eq!(result.residuals().len(), result.iterations() + 1);
+}
+
+#[test]
+fn solve_ilu0() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = convection_diffusion(n, 0.4, 0.1);
+    let b: Vector<f64> = rhs(n);
+    let ilu: Ilu0<f64> = Ilu0::new(&a).unwrap();
+
+    let gmres: Gmres<f64> = Gmres::new(100, 1.0e-10, 20);
+    let result: IterativeResult<f64> =
+        gmres.solve_preconditioned(&a, &b, &Vector::zero(n), &ilu);
+
+    assert!(result.converged());
+    assert_eq!(result.iterations(), 1);
+    assert!(residual(&a, &b, result.x()) < 1.0e-10);
+}
+
+#[test]
+fn solve_max_iter() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = convection_diffusion(n, 0.4, 0.1);
+    let b: Vector<f64> = rhs(n);
+
+    let gmres: Gmres<f64> = Gmres::new(7, 1.0e-10, 3);
+    let result: IterativeResult<f64> = gmres.solve(&a, &b, &Vector::zero(n));
+
+    assert!(!result.converged());
+    assert_eq!(result.iterations(), 7);
+    assert_eq!(result.residuals().len(), 8);
+}
@@ -0,0 +1,145 @@
+use super::poisson;
+use mathru::algebra::linear::{
+    iterative::{IterativeEigenResult, Lanczos, Which},
+    matrix::{CsrMatrix, Diagonal, General, Transpose},
+    vector::Vector,
+};
+use std::f64::consts::PI;
+
+fn poisson_eigenvalue(n: usize, j: usize) -> f64 {
+    2.0 - 2.0 * (j as f64 * PI / (n + 1) as f64).cos()
+}
+
+fn start(n: usize) -> Vector<f64> {
+    Vector::new_column((0..n).map(|i| 1.0 + 0.5 * (i as f64).cos()).collect())
+}
+
+fn assert_eigenpairs(a: &CsrMatrix<f64>, result: &IterativeEigenResult<f64>) {
+    let x: &General<f64> = result.vectors();
+    let k: usize = result.values().len();
+    for (j, lambda) in result.values().iter().enumerate() {
+        let x_j: Vector<f64> = x.get_column(j);
+        assert!((&(a * &x_j) - &(&x_j * lambda)).eucl_norm() < 1.0e-8);
+    }
+    assert_relative_eq!(&x.clone().transpose() * x, General::one(k), epsilon = 1.0e-10);
+}
+
+#[test]
+fn largest() {
+    let n: usize = 100;
+    let a: CsrMatrix<f64> = poisson(n);
+
+    let lanczos: Lanczos<f64> = Lanczos::new(200, 1.0e-10);
+    let result: IterativeEigenResult<f64> = lanczos.solve(&a, &start(n), 4, Which::LargestReal).unwrap();
+
+    assert!(result.converged());
+    assert!(result.iterations() > 0);
+    for (j, lambda) in result.values().iter().enumerate() {
+        assert_relative_eq!(*lambda, poisson_eigenvalue(n, n - j), epsilon = 1.0e-8);
+    }
+    assert_eigenpairs(&a, &result);
+}
+
+#[test]
+fn smallest() {
+    let n: usize = 60;
+    let a: CsrMatrix<f64> = poisson(n);
+
+    let lanczos: Lanczos<f64> = Lanczos::new(1000, 1.0e-10);
+    let result: IterativeEigenResult<f64> = lanczos.solve(&a, &start(n), 3, Which::SmallestReal).unwrap();
+
+    assert!(result.converged());
+    for (j, lambda) in result.values().iter().enumerate() {
+        assert_relative_eq!(*lambda, poisson_eigenvalue(n, j + 1), epsilon = 1.0e-8);
+    }
+    assert_eigenpairs(&a, &result);
+}
+
+#[test]
+fn largest_magnitude_indefinite() {
+    let a: Diagonal<f64> = Diagonal::new(&(0..50).map(|i| i as f64 - 40.0).collect::<Vec<f64>>());
+
+    let lanczos: Lanczos<f64> = Lanczos::new(100, 1.0e-10);
+    let result: IterativeEigenResult<f64> = lanczos.solve(&a, &start(50), 2, Which::LargestMagnitude).unwrap();
+
+    assert!(result.converged());
+    assert_relative_eq!(result.values()[0], -40.0, epsilon = 1.0e-8);
+    assert_relative_eq!(result.values()[1], -39.0, epsilon = 1.0e-8);
+}
+
+#[test]
+fn operator() {
+    let n: usize = 80;
+    let a = |x: &Vector<f64>| -> Vector<f64> {
+        let mut y: Vector<f64> = Vector::zero(n);
+        for i in 0..n {
+            y[i] = 2.0 * x[i];
+            if i > 0 {
+                y[i] -= x[i - 1];
+            }
+            if i + 1 < n {
+                y[i] -= x[i + 1];
+            }
+        }
+        y
+    };
+
+    let lanczos: Lanczos<f64> = Lanczos::new(200, 1.0e-10);
+    let result: IterativeEigenResult<f64> = lanczos.solve(&a, &start(n), 2, Which::LargestReal).unwrap();
+
+    assert!(result.converged());
+    assert_relative_eq!(result.values()[0], poisson_eigenvalue(n, n), epsilon = 1.0e-8);
+    assert_relative_eq!(result.values()[1], poisson_eigenvalue(n, n - 1), epsilon = 1.0e-8);
+}
+
+#[test]
+fn full_subspace() {
+    let a: General<f64> = matrix![  4.0, 1.0, 0.0;
+                                    1.0, 3.0, 1.0;
+                                    0.0, 1.0, 2.0];
+
+    let lanczos: Lanczos<f64> = Lanczos::new(0, 1.0e-10);
+    let result: IterativeEigenResult<f64> = lanczos.solve(&a, &vector![1.0; 0.0; 0.0], 3, Which::SmallestReal).unwrap();
+
+    assert!(result.converged());
+    assert_eq!(result.iterations(), 0);
+    let (values, vectors): (Vec<f64>, General<f64>) = result.pair();
+    assert_relative_eq!(&a * &vectors, &vectors * &General::from(Diagonal::new(&values)), epsilon = 1.0e-10);
+    assert!(values[0] < values[1] && values[1] < values[2]);
+}
+
+#[test]
+fn invariant_start() {
+    // The start vector lies in an invariant subspace of dimension 2
+    let a: Diagonal<f64> = Diagonal::new(&(1..=30).map(|i| i as f64).collect::<Vec<f64>>());
+    let mut v_0: Vector<f64> = Vector::zero(30);
+    v_0[0] = 1.0;
+    v_0[1] = 1.0;
+
+    let lanczos: Lanczos<f64> = Lanczos::new(100, 1.0e-10);
+    let result: IterativeEigenResult<f64> = lanczos.solve(&a, &v_0, 1, Which::LargestReal).unwrap();
+
+    assert!(result.converged());
+    assert_relative_eq!(result.values()[0], 30.0, epsilon = 1.0e-8);
+}
+
+#[test]
+fn not_converged() {
+    let n: usize = 200;
+    let a: CsrMatrix<f64> = poisson(n);
+
+    let lanczos: Lanczos<f64> = Lanczos::new(0, 1.0e-14);
+    let result: IterativeEigenResult<f64> = lanczos.solve(&a, &start(n), 4, Which::SmallestReal).unwrap();
+
+    assert!(!result.converged());
+    assert_eq!(result.iterations(), 0);
+    assert_eq!(result.residuals().len(), 4);
+}
+
+#[test]
+#[should_panic]
+fn zero_eigenpairs() {
+    let a: CsrMatrix<f64> = poisson(10);
+
+    let _ = Lanczos::new(10, 1.0e-10).solve(&a, &start(10), 0, Which::LargestReal);
+}
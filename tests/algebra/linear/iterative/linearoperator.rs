@@ -0,0 +1,70 @@
+use super::{poisson, rhs};
+use mathru::algebra::linear::{
+    iterative::{ConjugateGradient, IterativeResult, LinearOperator},
+    matrix::{CscMatrix, CsrMatrix, Diagonal, General},
+    vector::Vector,
+};
+
+#[test]
+fn mul_vec_general() {
+    let a: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+
+    assert_eq!(a.mul_vec(&vector![1.0; -1.0]), vector![-1.0; -1.0]);
+}
+
+#[test]
+fn mul_vec_diagonal() {
+    let a: Diagonal<f64> = Diagonal::new(&[2.0, -3.0]);
+
+    assert_eq!(a.mul_vec(&vector![1.0; 2.0]), vector![2.0; -6.0]);
+}
+
+#[test]
+fn mul_vec_sparse() {
+    let a: General<f64> = matrix![1.0, 0.0, 2.0; 0.0, 3.0, 0.0];
+    let x: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    assert_eq!(CsrMatrix::from(&a).mul_vec(&x), vector![7.0; 6.0]);
+    assert_eq!(CscMatrix::from(&a).mul_vec(&x), vector![7.0; 6.0]);
+}
+
+#[test]
+fn solve_closure() {
+    let n: usize = 30;
+    let a: CsrMatrix<f64> = poisson(n);
+    let b: Vector<f64> = rhs(n);
+
+    let op = |x: &Vector<f64>| -> Vector<f64> {
+        let mut y: Vector<f64> = Vector::zero(x.dim().0);
+        for i in 0..n {
+            y[i] = 2.0 * x[i];
+            if i > 0 {
+                y[i] -= x[i - 1];
+            }
+            if i + 1 < n {
+                y[i] -= x[i + 1];
+            }
+        }
+        y
+    };
+
+    let cg: ConjugateGradient<f64> = ConjugateGradient::new(100, 1.0e-10);
+    let result_op: IterativeResult<f64> = cg.solve(&op, &b, &Vector::zero(n));
+    let result_a: IterativeResult<f64> = cg.solve(&a, &b, &Vector::zero(n));
+
+    assert!(result_op.converged());
+    assert_eq!(result_op.iterations(), result_a.iterations());
+    assert_relative_eq!(*result_op.x(), *result_a.x(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn solve_diagonal() {
+    let a: Diagonal<f64> = Diagonal::new(&[1.0, 2.0, 4.0]);
+    let b: Vector<f64> = vector![1.0; 1.0; 1.0];
+
+    let cg: ConjugateGradient<f64> = ConjugateGradient::new(10, 1.0e-12);
+    let result: IterativeResult<f64> = cg.solve(&a, &b, &Vector::zero(3));
+
+    assert!(result.converged());
+    assert_relative_eq!(*result.x(), vector![1.0; 0.5; 0.25], epsilon = 1.0e-12);
+}
@@ -0,0 +1,102 @@
+use super::poisson;
+use mathru::algebra::linear::{
+    iterative::{IterativeEigenResult, Jacobi, Lobpcg, Which},
+    matrix::{CsrMatrix, Diagonal, General, SymmetricEigenDecomposition, Transpose},
+    vector::Vector,
+};
+use std::f64::consts::PI;
+
+fn start(n: usize, k: usize) -> General<f64> {
+    General::new(n, k, (0..n * k).map(|i| 1.0 + (i as f64 * 0.7).sin()).collect())
+}
+
+#[test]
+fn smallest() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = poisson(n);
+
+    let lobpcg: Lobpcg<f64> = Lobpcg::new(500, 1.0e-9);
+    let result: IterativeEigenResult<f64> = lobpcg.solve(&a, &start(n, 3), Which::SmallestReal).unwrap();
+
+    assert!(result.converged());
+    assert!(result.residuals().iter().all(|r| *r < 1.0e-8));
+    for (j, lambda) in result.values().iter().enumerate() {
+        assert_relative_eq!(*lambda, 2.0 - 2.0 * ((j + 1) as f64 * PI / (n + 1) as f64).cos(), epsilon = 1.0e-8);
+    }
+    let x: &General<f64> = result.vectors();
+    assert_relative_eq!(&x.clone().transpose() * x, General::one(3), epsilon = 1.0e-8);
+}
+
+#[test]
+fn largest() {
+    let a: Diagonal<f64> = Diagonal::new(&(1..=100).map(|i| i as f64).collect::<Vec<f64>>());
+
+    let lobpcg: Lobpcg<f64> = Lobpcg::new(200, 1.0e-9);
+    let result: IterativeEigenResult<f64> = lobpcg.solve(&a, &start(100, 2), Which::LargestReal).unwrap();
+
+    assert!(result.converged());
+    assert_relative_eq!(result.values()[0], 100.0, epsilon = 1.0e-7);
+    assert_relative_eq!(result.values()[1], 99.0, epsilon = 1.0e-7);
+}
+
+#[test]
+fn preconditioned() {
+    let n: usize = 100;
+    let mut a: General<f64> = General::from(&poisson(n));
+    for i in 0..n {
+        a[[i, i]] += i as f64;
+    }
+    let a_sparse: CsrMatrix<f64> = CsrMatrix::from(&a);
+    let reference: Vec<f64> = a.dec_symmetric_eigen().unwrap().values();
+
+    let jacobi: Jacobi<f64> = Jacobi::new(&a_sparse).unwrap();
+    let lobpcg: Lobpcg<f64> = Lobpcg::new(500, 1.0e-9);
+    let result: IterativeEigenResult<f64> = lobpcg.solve_preconditioned(&a_sparse, &start(n, 2), Which::SmallestReal, &jacobi).unwrap();
+    let unpreconditioned: IterativeEigenResult<f64> = lobpcg.solve(&a_sparse, &start(n, 2), Which::SmallestReal).unwrap();
+
+    assert!(result.converged());
+    assert!(result.iterations() < unpreconditioned.iterations());
+    assert_relative_eq!(result.values()[0], reference[0], epsilon = 1.0e-8);
+    assert_relative_eq!(result.values()[1], reference[1], epsilon = 1.0e-8);
+}
+
+#[test]
+fn not_converged() {
+    let a: CsrMatrix<f64> = poisson(100);
+
+    let lobpcg: Lobpcg<f64> = Lobpcg::new(2, 1.0e-12);
+    let result: IterativeEigenResult<f64> = lobpcg.solve(&a, &start(100, 2), Which::SmallestReal).unwrap();
+
+    assert!(!result.converged());
+    assert_eq!(result.iterations(), 2);
+}
+
+#[test]
+#[should_panic]
+fn magnitude() {
+    let a: CsrMatrix<f64> = poisson(10);
+
+    let _ = Lobpcg::new(10, 1.0e-10).solve(&a, &start(10, 1), Which::LargestMagnitude);
+}
+
+#[test]
+#[should_panic]
+fn linearly_dependent() {
+    let a: CsrMatrix<f64> = poisson(10);
+    let x_0: General<f64> = General::new(10, 2, vec![1.0; 20]);
+
+    let _ = Lobpcg::new(10, 1.0e-10).solve(&a, &x_0, Which::SmallestReal);
+}
+
+#[test]
+fn vector_start() {
+    let a: CsrMatrix<f64> = poisson(20);
+    let x_0: Vector<f64> = Vector::new_column((0..20).map(|i| 1.0 + i as f64).collect());
+    let mut x: General<f64> = General::zero(20, 1);
+    x.set_column(&x_0, 0);
+
+    let result: IterativeEigenResult<f64> = Lobpcg::new(200, 1.0e-9).solve(&a, &x, Which::SmallestReal).unwrap();
+
+    assert!(result.converged());
+    assert_relative_eq!(result.values()[0], 2.0 - 2.0 * (PI / 21.0).cos(), epsilon = 1.0e-8);
+}
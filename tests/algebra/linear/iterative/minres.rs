@@ -0,0 +1,68 @@
+use super::{poisson, residual, rhs};
+use mathru::algebra::linear::{
+    iterative::{IterativeResult, Jacobi, Minres},
+    matrix::{CsrMatrix, General},
+    vector::Vector,
+};
+
+#[test]
+fn solve_indefinite() {
+    let a: General<f64> = matrix![1.0, 2.0, 0.0;
+                                  2.0, -1.0, 1.0;
+                                  0.0, 1.0, -3.0];
+    let x_ref: Vector<f64> = vector![1.0; 2.0; -1.0];
+    let b: Vector<f64> = &a * &x_ref;
+
+    let minres: Minres<f64> = Minres::new(10, 1.0e-12);
+    let result: IterativeResult<f64> = minres.solve(&a, &b, &Vector::zero(3));
+
+    assert!(result.converged());
+    assert_relative_eq!(*result.x(), x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_sparse() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = poisson(n);
+    let b: Vector<f64> = rhs(n);
+
+    let minres: Minres<f64> = Minres::new(100, 1.0e-10);
+    let result: IterativeResult<f64> = minres.solve(&a, &b, &Vector::zero(n));
+
+    assert!(result.converged());
+    assert!(residual(&a, &b, result.x()) < 1.0e-9);
+    assert_eq!(result.residuals().len(), result.iterations() + 1);
+    // the residual norms of MINRES are non increasing
+    for r in result.residuals().windows(2) {
+        assert!(r[1] <= r[0] * (1.0 + 1.0e-12));
+    }
+}
+
+#[test]
+fn solve_shifted_indefinite() {
+    let n: usize = 40;
+    // poisson(n) - 1.5 I is indefinite
+    let a: CsrMatrix<f64> = &poisson(n) - &(&CsrMatrix::one(n) * &1.5);
+    let b: Vector<f64> = rhs(n);
+
+    let minres: Minres<f64> = Minres::new(200, 1.0e-10);
+    let result: IterativeResult<f64> = minres.solve(&a, &b, &Vector::zero(n));
+
+    assert!(result.converged());
+    assert!(residual(&a, &b, result.x()) < 1.0e-9);
+}
+
+#[test]
+fn solve_jacobi() {
+    let n: usize = 50;
+    let a: CsrMatrix<f64> = poisson(n);
+    let b: Vector<f64> = rhs(n);
+    let jacobi: Jacobi<f64> = Jacobi::new(&a).unwrap();
+
+    let minres: Minres<f64> = Minres::new(100, 1.0e-10);
+    let result: IterativeResult<f64> =
+        minres.solve_preconditioned(&a, &b, &Vector::zero(n), &jacobi);
+
+    assert!(result.converged());
+    assert!(residual(&a, &b, result.x()) < 1.0e-8);
+}
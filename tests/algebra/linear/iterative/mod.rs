@@ -0,0 +1,62 @@
+mod arnoldi;
+mod bicgstab;
+mod conjugategradient;
+mod gmres;
+mod lanczos;
+mod linearoperator;
+mod lobpcg;
+mod minres;
+mod preconditioner;
+
+use mathru::algebra::linear::{matrix::CsrMatrix, vector::Vector};
+
+/// Discretization of -u'' on n interior points, symmetric positive definite
+fn poisson(n: usize) -> CsrMatrix<f64> {
+    let mut row_offsets: Vec<usize> = vec![0];
+    let mut col_indices: Vec<usize> = Vec::new();
+    let mut values: Vec<f64> = Vec::new();
+    for i in 0..n {
+        if i > 0 {
+            col_indices.push(i - 1);
+            values.push(-1.0);
+        }
+        col_indices.push(i);
+        values.push(2.0);
+        if i + 1 < n {
+            col_indices.push(i + 1);
+            values.push(-1.0);
+        }
+        row_offsets.push(col_indices.len());
+    }
+    CsrMatrix::new(n, n, row_offsets, col_indices, values)
+}
+
+/// Discretization of -u'' + c u' + d u on n interior points, nonsymmetric
+fn convection_diffusion(n: usize, c: f64, d: f64) -> CsrMatrix<f64> {
+    let a: CsrMatrix<f64> = poisson(n);
+    let mut values: Vec<f64> = Vec::new();
+    for (i, j, v) in a.iter() {
+        values.push(if j < i {
+            v - c
+        } else if j > i {
+            v + c
+        } else {
+            v + d
+        });
+    }
+    CsrMatrix::new(
+        n,
+        n,
+        a.row_offsets().to_vec(),
+        a.col_indices().to_vec(),
+        values,
+    )
+}
+
+fn rhs(n: usize) -> Vector<f64> {
+    Vector::new_column((0..n).map(|i| 1.0 + (i as f64).sin()).collect())
+}
+
+fn residual(a: &CsrMatrix<f64>, b: &Vector<f64>, x: &Vector<f64>) -> f64 {
+    (b - &(a * x)).eucl_norm() / b.eucl_norm()
+}
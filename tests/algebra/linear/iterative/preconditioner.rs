@@ -0,0 +1,97 @@
+use super::{convection_diffusion, poisson};
+use mathru::algebra::linear::{
+    iterative::{IdentityPreconditioner, Ilu0, Jacobi, Preconditioner, Ssor},
+    matrix::{CsrMatrix, General, Transpose},
+    vector::Vector,
+};
+
+#[test]
+fn identity() {
+    let r: Vector<f64> = vector![1.0; 2.0];
+
+    assert_eq!(IdentityPreconditioner.precondition(&r), r);
+}
+
+#[test]
+fn jacobi() {
+    let a: General<f64> = matrix![2.0, 1.0, 0.0;
+                                  1.0, -4.0, 1.0;
+                                  0.0, 1.0, 8.0];
+    let m: Jacobi<f64> = Jacobi::new(&CsrMatrix::from(&a)).unwrap();
+
+    assert_eq!(m.precondition(&vector![1.0; 1.0; 1.0]), vector![0.5; -0.25; 0.125]);
+}
+
+#[test]
+fn jacobi_zero_diagonal() {
+    let a: General<f64> = matrix![2.0, 1.0; 1.0, 0.0];
+
+    assert!(Jacobi::new(&CsrMatrix::from(&a)).is_err());
+}
+
+#[test]
+fn ssor() {
+    let a: General<f64> = matrix![4.0, -1.0, 0.0;
+                                  -1.0, 4.0, -1.0;
+                                  0.0, -1.0, 4.0];
+    let omega: f64 = 1.2;
+    let m: Ssor<f64> = Ssor::new(&CsrMatrix::from(&a), omega).unwrap();
+
+    // M = omega / (2 - omega) (D / omega + L) (D / omega)^-1 (D / omega + U)
+    let d: General<f64> = matrix![4.0 / omega, 0.0, 0.0;
+                                  0.0, 4.0 / omega, 0.0;
+                                  0.0, 0.0, 4.0 / omega];
+    let d_inv: General<f64> = matrix![omega / 4.0, 0.0, 0.0;
+                                      0.0, omega / 4.0, 0.0;
+                                      0.0, 0.0, omega / 4.0];
+    let l: General<f64> = matrix![0.0, 0.0, 0.0;
+                                  -1.0, 0.0, 0.0;
+                                  0.0, -1.0, 0.0];
+    let u: General<f64> = l.clone().transpose();
+    let m_ref: General<f64> = (&(&(&d + &l) * &d_inv) * &(&d + &u)) * (omega / (2.0 - omega));
+
+    let r: Vector<f64> = vector![1.0; -2.0; 3.0];
+
+    assert_relative_eq!(&m_ref * &m.precondition(&r), r, epsilon = 1.0e-12);
+}
+
+#[test]
+#[should_panic]
+fn ssor_omega() {
+    let _ = Ssor::new(&poisson(3), 2.0);
+}
+
+#[test]
+fn ilu0_tridiagonal() {
+    let a: CsrMatrix<f64> = convection_diffusion(10, 0.3, 0.2);
+    let m: Ilu0<f64> = Ilu0::new(&a).unwrap();
+    let x: Vector<f64> = Vector::new_column((0..10).map(|i| i as f64).collect());
+
+    assert_relative_eq!(m.precondition(&(&a * &x)), x, epsilon = 1.0e-12);
+}
+
+#[test]
+fn ilu0_pattern() {
+    // Fill-in at (2, 1) and (1, 2) is dropped
+    let a: General<f64> = matrix![4.0, 1.0, 1.0;
+                                  1.0, 4.0, 0.0;
+                                  1.0, 0.0, 4.0];
+    let m: Ilu0<f64> = Ilu0::new(&CsrMatrix::from(&a)).unwrap();
+
+    let l: General<f64> = matrix![1.0, 0.0, 0.0;
+                                  0.25, 1.0, 0.0;
+                                  0.25, 0.0, 1.0];
+    let u: General<f64> = matrix![4.0, 1.0, 1.0;
+                                  0.0, 3.75, 0.0;
+                                  0.0, 0.0, 3.75];
+    let r: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    assert_relative_eq!(&(&l * &u) * &m.precondition(&r), r, epsilon = 1.0e-12);
+}
+
+#[test]
+fn ilu0_missing_diagonal() {
+    let a: General<f64> = matrix![0.0, 1.0; 1.0, 2.0];
+
+    assert!(Ilu0::new(&CsrMatrix::from(&a)).is_err());
+}
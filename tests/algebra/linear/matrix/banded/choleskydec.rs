@@ -0,0 +1,34 @@
+use mathru::algebra::linear::matrix::{Banded, Determinant, General, Solve, Transpose};
+use mathru::algebra::linear::vector::Vector;
+use mathru::algebra::linear::LinalgError;
+
+#[test]
+fn dec_cholesky() {
+    let a: General<f64> = matrix![  6.0, 2.0, 1.0, 0.0, 0.0;
+                                    2.0, 6.0, 2.0, 1.0, 0.0;
+                                    1.0, 2.0, 6.0, 2.0, 1.0;
+                                    0.0, 1.0, 2.0, 6.0, 2.0;
+                                    0.0, 0.0, 1.0, 2.0, 6.0];
+    let x_ref: Vector<f64> = vector![1.0; -1.0; 2.0; 0.5; -3.0];
+    let b: Vector<f64> = &a * &x_ref;
+
+    let dec = Banded::from_general(&a, 2, 2).dec_cholesky().unwrap();
+
+    assert_relative_eq!(dec.solve(&b).unwrap(), x_ref, epsilon = 1.0e-10);
+    assert_relative_eq!(dec.det(), a.det(), epsilon = 1.0e-8);
+
+    let l: General<f64> = dec.l().into();
+    assert_relative_eq!(&l * &l.clone().transpose(), a, epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_cholesky_not_positive_definite() {
+    let a: General<f64> = matrix![  1.0, 2.0, 0.0;
+                                    2.0, 1.0, 2.0;
+                                    0.0, 2.0, 1.0];
+
+    assert_eq!(
+        Banded::from_general(&a, 1, 1).dec_cholesky().err(),
+        Some(LinalgError::NotPositiveDefinite)
+    );
+}
@@ -0,0 +1,30 @@
+use mathru::algebra::linear::matrix::{Banded, General};
+
+#[test]
+fn from_general() {
+    let a: General<f64> = matrix![  1.0, 2.0, 9.0, 9.0;
+                                    3.0, 4.0, 5.0, 9.0;
+                                    8.0, 6.0, 7.0, 1.0;
+                                    9.0, 7.0, 2.0, 3.0];
+
+    let b: Banded<f64> = Banded::from_general(&a, 2, 1);
+
+    let a_ref: General<f64> = matrix![  1.0, 2.0, 0.0, 0.0;
+                                        3.0, 4.0, 5.0, 0.0;
+                                        8.0, 6.0, 7.0, 1.0;
+                                        0.0, 7.0, 2.0, 3.0];
+
+    assert_eq!(b.dim(), (4, 4));
+    assert_eq!(b.bandwidth(), (2, 1));
+    assert_eq!(b.get(0, 3), 0.0);
+    assert_eq!(b[[2, 0]], 8.0);
+    assert_eq!(General::from(b), a_ref);
+}
+
+#[test]
+#[should_panic]
+fn index_outside_band() {
+    let b: Banded<f64> = Banded::zero(4, 1, 0);
+
+    let _ = b[[0, 1]];
+}
@@ -0,0 +1,43 @@
+use mathru::algebra::linear::matrix::{Banded, Determinant, General, Solve};
+use mathru::algebra::linear::vector::Vector;
+
+#[test]
+fn solve_pivoting() {
+    let a: General<f64> = matrix![  0.0, 2.0, 0.0, 0.0, 0.0;
+                                    3.0, 0.0, 2.0, 0.0, 0.0;
+                                    1.0, 3.0, 1.0, 2.0, 0.0;
+                                    0.0, 1.0, 3.0, 0.0, -1.0;
+                                    0.0, 0.0, 4.0, 1.0, 2.0];
+    let x_ref: Vector<f64> = vector![1.0; -1.0; 2.0; 0.5; -3.0];
+    let b: Vector<f64> = &a * &x_ref;
+
+    let dec = Banded::from_general(&a, 2, 1).dec_lu().unwrap();
+
+    assert_relative_eq!(dec.solve(&b).unwrap(), x_ref, epsilon = 1.0e-10);
+    assert_relative_eq!(dec.det(), a.det(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_general() {
+    let a: General<f64> = matrix![  4.0, 1.0, 2.0, 0.0;
+                                    1.0, 4.0, 1.0, 2.0;
+                                    0.0, 1.0, 4.0, 1.0;
+                                    0.0, 0.0, 1.0, 4.0];
+    let b: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.0;
+                                    3.0, 1.0;
+                                    0.0, 5.0];
+
+    let x: General<f64> = Banded::from_general(&a, 1, 2).dec_lu().unwrap().solve(&b).unwrap();
+
+    assert_relative_eq!(&a * &x, b, epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_lu_singular() {
+    let a: General<f64> = matrix![  1.0, 1.0, 0.0;
+                                    1.0, 1.0, 0.0;
+                                    0.0, 1.0, 1.0];
+
+    assert!(Banded::from_general(&a, 1, 1).dec_lu().is_err());
+}
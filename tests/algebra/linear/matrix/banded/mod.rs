@@ -0,0 +1,4 @@
+mod choleskydec;
+mod from;
+mod ludec;
+mod mul;
@@ -0,0 +1,27 @@
+use mathru::algebra::linear::matrix::{Banded, General};
+use mathru::algebra::linear::vector::Vector;
+
+#[test]
+fn mul_vector() {
+    let a: General<f64> = matrix![  1.0, 2.0, 0.0, 0.0;
+                                    3.0, 4.0, 5.0, 0.0;
+                                    8.0, 6.0, 7.0, 1.0;
+                                    0.0, 7.0, 2.0, 3.0];
+    let b: Banded<f64> = Banded::from_general(&a, 2, 1);
+    let x: Vector<f64> = vector![1.0; -2.0; 3.0; 4.0];
+
+    assert_eq!(&b * &x, &a * &x);
+}
+
+#[test]
+fn mul_general() {
+    let a: General<f64> = matrix![  1.0, 2.0, 0.0;
+                                    3.0, 4.0, 5.0;
+                                    0.0, 6.0, 7.0];
+    let b: Banded<f64> = Banded::from_general(&a, 1, 1);
+    let c: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.0;
+                                    3.0, 1.0];
+
+    assert_eq!(&b * &c, &a * &c);
+}
@@ -0,0 +1,22 @@
+use mathru::algebra::abstr::Complex;
+use mathru::algebra::linear::matrix::Circulant;
+
+#[test]
+fn eigenvalues() {
+    // the eigenvalues of a circulant matrix are
+    // lambda_k = sum_j c_j exp(-2 pi i jk / n)
+    let column: Vec<f64> = vec![1.0, -2.0, 0.5, 3.0, 1.5];
+    let n: usize = column.len();
+    let c: Circulant<f64> = Circulant::new(column.clone());
+
+    let lambda: Vec<Complex<f64>> = c.eigenvalues();
+
+    for (k, lambda_k) in lambda.iter().enumerate() {
+        let (re, im) = column.iter().enumerate().fold((0.0, 0.0), |(re, im), (j, c_j)| {
+            let phi: f64 = -2.0 * std::f64::consts::PI * (j * k) as f64 / n as f64;
+            (re + c_j * phi.cos(), im + c_j * phi.sin())
+        });
+        assert_relative_eq!(lambda_k.re, re, epsilon = 1.0e-10);
+        assert_relative_eq!(lambda_k.im, im, epsilon = 1.0e-10);
+    }
+}
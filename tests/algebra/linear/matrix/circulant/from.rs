@@ -0,0 +1,23 @@
+use mathru::algebra::linear::matrix::{Circulant, General};
+
+#[test]
+fn from_circulant() {
+    let c: Circulant<f64> = Circulant::new(vec![1.0, 2.0, 3.0]);
+    let c_ref: General<f64> = matrix![  1.0, 3.0, 2.0;
+                                        2.0, 1.0, 3.0;
+                                        3.0, 2.0, 1.0];
+
+    assert_eq!(General::from(c), c_ref);
+}
+
+#[test]
+fn get() {
+    let c: Circulant<f64> = Circulant::new(vec![1.0, 2.0, 3.0, 4.0]);
+    let c_ref: General<f64> = General::from(c.clone());
+
+    for i in 0..4 {
+        for j in 0..4 {
+            assert_eq!(c.get(i, j), c_ref[[i, j]]);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod eigenvalues;
+mod from;
+mod mul;
+mod solve;
@@ -0,0 +1,22 @@
+use mathru::algebra::linear::matrix::{Circulant, General};
+use mathru::algebra::linear::vector::Vector;
+
+#[test]
+fn mul_vector_power_of_two() {
+    let c: Circulant<f64> = Circulant::new(vec![1.0, 2.0, -1.0, 0.5, 3.0, 0.0, -2.0, 1.0]);
+    let x: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5; 1.0; 0.0; -1.0; 2.0];
+
+    let c_g: General<f64> = General::from(c.clone());
+
+    assert_relative_eq!(&c * &x, &c_g * &x, epsilon = 1.0e-10);
+}
+
+#[test]
+fn mul_vector_non_power_of_two() {
+    let c: Circulant<f64> = Circulant::new(vec![1.0, 2.0, -1.0, 0.5, 3.0, 0.0, -2.0]);
+    let x: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5; 1.0; 0.0; -1.0];
+
+    let c_g: General<f64> = General::from(c.clone());
+
+    assert_relative_eq!(&c * &x, &c_g * &x, epsilon = 1.0e-10);
+}
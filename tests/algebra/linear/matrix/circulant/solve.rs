@@ -0,0 +1,49 @@
+use mathru::algebra::linear::matrix::{Circulant, General, Solve};
+use mathru::algebra::linear::vector::Vector;
+
+#[test]
+fn solve_vector_power_of_two() {
+    let c: Circulant<f64> = Circulant::new(vec![6.0, 1.0, -1.0, 0.5]);
+    let x_ref: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5];
+    let b: Vector<f64> = &General::from(c.clone()) * &x_ref;
+
+    assert_relative_eq!(c.solve(&b).unwrap(), x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_vector_non_power_of_two() {
+    let c: Circulant<f64> = Circulant::new(vec![6.0, 1.0, -1.0, 0.5, 2.0, 0.25]);
+    let x_ref: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5; -1.0; 2.0];
+    let b: Vector<f64> = &General::from(c.clone()) * &x_ref;
+
+    assert_relative_eq!(c.solve(&b).unwrap(), x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_general() {
+    let c: Circulant<f64> = Circulant::new(vec![3.0, 1.0, 0.5]);
+    let b: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.0;
+                                    3.0, 1.0];
+
+    let x: General<f64> = c.solve(&b).unwrap();
+
+    assert_relative_eq!(&General::from(c) * &x, b, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_singular() {
+    // row sums vanish, so the eigenvalue lambda_0 is zero
+    let c: Circulant<f64> = Circulant::new(vec![2.0, -1.0, 0.0, -1.0]);
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0; 4.0];
+
+    assert!(c.solve(&b).is_err());
+}
+
+#[test]
+fn solve_f32() {
+    let c: Circulant<f32> = Circulant::new(vec![4.0, 1.0, 0.0, 1.0, 2.0]);
+    let b: Vector<f32> = vector![8.0; 8.0; 8.0; 8.0; 8.0];
+
+    assert_relative_eq!(c.solve(&b).unwrap(), vector![1.0; 1.0; 1.0; 1.0; 1.0], epsilon = 1.0e-5);
+}
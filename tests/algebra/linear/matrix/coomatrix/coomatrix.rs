@@ -0,0 +1,43 @@
+use mathru::algebra::linear::matrix::{CooIterator, CooMatrix, General};
+
+#[test]
+fn push() {
+    let mut a: CooMatrix<f64> = CooMatrix::with_capacity(2, 3, 2);
+    a.push(1, 2, 4.0);
+    a.push(0, 0, -1.0);
+
+    assert_eq!(a.dim(), (2, 3));
+    assert_eq!(a.nnz(), 2);
+
+    let mut iter: CooIterator<f64> = a.iter();
+    assert_eq!(iter.next(), Some((1, 2, &4.0)));
+    assert_eq!(iter.next(), Some((0, 0, &-1.0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[should_panic]
+fn push_out_of_bounds() {
+    let mut a: CooMatrix<f64> = CooMatrix::new(2, 3);
+    a.push(2, 0, 4.0);
+}
+
+#[test]
+fn into_general() {
+    let mut a: CooMatrix<f64> = CooMatrix::new(2, 2);
+    a.push(1, 0, 4.0);
+    a.push(1, 0, 1.0);
+    a.push(0, 1, -1.0);
+
+    assert_eq!(General::from(a), matrix![0.0, -1.0; 5.0, 0.0]);
+}
+
+#[test]
+fn from_general() {
+    let a: General<f64> = matrix![0.0, -1.0; 5.0, 0.0];
+
+    let coo: CooMatrix<f64> = CooMatrix::from(&a);
+
+    assert_eq!(coo.nnz(), 2);
+    assert_eq!(General::from(&coo), a);
+}
@@ -0,0 +1,14 @@
+use mathru::algebra::linear::matrix::{CscMatrix, General};
+
+#[test]
+fn add() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0];
+    let b: General<f64> = matrix![  0.0, 1.0, -2.0;
+                                    0.0, 0.0, 7.0];
+
+    let sum: CscMatrix<f64> = &CscMatrix::from(&a) + &CscMatrix::from(&b);
+
+    assert_eq!(General::from(&sum), &a + &b);
+    assert_eq!(sum.nnz(), 4);
+}
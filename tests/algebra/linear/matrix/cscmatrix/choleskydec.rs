@@ -0,0 +1,170 @@
+use mathru::algebra::linear::{
+    matrix::{CscMatrix, General, Solve, SparseOrdering, Transpose},
+    vector::Vector,
+    LinalgError,
+};
+
+const ORDERINGS: [SparseOrdering; 4] = [SparseOrdering::Natural, SparseOrdering::ReverseCuthillMcKee, SparseOrdering::Amd, SparseOrdering::Colamd];
+
+fn laplacian(k: usize) -> General<f64> {
+    let n: usize = k * k;
+    let mut a: General<f64> = General::zero(n, n);
+    for i in 0..k {
+        for j in 0..k {
+            let r: usize = i * k + j;
+            a[[r, r]] = 4.0;
+            if i + 1 < k {
+                a[[r, r + k]] = -1.0;
+                a[[r + k, r]] = -1.0;
+            }
+            if j + 1 < k {
+                a[[r, r + 1]] = -1.0;
+                a[[r + 1, r]] = -1.0;
+            }
+        }
+    }
+    a
+}
+
+fn permuted(a: &General<f64>, p: &[usize]) -> General<f64> {
+    let n: usize = p.len();
+    let mut b: General<f64> = General::zero(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            b[[i, j]] = a[[p[i], p[j]]];
+        }
+    }
+    b
+}
+
+#[test]
+fn dec_cholesky() {
+    let a: General<f64> = matrix![  4.0, 1.0, 0.0, 2.0;
+                                    1.0, 5.0, 1.0, 0.0;
+                                    0.0, 1.0, 3.0, 0.0;
+                                    2.0, 0.0, 0.0, 6.0];
+    let a_sparse: CscMatrix<f64> = CscMatrix::from(&a);
+
+    for ordering in ORDERINGS {
+        let dec = a_sparse.dec_cholesky(ordering).unwrap();
+        let p: Vec<usize> = dec.symbolic().permutation().to_vec();
+        let l: General<f64> = General::from(&dec.l());
+
+        assert_relative_eq!(&l * &l.clone().transpose(), permuted(&a, &p), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn solve_vector() {
+    let a: General<f64> = laplacian(6);
+    let a_sparse: CscMatrix<f64> = CscMatrix::from(&a);
+    let x: Vector<f64> = Vector::new_column((0..36).map(|i| (i as f64).sin()).collect());
+    let b: Vector<f64> = &a * &x;
+
+    for ordering in ORDERINGS {
+        let dec = a_sparse.dec_cholesky(ordering).unwrap();
+
+        assert_relative_eq!(dec.solve(&b).unwrap(), x, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn solve_general() {
+    let a: General<f64> = laplacian(3);
+    let x: General<f64> = General::new(9, 2, (0..18).map(|i| i as f64 - 4.0).collect());
+    let b: General<f64> = &a * &x;
+
+    let dec = CscMatrix::from(&a).dec_cholesky(SparseOrdering::Amd).unwrap();
+
+    assert_relative_eq!(dec.solve(&b).unwrap(), x, epsilon = 1.0e-10);
+}
+
+#[test]
+fn fill_reduction() {
+    let a: CscMatrix<f64> = CscMatrix::from(&laplacian(10));
+
+    let natural: usize = a.symbolic_cholesky(SparseOrdering::Natural).unwrap().nnz();
+    let amd: usize = a.symbolic_cholesky(SparseOrdering::Amd).unwrap().nnz();
+
+    assert!(amd < natural);
+}
+
+#[test]
+fn elimination_tree() {
+    // Tridiagonal matrix, the elimination tree is a path
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   2.0, -1.0, 0.0, 0.0;
+                                                        -1.0, 2.0, -1.0, 0.0;
+                                                        0.0, -1.0, 2.0, -1.0;
+                                                        0.0, 0.0, -1.0, 2.0]);
+
+    let symbolic = a.symbolic_cholesky(SparseOrdering::Natural).unwrap();
+
+    assert_eq!(symbolic.elimination_tree(), &[Some(1), Some(2), Some(3), None]);
+    assert_eq!(symbolic.nnz(), 7);
+}
+
+#[test]
+fn not_positive_definite() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   1.0, 2.0;
+                                                        2.0, 1.0]);
+
+    assert_eq!(a.dec_cholesky(SparseOrdering::Amd).unwrap_err(), LinalgError::NotPositiveDefinite);
+}
+
+#[test]
+fn not_square() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   1.0, 0.0, 2.0;
+                                                        0.0, 3.0, 0.0]);
+
+    assert_eq!(a.dec_cholesky(SparseOrdering::Natural).unwrap_err(), LinalgError::DimensionMismatch);
+}
+
+#[test]
+fn refactor() {
+    let a: General<f64> = laplacian(4);
+    let a_sparse: CscMatrix<f64> = CscMatrix::from(&a);
+    let mut dec = a_sparse.dec_cholesky(SparseOrdering::Amd).unwrap();
+
+    let shifted: General<f64> = &a + &General::one(16);
+    dec.refactor(&CscMatrix::from(&shifted)).unwrap();
+
+    let x: Vector<f64> = Vector::new_column((0..16).map(|i| i as f64).collect());
+    let b: Vector<f64> = &shifted * &x;
+    assert_relative_eq!(dec.solve(&b).unwrap(), x, epsilon = 1.0e-10);
+}
+
+#[test]
+fn refactor_symbolic() {
+    let a: CscMatrix<f64> = CscMatrix::from(&laplacian(3));
+    let symbolic = a.symbolic_cholesky(SparseOrdering::ReverseCuthillMcKee).unwrap();
+
+    let b: CscMatrix<f64> = a.apply(&|a_ij| 3.0 * a_ij);
+    let dec = symbolic.factor(&b).unwrap();
+
+    let x: Vector<f64> = dec.solve(&(&b * &Vector::new_column(vec![1.0; 9]))).unwrap();
+    assert_relative_eq!(x, Vector::new_column(vec![1.0; 9]), epsilon = 1.0e-10);
+}
+
+#[test]
+fn refactor_pattern_mismatch() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   2.0, 0.0, 0.0;
+                                                        0.0, 2.0, 0.0;
+                                                        0.0, 0.0, 2.0]);
+    let b: CscMatrix<f64> = CscMatrix::from(&matrix![   2.0, 1.0, 0.0;
+                                                        1.0, 2.0, 0.0;
+                                                        0.0, 0.0, 2.0]);
+    let mut dec = a.dec_cholesky(SparseOrdering::Natural).unwrap();
+
+    assert_eq!(dec.refactor(&b).unwrap_err(), LinalgError::DimensionMismatch);
+}
+
+#[test]
+fn refactor_not_positive_definite() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   2.0, 1.0;
+                                                        1.0, 2.0]);
+    let mut dec = a.dec_cholesky(SparseOrdering::Natural).unwrap();
+    let b: Vector<f64> = vector![3.0; 3.0];
+
+    assert_eq!(dec.refactor(&a.apply(&|a_ij| -a_ij)).unwrap_err(), LinalgError::NotPositiveDefinite);
+    assert_relative_eq!(dec.solve(&b).unwrap(), vector![1.0; 1.0], epsilon = 1.0e-10);
+}
@@ -0,0 +1,34 @@
+use mathru::algebra::linear::matrix::CscMatrix;
+
+#[test]
+fn new() {
+    let a: CscMatrix<f64> = CscMatrix::new(3, 3, vec![0, 1, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+
+    assert_eq!(a.dim(), (3, 3));
+    assert_eq!(a.nnz(), 3);
+    assert_eq!(a.get(2, 0), 1.0);
+    assert_eq!(a.get(1, 1), 0.0);
+    assert_eq!(a.get(0, 2), 2.0);
+    assert_eq!(a.get(1, 2), 3.0);
+}
+
+#[test]
+#[should_panic]
+fn new_wrong_offsets() {
+    let _: CscMatrix<f64> = CscMatrix::new(2, 2, vec![0, 1], vec![0], vec![1.0]);
+}
+
+#[test]
+fn column() {
+    let a: CscMatrix<f64> = CscMatrix::new(3, 2, vec![0, 2, 3], vec![0, 2, 1], vec![1.0, 2.0, 3.0]);
+
+    assert_eq!(a.column(0), (&[0usize, 2][..], &[1.0, 2.0][..]));
+    assert_eq!(a.column(1), (&[1usize][..], &[3.0][..]));
+}
+
+#[test]
+fn one() {
+    let a: CscMatrix<f64> = CscMatrix::one(2);
+
+    assert_eq!(a.diagonal(), vec![1.0, 1.0]);
+}
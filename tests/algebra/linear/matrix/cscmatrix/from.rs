@@ -0,0 +1,43 @@
+use mathru::algebra::linear::matrix::{CooMatrix, CscMatrix, CsrMatrix, General};
+
+#[test]
+fn from_general() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, 0.0, 3.0;
+                                    4.0, 0.0, 0.0];
+
+    let b: CscMatrix<f64> = CscMatrix::from(&a);
+
+    assert_eq!(b.col_offsets(), &[0, 2, 2, 4]);
+    assert_eq!(b.row_indices(), &[0, 2, 0, 1]);
+    assert_eq!(b.values(), &[1.0, 4.0, 2.0, 3.0]);
+    assert_eq!(General::from(&b), a);
+}
+
+#[test]
+fn from_coo() {
+    let mut coo: CooMatrix<f64> = CooMatrix::new(3, 2);
+    coo.push(2, 1, 1.0);
+    coo.push(0, 1, 2.0);
+    coo.push(2, 1, 3.0);
+    coo.push(0, 0, -1.0);
+
+    let a: CscMatrix<f64> = CscMatrix::from(coo);
+
+    assert_eq!(a.col_offsets(), &[0, 1, 3]);
+    assert_eq!(a.row_indices(), &[0, 0, 2]);
+    assert_eq!(a.values(), &[-1.0, 2.0, 4.0]);
+}
+
+#[test]
+fn from_csr() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0, 0.0;
+                                    0.0, 5.0, 3.0, 0.0;
+                                    4.0, 0.0, 0.0, 6.0];
+
+    let csr: CsrMatrix<f64> = CsrMatrix::from(&a);
+    let csc: CscMatrix<f64> = CscMatrix::from(csr);
+
+    assert_eq!(csc, CscMatrix::from(&a));
+    assert_eq!(General::from(csc), a);
+}
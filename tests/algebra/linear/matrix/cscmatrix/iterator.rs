@@ -0,0 +1,26 @@
+use mathru::algebra::linear::matrix::{CscColumnIterator, CscIterator, CscMatrix, General};
+
+#[test]
+fn iter() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![  0.0, 2.0;
+                                                        0.0, 0.0;
+                                                        3.0, 4.0]);
+    let mut iter: CscIterator<f64> = a.iter();
+
+    assert_eq!(iter.next(), Some((2, 0, &3.0)));
+    assert_eq!(iter.next(), Some((0, 1, &2.0)));
+    assert_eq!(iter.next(), Some((2, 1, &4.0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn column_iter() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![  0.0, 2.0, 0.0;
+                                                        3.0, 4.0, 0.0]);
+    let mut iter: CscColumnIterator<f64> = a.column_iter();
+
+    assert_eq!(iter.next(), Some((&[1usize][..], &[3.0][..])));
+    assert_eq!(iter.next(), Some((&[0usize, 1][..], &[2.0, 4.0][..])));
+    assert_eq!(iter.next(), Some((&[][..], &[][..])));
+    assert_eq!(iter.next(), None);
+}
@@ -0,0 +1,183 @@
+use mathru::algebra::linear::{
+    matrix::{CscMatrix, General, Solve, SparseOrdering},
+    vector::Vector,
+    LinalgError,
+};
+
+const ORDERINGS: [SparseOrdering; 4] = [SparseOrdering::Natural, SparseOrdering::ReverseCuthillMcKee, SparseOrdering::Amd, SparseOrdering::Colamd];
+
+fn convection_diffusion(k: usize) -> General<f64> {
+    let n: usize = k * k;
+    let mut a: General<f64> = General::zero(n, n);
+    for i in 0..k {
+        for j in 0..k {
+            let r: usize = i * k + j;
+            a[[r, r]] = 4.0;
+            if i + 1 < k {
+                a[[r, r + k]] = -1.5;
+                a[[r + k, r]] = -0.5;
+            }
+            if j + 1 < k {
+                a[[r, r + 1]] = -1.25;
+                a[[r + 1, r]] = -0.75;
+            }
+        }
+    }
+    a
+}
+
+fn permuted(a: &General<f64>, p: &[usize], q: &[usize]) -> General<f64> {
+    let n: usize = p.len();
+    let mut b: General<f64> = General::zero(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            b[[i, j]] = a[[p[i], q[j]]];
+        }
+    }
+    b
+}
+
+#[test]
+fn dec_lu() {
+    let a: General<f64> = matrix![  0.0, 2.0, 0.0, 1.0;
+                                    1.0, 0.0, 3.0, 0.0;
+                                    0.0, 1.0, 1.0, 0.0;
+                                    5.0, 0.0, 0.0, 2.0];
+    let a_sparse: CscMatrix<f64> = CscMatrix::from(&a);
+
+    for ordering in ORDERINGS {
+        let dec = a_sparse.dec_lu(ordering).unwrap();
+        let p: Vec<usize> = dec.row_permutation().to_vec();
+        let q: Vec<usize> = dec.column_permutation().to_vec();
+        let l: General<f64> = General::from(&dec.clone().l());
+        let u: General<f64> = General::from(&dec.u());
+
+        for i in 0..4 {
+            assert_eq!(l[[i, i]], 1.0);
+            for j in i + 1..4 {
+                assert_eq!(l[[i, j]], 0.0);
+                assert_eq!(u[[j, i]], 0.0);
+            }
+        }
+        assert_relative_eq!(&l * &u, permuted(&a, &p, &q), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn solve_vector() {
+    let a: General<f64> = convection_diffusion(6);
+    let a_sparse: CscMatrix<f64> = CscMatrix::from(&a);
+    let x: Vector<f64> = Vector::new_column((0..36).map(|i| (i as f64).cos()).collect());
+    let b: Vector<f64> = &a * &x;
+
+    for ordering in ORDERINGS {
+        let dec = a_sparse.dec_lu(ordering).unwrap();
+
+        assert_relative_eq!(dec.solve(&b).unwrap(), x, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn solve_general() {
+    let a: General<f64> = convection_diffusion(3);
+    let x: General<f64> = General::new(9, 3, (0..27).map(|i| 0.5 * i as f64 - 2.0).collect());
+    let b: General<f64> = &a * &x;
+
+    let dec = CscMatrix::from(&a).dec_lu(SparseOrdering::Colamd).unwrap();
+
+    assert_relative_eq!(dec.solve(&b).unwrap(), x, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_csc() {
+    let a: General<f64> = matrix![  0.0, 0.0, 2.0;
+                                    3.0, 0.0, 0.0;
+                                    0.0, 4.0, 1.0];
+    let b: Vector<f64> = vector![2.0; 6.0; 1.0];
+
+    let x: Vector<f64> = CscMatrix::from(&a).solve(&b).unwrap();
+
+    assert_relative_eq!(x, vector![2.0; 0.0; 1.0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn pivoting() {
+    // Without pivoting the second pivot vanishes
+    let a: General<f64> = matrix![  1.0, 1.0, 0.0;
+                                    1.0, 1.0, 1.0;
+                                    0.0, 1.0, 1.0];
+    let b: Vector<f64> = vector![2.0; 3.0; 2.0];
+
+    let dec = CscMatrix::from(&a).dec_lu(SparseOrdering::Natural).unwrap();
+
+    assert_relative_eq!(dec.solve(&b).unwrap(), vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn singular() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   1.0, 2.0, 0.0;
+                                                        2.0, 4.0, 0.0;
+                                                        0.0, 0.0, 1.0]);
+
+    for ordering in ORDERINGS {
+        assert_eq!(a.dec_lu(ordering).unwrap_err(), LinalgError::Singular);
+    }
+}
+
+#[test]
+fn not_square() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   1.0, 0.0, 2.0;
+                                                        0.0, 3.0, 0.0]);
+
+    assert_eq!(a.dec_lu(SparseOrdering::Colamd).unwrap_err(), LinalgError::DimensionMismatch);
+}
+
+#[test]
+fn refactor() {
+    let a: General<f64> = convection_diffusion(4);
+    let mut dec = CscMatrix::from(&a).dec_lu(SparseOrdering::Colamd).unwrap();
+
+    let b: General<f64> = &a + &General::one(16);
+    dec.refactor(&CscMatrix::from(&b)).unwrap();
+
+    let x: Vector<f64> = Vector::new_column((0..16).map(|i| i as f64 - 8.0).collect());
+    assert_relative_eq!(dec.solve(&(&b * &x)).unwrap(), x, epsilon = 1.0e-10);
+}
+
+#[test]
+fn refactor_symbolic() {
+    let a: CscMatrix<f64> = CscMatrix::from(&convection_diffusion(3));
+    let symbolic = a.symbolic_lu(SparseOrdering::Amd).unwrap();
+
+    let b: CscMatrix<f64> = a.apply(&|a_ij| -2.0 * a_ij);
+    let dec = symbolic.factor(&b).unwrap();
+
+    let x: Vector<f64> = dec.solve(&(&b * &Vector::new_column(vec![1.0; 9]))).unwrap();
+    assert_relative_eq!(x, Vector::new_column(vec![1.0; 9]), epsilon = 1.0e-10);
+}
+
+#[test]
+fn refactor_pattern_mismatch() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   2.0, 0.0, 0.0;
+                                                        0.0, 2.0, 0.0;
+                                                        0.0, 0.0, 2.0]);
+    let b: CscMatrix<f64> = CscMatrix::from(&matrix![   2.0, 0.0, 0.0;
+                                                        1.0, 2.0, 0.0;
+                                                        0.0, 0.0, 2.0]);
+    let mut dec = a.dec_lu(SparseOrdering::Natural).unwrap();
+
+    assert_eq!(dec.refactor(&b).unwrap_err(), LinalgError::DimensionMismatch);
+}
+
+#[test]
+fn refactor_singular() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   2.0, 1.0;
+                                                        1.0, 2.0]);
+    let mut dec = a.dec_lu(SparseOrdering::Natural).unwrap();
+
+    let b: CscMatrix<f64> = CscMatrix::from(&matrix![   1.0, 1.0;
+                                                        1.0, 1.0]);
+
+    assert_eq!(dec.refactor(&b).unwrap_err(), LinalgError::Singular);
+    assert_relative_eq!(dec.solve(&vector![3.0; 3.0]).unwrap(), vector![1.0; 1.0], epsilon = 1.0e-10);
+}
@@ -0,0 +1,10 @@
+mod add;
+mod choleskydec;
+mod cscmatrix;
+mod from;
+mod iterator;
+mod ludec;
+mod mul;
+mod ordering;
+mod sub;
+mod transpose;
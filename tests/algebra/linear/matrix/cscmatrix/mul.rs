@@ -0,0 +1,42 @@
+use mathru::algebra::linear::{
+    matrix::{CscMatrix, General},
+    vector::Vector,
+};
+
+#[test]
+fn mul_vector() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0;
+                                    4.0, 0.0, 5.0;
+                                    0.0, 0.0, 0.0];
+    let x: Vector<f64> = vector![1.0; 2.0; -1.0];
+
+    let a_sparse: CscMatrix<f64> = CscMatrix::from(&a);
+
+    assert_relative_eq!(&a_sparse * &x, &a * &x);
+}
+
+#[test]
+fn mul_general() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0];
+    let b: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.5;
+                                    3.0, 4.0];
+
+    assert_relative_eq!(&CscMatrix::from(&a) * &b, &a * &b);
+    assert_relative_eq!(&a * &CscMatrix::from(&b), &a * &b);
+}
+
+#[test]
+fn mul_csc() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0];
+    let b: General<f64> = matrix![  1.0, 0.0;
+                                    0.0, 0.5;
+                                    3.0, 0.0];
+
+    let prod: CscMatrix<f64> = &CscMatrix::from(&a) * &CscMatrix::from(&b);
+
+    assert_relative_eq!(General::from(prod), &a * &b);
+}
@@ -0,0 +1,107 @@
+use mathru::algebra::linear::matrix::{CscMatrix, General, SparseOrdering};
+
+fn assert_permutation(p: &[usize], n: usize) {
+    let mut sorted: Vec<usize> = p.to_vec();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..n).collect::<Vec<usize>>());
+}
+
+fn laplacian(k: usize) -> General<f64> {
+    let n: usize = k * k;
+    let mut a: General<f64> = General::zero(n, n);
+    for i in 0..k {
+        for j in 0..k {
+            let r: usize = i * k + j;
+            a[[r, r]] = 4.0;
+            if i + 1 < k {
+                a[[r, r + k]] = -1.0;
+                a[[r + k, r]] = -1.0;
+            }
+            if j + 1 < k {
+                a[[r, r + 1]] = -1.0;
+                a[[r + 1, r]] = -1.0;
+            }
+        }
+    }
+    a
+}
+
+#[test]
+fn natural() {
+    let a: CscMatrix<f64> = CscMatrix::from(&laplacian(3));
+
+    assert_eq!(SparseOrdering::Natural.permutation(&a), (0..9).collect::<Vec<usize>>());
+}
+
+#[test]
+fn permutation() {
+    let a: CscMatrix<f64> = CscMatrix::from(&laplacian(5));
+
+    for ordering in [SparseOrdering::Natural, SparseOrdering::ReverseCuthillMcKee, SparseOrdering::Amd, SparseOrdering::Colamd] {
+        assert_permutation(&ordering.permutation(&a), 25);
+    }
+}
+
+#[test]
+fn reverse_cuthill_mckee_bandwidth() {
+    // Path graph 0 - 4 - 1 - 3 - 2 with a large bandwidth in the natural order
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   2.0, 0.0, 0.0, 0.0, 1.0;
+                                                        0.0, 2.0, 0.0, 1.0, 1.0;
+                                                        0.0, 0.0, 2.0, 1.0, 0.0;
+                                                        0.0, 1.0, 1.0, 2.0, 0.0;
+                                                        1.0, 1.0, 0.0, 0.0, 2.0]);
+
+    let p: Vec<usize> = SparseOrdering::ReverseCuthillMcKee.permutation(&a);
+    assert_permutation(&p, 5);
+
+    let mut p_inv: Vec<usize> = vec![0; 5];
+    for (k, p_k) in p.iter().enumerate() {
+        p_inv[*p_k] = k;
+    }
+    let bandwidth: usize = a.iter().map(|(i, j, _)| p_inv[i].abs_diff(p_inv[j])).max().unwrap();
+    assert_eq!(bandwidth, 1);
+}
+
+#[test]
+fn reverse_cuthill_mckee_disconnected() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   1.0, 0.0, 1.0, 0.0;
+                                                        0.0, 1.0, 0.0, 0.0;
+                                                        1.0, 0.0, 1.0, 0.0;
+                                                        0.0, 0.0, 0.0, 1.0]);
+
+    assert_permutation(&SparseOrdering::ReverseCuthillMcKee.permutation(&a), 4);
+}
+
+#[test]
+fn amd_arrow() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   4.0, 1.0, 1.0, 1.0, 1.0;
+                                                        1.0, 4.0, 0.0, 0.0, 0.0;
+                                                        1.0, 0.0, 4.0, 0.0, 0.0;
+                                                        1.0, 0.0, 0.0, 4.0, 0.0;
+                                                        1.0, 0.0, 0.0, 0.0, 4.0]);
+
+    let p: Vec<usize> = SparseOrdering::Amd.permutation(&a);
+
+    assert_permutation(&p, 5);
+    // The dense node is eliminated when only one other node is left
+    assert!(!p[..3].contains(&0));
+}
+
+#[test]
+fn colamd_rectangular() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   1.0, 0.0, 2.0;
+                                                        0.0, 3.0, 0.0;
+                                                        4.0, 0.0, 5.0;
+                                                        0.0, 1.0, 1.0]);
+
+    assert_permutation(&SparseOrdering::Colamd.permutation(&a), 3);
+}
+
+#[test]
+#[should_panic]
+fn amd_rectangular() {
+    let a: CscMatrix<f64> = CscMatrix::from(&matrix![   1.0, 0.0, 2.0;
+                                                        0.0, 3.0, 0.0]);
+
+    let _ = SparseOrdering::Amd.permutation(&a);
+}
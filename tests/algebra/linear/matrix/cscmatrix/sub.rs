@@ -0,0 +1,13 @@
+use mathru::algebra::linear::matrix::{CscMatrix, General};
+
+#[test]
+fn sub() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0];
+    let b: General<f64> = matrix![  1.0, 1.0, -2.0;
+                                    0.0, 0.0, 7.0];
+
+    let diff: CscMatrix<f64> = CscMatrix::from(&a) - CscMatrix::from(&b);
+
+    assert_eq!(General::from(&diff), &a - &b);
+}
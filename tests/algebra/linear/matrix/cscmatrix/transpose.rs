@@ -0,0 +1,14 @@
+use mathru::algebra::linear::matrix::{CscMatrix, General, Transpose};
+
+#[test]
+fn transpose() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0;
+                                    4.0, 0.0, 0.0;
+                                    0.0, 0.0, 6.0];
+
+    let a_t: CscMatrix<f64> = CscMatrix::from(&a).transpose();
+
+    assert_eq!(a_t.dim(), (3, 4));
+    assert_eq!(General::from(a_t), a.transpose());
+}
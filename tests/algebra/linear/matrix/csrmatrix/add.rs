@@ -0,0 +1,24 @@
+use mathru::algebra::linear::matrix::{CsrMatrix, General};
+
+#[test]
+fn add() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0];
+    let b: General<f64> = matrix![  0.0, 1.0, -2.0;
+                                    0.0, 0.0, 7.0];
+
+    let sum: CsrMatrix<f64> = &CsrMatrix::from(&a) + &CsrMatrix::from(&b);
+
+    assert_eq!(General::from(&sum), &a + &b);
+    // cancelled entries are not stored
+    assert_eq!(sum.nnz(), 4);
+}
+
+#[test]
+fn add_owner() {
+    let a: General<f64> = matrix![1.0, 0.0; 0.0, -3.0];
+
+    let sum: CsrMatrix<f64> = CsrMatrix::from(&a) + CsrMatrix::one(2);
+
+    assert_eq!(General::from(sum), matrix![2.0, 0.0; 0.0, -2.0]);
+}
@@ -0,0 +1,50 @@
+use mathru::algebra::linear::matrix::CsrMatrix;
+
+#[test]
+fn new() {
+    let a: CsrMatrix<f64> = CsrMatrix::new(3, 3, vec![0, 1, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+
+    assert_eq!(a.dim(), (3, 3));
+    assert_eq!(a.nnz(), 3);
+    assert_eq!(a.get(0, 2), 1.0);
+    assert_eq!(a.get(1, 1), 0.0);
+    assert_eq!(a.get(2, 0), 2.0);
+    assert_eq!(a.get(2, 1), 3.0);
+}
+
+#[test]
+#[should_panic]
+fn new_unsorted_indices() {
+    let _: CsrMatrix<f64> = CsrMatrix::new(2, 2, vec![0, 2, 2], vec![1, 0], vec![1.0, 2.0]);
+}
+
+#[test]
+#[should_panic]
+fn new_index_out_of_bounds() {
+    let _: CsrMatrix<f64> = CsrMatrix::new(2, 2, vec![0, 1, 1], vec![2], vec![1.0]);
+}
+
+#[test]
+fn one() {
+    let a: CsrMatrix<f64> = CsrMatrix::one(3);
+
+    assert_eq!(a.diagonal(), vec![1.0, 1.0, 1.0]);
+    assert_eq!(a.nnz(), 3);
+}
+
+#[test]
+fn zero() {
+    let a: CsrMatrix<f64> = CsrMatrix::zero(2, 3);
+
+    assert_eq!(a.dim(), (2, 3));
+    assert_eq!(a.nnz(), 0);
+    assert_eq!(a.get(1, 2), 0.0);
+}
+
+#[test]
+fn row() {
+    let a: CsrMatrix<f64> = CsrMatrix::new(2, 3, vec![0, 2, 3], vec![0, 2, 1], vec![1.0, 2.0, 3.0]);
+
+    assert_eq!(a.row(0), (&[0usize, 2][..], &[1.0, 2.0][..]));
+    assert_eq!(a.row(1), (&[1usize][..], &[3.0][..]));
+}
@@ -0,0 +1,43 @@
+use mathru::algebra::linear::matrix::{CooMatrix, CscMatrix, CsrMatrix, General};
+
+#[test]
+fn from_general() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, 0.0, 3.0;
+                                    4.0, 0.0, 0.0];
+
+    let b: CsrMatrix<f64> = CsrMatrix::from(&a);
+
+    assert_eq!(b.row_offsets(), &[0, 2, 3, 4]);
+    assert_eq!(b.col_indices(), &[0, 2, 2, 0]);
+    assert_eq!(b.values(), &[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(General::from(&b), a);
+}
+
+#[test]
+fn from_coo() {
+    let mut coo: CooMatrix<f64> = CooMatrix::new(3, 2);
+    coo.push(2, 1, 1.0);
+    coo.push(0, 1, 2.0);
+    coo.push(2, 1, 3.0);
+    coo.push(0, 0, -1.0);
+
+    let a: CsrMatrix<f64> = CsrMatrix::from(&coo);
+
+    assert_eq!(a.row_offsets(), &[0, 2, 2, 3]);
+    assert_eq!(a.col_indices(), &[0, 1, 1]);
+    assert_eq!(a.values(), &[-1.0, 2.0, 4.0]);
+}
+
+#[test]
+fn from_csc() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0, 0.0;
+                                    0.0, 5.0, 3.0, 0.0;
+                                    4.0, 0.0, 0.0, 6.0];
+
+    let csc: CscMatrix<f64> = CscMatrix::from(&a);
+    let csr: CsrMatrix<f64> = CsrMatrix::from(&csc);
+
+    assert_eq!(csr, CsrMatrix::from(&a));
+    assert_eq!(General::from(csr), a);
+}
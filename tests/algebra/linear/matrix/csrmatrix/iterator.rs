@@ -0,0 +1,27 @@
+use mathru::algebra::linear::matrix::{CsrIterator, CsrMatrix, CsrRowIterator, General};
+
+#[test]
+fn iter() {
+    let a: CsrMatrix<f64> = CsrMatrix::from(&matrix![  0.0, 2.0;
+                                                        0.0, 0.0;
+                                                        3.0, 4.0]);
+    let mut iter: CsrIterator<f64> = a.iter();
+
+    assert_eq!(iter.next(), Some((0, 1, &2.0)));
+    assert_eq!(iter.next(), Some((2, 0, &3.0)));
+    assert_eq!(iter.next(), Some((2, 1, &4.0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn row_iter() {
+    let a: CsrMatrix<f64> = CsrMatrix::from(&matrix![  0.0, 2.0;
+                                                        0.0, 0.0;
+                                                        3.0, 4.0]);
+    let mut iter: CsrRowIterator<f64> = a.row_iter();
+
+    assert_eq!(iter.next(), Some((&[1usize][..], &[2.0][..])));
+    assert_eq!(iter.next(), Some((&[][..], &[][..])));
+    assert_eq!(iter.next(), Some((&[0usize, 1][..], &[3.0, 4.0][..])));
+    assert_eq!(iter.next(), None);
+}
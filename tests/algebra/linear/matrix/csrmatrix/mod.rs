@@ -0,0 +1,8 @@
+mod add;
+mod csrmatrix;
+mod from;
+mod iterator;
+mod mul;
+mod partial_eq;
+mod sub;
+mod transpose;
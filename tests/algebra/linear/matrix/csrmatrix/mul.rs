@@ -0,0 +1,65 @@
+use mathru::algebra::linear::{
+    matrix::{CsrMatrix, General},
+    vector::Vector,
+};
+
+#[test]
+fn mul_vector() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0;
+                                    4.0, 0.0, 5.0;
+                                    0.0, 0.0, 0.0];
+    let x: Vector<f64> = vector![1.0; 2.0; -1.0];
+
+    let a_sparse: CsrMatrix<f64> = CsrMatrix::from(&a);
+
+    assert_relative_eq!(&a_sparse * &x, &a * &x);
+    assert_relative_eq!(a_sparse * x, vector![-1.0; -6.0; -1.0; 0.0]);
+}
+
+#[test]
+fn mul_general() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0];
+    let b: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.5;
+                                    3.0, 4.0];
+
+    let a_sparse: CsrMatrix<f64> = CsrMatrix::from(&a);
+
+    assert_relative_eq!(&a_sparse * &b, &a * &b);
+}
+
+#[test]
+fn general_mul() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0];
+    let b: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.5;
+                                    3.0, 4.0];
+
+    let b_sparse: CsrMatrix<f64> = CsrMatrix::from(&b);
+
+    assert_relative_eq!(&a * &b_sparse, &a * &b);
+}
+
+#[test]
+fn mul_csr() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0];
+    let b: General<f64> = matrix![  1.0, 0.0;
+                                    0.0, 0.5;
+                                    3.0, 0.0];
+
+    let prod: CsrMatrix<f64> = &CsrMatrix::from(&a) * &CsrMatrix::from(&b);
+
+    assert_relative_eq!(General::from(prod), &a * &b);
+}
+
+#[test]
+fn mul_scalar() {
+    let a: CsrMatrix<f64> = CsrMatrix::from(&matrix![1.0, 0.0; 0.0, -3.0]);
+
+    assert_eq!(General::from(&a * &2.0), matrix![2.0, 0.0; 0.0, -6.0]);
+    assert_eq!(General::from(a * -1.0), matrix![-1.0, 0.0; 0.0, 3.0]);
+}
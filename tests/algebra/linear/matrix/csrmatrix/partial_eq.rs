@@ -0,0 +1,19 @@
+use mathru::algebra::linear::matrix::{CsrMatrix, General};
+
+#[test]
+fn eq() {
+    let a: CsrMatrix<f64> = CsrMatrix::from(&matrix![1.0, 0.0; 0.0, 2.0]);
+    let b: CsrMatrix<f64> = CsrMatrix::new(2, 2, vec![0, 2, 3], vec![0, 1, 1], vec![1.0, 0.0, 2.0]);
+
+    assert_eq!(a, b);
+}
+
+#[test]
+fn ne() {
+    let a: CsrMatrix<f64> = CsrMatrix::from(&matrix![1.0, 0.0; 0.0, 2.0]);
+    let b: CsrMatrix<f64> = CsrMatrix::from(&matrix![1.0, 0.0; 1.0, 2.0]);
+    let c: CsrMatrix<f64> = CsrMatrix::from(&matrix![1.0, 0.0, 0.0; 0.0, 2.0, 0.0]);
+
+    assert_ne!(a, b);
+    assert_ne!(a, c);
+}
@@ -0,0 +1,14 @@
+use mathru::algebra::linear::matrix::{CsrMatrix, General};
+
+#[test]
+fn sub() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, -3.0, 0.0];
+    let b: General<f64> = matrix![  1.0, 1.0, -2.0;
+                                    0.0, 0.0, 7.0];
+
+    let diff: CsrMatrix<f64> = &CsrMatrix::from(&a) - &CsrMatrix::from(&b);
+
+    assert_eq!(General::from(&diff), &a - &b);
+    assert_eq!(diff.nnz(), 4);
+}
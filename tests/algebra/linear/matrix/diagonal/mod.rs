@@ -7,6 +7,7 @@ mod det;
 mod eigendec;
 mod index;
 mod mul;
+mod norm;
 mod partial_eq;
 mod relative_eq;
 mod sub;
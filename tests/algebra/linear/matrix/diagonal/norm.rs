@@ -0,0 +1,13 @@
+use mathru::algebra::linear::matrix::{Diagonal, General, MatrixNorm};
+
+#[test]
+fn norm() {
+    let d: Diagonal<f64> = Diagonal::new(&[1.0, -4.0, 2.0]);
+    let a: General<f64> = d.clone().into();
+
+    assert_relative_eq!(d.norm_1(), 4.0);
+    assert_relative_eq!(d.norm_inf(), 4.0);
+    assert_relative_eq!(d.norm_2(), 4.0);
+    assert_relative_eq!(d.norm_frobenius(), 21.0f64.sqrt());
+    assert_relative_eq!(d.norm_2(), a.norm_2(), epsilon = 1.0e-10);
+}
@@ -118,3 +118,12 @@ fn add_complex_f64() {
 
     assert_relative_eq!(sum_ref, a + b);
 }
+
+#[test]
+#[should_panic]
+fn add_matrix_own_dimension_mismatch() {
+    let a: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    let b: General<f64> = matrix![1.0, 2.0];
+
+    let _ = a + b;
+}
@@ -0,0 +1,103 @@
+use mathru::algebra::linear::{
+    matrix::{CheckedAdd, CheckedMul, CheckedSolve, CheckedSub, General},
+    vector::Vector,
+    LinalgError,
+};
+
+#[test]
+fn checked_add() {
+    let a: General<f64> = matrix![  1.0, -2.0;
+                                    3.0, 0.5];
+    let b: General<f64> = matrix![  2.0, 1.0;
+                                    -1.0, 0.5];
+
+    assert_eq!(a.checked_add(&b), Ok(&a + &b));
+    assert_eq!(
+        a.checked_add(&matrix![1.0, 2.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn checked_sub() {
+    let a: General<f64> = matrix![  1.0, -2.0;
+                                    3.0, 0.5];
+    let b: General<f64> = matrix![  2.0, 1.0;
+                                    -1.0, 0.5];
+
+    assert_eq!(a.checked_sub(&b), Ok(&a - &b));
+    assert_eq!(
+        a.checked_sub(&General::zero(2, 3)),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn checked_mul_matrix() {
+    let a: General<f64> = matrix![  1.0, 2.0, 5.0;
+                                    3.0, 4.0, 6.0];
+    let b: General<f64> = matrix![  5.0, 8.0;
+                                    6.0, 9.0;
+                                    7.0, 10.0];
+
+    assert_eq!(a.checked_mul(&b), Ok(&a * &b));
+    assert_eq!(a.checked_mul(&a), Err(LinalgError::DimensionMismatch));
+}
+
+#[test]
+fn checked_mul_vector() {
+    let a: General<f64> = matrix![  1.0, 2.0, 5.0;
+                                    3.0, 4.0, 6.0];
+    let x: Vector<f64> = vector![1.0; -1.0; 2.0];
+
+    assert_eq!(a.checked_mul(&x), Ok(&a * &x));
+    assert_eq!(
+        a.checked_mul(&vector![1.0; 2.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+    assert_eq!(
+        a.checked_mul(&vector![1.0, 2.0, 3.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn checked_solve_vector() {
+    let a: General<f64> = matrix![  4.0, 1.0;
+                                    1.0, 3.0];
+    let x: Vector<f64> = vector![1.0; 2.0];
+    let b: Vector<f64> = &a * &x;
+
+    assert_relative_eq!(a.checked_solve(&b).unwrap(), x, epsilon = 1.0e-10);
+    assert_eq!(
+        a.checked_solve(&vector![1.0; 2.0; 3.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn checked_solve_not_square() {
+    let a: General<f64> = matrix![  1.0, 2.0, 5.0;
+                                    3.0, 4.0, 6.0];
+
+    assert_eq!(
+        a.checked_solve(&vector![1.0; 2.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn checked_solve_matrix() {
+    let a: General<f64> = matrix![  4.0, 1.0;
+                                    1.0, 3.0];
+    let b: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, 1.0, -1.0];
+
+    let x: General<f64> = a.checked_solve(&b).unwrap();
+
+    assert_relative_eq!(&a * &x, b, epsilon = 1.0e-10);
+    assert_eq!(
+        a.checked_solve(&General::one(3)),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
@@ -1,7 +1,8 @@
 use crate::mathru::algebra::abstr::Zero;
 use mathru::algebra::abstr::Complex;
-use mathru::algebra::linear::matrix::CholeskyDecomposition;
+use mathru::algebra::linear::matrix::{CholeskyDecomposition, ConjugateTranspose};
 use mathru::algebra::linear::matrix::{General, LowerTriangular, UpperTriangular};
+use mathru::algebra::linear::{vector::Vector, LinalgError};
 
 #[test]
 fn cholesky_f32() {
@@ -104,3 +105,165 @@ fn dec_empty() {
     let a: General<f64> = General::new(0, 0, vec![]);
     let _ = a.dec_cholesky();
 }
+
+#[test]
+fn cholesky_complex_hermitian() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(4.0, 0.0), Complex::new(1.0, 1.0), Complex::new(0.0, 0.0);
+                                            Complex::new(1.0, -1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 1.0);
+                                            Complex::new(0.0, 0.0), Complex::new(0.0, -1.0), Complex::new(2.0, 0.0)];
+
+    let l: General<Complex<f64>> = a.dec_cholesky().unwrap().l().into();
+
+    assert_relative_eq!(&l * &l.clone().conj_transpose(), a, epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
+
+#[test]
+fn cholesky_complex_not_positive_definite() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(1.0, 0.0), Complex::new(2.0, 1.0);
+                                            Complex::new(2.0, -1.0), Complex::new(1.0, 0.0)];
+
+    assert_eq!(a.dec_cholesky().err(), Some(LinalgError::NotPositiveDefinite));
+}
+
+#[test]
+fn cholesky_complex_diagonal_not_real() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(4.0, 1.0), Complex::new(0.0, 0.0);
+                                            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+
+    assert_eq!(a.dec_cholesky().err(), Some(LinalgError::NotPositiveDefinite));
+}
+
+#[test]
+fn cholesky_complex_non_square() {
+    let a: General<Complex<f64>> = matrix![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)];
+
+    assert_eq!(a.dec_cholesky().err(), Some(LinalgError::DimensionMismatch));
+}
+
+#[test]
+fn cholesky_not_positive_definite() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    2.0, 1.0];
+
+    assert_eq!(a.dec_cholesky().err(), Some(LinalgError::NotPositiveDefinite));
+}
+
+fn cholesky_product(l: LowerTriangular<f64>) -> General<f64> {
+    use crate::mathru::algebra::linear::matrix::Transpose;
+
+    let l: General<f64> = l.into();
+    &l * &l.clone().transpose()
+}
+
+#[test]
+fn update() {
+    let a: General<f64> = matrix![  4.0, 2.0, 1.0;
+                                    2.0, 5.0, 3.0;
+                                    1.0, 3.0, 6.0];
+    let x: Vector<f64> = vector![1.0; -2.0; 0.5];
+    let a_ref: General<f64> = matrix![  5.0, 0.0, 1.5;
+                                        0.0, 9.0, 2.0;
+                                        1.5, 2.0, 6.25];
+
+    let mut dec = a.dec_cholesky().unwrap();
+    dec.update(&x).unwrap();
+
+    assert_relative_eq!(cholesky_product(dec.l()), a_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn downdate() {
+    let a: General<f64> = matrix![  5.0, 0.0, 1.5;
+                                    0.0, 9.0, 2.0;
+                                    1.5, 2.0, 6.25];
+    let x: Vector<f64> = vector![1.0; -2.0; 0.5];
+    let a_ref: General<f64> = matrix![  4.0, 2.0, 1.0;
+                                        2.0, 5.0, 3.0;
+                                        1.0, 3.0, 6.0];
+
+    let mut dec = a.dec_cholesky().unwrap();
+    dec.downdate(&x).unwrap();
+
+    assert_relative_eq!(cholesky_product(dec.l()), a_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn downdate_not_positive_definite() {
+    let a: General<f64> = matrix![  4.0, 2.0;
+                                    2.0, 5.0];
+
+    let mut dec = a.dec_cholesky().unwrap();
+
+    assert_eq!(dec.downdate(&vector![2.0; 1.0]), Err(LinalgError::NotPositiveDefinite));
+    assert_eq!(dec.downdate(&vector![1.0; 1.0; 1.0]), Err(LinalgError::DimensionMismatch));
+    assert_relative_eq!(cholesky_product(dec.l()), a, epsilon = 1.0e-10);
+}
+
+#[test]
+fn insert_row_column() {
+    let a: General<f64> = matrix![  4.0, 2.0, 1.0;
+                                    2.0, 5.0, 3.0;
+                                    1.0, 3.0, 6.0];
+    let a_ref: General<f64> = matrix![  4.0, 1.0, 2.0, 1.0;
+                                        1.0, 7.0, -1.0, 2.0;
+                                        2.0, -1.0, 5.0, 3.0;
+                                        1.0, 2.0, 3.0, 6.0];
+
+    let mut dec = a.dec_cholesky().unwrap();
+    dec.insert_row_column(1, &vector![1.0; 7.0; -1.0; 2.0]).unwrap();
+
+    assert_relative_eq!(cholesky_product(dec.l()), a_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn insert_row_column_last() {
+    let a: General<f64> = matrix![  4.0, 2.0;
+                                    2.0, 5.0];
+    let a_ref: General<f64> = matrix![  4.0, 2.0, 1.0;
+                                        2.0, 5.0, 3.0;
+                                        1.0, 3.0, 6.0];
+
+    let mut dec = a.dec_cholesky().unwrap();
+    dec.insert_row_column(2, &vector![1.0; 3.0; 6.0]).unwrap();
+
+    assert_relative_eq!(cholesky_product(dec.l()), a_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn insert_row_column_not_positive_definite() {
+    let a: General<f64> = matrix![  4.0, 2.0;
+                                    2.0, 5.0];
+
+    let mut dec = a.dec_cholesky().unwrap();
+
+    assert_eq!(
+        dec.insert_row_column(0, &vector![1.0; 2.0; 2.0]),
+        Err(LinalgError::NotPositiveDefinite)
+    );
+    assert_eq!(
+        dec.insert_row_column(3, &vector![1.0; 2.0; 2.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn remove_row_column() {
+    let a: General<f64> = matrix![  4.0, 1.0, 2.0, 1.0;
+                                    1.0, 7.0, -1.0, 2.0;
+                                    2.0, -1.0, 5.0, 3.0;
+                                    1.0, 2.0, 3.0, 6.0];
+
+    for k in 0..4 {
+        let mut dec = a.dec_cholesky().unwrap();
+        dec.remove_row_column(k).unwrap();
+
+        let mut a_ref: General<f64> = General::zero(3, 3);
+        for i in (0..4).filter(|i| *i != k) {
+            for j in (0..4).filter(|j| *j != k) {
+                a_ref[[if i < k { i } else { i - 1 }, if j < k { j } else { j - 1 }]] = a[[i, j]];
+            }
+        }
+
+        assert_relative_eq!(cholesky_product(dec.l()), a_ref, epsilon = 1.0e-10);
+    }
+}
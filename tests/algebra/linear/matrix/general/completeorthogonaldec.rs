@@ -0,0 +1,85 @@
+use mathru::algebra::linear::{
+    matrix::{
+        CompleteOrthogonalDec, CompleteOrthogonalDecomposition, General, Solve, Transpose,
+        UpperTriangular,
+    },
+    vector::Vector,
+};
+
+fn reconstruct(q: &General<f64>, t: UpperTriangular<f64>, z: &General<f64>) -> General<f64> {
+    let (m, _): (usize, usize) = q.dim();
+    let (n, _): (usize, usize) = z.dim();
+    let mut t_0: General<f64> = General::zero(m, n);
+    if t.dim().0 > 0 {
+        t_0 = t_0.set_slice(&General::from(t), 0, 0);
+    }
+
+    &(q * &t_0) * &z.clone().transpose()
+}
+
+#[test]
+fn dec_cod_full_rank() {
+    let a: General<f64> = matrix![  6.0, 2.0, -1.0;
+                                    -3.0, 5.0, 3.0;
+                                    -2.0, 1.0, 3.0];
+
+    let cod: CompleteOrthogonalDec<f64> = a.dec_cod(1.0e-12).unwrap();
+    assert_eq!(cod.rank(), 3);
+    assert_eq!(cod.null_space().dim(), (3, 0));
+
+    let (q, t, z, p) = cod.qtzp();
+    assert_relative_eq!(&a * &p, reconstruct(&q, t, &z), epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_cod_rank_deficient() {
+    let a: General<f64> = matrix![  1.0, 0.0, 1.0, 2.0;
+                                    0.0, 1.0, 1.0, -1.0;
+                                    1.0, 1.0, 2.0, 1.0];
+
+    let cod: CompleteOrthogonalDec<f64> = a.dec_cod(1.0e-12).unwrap();
+    assert_eq!(cod.rank(), 2);
+
+    let (q, t, z, p) = cod.qtzp();
+    assert_eq!(t.dim(), (2, 2));
+    assert_relative_eq!(&z.clone().transpose() * &z, General::one(4), epsilon = 1.0e-10);
+    assert_relative_eq!(&a * &p, reconstruct(&q, t, &z), epsilon = 1.0e-10);
+}
+
+#[test]
+fn null_space() {
+    let a: General<f64> = matrix![  1.0, 0.0, 1.0, 2.0;
+                                    0.0, 1.0, 1.0, -1.0;
+                                    1.0, 1.0, 2.0, 1.0];
+
+    let n: General<f64> = a.dec_cod(1.0e-12).unwrap().null_space();
+
+    assert_eq!(n.dim(), (4, 2));
+    assert_relative_eq!(&n.clone().transpose() * &n, General::one(2), epsilon = 1.0e-10);
+    assert_relative_eq!(&a * &n, General::zero(3, 2), epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_minimum_norm() {
+    let a: General<f64> = matrix![  1.0, 0.0, 1.0;
+                                    0.0, 1.0, 1.0;
+                                    1.0, 1.0, 2.0;
+                                    1.0, -1.0, 0.0];
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0; -1.0];
+
+    let x: Vector<f64> = a.dec_cod(1.0e-12).unwrap().solve(&b).unwrap();
+    let x_ref: Vector<f64> = &a.pinv().unwrap() * &b;
+
+    assert_relative_eq!(x, x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_zero_matrix() {
+    let a: General<f64> = General::zero(2, 3);
+    let b: Vector<f64> = vector![1.0; 2.0];
+
+    let cod: CompleteOrthogonalDec<f64> = a.dec_cod(1.0e-12).unwrap();
+
+    assert_eq!(cod.rank(), 0);
+    assert_eq!(cod.solve(&b).unwrap(), Vector::zero(3));
+}
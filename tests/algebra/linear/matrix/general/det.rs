@@ -176,3 +176,11 @@ fn determinant_is_additive_randomized() {
         assert_abs_diff_eq!(det_b, det_a, epsilon = 1.0e-4);
     }
 }
+
+#[test]
+fn determinant_complex_entries() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(1.0, 1.0), Complex::new(2.0, 0.0);
+                                            Complex::new(3.0, 0.0), Complex::new(0.0, 1.0)];
+
+    assert_relative_eq!(a.det(), Complex::new(-7.0, 1.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
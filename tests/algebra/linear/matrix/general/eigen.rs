@@ -1,6 +1,8 @@
+use mathru::algebra::abstr::Complex;
 use mathru::algebra::linear::matrix::Diagonal;
-use mathru::algebra::linear::matrix::EigenDecomposition;
+use mathru::algebra::linear::matrix::{ComplexEigenDec, ComplexEigenDecomposition, ConjugateTranspose, EigenDecomposition};
 use mathru::algebra::linear::matrix::General;
+use mathru::algebra::linear::LinalgError;
 
 #[test]
 fn eigen_f64_1() {
@@ -271,3 +273,112 @@ fn dec_empty() {
     let a: General<f64> = General::new(0, 0, vec![]);
     let _ = a.dec_eigen();
 }
+
+#[test]
+fn eigen_complex_hermitian() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(2.0, 0.0), Complex::new(1.0, 1.0);
+                                            Complex::new(1.0, -1.0), Complex::new(3.0, 0.0)];
+
+    let (values, vectors): (Diagonal<Complex<f64>>, General<Complex<f64>>) = a.dec_eigen().unwrap().pair();
+
+    let mut re: Vec<f64> = (0..2).map(|i| values[[i, i]].re).collect();
+    re.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_relative_eq!(re[0], 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(re[1], 4.0, epsilon = 1.0e-10);
+    assert_relative_eq!(&a * &vectors, &vectors * &General::from(values), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
+
+#[test]
+fn eigen_complex_entries() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(2.0, 1.0), Complex::new(1.0, -2.0), Complex::new(0.5, 0.0), Complex::new(0.0, 0.0);
+                                            Complex::new(-1.0, 1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 2.0), Complex::new(1.0, 0.0);
+                                            Complex::new(1.0, 0.0), Complex::new(0.0, -1.0), Complex::new(4.0, -1.0), Complex::new(0.0, 3.0);
+                                            Complex::new(0.0, 2.0), Complex::new(1.0, 1.0), Complex::new(-2.0, 0.0), Complex::new(1.0, 0.0)];
+
+    let (values, vectors): (Diagonal<Complex<f64>>, General<Complex<f64>>) = a.dec_eigen().unwrap().pair();
+
+    assert_relative_eq!(&a * &vectors, &vectors * &General::from(values.clone()), epsilon = Complex::new(1.0e-10, 1.0e-10));
+
+    // The sum of the eigenvalues is the trace
+    let sum: Complex<f64> = (0..4).fold(Complex::new(0.0, 0.0), |acc, i| acc + values[[i, i]]);
+    assert_relative_eq!(sum, Complex::new(10.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
+
+#[test]
+fn eigen_complex_f32() {
+    let a: General<Complex<f32>> = matrix![ Complex::new(1.0, 0.0), Complex::new(0.0, -1.0);
+                                            Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)];
+
+    let (values, vectors): (Diagonal<Complex<f32>>, General<Complex<f32>>) = a.dec_eigen().unwrap().pair();
+
+    assert_relative_eq!(&a * &vectors, &vectors * &General::from(values), epsilon = Complex::new(1.0e-5, 1.0e-5));
+}
+
+#[test]
+fn dec_eigen_complex_values() {
+    let a: General<f64> = matrix![  1.0, -2.0, 0.0;
+                                    2.0, 1.0, 0.0;
+                                    0.0, 0.0, 3.0];
+
+    let mut values: Vec<Complex<f64>> = a.dec_eigen_complex().unwrap().values();
+    values.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+
+    assert_relative_eq!(values[0], Complex::new(1.0, -2.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(values[1], Complex::new(3.0, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(values[2], Complex::new(1.0, 2.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
+
+#[test]
+fn dec_eigen_complex_vectors() {
+    let a: General<f64> = matrix![  4.0, -5.0, 0.0, 3.0;
+                                    0.0, 4.0, -3.0, -5.0;
+                                    5.0, -3.0, 4.0, 0.0;
+                                    3.0, 0.0, 5.0, 4.0];
+
+    let dec: ComplexEigenDec<f64> = a.dec_eigen_complex().unwrap();
+    let values: Vec<Complex<f64>> = dec.clone().values();
+    let left: General<Complex<f64>> = dec.clone().left_vectors();
+    let right: General<Complex<f64>> = dec.right_vectors();
+
+    let a: General<Complex<f64>> = General::from(&a);
+    let d: General<Complex<f64>> = General::from(Diagonal::new(&values));
+
+    assert_relative_eq!(&a * &right, &right * &d, epsilon = Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(&left.clone().conj_transpose() * &a, &d * &left.clone().conj_transpose(), epsilon = Complex::new(1.0e-10, 1.0e-10));
+
+    for j in 0..4 {
+        let norm_right: f64 = (0..4).map(|i| right[[i, j]].re.powi(2) + right[[i, j]].im.powi(2)).sum();
+        let norm_left: f64 = (0..4).map(|i| left[[i, j]].re.powi(2) + left[[i, j]].im.powi(2)).sum();
+        assert_relative_eq!(norm_right, 1.0, epsilon = 1.0e-10);
+        assert_relative_eq!(norm_left, 1.0, epsilon = 1.0e-10);
+    }
+
+    // the eigenvalues of a real matrix come in conjugate pairs
+    for lambda in values.iter() {
+        assert!(values
+            .iter()
+            .any(|mu| (mu.re - lambda.re).abs() < 1.0e-10 && (mu.im + lambda.im).abs() < 1.0e-10));
+    }
+}
+
+#[test]
+fn dec_eigen_complex_real_spectrum() {
+    let a: General<f64> = matrix![  2.0, 1.0;
+                                    1.0, 2.0];
+
+    let (values, vectors): (Vec<Complex<f64>>, General<Complex<f64>>) = a.dec_eigen_complex().unwrap().pair();
+
+    for lambda in values.iter() {
+        assert_relative_eq!(lambda.im, 0.0, epsilon = 1.0e-10);
+    }
+    let a: General<Complex<f64>> = General::from(&a);
+    assert_relative_eq!(&a * &vectors, &vectors * &General::from(Diagonal::new(&values)), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
+
+#[test]
+fn dec_eigen_real_with_complex_spectrum() {
+    let a: General<f64> = matrix![  0.0, -1.0;
+                                    1.0, 0.0];
+
+    assert_eq!(a.dec_eigen().err(), Some(LinalgError::ComplexEigenvalues));
+}
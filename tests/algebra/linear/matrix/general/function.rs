@@ -0,0 +1,238 @@
+use mathru::algebra::abstr::Complex;
+use mathru::algebra::linear::matrix::General;
+use mathru::algebra::linear::LinalgError;
+use mathru::elementary::{Exponential, Trigonometry};
+
+#[test]
+fn exp_zero() {
+    let a: General<f64> = General::zero(3, 3);
+
+    assert_relative_eq!(a.exp(), General::one(3));
+}
+
+#[test]
+fn exp_infinite_entry() {
+    let a: General<f32> = matrix![  1.0, f32::INFINITY;
+                                    0.0, 1.0];
+
+    assert!(a.exp().iter().all(|e| e.is_nan()));
+}
+
+#[test]
+fn exp_norm_overflow() {
+    let a: General<f32> = matrix![  3.0e38, 0.0;
+                                    3.0e38, 1.0];
+
+    assert!(a.exp().iter().all(|e| e.is_nan()));
+}
+
+#[test]
+fn exp_nan_entry() {
+    let a: General<f64> = matrix![  f64::NAN, 0.0;
+                                    0.0, 1.0];
+
+    assert!(a.exp().iter().all(|e| e.is_nan()));
+}
+
+#[test]
+fn exp_diagonal() {
+    let a: General<f64> = matrix![  1.0, 0.0, 0.0;
+                                    0.0, -2.0, 0.0;
+                                    0.0, 0.0, 0.5];
+
+    let exp_ref: General<f64> = matrix![1.0f64.exp(), 0.0, 0.0;
+                                        0.0, (-2.0f64).exp(), 0.0;
+                                        0.0, 0.0, 0.5f64.exp()];
+
+    assert_relative_eq!(a.exp(), exp_ref, epsilon = 1.0e-12, max_relative = 1.0e-12);
+}
+
+#[test]
+fn exp_nilpotent() {
+    let a: General<f64> = matrix![  0.0, 1.0, 0.0;
+                                    0.0, 0.0, 1.0;
+                                    0.0, 0.0, 0.0];
+
+    let exp_ref: General<f64> = matrix![1.0, 1.0, 0.5;
+                                        0.0, 1.0, 1.0;
+                                        0.0, 0.0, 1.0];
+
+    assert_relative_eq!(a.exp(), exp_ref, epsilon = 1.0e-12);
+}
+
+#[test]
+fn exp_rotation_f64() {
+    for t in [0.01, 0.3, 1.5, 10.0] {
+        let a: General<f64> = matrix![  0.0, -t;
+                                        t, 0.0];
+
+        let exp_ref: General<f64> = matrix![t.cos(), -t.sin();
+                                            t.sin(), t.cos()];
+
+        assert_relative_eq!(a.exp(), exp_ref, epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn exp_rotation_f32() {
+    let t: f32 = 2.0;
+    let a: General<f32> = matrix![  0.0, -t;
+                                    t, 0.0];
+
+    let exp_ref: General<f32> = matrix![t.cos(), -t.sin();
+                                        t.sin(), t.cos()];
+
+    assert_relative_eq!(a.exp(), exp_ref, epsilon = 1.0e-5);
+}
+
+#[test]
+fn exp_markov_generator() {
+    // generator of a two state Markov chain
+    let (p, q): (f64, f64) = (2.0, 3.0);
+    let a: General<f64> = matrix![  -p, p;
+                                    q, -q];
+
+    let t: f64 = 1.5;
+    let e: f64 = (-(p + q) * t).exp();
+    let exp_ref: General<f64> = &matrix![q + p * e, p - p * e;
+                                         q - q * e, p + q * e] * &(1.0 / (p + q));
+
+    assert_relative_eq!((&a * &t).exp(), exp_ref, epsilon = 1.0e-12);
+}
+
+#[test]
+fn logm_diagonal() {
+    let a: General<f64> = matrix![  2.0, 0.0;
+                                    0.0, 0.5];
+
+    let log_ref: General<f64> = matrix![2.0f64.ln(), 0.0;
+                                        0.0, 0.5f64.ln()];
+
+    assert_relative_eq!(a.logm().unwrap(), log_ref, epsilon = 1.0e-12);
+}
+
+#[test]
+fn logm_rotation_f64() {
+    let t: f64 = 0.8;
+    let a: General<f64> = matrix![  t.cos(), -t.sin();
+                                    t.sin(), t.cos()];
+
+    let log_ref: General<f64> = matrix![0.0, -t;
+                                        t, 0.0];
+
+    assert_relative_eq!(a.logm().unwrap(), log_ref, epsilon = 1.0e-12);
+}
+
+#[test]
+fn logm_rotation_f32() {
+    let t: f32 = 0.8;
+    let a: General<f32> = matrix![  t.cos(), -t.sin();
+                                    t.sin(), t.cos()];
+
+    let log_ref: General<f32> = matrix![0.0, -t;
+                                        t, 0.0];
+
+    assert_relative_eq!(a.logm().unwrap(), log_ref, epsilon = 1.0e-5);
+}
+
+#[test]
+fn logm_exp() {
+    let a: General<f64> = matrix![  0.5, 0.2, -0.1;
+                                    0.1, -0.3, 0.4;
+                                    0.0, 0.2, 0.1];
+
+    assert_relative_eq!(a.exp().logm().unwrap(), a, epsilon = 1.0e-12);
+}
+
+#[test]
+fn logm_negative_eigenvalue() {
+    let a: General<f64> = matrix![  -1.0, 0.0;
+                                    0.0, 2.0];
+
+    assert_eq!(a.logm().err(), Some(LinalgError::OutOfDomain));
+}
+
+#[test]
+fn sqrtm_triangular() {
+    let a: General<f64> = matrix![  4.0, 1.0;
+                                    0.0, 9.0];
+
+    let sqrt_ref: General<f64> = matrix![   2.0, 0.2;
+                                            0.0, 3.0];
+
+    assert_relative_eq!(a.sqrtm().unwrap(), sqrt_ref, epsilon = 1.0e-12);
+}
+
+#[test]
+fn sqrtm_rotation() {
+    // principal square root of the rotation by pi/2 is the rotation by pi/4
+    let a: General<f64> = matrix![  0.0, -1.0;
+                                    1.0, 0.0];
+
+    let c: f64 = 0.5f64.sqrt();
+    let sqrt_ref: General<f64> = matrix![   c, -c;
+                                            c, c];
+
+    assert_relative_eq!(a.sqrtm().unwrap(), sqrt_ref, epsilon = 1.0e-12);
+}
+
+#[test]
+fn sqrtm_f64() {
+    let a: General<f64> = matrix![  5.0, 2.0, 1.0;
+                                    2.0, 6.0, -1.0;
+                                    0.0, 1.0, 4.0];
+
+    let x: General<f64> = a.sqrtm().unwrap();
+
+    assert_relative_eq!(&x * &x, a, epsilon = 1.0e-12);
+}
+
+#[test]
+fn sqrtm_f32() {
+    let a: General<f32> = matrix![  5.0, 2.0, 1.0;
+                                    2.0, 6.0, -1.0;
+                                    0.0, 1.0, 4.0];
+
+    let x: General<f32> = a.sqrtm().unwrap();
+
+    assert_relative_eq!(&x * &x, a, epsilon = 1.0e-4);
+}
+
+#[test]
+fn sqrtm_negative_eigenvalue() {
+    let a: General<f64> = matrix![  -4.0, 1.0;
+                                    0.0, 1.0];
+
+    assert_eq!(a.sqrtm().err(), Some(LinalgError::OutOfDomain));
+}
+
+#[test]
+fn funm_exp() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.5];
+
+    let f_a: General<f64> = a.funm(|z: Complex<f64>| z.exp()).unwrap();
+
+    assert_relative_eq!(f_a, a.exp(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn funm_cos_f64() {
+    let t: f64 = 0.5;
+    let a: General<f64> = matrix![  0.0, -t;
+                                    t, 0.0];
+
+    let f_a: General<f64> = a.funm(|z: Complex<f64>| z.cos()).unwrap();
+
+    assert_relative_eq!(f_a, &General::one(2) * &t.cosh(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn funm_sin_f32() {
+    let a: General<f32> = matrix![  1.0, 0.0;
+                                    0.0, 2.0];
+
+    let f_a: General<f32> = a.funm(|z: Complex<f32>| z.sin()).unwrap();
+
+    assert_relative_eq!(f_a, matrix![1.0f32.sin(), 0.0; 0.0, 2.0f32.sin()], epsilon = 1.0e-6);
+}
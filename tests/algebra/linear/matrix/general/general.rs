@@ -81,6 +81,15 @@ fn ones() {
     assert_eq!(m_ones, ones_ref);
 }
 
+#[test]
+fn new_random() {
+    let a: General<f64> = General::new_random(4, 3);
+
+    assert_eq!(a.dim(), (4, 3));
+    assert!(a.iter().all(|a_ij| (0.0..1.0).contains(a_ij)));
+    assert!(a.iter().any(|a_ij| *a_ij != a[[0, 0]]));
+}
+
 #[test]
 fn get_column() {
     let a: General<f32> = matrix![4.0, 1.0, -3.0, 2.0; 1.0, 2.0, 0.0, 1.0; -2.0, 0.0, 3.0, -2.0; 2.0, 1.0, -2.0,
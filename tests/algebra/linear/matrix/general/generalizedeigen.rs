@@ -0,0 +1,126 @@
+use mathru::algebra::abstr::Complex;
+use mathru::algebra::linear::matrix::{
+    General, GeneralizedEigenDecomposition, SymmetricGeneralizedEigenDecomposition, Transpose,
+};
+use mathru::algebra::linear::LinalgError;
+
+fn residual(a: &General<f64>, b: &General<f64>, alpha: Complex<f64>, beta: f64, x: &General<Complex<f64>>) -> General<Complex<f64>> {
+    let a_c: General<Complex<f64>> = General::from(a);
+    let b_c: General<Complex<f64>> = General::from(b);
+
+    &(&a_c * x) * &Complex::new(beta, 0.0) - &(&b_c * x) * &alpha
+}
+
+#[test]
+fn dec_eigen_generalized_nonsymmetric() {
+    let a: General<f64> = matrix![  1.0, 2.0, 0.5;
+                                    3.0, 4.0, -1.0;
+                                    0.0, 2.0, 5.0];
+    let b: General<f64> = matrix![  2.0, 0.0, 1.0;
+                                    1.0, 1.0, 0.0;
+                                    0.0, 3.0, 1.0];
+
+    let dec = a.dec_eigen_generalized(&b).unwrap();
+    let alpha: Vec<Complex<f64>> = dec.clone().alpha();
+    let beta: Vec<f64> = dec.clone().beta();
+    let x: General<Complex<f64>> = dec.vectors();
+
+    for k in 0..3 {
+        let x_k: General<Complex<f64>> = x.get_column(k).into();
+        assert_relative_eq!(
+            residual(&a, &b, alpha[k], beta[k], &x_k),
+            General::zero(3, 1),
+            epsilon = Complex::new(1.0e-10, 1.0e-10)
+        );
+    }
+}
+
+#[test]
+fn dec_eigen_generalized_complex_pair() {
+    let a: General<f64> = matrix![  0.0, 1.0;
+                                    -1.0, 0.0];
+    let b: General<f64> = matrix![  1.0, 1.0;
+                                    0.0, 2.0];
+
+    let mut values: Vec<Complex<f64>> = a.dec_eigen_generalized(&b).unwrap().values();
+    values.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+
+    // det(A - lambda B) = 2 lambda^2 - lambda + 1
+    let im: f64 = 7.0f64.sqrt() / 4.0;
+    assert_relative_eq!(values[0], Complex::new(0.25, -im), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(values[1], Complex::new(0.25, im), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
+
+#[test]
+fn dec_eigen_generalized_symmetric_positive_definite() {
+    let a: General<f64> = matrix![  2.0, -1.0, 0.0;
+                                    -1.0, 2.0, -1.0;
+                                    0.0, -1.0, 2.0];
+    let b: General<f64> = matrix![  4.0, 1.0, 0.0;
+                                    1.0, 3.0, 1.0;
+                                    0.0, 1.0, 2.0];
+
+    let dec = a.dec_eigen_generalized(&b).unwrap();
+    let alpha: Vec<Complex<f64>> = dec.clone().alpha();
+    let beta: Vec<f64> = dec.clone().beta();
+    let x: General<Complex<f64>> = dec.vectors();
+
+    for k in 0..3 {
+        assert_relative_eq!(alpha[k].im, 0.0, epsilon = 1.0e-10);
+        let x_k: General<Complex<f64>> = x.get_column(k).into();
+        assert_relative_eq!(
+            residual(&a, &b, alpha[k], beta[k], &x_k),
+            General::zero(3, 1),
+            epsilon = Complex::new(1.0e-10, 1.0e-10)
+        );
+    }
+}
+
+#[test]
+fn dec_eigen_generalized_singular_b() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    3.0, 4.0];
+    let b: General<f64> = matrix![  1.0, 0.0;
+                                    0.0, 0.0];
+
+    let dec = a.dec_eigen_generalized(&b).unwrap();
+    let beta: Vec<f64> = dec.clone().beta();
+    let values: Vec<Complex<f64>> = dec.values();
+
+    // det(A - lambda B) = 4 - 4 lambda - 6, one finite and one infinite eigenvalue
+    let infinite: usize = if beta[0].abs() < 1.0e-10 { 0 } else { 1 };
+    assert_relative_eq!(beta[infinite], 0.0, epsilon = 1.0e-10);
+    assert!(values[infinite].re.is_infinite());
+    assert_relative_eq!(values[1 - infinite], Complex::new(-0.5, 0.0), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
+
+#[test]
+fn dec_symmetric_eigen_generalized() {
+    let a: General<f64> = matrix![  4.0, 1.0, -2.0;
+                                    1.0, 2.0, 0.0;
+                                    -2.0, 0.0, 3.0];
+    let b: General<f64> = matrix![  4.0, 1.0, 0.0;
+                                    1.0, 3.0, 1.0;
+                                    0.0, 1.0, 2.0];
+
+    let (values, x): (Vec<f64>, General<f64>) = a.dec_symmetric_eigen_generalized(&b).unwrap().pair();
+
+    for k in 1..3 {
+        assert!(values[k - 1] <= values[k]);
+    }
+    for (k, lambda) in values.iter().enumerate() {
+        let x_k: General<f64> = x.get_column(k).into();
+        assert_relative_eq!(&a * &x_k, &(&b * &x_k) * lambda, epsilon = 1.0e-10);
+    }
+    assert_relative_eq!(&(&x.clone().transpose() * &b) * &x, General::one(3), epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_symmetric_eigen_generalized_not_positive_definite() {
+    let a: General<f64> = matrix![  2.0, 1.0;
+                                    1.0, 2.0];
+    let b: General<f64> = matrix![  1.0, 2.0;
+                                    2.0, 1.0];
+
+    assert_eq!(a.dec_symmetric_eigen_generalized(&b).err(), Some(LinalgError::NotPositiveDefinite));
+}
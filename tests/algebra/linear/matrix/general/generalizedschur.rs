@@ -0,0 +1,131 @@
+use mathru::algebra::abstr::Complex;
+use mathru::algebra::linear::matrix::{
+    ConjugateTranspose, General, GeneralizedSchurDecomposition, UpperTriangular,
+};
+
+#[test]
+fn dec_generalized_schur() {
+    let a: General<f64> = matrix![  1.0, 2.0, 0.5, -1.0;
+                                    3.0, 4.0, -1.0, 2.0;
+                                    0.0, 2.0, 5.0, 1.0;
+                                    1.0, -3.0, 2.0, 0.0];
+    let b: General<f64> = matrix![  2.0, 0.0, 1.0, 0.0;
+                                    1.0, 1.0, 0.0, 3.0;
+                                    0.0, 3.0, 1.0, 1.0;
+                                    -1.0, 0.0, 2.0, 1.0];
+    let a: General<Complex<f64>> = General::from(&a);
+    let b: General<Complex<f64>> = General::from(&b);
+
+    let (q, z, s, t): (General<Complex<f64>>, General<Complex<f64>>, UpperTriangular<Complex<f64>>, UpperTriangular<Complex<f64>>) =
+        a.dec_generalized_schur(&b).unwrap().qzst();
+    let s: General<Complex<f64>> = s.into();
+    let t: General<Complex<f64>> = t.into();
+    let z_h: General<Complex<f64>> = z.clone().conj_transpose();
+
+    let eps: Complex<f64> = Complex::new(1.0e-10, 1.0e-10);
+    assert_relative_eq!(&(&q * &s) * &z_h, a, epsilon = eps);
+    assert_relative_eq!(&(&q * &t) * &z_h, b, epsilon = eps);
+    assert_relative_eq!(&q.clone().conj_transpose() * &q, General::one(4), epsilon = eps);
+    assert_relative_eq!(&z_h * &z, General::one(4), epsilon = eps);
+}
+
+#[test]
+fn dec_generalized_schur_singular_b() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 10.0];
+    let b: General<f64> = matrix![  1.0, 0.0, 0.0;
+                                    0.0, 0.0, 0.0;
+                                    0.0, 0.0, 1.0];
+    let a: General<Complex<f64>> = General::from(&a);
+    let b: General<Complex<f64>> = General::from(&b);
+
+    let (q, z, s, t) = a.dec_generalized_schur(&b).unwrap().qzst();
+    let s: General<Complex<f64>> = s.into();
+    let t: General<Complex<f64>> = t.into();
+    let z_h: General<Complex<f64>> = z.conj_transpose();
+
+    let eps: Complex<f64> = Complex::new(1.0e-10, 1.0e-10);
+    assert_relative_eq!(&(&q * &s) * &z_h, a, epsilon = eps);
+    assert_relative_eq!(&(&q * &t) * &z_h, b, epsilon = eps);
+}
+
+fn eigenvalues(s: &General<Complex<f64>>, t: &General<Complex<f64>>) -> Vec<Complex<f64>> {
+    (0..s.nrows()).map(|i| s[[i, i]] / t[[i, i]]).collect()
+}
+
+#[test]
+fn reorder() {
+    let a: General<f64> = matrix![  1.0, 2.0, 0.5, -1.0;
+                                    3.0, 4.0, -1.0, 2.0;
+                                    0.0, 2.0, 5.0, 1.0;
+                                    1.0, -3.0, 2.0, 0.0];
+    let b: General<f64> = matrix![  2.0, 0.0, 1.0, 0.0;
+                                    1.0, 1.0, 0.0, 3.0;
+                                    0.0, 3.0, 1.0, 1.0;
+                                    -1.0, 0.0, 2.0, 1.0];
+    let a: General<Complex<f64>> = General::from(&a);
+    let b: General<Complex<f64>> = General::from(&b);
+
+    let schur = a.dec_generalized_schur(&b).unwrap();
+    let s: General<Complex<f64>> = schur.clone().s().into();
+    let t: General<Complex<f64>> = schur.clone().t().into();
+    let lambda: Vec<Complex<f64>> = eigenvalues(&s, &t);
+
+    let (q, z, s, t) = schur.reorder(&[false, true, false, true]).qzst();
+    let s: General<Complex<f64>> = s.into();
+    let t: General<Complex<f64>> = t.into();
+    let z_h: General<Complex<f64>> = z.clone().conj_transpose();
+
+    let eps: Complex<f64> = Complex::new(1.0e-10, 1.0e-10);
+    let lambda_reordered: Vec<Complex<f64>> = eigenvalues(&s, &t);
+    assert_relative_eq!(lambda_reordered[0], lambda[1], epsilon = eps);
+    assert_relative_eq!(lambda_reordered[1], lambda[3], epsilon = eps);
+    assert_relative_eq!(lambda_reordered[2], lambda[0], epsilon = eps);
+    assert_relative_eq!(lambda_reordered[3], lambda[2], epsilon = eps);
+    assert_relative_eq!(&(&q * &s) * &z_h, a, epsilon = eps);
+    assert_relative_eq!(&(&q * &t) * &z_h, b, epsilon = eps);
+    assert_relative_eq!(&q.clone().conj_transpose() * &q, General::one(4), epsilon = eps);
+    assert_relative_eq!(&z_h * &z, General::one(4), epsilon = eps);
+    for j in 0..4 {
+        for i in j + 1..4 {
+            assert_eq!(s[[i, j]], Complex::new(0.0, 0.0));
+            assert_eq!(t[[i, j]], Complex::new(0.0, 0.0));
+        }
+    }
+}
+
+#[test]
+fn reorder_infinite_eigenvalue_last() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 10.0];
+    let b: General<f64> = matrix![  1.0, 0.0, 0.0;
+                                    0.0, 0.0, 0.0;
+                                    0.0, 0.0, 1.0];
+    let a: General<Complex<f64>> = General::from(&a);
+    let b: General<Complex<f64>> = General::from(&b);
+
+    let schur = a.dec_generalized_schur(&b).unwrap();
+    let t: General<Complex<f64>> = schur.clone().t().into();
+    let select: Vec<bool> = (0..3).map(|i| t[[i, i]].re.hypot(t[[i, i]].im) > 1.0e-10).collect();
+
+    let (q, z, s, t) = schur.reorder(&select).qzst();
+    let s: General<Complex<f64>> = s.into();
+    let t: General<Complex<f64>> = t.into();
+    let z_h: General<Complex<f64>> = z.conj_transpose();
+
+    let eps: Complex<f64> = Complex::new(1.0e-10, 1.0e-10);
+    assert_relative_eq!(t[[2, 2]], Complex::new(0.0, 0.0), epsilon = eps);
+    assert_relative_eq!(&(&q * &s) * &z_h, a, epsilon = eps);
+    assert_relative_eq!(&(&q * &t) * &z_h, b, epsilon = eps);
+}
+
+#[test]
+#[should_panic]
+fn reorder_select_dimension_mismatch() {
+    let a: General<Complex<f64>> = General::one(3);
+    let b: General<Complex<f64>> = General::one(3);
+
+    let _ = a.dec_generalized_schur(&b).unwrap().reorder(&[true; 2]);
+}
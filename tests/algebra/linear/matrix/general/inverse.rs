@@ -95,3 +95,14 @@ fn inv_2() {
 
     assert_relative_eq!(a_inv, a_inv_ref, epsilon = 1.0e-10);
 }
+
+#[test]
+fn inv_complex_entries() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(2.0, 1.0), Complex::new(1.0, -2.0), Complex::new(0.5, 0.0);
+                                            Complex::new(-1.0, 1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 2.0);
+                                            Complex::new(1.0, 0.0), Complex::new(0.0, -1.0), Complex::new(4.0, -1.0)];
+
+    let a_inv: General<Complex<f64>> = a.inv().unwrap();
+
+    assert_relative_eq!(&a * &a_inv, General::one(3), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
@@ -1,6 +1,6 @@
 use mathru::algebra::linear::matrix::{
-    MatrixColumnIntoIterator, MatrixIntoIterator, MatrixIterator, MatrixIteratorMut,
-    MatrixRowIntoIterator,
+    MatrixColumnIntoIterator, MatrixColumnIterator, MatrixColumnIteratorMut, MatrixIntoIterator,
+    MatrixIterator, MatrixIteratorMut, MatrixRowIntoIterator, MatrixRowIterator, MatrixViewMut,
 };
 use mathru::algebra::linear::{matrix::General, vector::Vector};
 use mathru::{matrix, vector};
@@ -55,3 +55,130 @@ fn row_iter() {
     assert_eq!(iter.next(), Some(vector![3.0f64, 4.0]));
     assert_eq!(iter.next(), None);
 }
+
+#[test]
+fn row_iter_borrowed() {
+    let m: General<f64> = matrix![1.0f64, 2.0; 3.0, 4.0; 5.0, 6.0];
+    let mut iter: MatrixRowIterator<f64> = m.row_iter();
+
+    assert_eq!(iter.len(), 3);
+    assert_eq!(General::from(iter.next().unwrap()), matrix![1.0, 2.0]);
+    assert_eq!(General::from(iter.next_back().unwrap()), matrix![5.0, 6.0]);
+    assert_eq!(General::from(iter.next().unwrap()), matrix![3.0, 4.0]);
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+}
+
+#[test]
+fn column_iter_borrowed() {
+    let m: General<f64> = matrix![1.0f64, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+    let columns: Vec<General<f64>> = m.column_iter().map(General::from).collect();
+
+    assert_eq!(
+        columns,
+        vec![matrix![1.0; 4.0], matrix![2.0; 5.0], matrix![3.0; 6.0]]
+    );
+}
+
+#[test]
+fn column_iter_enumerate_rev() {
+    let m: General<f64> = matrix![1.0f64, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+    let indices: Vec<(usize, f64)> = m
+        .column_iter()
+        .enumerate()
+        .rev()
+        .map(|(j, c)| (j, c[[1, 0]]))
+        .collect();
+
+    assert_eq!(indices, vec![(2, 6.0), (1, 5.0), (0, 4.0)]);
+}
+
+#[test]
+fn column_iter_nth() {
+    let m: General<f64> = matrix![1.0f64, 2.0, 3.0; 4.0, 5.0, 6.0];
+    let mut iter: MatrixColumnIterator<f64> = m.column_iter();
+
+    assert_eq!(iter.nth(2).unwrap()[[0, 0]], 3.0);
+    assert!(iter.nth(0).is_none());
+}
+
+#[test]
+fn row_iter_mut() {
+    let mut m: General<f64> = matrix![1.0f64, 2.0; 3.0, 4.0; 5.0, 6.0];
+
+    let mut rows: Vec<MatrixViewMut<f64>> = m.row_iter_mut().collect();
+    let (first, rest) = rows.split_at_mut(1);
+    rest[1] += &first[0].as_view();
+    first[0].fill(0.0);
+
+    assert_eq!(m, matrix![0.0, 0.0; 3.0, 4.0; 6.0, 8.0]);
+}
+
+#[test]
+fn column_iter_mut() {
+    let mut m: General<f64> = matrix![1.0f64, 2.0; 3.0, 4.0];
+
+    let mut iter: MatrixColumnIteratorMut<f64> = m.column_iter_mut();
+    assert_eq!(iter.len(), 2);
+    iter.next_back().unwrap().scale(-1.0);
+    iter.next().unwrap()[[1, 0]] = 0.0;
+    assert!(iter.next().is_none());
+
+    assert_eq!(m, matrix![1.0, -2.0; 0.0, -4.0]);
+}
+
+#[test]
+fn view_row_iter_mut() {
+    let mut m: General<f64> = General::zero(3, 3);
+
+    for (i, mut row) in m.view_slice_mut(1, 2, 0, 1).row_iter_mut().enumerate() {
+        row.fill((i + 1) as f64);
+    }
+    for (j, mut column) in m.view_slice_mut(0, 0, 1, 2).column_iter_mut().enumerate() {
+        column[[0, 0]] = 10.0 + j as f64;
+    }
+
+    assert_eq!(m, matrix![  0.0, 10.0, 11.0;
+                            1.0, 1.0, 0.0;
+                            2.0, 2.0, 0.0]);
+}
+
+#[test]
+fn iter_empty() {
+    let m: General<f64> = General::zero(2, 0);
+
+    assert_eq!(m.row_iter().len(), 2);
+    assert_eq!(m.column_iter().len(), 0);
+    assert_eq!(m.row_iter().next().unwrap().dim(), (1, 0));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_column_iter() {
+    use rayon::prelude::*;
+
+    let m: General<f64> = matrix![1.0f64, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+    let sums: Vec<f64> = m
+        .par_column_iter()
+        .map(|c| c[[0, 0]] + c[[1, 0]])
+        .collect();
+
+    assert_eq!(sums, vec![5.0, 7.0, 9.0]);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_column_iter_mut() {
+    use rayon::prelude::*;
+
+    let mut m: General<f64> = General::zero(2, 3);
+
+    m.par_column_iter_mut()
+        .enumerate()
+        .for_each(|(j, mut c)| c.fill(j as f64));
+
+    assert_eq!(m, matrix![0.0, 1.0, 2.0; 0.0, 1.0, 2.0]);
+}
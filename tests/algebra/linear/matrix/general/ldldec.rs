@@ -0,0 +1,106 @@
+use mathru::algebra::linear::matrix::{
+    Determinant, General, LDLDecomposition, Solve, Transpose, UnitLowerTriangular,
+};
+use mathru::algebra::linear::vector::Vector;
+
+fn assert_reconstruction(a: &General<f64>) {
+    let (l, d, p): (UnitLowerTriangular<f64>, General<f64>, General<f64>) = a.dec_ldl().unwrap().ldp();
+    let l: General<f64> = l.into();
+
+    assert_relative_eq!(&(&l * &d) * &l.clone().transpose(), &(&p * a) * &p.clone().transpose(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_ldl_positive_definite() {
+    let a: General<f64> = matrix![  4.0, 1.0, 0.0;
+                                    1.0, 3.0, 1.0;
+                                    0.0, 1.0, 2.0];
+
+    assert_reconstruction(&a);
+    assert_eq!(a.dec_ldl().unwrap().inertia(), (3, 0, 0));
+}
+
+#[test]
+fn dec_ldl_zero_diagonal() {
+    let a: General<f64> = matrix![  0.0, 1.0, 2.0, 0.5;
+                                    1.0, 0.0, 3.0, -1.0;
+                                    2.0, 3.0, 0.0, 4.0;
+                                    0.5, -1.0, 4.0, 0.0];
+
+    assert_reconstruction(&a);
+    assert_relative_eq!(a.dec_ldl().unwrap().det(), a.det(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_ldl_randomized() {
+    use rand::{thread_rng, Rng};
+
+    for _i in 0..100 {
+        let n: usize = 6;
+        let mut a: General<f64> = General::zero(n, n);
+        for j in 0..n {
+            for i in j..n {
+                let a_ij: f64 = thread_rng().gen_range(-1.0..1.0);
+                a[[i, j]] = a_ij;
+                a[[j, i]] = a_ij;
+            }
+        }
+
+        assert_reconstruction(&a);
+    }
+}
+
+#[test]
+fn ldl_solve_saddle_point() {
+    // KKT system [H A^T; A 0]
+    let k: General<f64> = matrix![  2.0, 0.0, 1.0, 1.0;
+                                    0.0, 3.0, 1.0, -1.0;
+                                    1.0, 1.0, 0.0, 0.0;
+                                    1.0, -1.0, 0.0, 0.0];
+    let x_ref: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5];
+    let b: Vector<f64> = &k * &x_ref;
+
+    let dec = k.dec_ldl().unwrap();
+
+    assert_relative_eq!(dec.solve(&b).unwrap(), x_ref, epsilon = 1.0e-10);
+    assert_eq!(dec.inertia(), (2, 2, 0));
+}
+
+#[test]
+fn ldl_solve_matrix() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    2.0, -4.0, 1.0;
+                                    3.0, 1.0, 0.0];
+    let b: General<f64> = matrix![  1.0, 0.0;
+                                    2.0, 1.0;
+                                    -1.0, 3.0];
+
+    let x: General<f64> = a.dec_ldl().unwrap().solve(&b).unwrap();
+
+    assert_relative_eq!(&a * &x, b, epsilon = 1.0e-10);
+}
+
+#[test]
+fn ldl_singular() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    2.0, 4.0, 6.0;
+                                    3.0, 6.0, -1.0];
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let dec = a.dec_ldl().unwrap();
+
+    assert_eq!(dec.inertia(), (1, 1, 1));
+    assert_relative_eq!(dec.det(), 0.0, epsilon = 1.0e-10);
+    assert!(dec.solve(&b).is_err());
+}
+
+#[test]
+fn ldl_f32() {
+    let a: General<f32> = matrix![  0.0, 1.0;
+                                    1.0, 0.0];
+
+    let dec = a.dec_ldl().unwrap();
+
+    assert_eq!(dec.inertia(), (1, 1, 0));
+    assert_relative_eq!(dec.det(), -1.0, epsilon = 1.0e-5);
+}
@@ -0,0 +1,142 @@
+use mathru::algebra::linear::{
+    matrix::{General, LeastSquares, LeastSquaresResult, SingularValueDecomposition},
+    vector::Vector,
+};
+
+#[test]
+fn solve_least_squares_qr_square() {
+    let a: General<f64> = matrix![  6.0, 2.0, -1.0;
+                                    -3.0, 5.0, 3.0;
+                                    -2.0, 1.0, 3.0];
+    let b: Vector<f64> = vector![48.0; 49.0; 24.0];
+
+    let result: LeastSquaresResult<f64> = a.solve_least_squares_qr(&b).unwrap();
+
+    assert_relative_eq!(*result.x(), vector![7.0; 8.0; 10.0], epsilon = 1.0e-10);
+    assert_relative_eq!(result.residual(), 0.0, epsilon = 1.0e-10);
+    assert_eq!(result.rank(), 3);
+    assert!(result.singular_values().is_none());
+}
+
+#[test]
+fn solve_least_squares_qr_overdetermined_f64() {
+    // fit of a parabola y = c_0 + c_1 t + c_2 t^2 through five points
+    let a: General<f64> = matrix![  1.0, -2.0, 4.0;
+                                    1.0, -1.0, 1.0;
+                                    1.0, 0.0, 0.0;
+                                    1.0, 1.0, 1.0;
+                                    1.0, 2.0, 4.0];
+    let b: Vector<f64> = vector![4.0; 1.0; 1.0; 1.0; 5.0];
+
+    let result: LeastSquaresResult<f64> = a.solve_least_squares_qr(&b).unwrap();
+    let x_ref: Vector<f64> = vector![0.4; 0.2; 1.0];
+
+    assert_relative_eq!(*result.x(), x_ref, epsilon = 1.0e-10);
+    assert_relative_eq!(result.residual(), (&b - &(&a * &x_ref)).eucl_norm(), epsilon = 1.0e-10);
+    assert_eq!(result.rank(), 3);
+}
+
+#[test]
+fn solve_least_squares_qr_overdetermined_f32() {
+    let a: General<f32> = matrix![  1.0, 0.0;
+                                    1.0, 1.0;
+                                    1.0, 2.0];
+    let b: Vector<f32> = vector![1.0; 2.0; 4.0];
+
+    let result: LeastSquaresResult<f32> = a.solve_least_squares_qr(&b).unwrap();
+
+    assert_relative_eq!(*result.x(), vector![5.0 / 6.0; 1.5], epsilon = 1.0e-5);
+    assert_relative_eq!(result.residual(), (1.0f32 / 6.0).sqrt(), epsilon = 1.0e-5);
+}
+
+#[test]
+fn solve_least_squares_qr_rank_deficient() {
+    // the third column is the sum of the first two
+    let a: General<f64> = matrix![  1.0, 0.0, 1.0;
+                                    0.0, 1.0, 1.0;
+                                    1.0, 1.0, 2.0;
+                                    1.0, -1.0, 0.0];
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0; -1.0];
+
+    let result: LeastSquaresResult<f64> = a.solve_least_squares_qr(&b).unwrap();
+    let x_min: Vector<f64> = a.solve_least_squares_svd(&b).unwrap().into_x();
+
+    assert_eq!(result.rank(), 2);
+    assert_relative_eq!(&a * result.x(), &a * &x_min, epsilon = 1.0e-10);
+    assert_eq!(result.x().iter().filter(|x_i| **x_i == 0.0).count(), 1);
+}
+
+#[test]
+fn solve_least_squares_qr_underdetermined() {
+    // basic solution of x_0 + 2 x_1 + x_2 = 4 with a single nonzero entry
+    let a: General<f64> = matrix![1.0, 2.0, 1.0];
+    let b: Vector<f64> = vector![4.0];
+
+    let result: LeastSquaresResult<f64> = a.solve_least_squares_qr(&b).unwrap();
+
+    assert_relative_eq!(*result.x(), vector![0.0; 2.0; 0.0], epsilon = 1.0e-10);
+    assert_relative_eq!(result.residual(), 0.0, epsilon = 1.0e-10);
+    assert_eq!(result.rank(), 1);
+}
+
+#[test]
+fn solve_least_squares_svd_overdetermined() {
+    let a: General<f64> = matrix![  1.0, -2.0, 4.0;
+                                    1.0, -1.0, 1.0;
+                                    1.0, 0.0, 0.0;
+                                    1.0, 1.0, 1.0;
+                                    1.0, 2.0, 4.0];
+    let b: Vector<f64> = vector![4.0; 1.0; 1.0; 1.0; 5.0];
+
+    let result: LeastSquaresResult<f64> = a.solve_least_squares_svd(&b).unwrap();
+    let x_ref: Vector<f64> = vector![0.4; 0.2; 1.0];
+
+    assert_relative_eq!(*result.x(), x_ref, epsilon = 1.0e-10);
+    assert_relative_eq!(result.residual(), (&b - &(&a * &x_ref)).eucl_norm(), epsilon = 1.0e-10);
+    assert_eq!(result.rank(), 3);
+    assert_relative_eq!(
+        *result.singular_values().unwrap(),
+        a.singular_values().unwrap(),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn solve_least_squares_svd_rank_deficient() {
+    let a: General<f64> = matrix![  1.0, 0.0, 1.0;
+                                    0.0, 1.0, 1.0;
+                                    1.0, 1.0, 2.0;
+                                    1.0, -1.0, 0.0];
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0; -1.0];
+
+    let result: LeastSquaresResult<f64> = a.solve_least_squares_svd(&b).unwrap();
+    let x_ref: Vector<f64> = &a.pinv().unwrap() * &b;
+
+    assert_eq!(result.rank(), 2);
+    assert_relative_eq!(*result.x(), x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_least_squares_svd_underdetermined() {
+    // minimum norm solution of x_0 + x_1 = 2
+    let a: General<f64> = matrix![1.0, 1.0];
+    let b: Vector<f64> = vector![2.0];
+
+    let result: LeastSquaresResult<f64> = a.solve_least_squares_svd(&b).unwrap();
+
+    assert_relative_eq!(*result.x(), vector![1.0; 1.0], epsilon = 1.0e-10);
+    assert_relative_eq!(result.residual(), 0.0, epsilon = 1.0e-10);
+    assert_eq!(result.rank(), 1);
+}
+
+#[test]
+fn solve_least_squares_svd_f32() {
+    let a: General<f32> = matrix![  1.0, 0.0;
+                                    1.0, 1.0;
+                                    1.0, 2.0];
+    let b: Vector<f32> = vector![1.0; 2.0; 4.0];
+
+    let result: LeastSquaresResult<f32> = a.solve_least_squares_svd(&b).unwrap();
+
+    assert_relative_eq!(*result.x(), vector![5.0 / 6.0; 1.5], epsilon = 1.0e-5);
+}
@@ -7,6 +7,7 @@ use mathru::algebra::linear::{
         UpperTriangular,
     },
     vector::Vector,
+    LinalgError,
 };
 
 #[test]
@@ -282,3 +283,117 @@ fn dec_7() {
             * <UpperTriangular<f64> as Into<General<f64>>>::into(u)
     );
 }
+
+#[test]
+fn dec_complex_entries() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(2.0, 1.0), Complex::new(1.0, -2.0), Complex::new(0.5, 0.0);
+                                            Complex::new(-1.0, 1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 2.0);
+                                            Complex::new(1.0, 0.0), Complex::new(0.0, -1.0), Complex::new(4.0, -1.0)];
+
+    let (l, u, p): (UnitLowerTriangular<Complex<f64>>, UpperTriangular<Complex<f64>>, General<Complex<f64>>) =
+        a.dec_lu().unwrap().lup();
+
+    assert_relative_eq!(&p * &a, &General::from(l) * &General::from(u), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
+
+fn lu_product(dec: LUDec<f64>) -> General<f64> {
+    let (l, u, p): (UnitLowerTriangular<f64>, UpperTriangular<f64>, General<f64>) = dec.lup();
+    &p.transpose() * &(&General::from(l) * &General::from(u))
+}
+
+fn update_matrix() -> General<f64> {
+    matrix![    1.0, 2.0, 0.5, -1.0;
+                3.0, -4.0, 1.0, 2.0;
+                5.0, 6.0, -2.0, 0.0;
+                -1.0, 0.0, 3.0, 4.0]
+}
+
+#[test]
+fn rank_one_update() {
+    let a: General<f64> = update_matrix();
+    let u: Vector<f64> = vector![1.0; 0.0; -2.0; 1.0];
+    let v: Vector<f64> = vector![0.5; 1.0; -1.0; 2.0];
+
+    let mut dec: LUDec<f64> = a.dec_lu().unwrap();
+    dec.rank_one_update(&u, &v).unwrap();
+
+    let a_ref: General<f64> = &a + &(&General::from(u) * &General::from(v.transpose()));
+    assert_relative_eq!(lu_product(dec), a_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn rank_one_update_singular() {
+    let a: General<f64> = matrix![  1.0, 0.0;
+                                    0.0, 1.0];
+
+    let mut dec: LUDec<f64> = a.dec_lu().unwrap();
+
+    assert_eq!(
+        dec.rank_one_update(&vector![0.0; 1.0], &vector![0.0; -1.0]),
+        Err(LinalgError::Singular)
+    );
+    assert_eq!(
+        dec.rank_one_update(&vector![0.0; 1.0; 0.0], &vector![0.0; -1.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+    assert_relative_eq!(lu_product(dec), a, epsilon = 1.0e-10);
+}
+
+#[test]
+fn insert_row_column() {
+    let a: General<f64> = update_matrix();
+    let row: Vector<f64> = vector![2.0; 1.0; 7.0; -1.0; 3.0];
+    let column: Vector<f64> = vector![-3.0; 2.0; 7.0; 1.0; 0.5];
+
+    for k in 0..5 {
+        let mut dec: LUDec<f64> = a.dec_lu().unwrap();
+        let mut row_k: Vector<f64> = row.clone();
+        let mut column_k: Vector<f64> = column.clone();
+        row_k[k] = 7.0;
+        column_k[k] = 7.0;
+        dec.insert_row_column(k, &row_k, &column_k).unwrap();
+
+        let mut a_ref: General<f64> = General::zero(5, 5);
+        for i in 0..5 {
+            for j in 0..5 {
+                a_ref[[i, j]] = if i == k {
+                    row_k[j]
+                } else if j == k {
+                    column_k[i]
+                } else {
+                    a[[if i < k { i } else { i - 1 }, if j < k { j } else { j - 1 }]]
+                };
+            }
+        }
+        assert_relative_eq!(lu_product(dec), a_ref, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn remove_row_column() {
+    let a: General<f64> = update_matrix();
+
+    for k in 0..4 {
+        let mut dec: LUDec<f64> = a.dec_lu().unwrap();
+        dec.remove_row_column(k).unwrap();
+
+        let mut a_ref: General<f64> = General::zero(3, 3);
+        for i in (0..4).filter(|i| *i != k) {
+            for j in (0..4).filter(|j| *j != k) {
+                a_ref[[if i < k { i } else { i - 1 }, if j < k { j } else { j - 1 }]] = a[[i, j]];
+            }
+        }
+        assert_relative_eq!(lu_product(dec), a_ref, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn remove_row_column_solve() {
+    let a: General<f64> = update_matrix();
+
+    let mut dec: LUDec<f64> = a.dec_lu().unwrap();
+    dec.remove_row_column(3).unwrap();
+    let x: Vector<f64> = dec.solve(&vector![3.5; 0.0; 9.0]).unwrap();
+
+    assert_relative_eq!(x, vector![1.0; 1.0; 1.0], epsilon = 1.0e-10);
+}
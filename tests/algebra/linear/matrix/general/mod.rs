@@ -1,23 +1,34 @@
 mod abs_diff_eq;
 mod add;
 mod add_assign;
+mod checked;
 mod cholesky;
+mod completeorthogonaldec;
 mod det;
 mod eigen;
+mod function;
 mod general;
+mod generalizedeigen;
+mod generalizedschur;
 mod hessenbergdec;
 mod index;
+mod ldldec;
+mod leastsquares;
 mod iterator;
 mod ludec;
 mod mul;
 mod mul_assign;
+mod norm;
 mod partial_eq;
+mod polar;
 mod qrdec;
 mod relative_eq;
+mod schur;
 mod singular;
 mod solve;
 mod sub;
 mod sub_assign;
+mod symmetriceigen;
 mod transpose;
 
 #[cfg(feature = "mint")]
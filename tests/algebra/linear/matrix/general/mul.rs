@@ -292,3 +292,50 @@ fn scalar_borrow_mut() {
 
     assert_relative_eq!(prod_ref, &mut m * &-0.5);
 }
+
+#[test]
+fn mul_complex() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(1.0, 1.0), Complex::new(2.0, 0.0);
+                                            Complex::new(0.0, 0.0), Complex::new(0.0, 1.0)];
+    let b: General<Complex<f64>> = matrix![ Complex::new(1.0, 0.0), Complex::new(0.0, 1.0);
+                                            Complex::new(1.0, -1.0), Complex::new(0.0, 0.0)];
+
+    let c_ref: General<Complex<f64>> = matrix![ Complex::new(3.0, -1.0), Complex::new(-1.0, 1.0);
+                                                Complex::new(1.0, 1.0), Complex::new(0.0, 0.0)];
+
+    assert_eq!(&a * &b, c_ref);
+}
+
+#[test]
+fn matrix_borrow_mut_wider_rhs() {
+    let mut a: General<f64> = matrix![  1.0, 2.0;
+                                        3.0, 4.0];
+
+    let b: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.0, 1.0, -1.0];
+
+    let prod_ref: General<f64> = matrix![   1.0, 2.0, 0.0;
+                                            3.0, 4.0, 2.0];
+
+    let _ = &mut a * &b;
+
+    assert_relative_eq!(prod_ref, a);
+}
+
+#[test]
+#[should_panic]
+fn matrix_dimension_mismatch() {
+    let a: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    let b: General<f64> = matrix![1.0, 2.0, 3.0];
+
+    let _ = &a * &b;
+}
+
+#[test]
+#[should_panic]
+fn matrix_mul_vector_dimension_mismatch() {
+    let a: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    let x: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let _ = &a * &x;
+}
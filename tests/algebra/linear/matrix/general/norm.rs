@@ -0,0 +1,172 @@
+use mathru::algebra::linear::{
+    matrix::{
+        CholeskyDecomposition, General, Inverse, LUDecomposition, MatrixNorm, Transpose,
+    },
+    vector::Vector,
+    LinalgError,
+};
+use rand::distributions::Uniform;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+#[test]
+fn norm_1() {
+    let a: General<f64> = matrix![  1.0, -7.0, 2.0;
+                                    -3.0, 4.0, 0.5];
+
+    assert_relative_eq!(a.norm_1(), 11.0);
+}
+
+#[test]
+fn norm_inf() {
+    let a: General<f64> = matrix![  1.0, -7.0, 2.0;
+                                    -3.0, 4.0, 0.5];
+
+    assert_relative_eq!(a.norm_inf(), 10.0);
+}
+
+#[test]
+fn norm_frobenius() {
+    let a: General<f64> = matrix![  1.0, -2.0;
+                                    -2.0, 4.0;
+                                    0.0, 0.0];
+
+    assert_relative_eq!(a.norm_frobenius(), 5.0);
+}
+
+#[test]
+fn norm_2() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    2.0, 4.0];
+
+    assert_relative_eq!(a.norm_2(), 5.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn norm_2_bounds() {
+    let a: General<f64> = matrix![  1.0, -2.0, 0.5;
+                                    3.0, 4.0, -1.0;
+                                    0.0, 2.5, 6.0;
+                                    -4.0, 1.0, 2.0;
+                                    1.5, -0.5, 3.0];
+    let norm_2: f64 = a.norm_2();
+
+    assert!(norm_2 <= a.norm_frobenius() + 1.0e-10);
+    assert!(norm_2 <= (a.norm_1() * a.norm_inf()).sqrt() + 1.0e-10);
+}
+
+#[test]
+fn norm_transpose() {
+    let a: General<f64> = matrix![  1.0, -2.0, 0.5, 7.0, 0.0, -1.0;
+                                    3.0, 4.0, -1.0, 2.0, 1.0, 1.0;
+                                    0.0, 2.5, 6.0, -3.0, 2.0, 0.5;
+                                    -4.0, 1.0, 2.0, 1.0, -6.0, 2.0];
+    let a_t: General<f64> = a.clone().transpose();
+
+    assert_relative_eq!(a.norm_1(), a_t.norm_inf());
+    assert_relative_eq!(a.norm_frobenius(), a_t.norm_frobenius());
+}
+
+#[test]
+fn norm_zero_dimension() {
+    let a: General<f64> = General::zero(0, 0);
+
+    assert_eq!(a.norm_1(), 0.0);
+    assert_eq!(a.norm_inf(), 0.0);
+    assert_eq!(a.norm_frobenius(), 0.0);
+    assert_eq!(a.norm_2(), 0.0);
+}
+
+#[test]
+fn cond_1_estimate_lu() {
+    let a: General<f64> = matrix![  1.0, -2.0, 3.0;
+                                    4.0, 5.0, -6.0;
+                                    -7.0, 8.0, 10.0];
+    let cond: f64 = a.norm_1() * a.inv().unwrap().norm_1();
+
+    let estimate: f64 = a.dec_lu().unwrap().cond_1_estimate(a.norm_1());
+
+    assert_relative_eq!(estimate, cond, epsilon = 1.0e-10);
+}
+
+#[test]
+fn cond_1_estimate_lu_randomized() {
+    // The estimate is a lower bound, which may be poor for a few matrices.
+    // A fixed seed keeps the test reproducible.
+    let mut rng: StdRng = StdRng::seed_from_u64(42);
+    for _ in 0..20 {
+        let n: usize = rng.sample(Uniform::from(1..10));
+        let data: Vec<f64> = (0..n * n).map(|_| rng.gen::<f64>() - 0.5).collect();
+        let a: General<f64> = General::new(n, n, data);
+        let a_inv: General<f64> = match a.inv() {
+            Ok(a_inv) => a_inv,
+            Err(_) => continue,
+        };
+        let cond: f64 = a.norm_1() * a_inv.norm_1();
+
+        let estimate: f64 = a.dec_lu().unwrap().cond_1_estimate(a.norm_1());
+
+        assert!(estimate <= cond * (1.0 + 1.0e-10));
+        assert!(estimate >= cond / 3.0);
+    }
+}
+
+#[test]
+fn cond_1_estimate_lu_singular() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    2.0, 4.0];
+
+    let estimate: f64 = a.dec_lu().unwrap().cond_1_estimate(a.norm_1());
+
+    assert_eq!(estimate, f64::INFINITY);
+}
+
+#[test]
+fn cond_1_estimate_cholesky() {
+    let a: General<f64> = matrix![  4.0, 1.0, 0.5;
+                                    1.0, 3.0, -1.0;
+                                    0.5, -1.0, 2.0];
+    let cond: f64 = a.norm_1() * a.inv().unwrap().norm_1();
+
+    let estimate: f64 = a.dec_cholesky().unwrap().cond_1_estimate(a.norm_1());
+
+    assert_relative_eq!(estimate, cond, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_with_cond() {
+    let a: General<f64> = matrix![  1.0, -2.0, 3.0;
+                                    4.0, 5.0, -6.0;
+                                    -7.0, 8.0, 10.0];
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    let (x, cond): (Vector<f64>, f64) = a.solve_with_cond(&b, None).unwrap();
+
+    assert_relative_eq!(&a * &x, b, epsilon = 1.0e-10);
+    assert_relative_eq!(cond, a.norm_1() * a.inv().unwrap().norm_1(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_with_cond_ill_conditioned() {
+    let n: usize = 10;
+    let mut hilbert: General<f64> = General::zero(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            hilbert[[i, j]] = 1.0 / ((i + j + 1) as f64);
+        }
+    }
+    let b: Vector<f64> = Vector::one(n);
+
+    assert_eq!(hilbert.solve_with_cond(&b, Some(1.0e8)).err(), Some(LinalgError::IllConditioned));
+
+    let (_, cond): (Vector<f64>, f64) = hilbert.solve_with_cond(&b, None).unwrap();
+    assert!(cond > 1.0e12);
+}
+
+#[test]
+fn solve_with_cond_singular() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    2.0, 4.0];
+    let b: Vector<f64> = vector![1.0; 2.0];
+
+    assert_eq!(a.solve_with_cond(&b, None).err(), Some(LinalgError::Singular));
+}
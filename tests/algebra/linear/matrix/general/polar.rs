@@ -0,0 +1,81 @@
+use mathru::algebra::linear::matrix::{
+    Determinant, General, PolarDecomposition, SymmetricEigenDecomposition, Transpose,
+};
+
+fn assert_positive_semidefinite(p: &General<f64>) {
+    assert_relative_eq!(p.clone().transpose(), p.clone(), epsilon = 1.0e-12);
+    let values: Vec<f64> = p.dec_symmetric_eigen().unwrap().values();
+    assert!(values.iter().all(|l| *l >= -1.0e-10));
+}
+
+#[test]
+fn dec_polar_square() {
+    let a: General<f64> = matrix![  4.0, 1.0, -2.0;
+                                    1.0, 2.0, 0.0;
+                                    -2.0, 3.0, 3.0];
+
+    let (u, p): (General<f64>, General<f64>) = a.dec_polar().unwrap().up();
+
+    assert_relative_eq!(&u * &p, a, epsilon = 1.0e-10);
+    assert_relative_eq!(&u.clone().transpose() * &u, General::one(3), epsilon = 1.0e-10);
+    assert_relative_eq!(u.det(), 1.0, epsilon = 1.0e-10);
+    assert_positive_semidefinite(&p);
+    // P = (A^T A)^{1/2}
+    assert_relative_eq!(&p * &p, &a.clone().transpose() * &a, epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_polar_rotation() {
+    let (sin, cos): (f64, f64) = 0.3f64.sin_cos();
+    let r: General<f64> = matrix![  cos, -sin;
+                                    sin, cos];
+    let s: General<f64> = matrix![  2.0, 0.5;
+                                    0.5, 1.0];
+
+    let (u, p): (General<f64>, General<f64>) = (&r * &s).dec_polar().unwrap().up();
+
+    assert_relative_eq!(u, r, epsilon = 1.0e-10);
+    assert_relative_eq!(p, s, epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_polar_tall() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    3.0, 4.0;
+                                    5.0, 6.0;
+                                    0.0, 1.0];
+
+    let (u, p): (General<f64>, General<f64>) = a.dec_polar().unwrap().up();
+
+    assert_eq!(u.dim(), (4, 2));
+    assert_eq!(p.dim(), (2, 2));
+    assert_relative_eq!(&u * &p, a, epsilon = 1.0e-10);
+    assert_relative_eq!(&u.clone().transpose() * &u, General::one(2), epsilon = 1.0e-10);
+    assert_positive_semidefinite(&p);
+}
+
+#[test]
+fn dec_polar_wide() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0, 0.0;
+                                    -1.0, 0.0, 2.0, 1.0];
+
+    let (u, p): (General<f64>, General<f64>) = a.dec_polar().unwrap().up();
+
+    assert_eq!(u.dim(), (2, 4));
+    assert_eq!(p.dim(), (4, 4));
+    assert_relative_eq!(&u * &p, a, epsilon = 1.0e-10);
+    assert_relative_eq!(&u * &u.clone().transpose(), General::one(2), epsilon = 1.0e-10);
+    assert_positive_semidefinite(&p);
+}
+
+#[test]
+fn dec_polar_singular() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    2.0, 4.0];
+
+    let (u, p): (General<f64>, General<f64>) = a.dec_polar().unwrap().up();
+
+    assert_relative_eq!(&u * &p, a, epsilon = 1.0e-10);
+    assert_relative_eq!(&u.clone().transpose() * &u, General::one(2), epsilon = 1.0e-10);
+    assert_positive_semidefinite(&p);
+}
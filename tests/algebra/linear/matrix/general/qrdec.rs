@@ -1,5 +1,8 @@
 use mathru::algebra::abstr::Complex;
-use mathru::algebra::linear::matrix::{General, QRDecomposition, UpperTriangular};
+use mathru::algebra::linear::matrix::{
+    ConjugateTranspose, General, QRDecomposition, QRPDecomposition, Transpose, UpperTriangular,
+};
+use mathru::algebra::linear::{vector::Vector, LinalgError};
 
 #[cfg(feature = "native")]
 #[test]
@@ -317,3 +320,218 @@ fn decompose_qr2() {
     assert_relative_eq!(r, r_ref, epsilon = 1.0e-10);
     // assert_relative_eq!(a, &q * &r, epsilon = 1.0e-10);
 }
+
+#[test]
+fn dec_complex_entries() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(2.0, 1.0), Complex::new(1.0, -2.0), Complex::new(0.5, 0.0);
+                                            Complex::new(-1.0, 1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 2.0);
+                                            Complex::new(1.0, 0.0), Complex::new(0.0, -1.0), Complex::new(4.0, -1.0)];
+
+    let (q, r): (General<Complex<f64>>, UpperTriangular<Complex<f64>>) = a.dec_qr().unwrap().qr();
+    let r: General<Complex<f64>> = r.into();
+
+    assert_relative_eq!(&q * &r, a, epsilon = Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(&q.clone().conj_transpose() * &q, General::one(3), epsilon = Complex::new(1.0e-10, 1.0e-10));
+    for j in 0..3 {
+        for i in (j + 1)..3 {
+            assert_eq!(r[[i, j]], Complex::new(0.0, 0.0));
+        }
+    }
+}
+
+#[test]
+fn dec_qrp_f64() {
+    let a: General<f64> = matrix![  1.0, 2.0, 0.0, 4.0;
+                                    0.0, 4.0, 1.0, -1.0;
+                                    1.0, 2.0, 0.0, 3.0;
+                                    2.0, -1.0, 5.0, 0.0];
+
+    let (q, r, p): (General<f64>, UpperTriangular<f64>, General<f64>) = a.dec_qrp().unwrap().qrp();
+    let r: General<f64> = r.into();
+
+    assert_relative_eq!(&q.clone().transpose() * &q, General::one(4), epsilon = 1.0e-10);
+    assert_relative_eq!(&p.clone().transpose() * &p, General::one(4));
+    assert_relative_eq!(&a * &p, &q * &r, epsilon = 1.0e-10);
+    for i in 1..4 {
+        assert!(r[[i, i]].abs() <= r[[i - 1, i - 1]].abs());
+        for j in 0..i {
+            assert_eq!(r[[i, j]], 0.0);
+        }
+    }
+}
+
+#[test]
+fn dec_qrp_f32() {
+    let a: General<f32> = matrix![  1.0, 2.0, 0.0;
+                                    0.0, 4.0, 1.0;
+                                    1.0, 2.0, 0.0];
+
+    let (q, r, p): (General<f32>, UpperTriangular<f32>, General<f32>) = a.dec_qrp().unwrap().qrp();
+
+    assert_relative_eq!(&a * &p, &q * &General::from(r), epsilon = 1.0e-5);
+}
+
+#[test]
+fn dec_qrp_wide() {
+    let a: General<f64> = matrix![  1.0, 2.0, 0.0, 3.0;
+                                    0.0, 4.0, 1.0, 1.0];
+
+    let (q, r, p): (General<f64>, UpperTriangular<f64>, General<f64>) = a.dec_qrp().unwrap().qrp();
+
+    assert_eq!(q.dim(), (2, 2));
+    assert_eq!(r.dim(), (2, 4));
+    assert_relative_eq!(&a * &p, &q * &General::from(r), epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_qrp_tall() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    0.0, 4.0;
+                                    3.0, 1.0;
+                                    1.0, 1.0];
+
+    let (q, r, p): (General<f64>, UpperTriangular<f64>, General<f64>) = a.dec_qrp().unwrap().qrp();
+
+    assert_eq!(q.dim(), (4, 4));
+    assert_relative_eq!(&q.clone().transpose() * &q, General::one(4), epsilon = 1.0e-10);
+    assert_relative_eq!(&a * &p, &q * &General::from(r), epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_qrp_rank() {
+    // the third column is the sum of the first two
+    let a: General<f64> = matrix![  1.0, 0.0, 1.0;
+                                    0.0, 1.0, 1.0;
+                                    1.0, 1.0, 2.0;
+                                    1.0, -1.0, 0.0];
+
+    assert_eq!(a.dec_qrp().unwrap().rank(1.0e-12), 2);
+    assert_eq!(General::<f64>::one(3).dec_qrp().unwrap().rank(1.0e-12), 3);
+    assert_eq!(General::<f64>::zero(3, 3).dec_qrp().unwrap().rank(1.0e-12), 0);
+}
+
+fn assert_qr(q: &General<f64>, r: &General<f64>, a: &General<f64>) {
+    let m: usize = a.nrows();
+    assert_relative_eq!(&q.clone().transpose() * q, General::one(m), epsilon = 1.0e-10);
+    assert_relative_eq!(q * r, *a, epsilon = 1.0e-10);
+    for j in 0..r.ncols() {
+        for i in j + 1..m {
+            assert_relative_eq!(r[[i, j]], 0.0, epsilon = 1.0e-10);
+        }
+    }
+}
+
+fn update_matrix() -> General<f64> {
+    matrix![    1.0, 2.0, 0.5;
+                3.0, -4.0, 1.0;
+                5.0, 6.0, -2.0;
+                -1.0, 0.0, 3.0]
+}
+
+#[test]
+fn rank_one_update() {
+    let a: General<f64> = update_matrix();
+    let u: Vector<f64> = vector![1.0; 0.0; -2.0; 1.0];
+    let v: Vector<f64> = vector![0.5; 1.0; -1.0];
+
+    let mut dec = a.dec_qr().unwrap();
+    dec.rank_one_update(&u, &v).unwrap();
+    let (q, r) = dec.qr();
+
+    let a_ref: General<f64> = &a + &(&General::from(u) * &General::from(v.transpose()));
+    assert_qr(&q, &r.into(), &a_ref);
+}
+
+#[test]
+fn rank_one_update_dimension_mismatch() {
+    let mut dec = update_matrix().dec_qr().unwrap();
+
+    assert_eq!(
+        dec.rank_one_update(&vector![1.0; 0.0; -2.0], &vector![0.5; 1.0; -1.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn insert_column() {
+    let a: General<f64> = update_matrix();
+    let column: Vector<f64> = vector![2.0; 1.0; 0.0; -1.0];
+
+    for k in 0..4 {
+        let mut dec = a.dec_qr().unwrap();
+        dec.insert_column(k, &column).unwrap();
+        let (q, r) = dec.qr();
+
+        let mut data: Vec<f64> = Vec::new();
+        for j in 0..4 {
+            if j == k {
+                data.extend((0..4).map(|i| column[i]));
+            }
+            if j < 3 {
+                data.extend((0..4).map(|i| a[[i, j]]));
+            }
+        }
+        assert_qr(&q, &r.into(), &General::new(4, 4, data));
+    }
+}
+
+#[test]
+fn remove_column() {
+    let a: General<f64> = update_matrix();
+
+    for k in 0..3 {
+        let mut dec = a.dec_qr().unwrap();
+        dec.remove_column(k).unwrap();
+        let (q, r) = dec.qr();
+
+        let data: Vec<f64> = (0..3)
+            .filter(|j| *j != k)
+            .flat_map(|j| (0..4).map(move |i| (i, j)))
+            .map(|(i, j)| a[[i, j]])
+            .collect();
+        assert_qr(&q, &r.into(), &General::new(4, 2, data));
+    }
+}
+
+#[test]
+fn insert_row() {
+    let a: General<f64> = update_matrix();
+    let row: Vector<f64> = vector![2.0; 1.0; -1.0];
+
+    for k in 0..5 {
+        let mut dec = a.dec_qr().unwrap();
+        dec.insert_row(k, &row).unwrap();
+        let (q, r) = dec.qr();
+
+        let mut a_ref: General<f64> = General::zero(5, 3);
+        for i in 0..5 {
+            for j in 0..3 {
+                a_ref[[i, j]] = match i.cmp(&k) {
+                    std::cmp::Ordering::Less => a[[i, j]],
+                    std::cmp::Ordering::Equal => row[j],
+                    std::cmp::Ordering::Greater => a[[i - 1, j]],
+                };
+            }
+        }
+        assert_qr(&q, &r.into(), &a_ref);
+    }
+}
+
+#[test]
+fn remove_row() {
+    let a: General<f64> = update_matrix();
+
+    for k in 0..4 {
+        let mut dec = a.dec_qr().unwrap();
+        dec.remove_row(k).unwrap();
+        let (q, r) = dec.qr();
+
+        let mut a_ref: General<f64> = General::zero(3, 3);
+        for i in (0..4).filter(|i| *i != k) {
+            for j in 0..3 {
+                a_ref[[if i < k { i } else { i - 1 }, j]] = a[[i, j]];
+            }
+        }
+        assert_qr(&q, &r.into(), &a_ref);
+    }
+}
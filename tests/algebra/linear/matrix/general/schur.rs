@@ -0,0 +1,88 @@
+use mathru::algebra::abstr::Complex;
+use mathru::algebra::linear::matrix::{
+    ConjugateTranspose, General, SchurDec, SchurDecomposition, UpperTriangular,
+};
+
+fn test_matrix() -> General<Complex<f64>> {
+    let a: General<f64> = matrix![  1.0, 2.0, 0.5, -1.0, 0.0;
+                                    3.0, -4.0, -1.0, 2.0, 1.0;
+                                    0.0, 2.0, 5.0, 1.0, -2.0;
+                                    1.0, -3.0, 2.0, 0.0, 1.0;
+                                    2.0, 0.0, 1.0, -1.0, -3.0];
+    General::from(&a)
+}
+
+fn diagonal(u: &General<Complex<f64>>) -> Vec<Complex<f64>> {
+    (0..u.nrows()).map(|i| u[[i, i]]).collect()
+}
+
+#[test]
+fn reorder() {
+    let a: General<Complex<f64>> = test_matrix();
+    let schur: SchurDec<Complex<f64>> = a.dec_schur().unwrap();
+    let values: Vec<Complex<f64>> = diagonal(&schur.clone().u().into());
+
+    let select: Vec<bool> = values.iter().map(|l| l.re < 0.0).collect();
+    let k: usize = select.iter().filter(|s| **s).count();
+    let (q, u): (General<Complex<f64>>, UpperTriangular<Complex<f64>>) = schur.reorder(&select).qu();
+    let u: General<Complex<f64>> = u.into();
+
+    let eps: Complex<f64> = Complex::new(1.0e-10, 1.0e-10);
+    assert_relative_eq!(&(&q * &u) * &q.clone().conj_transpose(), a, epsilon = eps);
+    assert_relative_eq!(&q.clone().conj_transpose() * &q, General::one(5), epsilon = eps);
+
+    // The selected and the remaining eigenvalues keep their relative order
+    let expected: Vec<Complex<f64>> = values
+        .iter()
+        .filter(|l| l.re < 0.0)
+        .chain(values.iter().filter(|l| l.re >= 0.0))
+        .copied()
+        .collect();
+    for (u_ii, l) in diagonal(&u).iter().zip(expected.iter()) {
+        assert_relative_eq!(*u_ii, *l, epsilon = Complex::new(1.0e-8, 1.0e-8));
+    }
+    for j in 0..5 {
+        for i in (j + 1)..5 {
+            assert_eq!(u[[i, j]], Complex::new(0.0, 0.0));
+        }
+    }
+
+    // The leading columns of Q span the invariant subspace
+    let q_k: General<Complex<f64>> = q.get_slice(0, 4, 0, k - 1);
+    let u_k: General<Complex<f64>> = u.get_slice(0, k - 1, 0, k - 1);
+    assert_relative_eq!(&a * &q_k, &q_k * &u_k, epsilon = eps);
+}
+
+#[test]
+fn reorder_nothing_selected() {
+    let a: General<Complex<f64>> = test_matrix();
+    let schur: SchurDec<Complex<f64>> = a.dec_schur().unwrap();
+
+    let (q, u) = schur.clone().qu();
+    let (q_r, u_r) = schur.reorder(&[false; 5]).qu();
+
+    assert_eq!(q_r, q);
+    assert_eq!(General::from(u_r), General::from(u));
+}
+
+#[test]
+fn reorder_last_to_first() {
+    let a: General<Complex<f64>> = test_matrix();
+    let schur: SchurDec<Complex<f64>> = a.dec_schur().unwrap();
+    let values: Vec<Complex<f64>> = diagonal(&schur.clone().u().into());
+
+    let (q, u) = schur.reorder(&[false, false, false, false, true]).qu();
+    let u: General<Complex<f64>> = u.into();
+
+    let eps: Complex<f64> = Complex::new(1.0e-10, 1.0e-10);
+    assert_relative_eq!(u[[0, 0]], values[4], epsilon = Complex::new(1.0e-8, 1.0e-8));
+    assert_relative_eq!(&(&q * &u) * &q.clone().conj_transpose(), a, epsilon = eps);
+}
+
+#[test]
+#[should_panic]
+fn reorder_wrong_length() {
+    let a: General<Complex<f64>> = test_matrix();
+
+    let _ = a.dec_schur().unwrap().reorder(&[true; 3]);
+}
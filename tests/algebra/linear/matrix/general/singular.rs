@@ -1,4 +1,7 @@
-use mathru::algebra::linear::matrix::General;
+use mathru::algebra::linear::{
+    matrix::{General, MatrixNorm, SingularValueDecomposition, Transpose},
+    vector::Vector,
+};
 
 #[test]
 fn svd_f32() {
@@ -57,3 +60,278 @@ fn svd_f64() {
     assert_relative_eq!(s_ref, s, epsilon = 0.0000001, max_relative = 1.0e-10);
     assert_relative_eq!(v_ref, v, epsilon = 0.0000001, max_relative = 1.0e-10);
 }
+
+fn sigma(m: usize, n: usize, s: &Vector<f64>) -> General<f64> {
+    let mut sigma: General<f64> = General::zero(m, n);
+    for i in 0..s.dim().0 {
+        sigma[[i, i]] = s[i];
+    }
+    sigma
+}
+
+#[test]
+fn dec_svd_tall() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    3.0, 4.0;
+                                    5.0, 6.0];
+
+    let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd().unwrap().usv();
+
+    assert_eq!(u.dim(), (3, 3));
+    assert_eq!(s.dim(), (2, 1));
+    assert_eq!(v.dim(), (2, 2));
+    assert!(s[0] >= s[1]);
+    assert_relative_eq!(&u.clone().transpose() * &u, General::one(3), epsilon = 1.0e-10);
+    assert_relative_eq!(&v.clone().transpose() * &v, General::one(2), epsilon = 1.0e-10);
+    assert_relative_eq!(&u * &(&sigma(3, 2, &s) * &v.transpose()), a, epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_svd_wide() {
+    let a: General<f64> = matrix![  1.0, 0.0, 2.0, -1.0;
+                                    0.0, 3.0, 1.0, 1.0];
+
+    let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd().unwrap().usv();
+
+    assert_eq!(u.dim(), (2, 2));
+    assert_eq!(v.dim(), (4, 4));
+    assert!(s[0] >= s[1]);
+    assert_relative_eq!(&v.clone().transpose() * &v, General::one(4), epsilon = 1.0e-10);
+    assert_relative_eq!(&u * &(&sigma(2, 4, &s) * &v.transpose()), a, epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_svd_thin_tall() {
+    let a: General<f64> = matrix![  2.0, 0.0, 1.0;
+                                    1.0, 3.0, 0.0;
+                                    0.0, 1.0, 4.0;
+                                    1.0, 1.0, 1.0;
+                                    -1.0, 2.0, 0.0];
+
+    let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_thin().unwrap().usv();
+
+    assert_eq!(u.dim(), (5, 3));
+    assert_eq!(v.dim(), (3, 3));
+    assert!(s[0] >= s[1] && s[1] >= s[2]);
+    assert_relative_eq!(&u.clone().transpose() * &u, General::one(3), epsilon = 1.0e-10);
+    assert_relative_eq!(&u * &(&sigma(3, 3, &s) * &v.transpose()), a, epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_svd_thin_wide() {
+    let a: General<f64> = matrix![  2.0, 1.0, 0.0, 1.0, -1.0;
+                                    0.0, 3.0, 1.0, 1.0, 2.0;
+                                    1.0, 0.0, 4.0, 1.0, 0.0];
+
+    let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_thin().unwrap().usv();
+
+    assert_eq!(u.dim(), (3, 3));
+    assert_eq!(v.dim(), (5, 3));
+    assert_relative_eq!(&v.clone().transpose() * &v, General::one(3), epsilon = 1.0e-10);
+    assert_relative_eq!(&u * &(&sigma(3, 3, &s) * &v.transpose()), a, epsilon = 1.0e-10);
+}
+
+#[test]
+fn singular_values() {
+    let a: General<f64> = matrix![  3.0, 0.0;
+                                    0.0, -4.0;
+                                    0.0, 0.0];
+
+    let s: Vector<f64> = a.singular_values().unwrap();
+
+    assert_relative_eq!(s, vector![4.0; 3.0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn singular_values_match_dec_svd() {
+    let a: General<f64> = matrix![   4.0, 1.0, -2.0, 2.0;
+                                    1.0, 2.0, 0.0, -2.0;
+                                    0.0, 3.0, -2.0, 2.0;
+                                    2.0, 1.0, -2.0, -1.0];
+
+    let s: Vector<f64> = a.singular_values().unwrap();
+    let s_ref: Vector<f64> = vector![6.216089837372844; 3.3812545631600996; 3.0918649662716553; 1.1694937978293738];
+
+    assert_relative_eq!(s, s_ref, epsilon = 1.0e-10);
+    assert_relative_eq!(a.dec_svd().unwrap().s(), s_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn pinv_rank_deficient() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    2.0, 4.0;
+                                    3.0, 6.0];
+
+    let a_pinv: General<f64> = a.pinv().unwrap();
+
+    assert_eq!(a_pinv.dim(), (2, 3));
+    assert_relative_eq!(&(&a * &a_pinv) * &a, a, epsilon = 1.0e-10);
+    assert_relative_eq!(&(&a_pinv * &a) * &a_pinv, a_pinv, epsilon = 1.0e-10);
+    assert_relative_eq!(a_pinv, &a.transpose() * &(1.0 / 70.0), epsilon = 1.0e-10);
+}
+
+#[test]
+fn rank() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 9.0];
+
+    assert_eq!(a.rank().unwrap(), 2);
+    assert_eq!(General::<f64>::one(4).rank().unwrap(), 4);
+    assert_eq!(General::<f64>::zero(3, 2).rank().unwrap(), 0);
+}
+
+#[test]
+fn cond() {
+    let a: General<f64> = matrix![  2.0, 0.0;
+                                    0.0, 0.5];
+
+    assert_relative_eq!(a.cond().unwrap(), 4.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn cond_singular() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    2.0, 4.0];
+
+    assert_eq!(a.cond().unwrap(), f64::INFINITY);
+    assert_eq!(General::<f64>::zero(3, 2).cond().unwrap(), f64::INFINITY);
+}
+
+#[test]
+fn cond_empty() {
+    assert_eq!(General::<f64>::zero(0, 0).cond().unwrap(), 0.0);
+}
+
+/// Matrix with orthonormal columns, which are the left singular vectors of
+/// a deterministic pseudo random matrix
+fn orthonormal_columns(m: usize, n: usize, seed: usize) -> General<f64> {
+    let a: General<f64> = General::new(
+        m,
+        n,
+        (0..m * n)
+            .map(|i| (((i + seed) * 7919 % 1009) as f64) / 1009.0 - 0.5)
+            .collect(),
+    );
+    let (u, _, _): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_thin().unwrap().usv();
+    u
+}
+
+/// Matrix $XSY^T$ with the singular values $s_i = 2^{-i}$
+fn decaying_spectrum(m: usize, n: usize, r: usize) -> (General<f64>, Vec<f64>) {
+    let s: Vec<f64> = (0..r).map(|i| 0.5f64.powi(i as i32)).collect();
+    let mut x: General<f64> = orthonormal_columns(m, r, 1);
+    let y: General<f64> = orthonormal_columns(n, r, 2);
+    for j in 0..r {
+        for i in 0..m {
+            x[[i, j]] *= s[j];
+        }
+    }
+    (&x * &y.transpose(), s)
+}
+
+#[test]
+fn svd_randomized_low_rank() {
+    let x: General<f64> = orthonormal_columns(200, 5, 3);
+    let y: General<f64> = orthonormal_columns(50, 5, 4);
+    let c: General<f64> = matrix![  9.0, 1.0, 0.0, 2.0, 1.0;
+                                    1.0, 7.0, 3.0, 0.0, 2.0;
+                                    0.0, 2.0, 5.0, 1.0, 0.0;
+                                    1.0, 0.0, 2.0, 4.0, 1.0;
+                                    3.0, 1.0, 0.0, 1.0, 2.0];
+    let a: General<f64> = &(&x * &c) * &y.transpose();
+
+    let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_randomized(5, 5, 0).unwrap().usv();
+    let (_, s_ref, _): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_thin().unwrap().usv();
+
+    assert_eq!(u.dim(), (200, 5));
+    assert_eq!(s.dim(), (5, 1));
+    assert_eq!(v.dim(), (50, 5));
+    assert_relative_eq!(s, s_ref.get_slice(0, 4), epsilon = 1.0e-10);
+    assert_relative_eq!(a.low_rank_approximation(5, 5, 0).unwrap(), a, epsilon = 1.0e-10);
+}
+
+#[test]
+fn svd_randomized_orthonormal() {
+    let (a, _): (General<f64>, Vec<f64>) = decaying_spectrum(80, 40, 30);
+
+    let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_randomized(6, 5, 1).unwrap().usv();
+
+    assert_relative_eq!(&u.clone().transpose() * &u, General::one(6), epsilon = 1.0e-10);
+    assert_relative_eq!(&v.clone().transpose() * &v, General::one(6), epsilon = 1.0e-10);
+    for i in 1..6 {
+        assert!(s[i - 1] >= s[i]);
+    }
+}
+
+#[test]
+fn svd_randomized_power_iterations() {
+    let (a, s_ref): (General<f64>, Vec<f64>) = decaying_spectrum(100, 60, 40);
+
+    let (_, s, _): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_randomized(5, 5, 2).unwrap().usv();
+
+    assert_relative_eq!(s, Vector::new_column(s_ref[..5].to_vec()), epsilon = 1.0e-8);
+}
+
+#[test]
+fn svd_randomized_wide() {
+    let (a, s_ref): (General<f64>, Vec<f64>) = decaying_spectrum(30, 90, 20);
+
+    let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_randomized(4, 6, 2).unwrap().usv();
+
+    assert_eq!(u.dim(), (30, 4));
+    assert_eq!(v.dim(), (90, 4));
+    assert_relative_eq!(s, Vector::new_column(s_ref[..4].to_vec()), epsilon = 1.0e-8);
+}
+
+#[test]
+fn svd_randomized_tall() {
+    // rank three, the basis of the sample must not be formed with a full m x m factor
+    let m: usize = 20000;
+    let x: General<f64> = General::new(m, 3, (0..3 * m).map(|i| ((i * 7919 % 1009) as f64) / 1009.0 - 0.5).collect());
+    let y: General<f64> = matrix![  1.0, 0.0, 2.0;
+                                    0.5, 1.0, 0.0;
+                                    0.0, 3.0, 1.0;
+                                    2.0, 1.0, 1.0;
+                                    1.0, -1.0, 0.5;
+                                    0.0, 0.5, -2.0;
+                                    1.5, 0.0, 1.0;
+                                    -1.0, 2.0, 0.0];
+    let a: General<f64> = &x * &y.transpose();
+
+    let (u, s, v): (General<f64>, Vector<f64>, General<f64>) = a.dec_svd_randomized(3, 5, 1).unwrap().usv();
+
+    assert_eq!(u.dim(), (m, 3));
+    assert_eq!(v.dim(), (8, 3));
+    assert_relative_eq!(&u.clone().transpose() * &u, General::one(3), epsilon = 1.0e-10);
+    for i in 1..3 {
+        assert!(s[i - 1] >= s[i]);
+    }
+    assert_relative_eq!(a.low_rank_approximation(3, 5, 1).unwrap(), a, epsilon = 1.0e-8);
+}
+
+#[test]
+fn low_rank_approximation_error() {
+    let (a, s_ref): (General<f64>, Vec<f64>) = decaying_spectrum(60, 50, 30);
+
+    let a_k: General<f64> = a.low_rank_approximation(8, 10, 2).unwrap();
+    let tail: f64 = s_ref[8..].iter().map(|s_i| s_i * s_i).sum::<f64>().sqrt();
+
+    assert_relative_eq!((a - a_k).norm_frobenius(), tail, epsilon = 1.0e-6 * tail);
+}
+
+#[test]
+#[should_panic]
+fn svd_randomized_rank_zero() {
+    let a: General<f64> = General::one(3);
+
+    let _ = a.dec_svd_randomized(0, 2, 1);
+}
+
+#[test]
+#[should_panic]
+fn svd_randomized_rank_too_large() {
+    let a: General<f64> = General::zero(4, 3);
+
+    let _ = a.dec_svd_randomized(4, 2, 1);
+}
@@ -2,6 +2,7 @@ use mathru::algebra::abstr::Complex;
 use mathru::algebra::linear::{
     matrix::{General, Solve},
     vector::Vector,
+    LinalgError,
 };
 
 #[test]
@@ -227,5 +228,18 @@ fn solve_vector_no_solution() {
                                     3.0;
                                     -1.0];
 
-    assert_eq!(a.solve(&b), Err(()));
+    assert_eq!(a.solve(&b), Err(LinalgError::Singular));
+}
+
+#[test]
+fn solve_vector_complex_entries() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(2.0, 1.0), Complex::new(1.0, -2.0), Complex::new(0.5, 0.0);
+                                            Complex::new(-1.0, 1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 2.0);
+                                            Complex::new(1.0, 0.0), Complex::new(0.0, -1.0), Complex::new(4.0, -1.0)];
+    let x_ref: Vector<Complex<f64>> = vector![Complex::new(1.0, 1.0); Complex::new(2.0, 0.0); Complex::new(0.0, -1.0)];
+    let b: Vector<Complex<f64>> = &a * &x_ref;
+
+    let x: Vector<Complex<f64>> = a.solve(&b).unwrap();
+
+    assert_relative_eq!(x, x_ref, epsilon = Complex::new(1.0e-10, 1.0e-10));
 }
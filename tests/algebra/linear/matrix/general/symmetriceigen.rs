@@ -0,0 +1,153 @@
+use mathru::algebra::abstr::Complex;
+use mathru::algebra::linear::matrix::{
+    ConjugateTranspose, Diagonal, General, SymmetricEigenDecomposition, Transpose,
+};
+
+#[test]
+fn dec_symmetric_eigen_tridiagonal() {
+    let a: General<f64> = matrix![  2.0, -1.0, 0.0, 0.0, 0.0;
+                                    -1.0, 2.0, -1.0, 0.0, 0.0;
+                                    0.0, -1.0, 2.0, -1.0, 0.0;
+                                    0.0, 0.0, -1.0, 2.0, -1.0;
+                                    0.0, 0.0, 0.0, -1.0, 2.0];
+
+    let values: Vec<f64> = a.dec_symmetric_eigen().unwrap().values();
+
+    for (k, lambda) in values.iter().enumerate() {
+        let lambda_ref: f64 = 2.0 - 2.0 * ((k + 1) as f64 * std::f64::consts::PI / 6.0).cos();
+        assert_relative_eq!(*lambda, lambda_ref, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn dec_symmetric_eigen_f64() {
+    let a: General<f64> = matrix![  4.0, 1.0, -2.0, 2.0, 0.5;
+                                    1.0, 2.0, 0.0, 1.0, -1.0;
+                                    -2.0, 0.0, 3.0, -2.0, 0.0;
+                                    2.0, 1.0, -2.0, -1.0, 3.0;
+                                    0.5, -1.0, 0.0, 3.0, 1.5];
+
+    let (values, vectors): (Vec<f64>, General<f64>) = a.dec_symmetric_eigen().unwrap().pair();
+
+    for k in 1..5 {
+        assert!(values[k - 1] <= values[k]);
+    }
+    assert_relative_eq!(values.iter().sum::<f64>(), 9.5, epsilon = 1.0e-10);
+    assert_relative_eq!(&a * &vectors, &vectors * &General::from(Diagonal::new(&values)), epsilon = 1.0e-10);
+    assert_relative_eq!(&vectors.clone().transpose() * &vectors, General::one(5), epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_symmetric_eigen_f32() {
+    let a: General<f32> = matrix![  2.0, 1.0, 0.0;
+                                    1.0, 3.0, 1.0;
+                                    0.0, 1.0, 4.0];
+
+    let (values, vectors): (Vec<f32>, General<f32>) = a.dec_symmetric_eigen().unwrap().pair();
+
+    assert_relative_eq!(values[0], 3.0 - 3.0f32.sqrt(), epsilon = 1.0e-5);
+    assert_relative_eq!(values[1], 3.0, epsilon = 1.0e-5);
+    assert_relative_eq!(values[2], 3.0 + 3.0f32.sqrt(), epsilon = 1.0e-5);
+    assert_relative_eq!(&a * &vectors, &vectors * &General::from(Diagonal::new(&values)), epsilon = 1.0e-5);
+}
+
+#[test]
+fn dec_symmetric_eigen_multiple_eigenvalue() {
+    let a: General<f64> = matrix![  2.0, 1.0, 1.0;
+                                    1.0, 2.0, 1.0;
+                                    1.0, 1.0, 2.0];
+
+    let (values, vectors): (Vec<f64>, General<f64>) = a.dec_symmetric_eigen().unwrap().pair();
+
+    assert_relative_eq!(values[0], 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(values[1], 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(values[2], 4.0, epsilon = 1.0e-10);
+    assert_relative_eq!(&vectors.clone().transpose() * &vectors, General::one(3), epsilon = 1.0e-10);
+}
+
+#[test]
+fn dec_symmetric_eigen_range() {
+    let a: General<f64> = matrix![  4.0, 1.0, -2.0, 2.0, 0.5;
+                                    1.0, 2.0, 0.0, 1.0, -1.0;
+                                    -2.0, 0.0, 3.0, -2.0, 0.0;
+                                    2.0, 1.0, -2.0, -1.0, 3.0;
+                                    0.5, -1.0, 0.0, 3.0, 1.5];
+
+    let values_all: Vec<f64> = a.dec_symmetric_eigen().unwrap().values();
+    let (values, vectors): (Vec<f64>, General<f64>) = a.dec_symmetric_eigen_range(1..3).unwrap().pair();
+
+    assert_eq!(values.len(), 2);
+    assert_eq!(vectors.dim(), (5, 2));
+    assert_relative_eq!(values[0], values_all[1], epsilon = 1.0e-10);
+    assert_relative_eq!(values[1], values_all[2], epsilon = 1.0e-10);
+    assert_relative_eq!(&a * &vectors, &vectors * &General::from(Diagonal::new(&values)), epsilon = 1.0e-8);
+    assert_relative_eq!(&vectors.clone().transpose() * &vectors, General::one(2), epsilon = 1.0e-8);
+}
+
+#[test]
+fn dec_symmetric_eigen_range_multiple_eigenvalue() {
+    let a: General<f64> = matrix![  2.0, 1.0, 1.0;
+                                    1.0, 2.0, 1.0;
+                                    1.0, 1.0, 2.0];
+
+    let (values, vectors): (Vec<f64>, General<f64>) = a.dec_symmetric_eigen_range(0..2).unwrap().pair();
+
+    assert_relative_eq!(values[0], 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(values[1], 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(&a * &vectors, &vectors, epsilon = 1.0e-8);
+    assert_relative_eq!(&vectors.clone().transpose() * &vectors, General::one(2), epsilon = 1.0e-8);
+}
+
+#[test]
+fn dec_symmetric_eigen_lower_triangle() {
+    let a: General<f64> = matrix![  2.0, 1.0;
+                                    1.0, 2.0];
+    let b: General<f64> = matrix![  2.0, 100.0;
+                                    1.0, 2.0];
+
+    assert_relative_eq!(
+        a.dec_symmetric_eigen().unwrap().vectors(),
+        b.dec_symmetric_eigen().unwrap().vectors()
+    );
+}
+
+#[test]
+fn dec_symmetric_eigen_hermitian() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(4.0, 0.0), Complex::new(1.0, 1.0), Complex::new(0.0, -2.0), Complex::new(0.5, 0.0);
+                                            Complex::new(1.0, -1.0), Complex::new(2.0, 0.0), Complex::new(1.0, 0.5), Complex::new(0.0, 1.0);
+                                            Complex::new(0.0, 2.0), Complex::new(1.0, -0.5), Complex::new(3.0, 0.0), Complex::new(-1.0, 0.0);
+                                            Complex::new(0.5, 0.0), Complex::new(0.0, -1.0), Complex::new(-1.0, 0.0), Complex::new(-1.0, 0.0)];
+
+    let (values, vectors): (Vec<f64>, General<Complex<f64>>) = a.dec_symmetric_eigen().unwrap().pair();
+
+    for k in 1..4 {
+        assert!(values[k - 1] <= values[k]);
+    }
+    let values_complex: Vec<Complex<f64>> = values.iter().map(|v| Complex::new(*v, 0.0)).collect();
+    let d: General<Complex<f64>> = General::from(Diagonal::new(&values_complex));
+    assert_relative_eq!(&a * &vectors, &vectors * &d, epsilon = Complex::new(1.0e-10, 1.0e-10));
+    assert_relative_eq!(&vectors.clone().conj_transpose() * &vectors, General::one(4), epsilon = Complex::new(1.0e-10, 1.0e-10));
+}
+
+#[test]
+fn dec_symmetric_eigen_hermitian_range() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(4.0, 0.0), Complex::new(1.0, 1.0), Complex::new(0.0, -2.0);
+                                            Complex::new(1.0, -1.0), Complex::new(2.0, 0.0), Complex::new(1.0, 0.5);
+                                            Complex::new(0.0, 2.0), Complex::new(1.0, -0.5), Complex::new(3.0, 0.0)];
+
+    let values_all: Vec<f64> = a.dec_symmetric_eigen().unwrap().values();
+    let (values, vectors): (Vec<f64>, General<Complex<f64>>) = a.dec_symmetric_eigen_range(2..3).unwrap().pair();
+
+    assert_relative_eq!(values[0], values_all[2], epsilon = 1.0e-10);
+    let lambda: Complex<f64> = Complex::new(values[0], 0.0);
+    assert_relative_eq!(&a * &vectors, &vectors * &General::from(Diagonal::new(&[lambda])), epsilon = Complex::new(1.0e-8, 1.0e-8));
+}
+
+#[test]
+#[should_panic]
+fn dec_symmetric_eigen_range_out_of_bounds() {
+    let a: General<f64> = matrix![  2.0, 1.0;
+                                    1.0, 2.0];
+
+    let _ = a.dec_symmetric_eigen_range(1..3);
+}
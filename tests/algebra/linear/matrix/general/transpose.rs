@@ -1,5 +1,5 @@
 use mathru::algebra::abstr::Complex;
-use mathru::algebra::linear::matrix::{General, Transpose};
+use mathru::algebra::linear::matrix::{ConjugateTranspose, General, Transpose};
 
 #[test]
 fn transpose_f32() {
@@ -102,3 +102,23 @@ fn transpose_complex_f64() {
 
     assert_relative_eq!(uut_t_ref, uut.transpose());
 }
+
+#[test]
+fn conj_transpose_complex() {
+    let uut: General<Complex<f64>> = matrix![   Complex::new(1.0, 2.0), Complex::new(3.0, -4.0), Complex::new(0.0, 1.0);
+                                                Complex::new(-1.0, 0.0), Complex::new(0.5, 0.5), Complex::new(2.0, -2.0)];
+
+    let res_ref: General<Complex<f64>> = matrix![   Complex::new(1.0, -2.0), Complex::new(-1.0, 0.0);
+                                                    Complex::new(3.0, 4.0), Complex::new(0.5, -0.5);
+                                                    Complex::new(0.0, -1.0), Complex::new(2.0, 2.0)];
+
+    assert_eq!(uut.conj_transpose(), res_ref);
+}
+
+#[test]
+fn conj_transpose_real() {
+    let uut: General<f64> = matrix![ 1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0];
+
+    assert_eq!(uut.clone().conj_transpose(), uut.transpose());
+}
@@ -0,0 +1,14 @@
+use mathru::algebra::linear::matrix::{General, LowerTriangular, MatrixNorm};
+
+#[test]
+fn norm() {
+    let a: General<f64> = matrix![  1.0, 0.0, 0.0;
+                                    -2.0, 4.0, 0.0;
+                                    3.0, 1.0, -5.0];
+    let l: LowerTriangular<f64> = LowerTriangular::new(a.clone());
+
+    assert_relative_eq!(l.norm_1(), a.norm_1());
+    assert_relative_eq!(l.norm_inf(), a.norm_inf());
+    assert_relative_eq!(l.norm_frobenius(), a.norm_frobenius());
+    assert_relative_eq!(l.norm_2(), a.norm_2(), epsilon = 1.0e-10);
+}
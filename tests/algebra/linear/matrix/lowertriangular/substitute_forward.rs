@@ -2,6 +2,7 @@ use mathru::algebra::linear::matrix::General;
 use mathru::algebra::linear::matrix::LowerTriangular;
 use mathru::algebra::linear::matrix::SubstituteForward;
 use mathru::algebra::linear::vector::Vector;
+use mathru::algebra::linear::LinalgError;
 
 #[test]
 fn subst_forward() {
@@ -55,9 +56,9 @@ fn subst_forward_error() {
                                 7.0; 
                                 7.0];
 
-    let c: Result<Vector<f64>, ()> = a.substitute_forward(b);
+    let c: Result<Vector<f64>, LinalgError> = a.substitute_forward(b);
 
-    assert_eq!(c, Result::Err(()));
+    assert_eq!(c, Result::Err(LinalgError::Singular));
 }
 
 #[cfg(feature = "native")]
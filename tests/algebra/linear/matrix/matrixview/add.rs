@@ -0,0 +1,36 @@
+use mathru::algebra::linear::matrix::General;
+
+#[test]
+fn add_view_view() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 9.0];
+
+    let c: General<f64> = &a.view_slice(0, 1, 0, 1) + &a.view_slice(1, 2, 1, 2);
+
+    assert_eq!(c, matrix![6.0, 8.0; 12.0, 14.0]);
+}
+
+#[test]
+fn add_view_general() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    3.0, 4.0];
+    let b: General<f64> = General::one(2);
+
+    assert_eq!(&a.view().transpose() + &b, matrix![2.0, 3.0; 2.0, 5.0]);
+    assert_eq!(&b + &a.view(), matrix![2.0, 2.0; 3.0, 5.0]);
+}
+
+#[test]
+fn add_assign() {
+    let mut a: General<f64> = General::zero(3, 3);
+    let b: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+
+    let mut v = a.view_slice_mut(1, 2, 1, 2);
+    v += &b.view();
+    v += &b.view().transpose();
+
+    assert_eq!(a, matrix![  0.0, 0.0, 0.0;
+                            0.0, 2.0, 5.0;
+                            0.0, 5.0, 8.0]);
+}
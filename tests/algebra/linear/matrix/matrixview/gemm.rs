@@ -0,0 +1,110 @@
+use mathru::algebra::{
+    abstr::Complex,
+    linear::matrix::{General, MatrixView, Transpose},
+};
+
+#[test]
+fn gemm_sub_block() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    3.0, 4.0];
+    let b: General<f64> = matrix![  1.0, -1.0;
+                                    2.0, 0.5];
+    let mut c: General<f64> = General::one(4);
+
+    c.view_slice_mut(1, 2, 1, 2)
+        .gemm(2.0, &a.view(), &b.view(), -1.0);
+
+    let block: General<f64> = (&a * &b) * 2.0 - General::one(2);
+    let mut c_ref: General<f64> = General::one(4);
+    c_ref = c_ref.set_slice(&block, 1, 1);
+
+    assert_relative_eq!(c, c_ref);
+}
+
+#[test]
+fn gemm_transposed_operands() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0];
+    let b: General<f64> = matrix![  1.0, 0.0, -1.0;
+                                    2.0, 1.0, 0.5];
+    let mut c: General<f64> = General::zero(2, 2);
+
+    c.view_mut()
+        .gemm(1.0, &a.view(), &b.view().transpose(), 0.0);
+
+    assert_relative_eq!(c, &a * &b.clone().transpose());
+}
+
+#[test]
+fn gemm_transposed_output() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    3.0, 4.0;
+                                    5.0, 6.0];
+    let b: General<f64> = matrix![  1.0, -1.0;
+                                    2.0, 0.5];
+    let mut c: General<f64> = General::zero(2, 3);
+
+    c.view_mut()
+        .transpose_mut()
+        .gemm(1.0, &a.view(), &b.view(), 0.0);
+
+    assert_relative_eq!(c, (&a * &b).transpose());
+}
+
+#[test]
+fn gemm_diagonal_views() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 9.0];
+    let mut c: General<f64> = General::zero(3, 3);
+
+    // outer product of the diagonal with itself
+    let d: MatrixView<f64> = a.view_diagonal();
+    c.view_mut().gemm(1.0, &d, &d.transpose(), 0.0);
+
+    let d_ref: General<f64> = matrix![1.0; 5.0; 9.0];
+    assert_relative_eq!(c, &d_ref * &d_ref.clone().transpose());
+
+    // write into the diagonal
+    let x: General<f64> = matrix![1.0, 1.0; 1.0, -1.0; 0.0, 2.0];
+    let y: General<f64> = matrix![1.0; 2.0];
+    c.view_diagonal_mut()
+        .gemm(1.0, &x.view(), &y.view(), 1.0);
+    assert_relative_eq!(General::from(c.view_diagonal()), matrix![4.0; 24.0; 85.0]);
+}
+
+#[test]
+fn gemm_inner_dimension_zero() {
+    let a: General<f64> = General::zero(2, 0);
+    let b: General<f64> = General::zero(0, 2);
+    let mut c: General<f64> = General::one(2);
+
+    c.view_mut().gemm(1.0, &a.view(), &b.view(), 3.0);
+
+    assert_eq!(c, General::one(2) * 3.0);
+}
+
+#[test]
+#[should_panic]
+fn gemm_dimension_mismatch() {
+    let a: General<f64> = General::zero(2, 3);
+    let mut c: General<f64> = General::zero(2, 2);
+
+    c.view_mut().gemm(1.0, &a.view(), &a.view(), 0.0);
+}
+
+#[test]
+fn gemm_complex() {
+    let a: General<Complex<f64>> = matrix![ Complex::new(1.0, 1.0), Complex::new(0.0, 2.0);
+                                            Complex::new(3.0, 0.0), Complex::new(1.0, -1.0)];
+    let mut c: General<Complex<f64>> = General::zero(2, 2);
+
+    c.view_mut().gemm(
+        Complex::new(1.0, 0.0),
+        &a.view().transpose(),
+        &a.view(),
+        Complex::new(0.0, 0.0),
+    );
+
+    assert_relative_eq!(c, &a.clone().transpose() * &a);
+}
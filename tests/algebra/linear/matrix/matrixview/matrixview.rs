@@ -0,0 +1,119 @@
+use mathru::algebra::linear::{
+    matrix::{General, MatrixView, Transpose},
+    vector::Vector,
+};
+
+fn matrix_3x4() -> General<f64> {
+    matrix![    1.0, 2.0, 3.0, 4.0;
+                5.0, 6.0, 7.0, 8.0;
+                9.0, 10.0, 11.0, 12.0]
+}
+
+#[test]
+fn view() {
+    let a: General<f64> = matrix_3x4();
+
+    let v: MatrixView<f64> = a.view();
+
+    assert_eq!(v.dim(), (3, 4));
+    assert_eq!(v.strides(), (1, 3));
+    assert_eq!(General::from(v), a);
+}
+
+#[test]
+fn view_slice() {
+    let a: General<f64> = matrix_3x4();
+
+    let v: MatrixView<f64> = a.view_slice(1, 2, 1, 3);
+
+    assert_eq!(General::from(v), a.get_slice(1, 2, 1, 3));
+}
+
+#[test]
+fn view_slice_of_slice() {
+    let a: General<f64> = matrix_3x4();
+
+    let v: MatrixView<f64> = a.view_slice(1, 2, 1, 3).slice(0, 1, 1, 1);
+
+    assert_eq!(General::from(v), matrix![7.0; 11.0]);
+}
+
+#[test]
+fn row() {
+    let a: General<f64> = matrix_3x4();
+
+    assert_eq!(General::from(a.view_row(1)), matrix![5.0, 6.0, 7.0, 8.0]);
+}
+
+#[test]
+fn column() {
+    let a: General<f64> = matrix_3x4();
+
+    assert_eq!(General::from(a.view_column(2)), matrix![3.0; 7.0; 11.0]);
+}
+
+#[test]
+fn diagonal() {
+    let a: General<f64> = matrix_3x4();
+
+    assert_eq!(General::from(a.view_diagonal()), matrix![1.0; 6.0; 11.0]);
+    assert_eq!(
+        General::from(a.view_slice(0, 2, 1, 3).diagonal()),
+        matrix![2.0; 7.0; 12.0]
+    );
+}
+
+#[test]
+fn transpose() {
+    let a: General<f64> = matrix_3x4();
+
+    assert_eq!(General::from(a.view().transpose()), a.clone().transpose());
+}
+
+#[test]
+fn index() {
+    let a: General<f64> = matrix_3x4();
+    let v: MatrixView<f64> = a.view_slice(1, 2, 2, 3);
+
+    assert_eq!(v[[0, 0]], 7.0);
+    assert_eq!(v[[1, 1]], 12.0);
+    assert_eq!(v.get(1, 0), Some(&11.0));
+    assert_eq!(v.get(2, 0), None);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+    let a: General<f64> = matrix_3x4();
+    let v: MatrixView<f64> = a.view_slice(1, 2, 2, 3);
+
+    let _ = v[[2, 0]];
+}
+
+#[test]
+fn new_strided() {
+    let data: Vec<f64> = (0..12).map(|i| i as f64).collect();
+
+    // row major 3 x 4 matrix
+    let v: MatrixView<f64> = MatrixView::new(&data, 3, 4, 4, 1);
+
+    assert_eq!(v[[1, 2]], 6.0);
+    assert_eq!(General::from(v.column(3)), matrix![3.0; 7.0; 11.0]);
+}
+
+#[test]
+#[should_panic]
+fn new_too_short() {
+    let data: Vec<f64> = vec![0.0; 5];
+
+    let _ = MatrixView::new(&data, 2, 3, 1, 2);
+}
+
+#[test]
+fn vector_view_slice() {
+    let a: Vector<f64> = vector![1.0; 2.0; 3.0; 4.0];
+    let b: Vector<f64> = vector![1.0, 2.0, 3.0, 4.0];
+
+    assert_eq!(General::from(a.view_slice(1, 3)), matrix![2.0; 3.0; 4.0]);
+    assert_eq!(General::from(b.view_slice(0, 1)), matrix![1.0, 2.0]);
+}
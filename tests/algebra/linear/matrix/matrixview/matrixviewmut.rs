@@ -0,0 +1,103 @@
+use mathru::algebra::linear::{
+    matrix::{General, MatrixViewMut},
+    vector::Vector,
+};
+
+#[test]
+fn view_slice_mut() {
+    let mut a: General<f64> = General::zero(3, 3);
+
+    {
+        let mut v: MatrixViewMut<f64> = a.view_slice_mut(1, 2, 0, 1);
+        v[[0, 0]] = 1.0;
+        v[[1, 1]] = 2.0;
+        *v.get_mut(0, 1).unwrap() = 3.0;
+        assert!(v.get_mut(2, 0).is_none());
+    }
+
+    assert_eq!(a, matrix![  0.0, 0.0, 0.0;
+                            1.0, 3.0, 0.0;
+                            0.0, 2.0, 0.0]);
+}
+
+#[test]
+fn copy_from() {
+    let mut a: General<f64> = General::zero(3, 3);
+    let b: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+
+    a.view_slice_mut(0, 1, 1, 2).copy_from(&b.view().transpose());
+
+    assert_eq!(a, matrix![  0.0, 1.0, 3.0;
+                            0.0, 2.0, 4.0;
+                            0.0, 0.0, 0.0]);
+}
+
+#[test]
+#[should_panic]
+fn copy_from_dimension_mismatch() {
+    let mut a: General<f64> = General::zero(3, 3);
+    let b: General<f64> = General::zero(2, 3);
+
+    a.view_slice_mut(0, 1, 1, 2).copy_from(&b.view());
+}
+
+#[test]
+fn row_column_diagonal_mut() {
+    let mut a: General<f64> = General::zero(3, 3);
+
+    a.view_row_mut(0).fill(1.0);
+    a.view_column_mut(2).fill(2.0);
+    a.view_diagonal_mut().fill(3.0);
+
+    assert_eq!(a, matrix![  3.0, 1.0, 2.0;
+                            0.0, 3.0, 2.0;
+                            0.0, 0.0, 3.0]);
+}
+
+#[test]
+fn nested_mut() {
+    let mut a: General<f64> = General::zero(3, 3);
+
+    {
+        let mut v: MatrixViewMut<f64> = a.view_slice_mut(1, 2, 1, 2);
+        v.row_mut(1).fill(1.0);
+        v.column_mut(0).scale(5.0);
+        v.transpose_mut()[[0, 1]] = 7.0;
+        v.diagonal_mut()[[0, 0]] = 4.0;
+    }
+
+    assert_eq!(a, matrix![  0.0, 0.0, 0.0;
+                            0.0, 4.0, 0.0;
+                            0.0, 7.0, 1.0]);
+}
+
+#[test]
+fn split_at_column_mut() {
+    let mut a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                        4.0, 5.0, 6.0;
+                                        7.0, 8.0, 9.0];
+
+    {
+        let v: MatrixViewMut<f64> = a.view_slice_mut(0, 1, 0, 2);
+        let (mut left, mut right): (MatrixViewMut<f64>, MatrixViewMut<f64>) =
+            v.split_at_column_mut(2);
+        assert_eq!(left.dim(), (2, 2));
+        assert_eq!(right.dim(), (2, 1));
+
+        right += &left.as_view().column(0);
+        left.fill(0.0);
+    }
+
+    assert_eq!(a, matrix![  0.0, 0.0, 4.0;
+                            0.0, 0.0, 10.0;
+                            7.0, 8.0, 9.0]);
+}
+
+#[test]
+fn vector_view_slice_mut() {
+    let mut a: Vector<f64> = vector![1.0; 2.0; 3.0; 4.0];
+
+    a.view_slice_mut(1, 2).fill(0.0);
+
+    assert_eq!(a, vector![1.0; 0.0; 0.0; 4.0]);
+}
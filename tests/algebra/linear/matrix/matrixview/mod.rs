@@ -0,0 +1,7 @@
+mod add;
+mod gemm;
+mod matrixview;
+mod matrixviewmut;
+mod mul;
+mod solve;
+mod sub;
@@ -0,0 +1,35 @@
+use mathru::algebra::linear::{
+    matrix::{General, Transpose},
+    vector::Vector,
+};
+
+#[test]
+fn mul_view_view() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 9.0];
+
+    let c: General<f64> = &a.view_slice(0, 1, 0, 2) * &a.view_slice(0, 2, 1, 2);
+
+    assert_relative_eq!(c, &a.get_slice(0, 1, 0, 2) * &a.get_slice(0, 2, 1, 2));
+}
+
+#[test]
+fn mul_view_general() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0];
+    let b: General<f64> = matrix![  1.0, -1.0;
+                                    0.5, 2.0];
+
+    assert_relative_eq!(&a.view().transpose() * &b, &a.clone().transpose() * &b);
+    assert_relative_eq!(&b * &a.view_slice(0, 1, 1, 2), &b * &a.get_slice(0, 1, 1, 2));
+}
+
+#[test]
+fn mul_view_vector() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0];
+    let x: Vector<f64> = vector![1.0; 2.0];
+
+    assert_relative_eq!(&a.view().transpose() * &x, vector![9.0; 12.0; 15.0]);
+}
@@ -0,0 +1,28 @@
+use mathru::algebra::linear::{
+    matrix::{General, Solve, Transpose},
+    vector::Vector,
+};
+
+#[test]
+fn solve_vector() {
+    let a: General<f64> = matrix![  9.0, 9.0, 9.0;
+                                    9.0, 6.0, 2.0;
+                                    9.0, -1.0, 3.0];
+    let b: Vector<f64> = vector![2.0; 5.0];
+
+    let x: Vector<f64> = a.view_slice(1, 2, 1, 2).solve(&b).unwrap();
+
+    assert_relative_eq!(&a.get_slice(1, 2, 1, 2) * &x, b, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_matrix() {
+    let a: General<f64> = matrix![  6.0, 2.0;
+                                    -1.0, 3.0];
+    let b: General<f64> = matrix![  1.0, 0.0;
+                                    2.0, 1.0];
+
+    let x: General<f64> = a.view().transpose().solve(&b).unwrap();
+
+    assert_relative_eq!(&a.clone().transpose() * &x, b, epsilon = 1.0e-10);
+}
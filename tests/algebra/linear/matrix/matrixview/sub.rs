@@ -0,0 +1,33 @@
+use mathru::algebra::linear::matrix::General;
+
+#[test]
+fn sub_view_view() {
+    let a: General<f64> = matrix![  1.0, 2.0, 3.0;
+                                    4.0, 5.0, 6.0;
+                                    7.0, 8.0, 9.0];
+
+    let c: General<f64> = &a.view_slice(1, 2, 1, 2) - &a.view_slice(0, 1, 0, 1);
+
+    assert_eq!(c, matrix![4.0, 4.0; 4.0, 4.0]);
+}
+
+#[test]
+fn sub_view_general() {
+    let a: General<f64> = matrix![  1.0, 2.0;
+                                    3.0, 4.0];
+    let b: General<f64> = General::one(2);
+
+    assert_eq!(&a.view() - &b, matrix![0.0, 2.0; 3.0, 3.0]);
+    assert_eq!(&b - &a.view().transpose(), matrix![0.0, -3.0; -2.0, -3.0]);
+}
+
+#[test]
+fn sub_assign() {
+    let mut a: General<f64> = General::one(2);
+    let b: General<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+
+    let mut v = a.view_mut();
+    v -= &b.view();
+
+    assert_eq!(a, matrix![0.0, -2.0; -3.0, -3.0]);
+}
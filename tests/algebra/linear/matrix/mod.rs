@@ -5,4 +5,15 @@ mod unituppertriangular;
 mod upperhessenberg;
 mod uppertriangular;
 
+mod banded;
+mod circulant;
+mod toeplitz;
+mod tridiagonal;
+mod vandermonde;
+
+mod coomatrix;
+mod cscmatrix;
+mod csrmatrix;
 mod diagonal;
+mod matrixview;
+mod smatrix;
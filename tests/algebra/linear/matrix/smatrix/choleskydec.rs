@@ -0,0 +1,31 @@
+use mathru::algebra::linear::matrix::{SMatrix, SVector, Solve, Transpose};
+
+#[test]
+fn cholesky() {
+    let a: SMatrix<f64, 3, 3> =
+        SMatrix::from_rows([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+
+    let l: SMatrix<f64, 3, 3> = a.dec_cholesky().unwrap().l();
+
+    assert_eq!(l[[0, 1]], 0.0);
+    assert_eq!(l[[0, 2]], 0.0);
+    assert_eq!(l[[1, 2]], 0.0);
+    assert_relative_eq!(l * l.transpose(), a, epsilon = 1.0e-10);
+}
+
+#[test]
+fn cholesky_solve() {
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[4.0, 2.0], [2.0, 5.0]]);
+    let b: SVector<f64, 2> = SVector::new_column([6.0, 7.0]);
+
+    let x: SVector<f64, 2> = a.dec_cholesky().unwrap().solve(&b).unwrap();
+
+    assert_relative_eq!(x, SVector::new_column([1.0, 1.0]), epsilon = 1.0e-10);
+}
+
+#[test]
+fn cholesky_not_positive_definite() {
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [2.0, 1.0]]);
+
+    assert!(a.dec_cholesky().is_err());
+}
@@ -0,0 +1,28 @@
+use mathru::algebra::linear::matrix::{Determinant, General, SMatrix};
+
+#[test]
+fn det_2x2() {
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, -2.0], [3.0, -7.0]]);
+
+    assert_relative_eq!(a.det(), -1.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn det_4x4() {
+    let a: SMatrix<f64, 4, 4> = SMatrix::from_rows([
+        [4.0, 1.0, -2.0, 2.0],
+        [1.0, 2.0, 0.0, -2.0],
+        [0.0, 3.0, -2.0, 2.0],
+        [2.0, 1.0, -2.0, -1.0],
+    ]);
+
+    assert_relative_eq!(a.det(), General::from(a).det(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn det_singular() {
+    let a: SMatrix<f64, 3, 3> =
+        SMatrix::from_rows([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]);
+
+    assert_eq!(a.det(), 0.0);
+}
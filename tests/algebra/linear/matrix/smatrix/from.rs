@@ -0,0 +1,41 @@
+use mathru::algebra::linear::{
+    matrix::{General, SMatrix, SVector},
+    vector::Vector,
+    LinalgError,
+};
+
+#[test]
+fn general_from_smatrix() {
+    let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    assert_eq!(General::from(a), matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0]);
+}
+
+#[test]
+fn smatrix_try_from_general() {
+    let a: General<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+    let b: SMatrix<f64, 2, 3> = SMatrix::try_from(a.clone()).unwrap();
+
+    assert_eq!(b, SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+    assert_eq!(
+        SMatrix::<f64, 3, 2>::try_from(a).err(),
+        Some(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn vector_from_svector() {
+    let v: SVector<f64, 3> = SVector::new_column([1.0, 2.0, 3.0]);
+
+    assert_eq!(Vector::from(v), vector![1.0; 2.0; 3.0]);
+}
+
+#[test]
+fn svector_try_from_vector() {
+    let v: SVector<f64, 2> = SVector::try_from(vector![1.0; 2.0]).unwrap();
+
+    assert_eq!(v, SVector::new_column([1.0, 2.0]));
+    assert!(SVector::<f64, 2>::try_from(vector![1.0, 2.0]).is_err());
+    assert!(SVector::<f64, 3>::try_from(vector![1.0; 2.0]).is_err());
+}
@@ -0,0 +1,23 @@
+use mathru::algebra::linear::matrix::{Inverse, SMatrix};
+
+#[test]
+fn inv() {
+    let a: SMatrix<f64, 3, 3> =
+        SMatrix::from_rows([[1.0, -2.0, 3.0], [2.0, -5.0, 12.0], [0.0, 2.0, -10.0]]);
+
+    let a_inv: SMatrix<f64, 3, 3> = a.inv().unwrap();
+
+    assert_relative_eq!(
+        a_inv,
+        SMatrix::from_rows([[-13.0, 7.0, 4.5], [-10.0, 5.0, 3.0], [-2.0, 1.0, 0.5]]),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(a * a_inv, SMatrix::one(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn inv_singular() {
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [2.0, 4.0]]);
+
+    assert!(a.inv().is_err());
+}
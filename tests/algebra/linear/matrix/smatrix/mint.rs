@@ -0,0 +1,51 @@
+use mathru::algebra::linear::matrix::{SMatrix, SVector};
+use mint::{ColumnMatrix2x3, RowMatrix2x3, Vector2, Vector3};
+
+#[test]
+fn from_column_matrix_2x3() {
+    let m_mint = ColumnMatrix2x3 {
+        x: Vector2 { x: 1.0, y: 4.0 },
+        y: Vector2 { x: 2.0, y: 5.0 },
+        z: Vector2 { x: 3.0, y: 6.0 },
+    };
+
+    let m: SMatrix<f64, 2, 3> = SMatrix::from(m_mint);
+
+    assert_eq!(m, SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+    assert_eq!(ColumnMatrix2x3::from(m), m_mint);
+}
+
+#[test]
+fn from_row_matrix_2x3() {
+    let m_mint = RowMatrix2x3 {
+        x: Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        },
+        y: Vector3 {
+            x: 4.0,
+            y: 5.0,
+            z: 6.0,
+        },
+    };
+
+    let m: SMatrix<f64, 2, 3> = SMatrix::from(m_mint);
+
+    assert_eq!(m, SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+    assert_eq!(RowMatrix2x3::from(m), m_mint);
+}
+
+#[test]
+fn from_vector_3() {
+    let v_mint = Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+
+    let v: SVector<f64, 3> = SVector::from(v_mint);
+
+    assert_eq!(v, SVector::new_column([1.0, 2.0, 3.0]));
+    assert_eq!(Vector3::from(v), v_mint);
+}
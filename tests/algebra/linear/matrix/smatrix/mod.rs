@@ -0,0 +1,13 @@
+mod add;
+mod choleskydec;
+mod det;
+mod from;
+mod inverse;
+#[cfg(feature = "convert-mint")]
+mod mint;
+mod mul;
+mod qrdec;
+mod smatrix;
+mod solve;
+mod sub;
+mod transpose;
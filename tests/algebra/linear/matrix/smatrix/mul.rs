@@ -0,0 +1,43 @@
+use mathru::algebra::linear::matrix::{General, SMatrix, SVector};
+use mathru::matrix;
+
+#[test]
+fn mul_matrix() {
+    let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let b: SMatrix<f64, 3, 2> = SMatrix::from_rows([[1.0, -1.0], [0.0, 2.0], [3.0, 0.5]]);
+
+    let c: SMatrix<f64, 2, 2> = a * b;
+
+    assert_eq!(c, SMatrix::from_rows([[10.0, 4.5], [22.0, 9.0]]));
+}
+
+#[test]
+fn mul_matrix_general() {
+    let a: SMatrix<f64, 3, 4> = SMatrix::from_rows([
+        [1.0, 2.0, 3.0, 4.0],
+        [-1.0, 0.5, 2.0, 0.0],
+        [3.0, -2.0, 1.0, 7.0],
+    ]);
+    let b: SMatrix<f64, 4, 2> =
+        SMatrix::from_rows([[1.0, -1.0], [0.0, 2.0], [3.0, 0.5], [2.0, 1.0]]);
+
+    assert_eq!(General::from(a * b), &General::from(a) * &General::from(b));
+}
+
+#[test]
+fn mul_vector() {
+    let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let x: SVector<f64, 3> = SVector::new_column([1.0, 0.0, -1.0]);
+
+    assert_eq!(a * x, SVector::new_column([-2.0, -2.0]));
+}
+
+#[test]
+fn mul_scalar() {
+    let mut a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+
+    assert_eq!(a * 2.0, SMatrix::from_rows([[2.0, 4.0], [6.0, 8.0]]));
+
+    a *= -1.0;
+    assert_eq!(General::from(a), matrix![-1.0, -2.0; -3.0, -4.0]);
+}
@@ -0,0 +1,38 @@
+use mathru::algebra::linear::matrix::{SMatrix, Transpose};
+
+#[test]
+fn qr_square() {
+    let a: SMatrix<f64, 3, 3> =
+        SMatrix::from_rows([[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]]);
+
+    let (q, r): (SMatrix<f64, 3, 3>, SMatrix<f64, 3, 3>) = a.dec_qr().unwrap().qr();
+
+    assert_relative_eq!(q * r, a, epsilon = 1.0e-10);
+    assert_relative_eq!(q.transpose() * q, SMatrix::one(), epsilon = 1.0e-10);
+    assert_eq!(r[[1, 0]], 0.0);
+    assert_eq!(r[[2, 0]], 0.0);
+    assert_eq!(r[[2, 1]], 0.0);
+}
+
+#[test]
+fn qr_tall() {
+    let a: SMatrix<f64, 4, 2> =
+        SMatrix::from_rows([[1.0, 2.0], [3.0, -1.0], [0.0, 4.0], [-2.0, 1.0]]);
+
+    let (q, r): (SMatrix<f64, 4, 4>, SMatrix<f64, 4, 2>) = a.dec_qr().unwrap().qr();
+
+    assert_relative_eq!(q * r, a, epsilon = 1.0e-10);
+    assert_relative_eq!(q.transpose() * q, SMatrix::one(), epsilon = 1.0e-10);
+    assert_eq!(r[[2, 1]], 0.0);
+    assert_eq!(r[[3, 1]], 0.0);
+}
+
+#[test]
+fn qr_wide() {
+    let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    let (q, r): (SMatrix<f64, 2, 2>, SMatrix<f64, 2, 3>) = a.dec_qr().unwrap().qr();
+
+    assert_relative_eq!(q * r, a, epsilon = 1.0e-10);
+    assert_eq!(r[[1, 0]], 0.0);
+}
@@ -0,0 +1,64 @@
+use mathru::algebra::linear::matrix::{SMatrix, SVector};
+
+#[test]
+fn new() {
+    let a: SMatrix<f64, 2, 3> = SMatrix::new([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+
+    assert_eq!(a, SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+    assert_eq!(a.dim(), (2, 3));
+    assert_eq!(a.into_columns(), [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+}
+
+#[test]
+fn index() {
+    let mut a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    a[[1, 0]] = -4.0;
+
+    assert_eq!(a[[0, 2]], 3.0);
+    assert_eq!(a[[1, 0]], -4.0);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+    let a: SMatrix<f64, 2, 3> = SMatrix::zero();
+    let _ = a[[2, 0]];
+}
+
+#[test]
+fn one() {
+    let a: SMatrix<f64, 3, 3> = SMatrix::one();
+
+    assert_eq!(
+        a,
+        SMatrix::from_rows([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    );
+    assert_eq!(a.trace(), 3.0);
+}
+
+#[test]
+fn row_column() {
+    let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    assert_eq!(a.column(1), SVector::new_column([2.0, 5.0]));
+    assert_eq!(a.row(1), SMatrix::from_rows([[4.0, 5.0, 6.0]]));
+}
+
+#[test]
+fn vector() {
+    let mut v: SVector<f64, 3> = SVector::new_column([1.0, 2.0, 2.0]);
+
+    assert_eq!(v[1], 2.0);
+    assert_eq!(v.norm(), 3.0);
+    assert_eq!(v.dot(&v), 9.0);
+
+    v[0] = 0.0;
+    assert_eq!(v, SVector::new_column([0.0, 2.0, 2.0]));
+}
+
+#[test]
+fn neg() {
+    let a: SMatrix<f64, 1, 2> = SMatrix::from_rows([[1.0, -2.0]]);
+
+    assert_eq!(-a, SMatrix::from_rows([[-1.0, 2.0]]));
+}
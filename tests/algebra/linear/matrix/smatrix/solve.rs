@@ -0,0 +1,30 @@
+use mathru::algebra::linear::matrix::{SMatrix, SVector, Solve};
+
+#[test]
+fn solve() {
+    let a: SMatrix<f64, 3, 3> =
+        SMatrix::from_rows([[6.0, 2.0, -1.0], [-3.0, 5.0, 3.0], [-2.0, 1.0, 3.0]]);
+    let b: SVector<f64, 3> = SVector::new_column([48.0, 16.0, 3.0]);
+
+    let x: SVector<f64, 3> = a.solve(&b).unwrap();
+
+    assert_relative_eq!(x, SVector::new_column([7.0, 5.0, 4.0]), epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_lu() {
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[0.0, 1.0], [2.0, 1.0]]);
+    let b: SVector<f64, 2> = SVector::new_column([1.0, 3.0]);
+
+    let x: SVector<f64, 2> = a.dec_lu().unwrap().solve(&b).unwrap();
+
+    assert_relative_eq!(x, SVector::new_column([1.0, 1.0]), epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_singular() {
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [2.0, 4.0]]);
+    let b: SVector<f64, 2> = SVector::new_column([1.0, 3.0]);
+
+    assert!(a.solve(&b).is_err());
+}
@@ -0,0 +1,27 @@
+use mathru::algebra::linear::matrix::SMatrix;
+
+#[test]
+fn sub() {
+    let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let b: SMatrix<f64, 2, 3> = SMatrix::from_rows([[-1.0, 0.5, 1.0], [2.0, -3.0, 0.0]]);
+
+    assert_eq!(
+        a - b,
+        SMatrix::from_rows([[2.0, 1.5, 2.0], [2.0, 8.0, 6.0]])
+    );
+}
+
+#[test]
+fn sub_scalar() {
+    let a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+
+    assert_eq!(a - 1.0, SMatrix::from_rows([[0.0, 1.0], [2.0, 3.0]]));
+}
+
+#[test]
+fn sub_assign() {
+    let mut a: SMatrix<f64, 2, 2> = SMatrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+    a -= SMatrix::one();
+
+    assert_eq!(a, SMatrix::from_rows([[0.0, 2.0], [3.0, 3.0]]));
+}
@@ -0,0 +1,26 @@
+use mathru::algebra::abstr::Complex;
+use mathru::algebra::linear::matrix::{ConjugateTranspose, SMatrix, Transpose};
+
+#[test]
+fn transpose() {
+    let a: SMatrix<f64, 2, 3> = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    let a_t: SMatrix<f64, 3, 2> = a.transpose();
+
+    assert_eq!(
+        a_t,
+        SMatrix::from_rows([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]])
+    );
+    assert_eq!(a_t.transpose(), a);
+}
+
+#[test]
+fn conj_transpose() {
+    let a: SMatrix<Complex<f64>, 1, 2> =
+        SMatrix::from_rows([[Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)]]);
+
+    assert_eq!(
+        a.conj_transpose(),
+        SMatrix::from_rows([[Complex::new(1.0, -2.0)], [Complex::new(3.0, 4.0)]])
+    );
+}
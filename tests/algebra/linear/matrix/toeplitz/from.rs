@@ -0,0 +1,39 @@
+use mathru::algebra::linear::matrix::{General, Toeplitz};
+
+#[test]
+fn from_toeplitz() {
+    let t: Toeplitz<f64> = Toeplitz::new(vec![1.0, 2.0, 3.0], vec![1.0, -1.0, -2.0]);
+    let t_ref: General<f64> = matrix![  1.0, -1.0, -2.0;
+                                        2.0, 1.0, -1.0;
+                                        3.0, 2.0, 1.0];
+
+    assert_eq!(General::from(t), t_ref);
+}
+
+#[test]
+fn from_symmetric() {
+    let t: Toeplitz<f64> = Toeplitz::new_symmetric(vec![2.0, 1.0, 0.5]);
+    let t_ref: General<f64> = matrix![  2.0, 1.0, 0.5;
+                                        1.0, 2.0, 1.0;
+                                        0.5, 1.0, 2.0];
+
+    assert_eq!(General::from(t), t_ref);
+}
+
+#[test]
+fn get() {
+    let t: Toeplitz<f64> = Toeplitz::new(vec![1.0, 2.0, 3.0, 4.0], vec![1.0, 5.0, 6.0, 7.0]);
+    let t_ref: General<f64> = General::from(t.clone());
+
+    for i in 0..4 {
+        for j in 0..4 {
+            assert_eq!(t.get(i, j), t_ref[[i, j]]);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn new_corner_mismatch() {
+    let _: Toeplitz<f64> = Toeplitz::new(vec![1.0, 2.0], vec![3.0, 4.0]);
+}
@@ -0,0 +1,12 @@
+use mathru::algebra::linear::matrix::{General, Toeplitz};
+use mathru::algebra::linear::vector::Vector;
+
+#[test]
+fn mul_vector() {
+    let t: Toeplitz<f64> = Toeplitz::new(vec![1.0, 2.0, 3.0, 4.0], vec![1.0, -1.0, 0.5, 2.0]);
+    let x: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5];
+
+    let t_g: General<f64> = General::from(t.clone());
+
+    assert_relative_eq!(&t * &x, &t_g * &x, epsilon = 1.0e-10);
+}
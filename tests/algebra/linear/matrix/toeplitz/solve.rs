@@ -0,0 +1,58 @@
+use mathru::algebra::linear::matrix::{General, Solve, Toeplitz};
+use mathru::algebra::linear::vector::Vector;
+
+#[test]
+fn solve_vector() {
+    let t: Toeplitz<f64> = Toeplitz::new(vec![5.0, 2.0, 1.0, 0.5, 0.25], vec![5.0, -1.0, 0.5, 1.0, -0.5]);
+    let x_ref: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5; -1.0];
+    let b: Vector<f64> = &t * &x_ref;
+
+    assert_relative_eq!(t.solve(&b).unwrap(), x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_symmetric() {
+    let t: Toeplitz<f64> = Toeplitz::new_symmetric(vec![4.0, 1.0, 0.5, 0.25]);
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0; 4.0];
+
+    let x: Vector<f64> = t.solve(&b).unwrap();
+    let x_ref: Vector<f64> = General::from(t).solve(&b).unwrap();
+
+    assert_relative_eq!(x, x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_dim_one() {
+    let t: Toeplitz<f64> = Toeplitz::new(vec![2.0], vec![2.0]);
+    let b: Vector<f64> = vector![3.0];
+
+    assert_relative_eq!(t.solve(&b).unwrap(), vector![1.5], epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_general() {
+    let t: Toeplitz<f64> = Toeplitz::new(vec![3.0, 1.0, 0.5], vec![3.0, -1.0, 0.25]);
+    let b: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.0;
+                                    3.0, 1.0];
+
+    let x: General<f64> = t.solve(&b).unwrap();
+
+    assert_relative_eq!(&General::from(t) * &x, b, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_singular_minor() {
+    let t: Toeplitz<f64> = Toeplitz::new(vec![0.0, 1.0], vec![0.0, 1.0]);
+    let b: Vector<f64> = vector![1.0; 2.0];
+
+    assert!(t.solve(&b).is_err());
+}
+
+#[test]
+fn solve_f32() {
+    let t: Toeplitz<f32> = Toeplitz::new(vec![4.0, 2.0, 1.0], vec![4.0, 1.0, 0.5]);
+    let b: Vector<f32> = vector![5.5; 7.0; 7.0];
+
+    assert_relative_eq!(t.solve(&b).unwrap(), vector![1.0; 1.0; 1.0], epsilon = 1.0e-5);
+}
@@ -0,0 +1,17 @@
+use mathru::algebra::linear::matrix::{Determinant, General, Tridiagonal};
+
+#[test]
+fn det() {
+    let a: Tridiagonal<f64> = Tridiagonal::new(vec![1.0, -2.0, 0.5], vec![3.0, 4.0, -1.0, 2.0], vec![2.0, 1.0, 3.0]);
+
+    let g: General<f64> = a.clone().into();
+
+    assert_relative_eq!(a.det(), g.det(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn det_one() {
+    let a: Tridiagonal<f64> = Tridiagonal::new(vec![], vec![-3.0], vec![]);
+
+    assert_eq!(a.det(), -3.0);
+}
@@ -0,0 +1,45 @@
+use mathru::algebra::linear::matrix::{Banded, General, Tridiagonal};
+
+#[test]
+fn from_general() {
+    let a: General<f64> = matrix![  1.0, 2.0, 9.0;
+                                    3.0, 4.0, 5.0;
+                                    9.0, 6.0, 7.0];
+
+    let t: Tridiagonal<f64> = Tridiagonal::from(&a);
+
+    assert_eq!(t.lower(), &[3.0, 6.0]);
+    assert_eq!(t.diag(), &[1.0, 4.0, 7.0]);
+    assert_eq!(t.upper(), &[2.0, 5.0]);
+    assert_eq!(t[[1, 2]], 5.0);
+    assert_eq!(t.get(0, 2), 0.0);
+}
+
+#[test]
+fn into_general() {
+    let t: Tridiagonal<f64> = Tridiagonal::new(vec![3.0, 6.0], vec![1.0, 4.0, 7.0], vec![2.0, 5.0]);
+
+    let a_ref: General<f64> = matrix![  1.0, 2.0, 0.0;
+                                        3.0, 4.0, 5.0;
+                                        0.0, 6.0, 7.0];
+
+    assert_eq!(General::from(t), a_ref);
+}
+
+#[test]
+fn into_banded() {
+    let t: Tridiagonal<f64> = Tridiagonal::new(vec![3.0, 6.0], vec![1.0, 4.0, 7.0], vec![2.0, 5.0]);
+
+    let b: Banded<f64> = Banded::from(t.clone());
+
+    assert_eq!(b.bandwidth(), (1, 1));
+    assert_eq!(General::from(b), General::from(t));
+}
+
+#[test]
+#[should_panic]
+fn index_outside_band() {
+    let t: Tridiagonal<f64> = Tridiagonal::new(vec![3.0, 6.0], vec![1.0, 4.0, 7.0], vec![2.0, 5.0]);
+
+    let _ = t[[0, 2]];
+}
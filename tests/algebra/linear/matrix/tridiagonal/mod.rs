@@ -0,0 +1,4 @@
+mod det;
+mod from;
+mod mul;
+mod solve;
@@ -0,0 +1,24 @@
+use mathru::algebra::linear::matrix::{General, Tridiagonal};
+use mathru::algebra::linear::vector::Vector;
+
+#[test]
+fn mul_vector() {
+    let t: Tridiagonal<f64> = Tridiagonal::new(vec![3.0, 6.0, -1.0], vec![1.0, 4.0, 7.0, 2.0], vec![2.0, 5.0, 0.5]);
+    let x: Vector<f64> = vector![1.0; -2.0; 3.0; 4.0];
+
+    let a: General<f64> = t.clone().into();
+
+    assert_eq!(&t * &x, &a * &x);
+}
+
+#[test]
+fn mul_general() {
+    let t: Tridiagonal<f64> = Tridiagonal::new(vec![3.0, 6.0], vec![1.0, 4.0, 7.0], vec![2.0, 5.0]);
+    let b: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.0;
+                                    3.0, 1.0];
+
+    let a: General<f64> = t.clone().into();
+
+    assert_eq!(&t * &b, &a * &b);
+}
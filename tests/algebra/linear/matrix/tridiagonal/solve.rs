@@ -0,0 +1,50 @@
+use mathru::algebra::linear::matrix::{General, Solve, Tridiagonal};
+use mathru::algebra::linear::vector::Vector;
+
+#[test]
+fn solve_vector() {
+    let t: Tridiagonal<f64> = Tridiagonal::new(vec![1.0; 4], vec![-2.0; 5], vec![1.0; 4]);
+    let b: Vector<f64> = vector![-1.0; -1.0; -1.0; -1.0; -1.0];
+
+    let x: Vector<f64> = t.solve(&b).unwrap();
+
+    assert_relative_eq!(x, vector![2.5; 4.0; 4.5; 4.0; 2.5], epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_pivoting() {
+    // zero diagonal entries require row interchanges
+    let t: Tridiagonal<f64> = Tridiagonal::new(vec![1.0, 2.0, 1.0, 3.0], vec![0.0, 0.0, 1.0, 0.0, 2.0], vec![1.0, 1.0, 2.0, 1.0]);
+    let x_ref: Vector<f64> = vector![1.0; -1.0; 2.0; 0.5; -3.0];
+    let b: Vector<f64> = &t * &x_ref;
+
+    assert_relative_eq!(t.solve(&b).unwrap(), x_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_general() {
+    let t: Tridiagonal<f64> = Tridiagonal::new(vec![3.0, 6.0], vec![1.0, 4.0, 7.0], vec![2.0, 5.0]);
+    let b: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.0;
+                                    3.0, 1.0];
+
+    let x: General<f64> = t.solve(&b).unwrap();
+
+    assert_relative_eq!(&t * &x, b, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_singular() {
+    let t: Tridiagonal<f64> = Tridiagonal::new(vec![1.0, 1.0], vec![1.0, 1.0, 1.0], vec![1.0, 0.0]);
+    let b: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    assert!(t.solve(&b).is_err());
+}
+
+#[test]
+fn solve_f32() {
+    let t: Tridiagonal<f32> = Tridiagonal::new(vec![-1.0, -1.0], vec![2.0, 2.0, 2.0], vec![-1.0, -1.0]);
+    let b: Vector<f32> = vector![1.0; 0.0; 1.0];
+
+    assert_relative_eq!(t.solve(&b).unwrap(), vector![1.0; 1.0; 1.0], epsilon = 1.0e-5);
+}
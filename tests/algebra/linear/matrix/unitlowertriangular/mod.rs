@@ -2,6 +2,7 @@ mod det;
 mod eigendec;
 mod index;
 mod mul;
+mod norm;
 mod partial_eq;
 mod substitute_forward;
 mod transpose;
@@ -0,0 +1,14 @@
+use mathru::algebra::linear::matrix::{General, MatrixNorm, UnitLowerTriangular};
+
+#[test]
+fn norm() {
+    let l: UnitLowerTriangular<f64> = UnitLowerTriangular::new(matrix![ 7.0, 5.0;
+                                                                        -2.0, 7.0]);
+    let a: General<f64> = matrix![  1.0, 0.0;
+                                    -2.0, 1.0];
+
+    assert_relative_eq!(l.norm_1(), 3.0);
+    assert_relative_eq!(l.norm_inf(), 3.0);
+    assert_relative_eq!(l.norm_frobenius(), 6.0f64.sqrt());
+    assert_relative_eq!(l.norm_2(), a.norm_2(), epsilon = 1.0e-10);
+}
@@ -2,6 +2,7 @@ mod det;
 mod eigendec;
 mod index;
 mod mul;
+mod norm;
 mod substitute_backward;
 mod transpose;
 mod unituppertriangular;
@@ -0,0 +1,14 @@
+use mathru::algebra::linear::matrix::{General, MatrixNorm, UnitUpperTriangular};
+
+#[test]
+fn norm() {
+    let u: UnitUpperTriangular<f64> = UnitUpperTriangular::new(matrix![ 0.0, 3.0;
+                                                                        8.0, 0.0]);
+    let a: General<f64> = matrix![  1.0, 3.0;
+                                    0.0, 1.0];
+
+    assert_relative_eq!(u.norm_1(), 4.0);
+    assert_relative_eq!(u.norm_inf(), 4.0);
+    assert_relative_eq!(u.norm_frobenius(), 11.0f64.sqrt());
+    assert_relative_eq!(u.norm_2(), a.norm_2(), epsilon = 1.0e-10);
+}
@@ -1,4 +1,5 @@
-use mathru::algebra::linear::matrix::{General, SchurDecomposition, UpperHessenberg};
+use mathru::algebra::linear::matrix::{General, SchurDecomposition, Transpose, UpperHessenberg};
+use mathru::algebra::linear::LinalgError;
 
 #[test]
 fn schurdec() {
@@ -17,8 +18,138 @@ fn schurdec() {
                                     0.0, 0.0, 0.0, 0.0, 3.9995, 4.9227;
                                     0.0, 0.0, 0.0, 0.0, 0.0, 3.0004];
 
-    let (_q, u) = h.dec_schur().unwrap().qu();
+    let (q, u) = h.clone().dec_schur().unwrap().qu();
 
-    assert_abs_diff_eq!(u, u_ref.into(), epsilon = 1.0e-4);
-    //assert_abs_diff_eq!(Into::<General<f64>>::into(h), &(&q * &Into::<General<f64>>::into(u)) * &q.transpose() , epsilon = 1.0e-4);
+    assert_abs_diff_eq!(u.clone(), u_ref.into(), epsilon = 1.0e-4);
+    assert_abs_diff_eq!(Into::<General<f64>>::into(h), &(&q * &Into::<General<f64>>::into(u)) * &q.transpose(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn schurdec_2x2_real_eigenvalues() {
+    let h: UpperHessenberg<f64> = matrix![1.0, 2.0; 3.0, 4.0].into();
+
+    let (q, u) = h.clone().dec_schur().unwrap().qu();
+    let u: General<f64> = u.into();
+
+    assert_eq!(u[[1, 0]], 0.0);
+    assert_abs_diff_eq!(&q.clone().transpose() * &q, General::one(2), epsilon = 1.0e-10);
+    assert_abs_diff_eq!(Into::<General<f64>>::into(h), &(&q * &u) * &q.transpose(), epsilon = 1.0e-10);
+}
+
+fn schur_test_matrix() -> UpperHessenberg<f64> {
+    matrix![7.0000, 7.2761, 5.8120, -0.1397,9.0152, 7.9363;
+            12.3693, 4.1307, 18.9685, -1.2071, 10.6833, 2.4160;
+            0.0, -7.1603, 2.4478, -0.5656, -4.1814, -3.2510;
+            0.0, 0.0, -8.5988, 2.9151, -3.4169, 5.7230;
+            0.0, 0.0, 0.0, 1.0464, -2.8351, -10.9792;
+            0.0, 0.0, 0.0, 0.0, 1.4143, 5.3415]
+    .into()
+}
+
+fn assert_reordered(h: &UpperHessenberg<f64>, q: &General<f64>, u: &General<f64>) {
+    assert_abs_diff_eq!(&q.clone().transpose() * q, General::one(6), epsilon = 1.0e-10);
+    assert_abs_diff_eq!(Into::<General<f64>>::into(h.clone()), &(q * u) * &q.clone().transpose(), epsilon = 1.0e-10);
+    for j in 0..6 {
+        for i in j + 2..6 {
+            assert_eq!(u[[i, j]], 0.0);
+        }
+    }
+}
+
+#[test]
+fn reorder_real_eigenvalues_first() {
+    let h: UpperHessenberg<f64> = schur_test_matrix();
+
+    let (q, u) = h.dec_schur().unwrap().reorder(&[false, false, false, false, true, true]).unwrap().qu();
+    let u: General<f64> = u.into();
+
+    assert_reordered(&h, &q, &u);
+    assert_abs_diff_eq!(u[[0, 0]], 4.0, epsilon = 1.0e-3);
+    assert_abs_diff_eq!(u[[1, 1]], 3.0, epsilon = 1.0e-3);
+    assert_eq!(u[[1, 0]], 0.0);
+    assert_eq!(u[[2, 1]], 0.0);
+    assert_eq!(u[[4, 3]], 0.0);
+    // 5 ± 6i and 1 ± 2i
+    assert_abs_diff_eq!(u[[2, 2]] + u[[3, 3]], 10.0, epsilon = 1.0e-3);
+    assert_abs_diff_eq!(u[[2, 2]] * u[[3, 3]] - u[[2, 3]] * u[[3, 2]], 61.0, epsilon = 1.0e-2);
+    assert_abs_diff_eq!(u[[4, 4]] + u[[5, 5]], 2.0, epsilon = 1.0e-3);
+    assert_abs_diff_eq!(u[[4, 4]] * u[[5, 5]] - u[[4, 5]] * u[[5, 4]], 5.0, epsilon = 1.0e-2);
+}
+
+#[test]
+fn reorder_swap_complex_blocks() {
+    let h: UpperHessenberg<f64> = schur_test_matrix();
+
+    let (q, u) = h.dec_schur().unwrap().reorder(&[false, false, true, false, false, false]).unwrap().qu();
+    let u: General<f64> = u.into();
+
+    assert_reordered(&h, &q, &u);
+    assert_eq!(u[[2, 1]], 0.0);
+    assert_eq!(u[[4, 3]], 0.0);
+    assert_abs_diff_eq!(u[[0, 0]] + u[[1, 1]], 2.0, epsilon = 1.0e-3);
+    assert_abs_diff_eq!(u[[0, 0]] * u[[1, 1]] - u[[0, 1]] * u[[1, 0]], 5.0, epsilon = 1.0e-2);
+    assert_abs_diff_eq!(u[[2, 2]] + u[[3, 3]], 10.0, epsilon = 1.0e-3);
+    assert_abs_diff_eq!(u[[4, 4]], 4.0, epsilon = 1.0e-3);
+    assert_abs_diff_eq!(u[[5, 5]], 3.0, epsilon = 1.0e-3);
+}
+
+#[test]
+fn reorder_real_block_past_complex_block() {
+    let h: UpperHessenberg<f64> = schur_test_matrix();
+
+    let (q, u) = h.dec_schur().unwrap().reorder(&[false, false, false, false, false, true]).unwrap().qu();
+    let u: General<f64> = u.into();
+
+    assert_reordered(&h, &q, &u);
+    assert_abs_diff_eq!(u[[0, 0]], 3.0, epsilon = 1.0e-3);
+    assert_eq!(u[[1, 0]], 0.0);
+    assert_abs_diff_eq!(u[[1, 1]] + u[[2, 2]], 10.0, epsilon = 1.0e-3);
+    assert_abs_diff_eq!(u[[3, 3]] + u[[4, 4]], 2.0, epsilon = 1.0e-3);
+    assert_abs_diff_eq!(u[[5, 5]], 4.0, epsilon = 1.0e-3);
+}
+
+/// Two 2 x 2 blocks with the eigenvalues 1 ± √2 i and 1 ± √(2 + 2δ) i
+fn close_blocks(delta: f64) -> UpperHessenberg<f64> {
+    matrix![1.0, 2.0, 1.0, 0.5;
+            -1.0, 1.0, 0.3, 1.0;
+            0.0, 0.0, 1.0, 2.0;
+            0.0, 0.0, -1.0 - delta, 1.0]
+    .into()
+}
+
+#[test]
+fn reorder_close_complex_blocks() {
+    let delta: f64 = 1.0e-6;
+    let h: UpperHessenberg<f64> = close_blocks(delta);
+
+    let (q, u) = h.dec_schur().unwrap().reorder(&[false, false, true, true]).unwrap().qu();
+    let u: General<f64> = u.into();
+
+    assert_abs_diff_eq!(&q.clone().transpose() * &q, General::one(4), epsilon = 1.0e-10);
+    assert_abs_diff_eq!(Into::<General<f64>>::into(h), &(&q * &u) * &q.transpose(), epsilon = 1.0e-10);
+    assert_eq!(u[[2, 0]], 0.0);
+    assert_eq!(u[[2, 1]], 0.0);
+    assert_eq!(u[[3, 0]], 0.0);
+    assert_eq!(u[[3, 1]], 0.0);
+    // The leading block holds the eigenvalues 1 ± √(2 + 2δ) i
+    assert_abs_diff_eq!(u[[0, 0]] + u[[1, 1]], 2.0, epsilon = 1.0e-10);
+    assert_abs_diff_eq!(u[[0, 0]] * u[[1, 1]] - u[[0, 1]] * u[[1, 0]], 3.0 + 2.0 * delta, epsilon = 1.0e-9);
+    assert_abs_diff_eq!(u[[2, 2]] * u[[3, 3]] - u[[2, 3]] * u[[3, 2]], 3.0, epsilon = 1.0e-9);
+}
+
+#[test]
+fn reorder_equal_complex_blocks() {
+    let h: UpperHessenberg<f64> = close_blocks(0.0);
+
+    let result = h.dec_schur().unwrap().reorder(&[false, false, true, true]);
+
+    assert_eq!(result.err(), Some(LinalgError::IllConditioned));
+}
+
+#[test]
+#[should_panic]
+fn reorder_select_dimension_mismatch() {
+    let h: UpperHessenberg<f64> = schur_test_matrix();
+
+    let _ = h.dec_schur().unwrap().reorder(&[true; 5]);
 }
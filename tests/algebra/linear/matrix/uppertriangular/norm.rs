@@ -0,0 +1,23 @@
+use mathru::algebra::linear::matrix::{General, MatrixNorm, UpperTriangular};
+
+#[test]
+fn norm() {
+    let a: General<f64> = matrix![  1.0, -2.0, 3.0;
+                                    0.0, 4.0, 1.0;
+                                    0.0, 0.0, -5.0];
+    let u: UpperTriangular<f64> = UpperTriangular::new(a.clone());
+
+    assert_relative_eq!(u.norm_1(), a.norm_1());
+    assert_relative_eq!(u.norm_inf(), a.norm_inf());
+    assert_relative_eq!(u.norm_frobenius(), a.norm_frobenius());
+    assert_relative_eq!(u.norm_2(), a.norm_2(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn norm_ignores_lower_triangle() {
+    let u: UpperTriangular<f64> = UpperTriangular::new(matrix![ 1.0, 2.0;
+                                                                100.0, 3.0]);
+
+    assert_relative_eq!(u.norm_1(), 5.0);
+    assert_relative_eq!(u.norm_inf(), 3.0);
+}
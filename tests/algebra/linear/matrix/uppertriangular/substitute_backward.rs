@@ -2,6 +2,7 @@ use mathru::algebra::linear::matrix::General;
 use mathru::algebra::linear::matrix::SubstituteBackward;
 use mathru::algebra::linear::matrix::UpperTriangular;
 use mathru::algebra::linear::vector::Vector;
+use mathru::algebra::linear::LinalgError;
 
 #[test]
 fn subst_backward() {
@@ -52,9 +53,9 @@ fn subst_backward_error() {
                                 7.0; 
                                 9.0];
 
-    let x: Result<Vector<f64>, ()> = a.substitute_backward(b);
+    let x: Result<Vector<f64>, LinalgError> = a.substitute_backward(b);
 
-    assert_eq!(x, Result::Err(()));
+    assert_eq!(x, Result::Err(LinalgError::Singular));
 }
 
 #[cfg(feature = "native")]
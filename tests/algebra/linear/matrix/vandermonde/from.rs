@@ -0,0 +1,23 @@
+use mathru::algebra::linear::matrix::{General, Vandermonde};
+
+#[test]
+fn from_vandermonde() {
+    let v: Vandermonde<f64> = Vandermonde::new(vec![2.0, -1.0, 0.5]);
+    let v_ref: General<f64> = matrix![  1.0, 2.0, 4.0;
+                                        1.0, -1.0, 1.0;
+                                        1.0, 0.5, 0.25];
+
+    assert_eq!(General::from(v), v_ref);
+}
+
+#[test]
+fn get() {
+    let v: Vandermonde<f64> = Vandermonde::new(vec![2.0, -1.0, 0.5, 3.0]);
+    let v_ref: General<f64> = General::from(v.clone());
+
+    for i in 0..4 {
+        for j in 0..4 {
+            assert_eq!(v.get(i, j), v_ref[[i, j]]);
+        }
+    }
+}
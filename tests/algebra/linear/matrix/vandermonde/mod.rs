@@ -0,0 +1,3 @@
+mod from;
+mod mul;
+mod solve;
@@ -0,0 +1,12 @@
+use mathru::algebra::linear::matrix::{General, Vandermonde};
+use mathru::algebra::linear::vector::Vector;
+
+#[test]
+fn mul_vector() {
+    let v: Vandermonde<f64> = Vandermonde::new(vec![2.0, -1.0, 0.5, 3.0]);
+    let a: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5];
+
+    let v_g: General<f64> = General::from(v.clone());
+
+    assert_relative_eq!(&v * &a, &v_g * &a, epsilon = 1.0e-10);
+}
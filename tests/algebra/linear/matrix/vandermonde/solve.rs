@@ -0,0 +1,47 @@
+use mathru::algebra::linear::matrix::{General, Solve, Vandermonde};
+use mathru::algebra::linear::vector::Vector;
+
+#[test]
+fn solve_vector() {
+    let v: Vandermonde<f64> = Vandermonde::new(vec![-1.0, 0.0, 0.5, 2.0, 3.0]);
+    let a_ref: Vector<f64> = vector![1.0; -2.0; 3.0; 0.5; -1.0];
+    let f: Vector<f64> = &v * &a_ref;
+
+    assert_relative_eq!(v.solve(&f).unwrap(), a_ref, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_dim_one() {
+    let v: Vandermonde<f64> = Vandermonde::new(vec![4.0]);
+    let f: Vector<f64> = vector![3.0];
+
+    assert_relative_eq!(v.solve(&f).unwrap(), vector![3.0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_general() {
+    let v: Vandermonde<f64> = Vandermonde::new(vec![1.0, 2.0, 4.0]);
+    let f: General<f64> = matrix![  1.0, 2.0;
+                                    -1.0, 0.0;
+                                    3.0, 1.0];
+
+    let a: General<f64> = v.solve(&f).unwrap();
+
+    assert_relative_eq!(&General::from(v) * &a, f, epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_duplicate_nodes() {
+    let v: Vandermonde<f64> = Vandermonde::new(vec![1.0, 2.0, 1.0]);
+    let f: Vector<f64> = vector![1.0; 2.0; 3.0];
+
+    assert!(v.solve(&f).is_err());
+}
+
+#[test]
+fn solve_f32() {
+    let v: Vandermonde<f32> = Vandermonde::new(vec![1.0, 2.0, 3.0]);
+    let f: Vector<f32> = vector![1.0; 3.0; 7.0];
+
+    assert_relative_eq!(v.solve(&f).unwrap(), vector![1.0; -1.0; 1.0], epsilon = 1.0e-5);
+}
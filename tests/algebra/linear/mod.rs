@@ -5,3 +5,6 @@
 mod vector;
 #[macro_use]
 mod matrix;
+mod equations;
+mod error;
+mod iterative;
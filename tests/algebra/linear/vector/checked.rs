@@ -0,0 +1,58 @@
+use mathru::algebra::linear::{
+    matrix::{CheckedAdd, CheckedMul, CheckedSub, General},
+    vector::Vector,
+    LinalgError,
+};
+
+#[test]
+fn checked_add() {
+    let a: Vector<f64> = vector![1.0; 2.0; 3.0];
+    let b: Vector<f64> = vector![-1.0; 0.5; 2.0];
+
+    assert_eq!(a.checked_add(&b), Ok(vector![0.0; 2.5; 5.0]));
+    assert_eq!(
+        a.checked_add(&vector![1.0, 2.0, 3.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn checked_sub() {
+    let a: Vector<f64> = vector![1.0; 2.0; 3.0];
+    let b: Vector<f64> = vector![-1.0; 0.5; 2.0];
+
+    assert_eq!(a.checked_sub(&b), Ok(vector![2.0; 1.5; 1.0]));
+    assert_eq!(
+        a.checked_sub(&vector![1.0; 2.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn checked_mul_matrix() {
+    let x: Vector<f64> = vector![1.0, -1.0];
+    let a: General<f64> = matrix![  1.0, 2.0, 5.0;
+                                    3.0, 4.0, 6.0];
+
+    assert_eq!(x.checked_mul(&a), Ok(vector![-2.0, -2.0, -1.0]));
+    assert_eq!(
+        vector![1.0, 2.0, 3.0].checked_mul(&a),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn checked_dotp() {
+    let a: Vector<f64> = vector![1.0; 0.0; 3.0];
+    let b: Vector<f64> = vector![-1.0; 2.0; 3.0];
+
+    assert_eq!(a.checked_dotp(&b), Ok(8.0));
+    assert_eq!(
+        a.checked_dotp(&vector![1.0; 2.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+    assert_eq!(
+        vector![1.0, 2.0, 3.0].checked_dotp(&vector![1.0, 2.0, 3.0]),
+        Err(LinalgError::DimensionMismatch)
+    );
+}
@@ -1,5 +1,6 @@
 mod add;
 mod add_assign;
+mod checked;
 mod index;
 mod mul;
 mod mul_assign;
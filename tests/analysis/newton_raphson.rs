@@ -1,4 +1,4 @@
-use mathru::algebra::linear::{matrix::General, vector::Vector};
+use mathru::algebra::linear::{matrix::General, vector::Vector, LinalgError};
 use mathru::analysis::NewtonRaphson;
 use mathru::analysis::{Function, Jacobian};
 
@@ -53,3 +53,26 @@ fn find_root_default() {
 
     assert_relative_eq!(root, root_hat, epsilon = 10e-3);
 }
+
+#[test]
+fn find_root_no_convergence() {
+    let nr: NewtonRaphson<f64> = NewtonRaphson::new(2, 10e-12);
+    let x_0: Vector<f64> = vector![1.0];
+
+    let problem: Func1 = Func1::new();
+
+    assert_eq!(
+        nr.find_root(&problem, &x_0),
+        Err(LinalgError::NoConvergence { iters: 2 })
+    );
+}
+
+#[test]
+fn find_root_singular_jacobian() {
+    let nr: NewtonRaphson<f64> = NewtonRaphson::default();
+    let x_0: Vector<f64> = vector![0.0];
+
+    let problem: Func1 = Func1::new();
+
+    assert_eq!(nr.find_root(&problem, &x_0), Err(LinalgError::Singular));
+}